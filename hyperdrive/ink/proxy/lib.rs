@@ -15,15 +15,16 @@ mod proxy {
 			string::{String, ToString},
 			vec::Vec,
 		},
-		storage::Mapping,
+		storage::{Lazy, Mapping},
 	};
 
 	use scale::{Decode, Encode};
 
 	use acurast_hyperdrive_substrate_core::types::{
-		IncomingAction, IncomingActionPayloadV1, JobRegistrationV1, OutgoingActionPayloadV1,
-		RegisterJobPayloadV1, ScheduleV1, SetJobEnvironmentPayloadV1, SetProcessorJobEnvironmentV1,
-		Version, VersionedIncomingActionPayload,
+		ExecutionResultV1, IncomingAction, IncomingActionPayloadV1, JobRegistrationV1,
+		OutgoingActionPayloadV1, RegisterJobOptionsV1, RegisterJobPayloadV1, ScheduleV1,
+		SetJobEnvironmentPayloadV1, SetProcessorJobEnvironmentV1, Version,
+		VersionedIncomingActionPayload,
 	};
 
 	pub type OuterError<T> = Result<Result<T, ink::LangError>, ink::env::Error>;
@@ -59,6 +60,8 @@ mod proxy {
 		FinalizeJob(Vec<u128>),
 		SetJobEnvironment(SetJobEnvironmentUserInput),
 		Noop,
+		/// Registers a job with options, e.g. to receive its execution results in [JobExecutionResultReceived] events.
+		RegisterJobWithOptions(RegisterJobUserInput, RegisterJobOptionsV1),
 	}
 
 	#[derive(Clone, Eq, PartialEq, Encode, Decode)]
@@ -142,6 +145,7 @@ mod proxy {
 		MaxMessageBytes(u16),
 		ExchangeRatio(ExchangeRatio),
 		Code(Hash),
+		ResultDeliveryFee(u128),
 	}
 
 	#[ink(event)]
@@ -149,6 +153,13 @@ mod proxy {
 		action_id: u64,
 	}
 
+	#[ink(event)]
+	pub struct JobExecutionResultReceived {
+		#[ink(topic)]
+		job_id: u128,
+		result: ExecutionResultV1,
+	}
+
 	/// Errors returned by the contract's methods.
 	#[derive(scale_info::TypeInfo, Debug, PartialEq, Eq, Encode, Decode)]
 	pub enum Error {
@@ -216,6 +227,10 @@ mod proxy {
 		next_outgoing_action_id: u64,
 		next_job_id: u128,
 		job_info: Mapping<u128, (u16, Vec<u8>)>,
+		/// The fee in ACU charged on Acurast for every execution result delivered to this contract.
+		///
+		/// Kept outside of [Config] to not change the layout of the existing storage.
+		result_delivery_fee: Lazy<u128>,
 	}
 
 	impl Proxy {
@@ -252,6 +267,7 @@ mod proxy {
 				next_outgoing_action_id: 1,
 				next_job_id: 1,
 				job_info: Mapping::new(),
+				result_delivery_fee: Lazy::new(),
 			}
 		}
 
@@ -282,6 +298,64 @@ mod proxy {
 			}
 		}
 
+		/// Stores the information of a newly registered job and returns the payload registering it on Acurast.
+		///
+		/// `result_delivery_fee` is the fee charged on Acurast for every result delivered back to this contract, zero if results are not delivered.
+		fn register_job(
+			&mut self,
+			payload: RegisterJobUserInput,
+			result_delivery_fee: u128,
+		) -> Result<RegisterJobPayloadV1, Error> {
+			// Increment job identifier
+			let job_id = self.next_job_id;
+			self.next_job_id += 1;
+
+			// Calculate the number of executions that fit the job schedule
+			let start_time = payload.job_registration.schedule.start_time;
+			let end_time = payload.job_registration.schedule.end_time;
+			let interval = payload.job_registration.schedule.interval;
+			if interval == 0 {
+				return Err(Error::Verbose("INTERVAL_CANNNOT_BE_ZERO".to_string()))
+			}
+			let execution_count = ((end_time - start_time - 1) / interval) + 1;
+
+			// Calculate the fee required for all job executions
+			let slots = payload.job_registration.extra.slots;
+			let expected_fulfillment_fee = payload.expected_fulfillment_fee;
+			let expected_fee =
+				((slots as u128) * execution_count as u128) * expected_fulfillment_fee;
+
+			// Calculate the total reward required to pay all executions, including the fees for delivering their results
+			let reward_per_execution = payload.job_registration.extra.reward;
+			let maximum_reward = (slots as u128) *
+				(execution_count as u128) *
+				(reward_per_execution + result_delivery_fee);
+
+			// Get exchange price
+			let cost: u128 = self.config.exchange_ratio.exchange_price(maximum_reward);
+
+			// Validate job registration payment
+			if self.env().transferred_value() < expected_fee + cost {
+				return Err(Error::Verbose("AMOUNT_CANNOT_COVER_JOB_COSTS".to_string()))
+			}
+
+			let info = JobInformationV1 {
+				status: JobStatus::Open,
+				creator: self.env().caller(),
+				destination: payload.destination,
+				processors: Vec::new(),
+				expected_fulfillment_fee,
+				remaining_fee: expected_fee,
+				maximum_reward,
+				slots,
+				schedule: payload.job_registration.schedule,
+			};
+
+			self.job_info.insert(job_id, &(Version::V1 as u16, info.encode()));
+
+			Ok(RegisterJobPayloadV1 { job_id, job_registration: payload.job_registration })
+		}
+
 		/// Modifies the code which is used to execute calls to this contract.
 		pub fn set_code(&mut self, code_hash: Hash) {
 			ink::env::set_code_hash::<DefaultEnvironment>(&code_hash).unwrap_or_else(|err| {
@@ -308,6 +382,7 @@ mod proxy {
 
 					ConfigureArgument::ExchangeRatio(ratio) => self.config.exchange_ratio = ratio,
 					ConfigureArgument::Code(code_hash) => self.set_code(code_hash),
+					ConfigureArgument::ResultDeliveryFee(fee) => self.result_delivery_fee.set(&fee),
 				}
 			}
 
@@ -330,57 +405,7 @@ mod proxy {
 			for action in actions {
 				let outgoing_action = match action {
 					UserAction::RegisterJob(payload) => {
-						// Increment job identifier
-						let job_id = self.next_job_id;
-						self.next_job_id += 1;
-
-						// Calculate the number of executions that fit the job schedule
-						let start_time = payload.job_registration.schedule.start_time;
-						let end_time = payload.job_registration.schedule.end_time;
-						let interval = payload.job_registration.schedule.interval;
-						if interval == 0 {
-							return Err(Error::Verbose("INTERVAL_CANNNOT_BE_ZERO".to_string()))
-						}
-						let execution_count = ((end_time - start_time - 1) / interval) + 1;
-
-						// Calculate the fee required for all job executions
-						let slots = payload.job_registration.extra.slots;
-						let expected_fulfillment_fee = payload.expected_fulfillment_fee;
-						let expected_fee =
-							((slots as u128) * execution_count as u128) * expected_fulfillment_fee;
-
-						// Calculate the total reward required to pay all executions
-						let reward_per_execution = payload.job_registration.extra.reward;
-						let maximum_reward =
-							(slots as u128) * (execution_count as u128) * reward_per_execution;
-
-						// Get exchange price
-						let cost: u128 = self.config.exchange_ratio.exchange_price(maximum_reward);
-
-						// Validate job registration payment
-						if self.env().transferred_value() < expected_fee + cost {
-							return Err(Error::Verbose("AMOUNT_CANNOT_COVER_JOB_COSTS".to_string()))
-						}
-
-						let info = JobInformationV1 {
-							status: JobStatus::Open,
-							creator: self.env().caller(),
-							destination: payload.destination,
-							processors: Vec::new(),
-							expected_fulfillment_fee,
-							remaining_fee: expected_fee,
-							maximum_reward,
-							slots,
-							schedule: payload.job_registration.schedule,
-						};
-
-						self.job_info
-							.insert(job_id, &(Version::V1 as u16, info.encode()));
-
-						OutgoingActionPayloadV1::RegisterJob(RegisterJobPayloadV1 {
-							job_id,
-							job_registration: payload.job_registration,
-						})
+						OutgoingActionPayloadV1::RegisterJob(self.register_job(payload, 0)?)
 					},
 					UserAction::DeregisterJob(job_id) => {
 						match JobInformation::decode(self, job_id)? {
@@ -437,6 +462,17 @@ mod proxy {
 						})
 					},
 					UserAction::Noop => OutgoingActionPayloadV1::Noop,
+					UserAction::RegisterJobWithOptions(payload, options) => {
+						let result_delivery_fee = if options.deliver_results_to_origin {
+							self.result_delivery_fee()
+						} else {
+							0
+						};
+						OutgoingActionPayloadV1::RegisterJobWithOptions(
+							self.register_job(payload, result_delivery_fee)?,
+							options,
+						)
+					},
 				};
 
 				let action = RawOutgoingAction {
@@ -564,6 +600,17 @@ mod proxy {
 					// Intentionally do nothing
 					Ok(())
 				},
+				VersionedIncomingActionPayload::V1(IncomingActionPayloadV1::ExecutionResult(
+					payload,
+				)) => {
+					// Results are only relayed for jobs that opted in, consumers pick them up from the event
+					Self::env().emit_event(JobExecutionResultReceived {
+						job_id: payload.job_id,
+						result: payload.result,
+					});
+
+					Ok(())
+				},
 			}?;
 
 			// Emit event informing that a given incoming message has been processed
@@ -647,6 +694,11 @@ mod proxy {
 		pub fn next_job_id(&self) -> u128 {
 			self.next_job_id
 		}
+
+		#[ink(message)]
+		pub fn result_delivery_fee(&self) -> u128 {
+			self.result_delivery_fee.get().unwrap_or_default()
+		}
 	}
 
 	#[cfg(test)]
@@ -655,6 +707,67 @@ mod proxy {
 
 		/// Imports all the definitions from the outer scope so we can use them here.
 		use super::*;
+		use acurast_hyperdrive_substrate_core::types::{AssignmentStrategyV1, JobRequirementsV1};
+
+		#[ink::test]
+		fn test_register_job_with_options() {
+			let accounts = ink::env::test::default_accounts::<DefaultEnvironment>();
+			ink::env::test::set_caller::<DefaultEnvironment>(accounts.alice);
+			let mut proxy = Proxy::new(accounts.alice, accounts.bob);
+			assert_eq!(proxy.configure(vec![ConfigureArgument::ResultDeliveryFee(5)]), Ok(()));
+
+			let input = RegisterJobUserInput {
+				job_registration: JobRegistrationV1 {
+					script: b"ipfs://QmUJ8qm2sMb3kdqbMf6zyzuSPHtfESbG9ZPVzYTiX3Xd2X".to_vec(),
+					allowed_sources: None,
+					allow_only_verified_sources: false,
+					schedule: ScheduleV1 {
+						duration: 5000,
+						start_time: 1_671_800_400_000,
+						end_time: 1_671_804_000_000,
+						interval: 1_800_000,
+						max_start_delay: 0,
+					},
+					memory: 5000,
+					network_requests: 5,
+					storage: 20000,
+					required_modules: Vec::new(),
+					extra: JobRequirementsV1 {
+						assignment_strategy: AssignmentStrategyV1::Competing,
+						slots: 2,
+						reward: 1000,
+						min_reputation: None,
+					},
+				},
+				destination: accounts.charlie,
+				expected_fulfillment_fee: 0,
+			};
+			// 2 slots, 2 executions, each with the reward and the result delivery fee
+			let maximum_reward = 2 * 2 * (1000 + 5);
+			ink::env::test::set_value_transferred::<DefaultEnvironment>(
+				proxy.config.exchange_ratio.exchange_price(maximum_reward) - 1,
+			);
+			assert!(matches!(
+				proxy.register_job(input.clone(), proxy.result_delivery_fee()),
+				Err(Error::Verbose(_))
+			));
+
+			ink::env::test::set_value_transferred::<DefaultEnvironment>(
+				proxy.config.exchange_ratio.exchange_price(maximum_reward),
+			);
+			let payload = proxy.register_job(input, proxy.result_delivery_fee()).unwrap();
+			match proxy.job(payload.job_id).unwrap() {
+				JobInformation::V1(job) => assert_eq!(job.maximum_reward, maximum_reward),
+			}
+
+			let encoded = OutgoingActionPayloadV1::RegisterJobWithOptions(
+				payload,
+				RegisterJobOptionsV1 { deliver_results_to_origin: true },
+			)
+			.encode();
+			assert_eq!(encoded.first(), Some(&5));
+			assert_eq!(encoded.last(), Some(&1));
+		}
 
 		#[ink::test]
 		fn test_action_encoding() {
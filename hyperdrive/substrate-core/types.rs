@@ -37,6 +37,7 @@ pub enum IncomingActionPayloadV1 {
 	AssignJobProcessor(AssignProcessorPayloadV1),
 	FinalizeJob(FinalizeJobPayloadV1),
 	Noop,
+	ExecutionResult(ExecutionResultPayloadV1),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
@@ -51,6 +52,20 @@ pub struct FinalizeJobPayloadV1 {
 	pub unused_reward: u128,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct ExecutionResultPayloadV1 {
+	pub job_id: u128,
+	pub result: ExecutionResultV1,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub enum ExecutionResultV1 {
+	/// Success with operation hash.
+	Success(Vec<u8>),
+	/// Failure with message.
+	Failure(Vec<u8>),
+}
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
 pub struct RawOutgoingAction {
 	pub id: u64,
@@ -107,6 +122,7 @@ pub enum OutgoingActionPayloadV1 {
 	FinalizeJob(Vec<u128>),
 	SetJobEnvironment(SetJobEnvironmentPayloadV1),
 	Noop,
+	RegisterJobWithOptions(RegisterJobPayloadV1, RegisterJobOptionsV1),
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
@@ -121,6 +137,13 @@ pub struct RegisterJobPayloadV1 {
 	pub job_registration: JobRegistrationV1,
 }
 
+/// Options of a job registration not covered by [`JobRegistrationV1`].
+#[derive(Clone, Default, Eq, PartialEq, Encode, Decode, TypeInfo)]
+pub struct RegisterJobOptionsV1 {
+	/// If the results reported for the job are sent back to the proxy.
+	pub deliver_results_to_origin: bool,
+}
+
 #[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
 pub struct JobRegistrationV1 {
 	pub script: Vec<u8>,
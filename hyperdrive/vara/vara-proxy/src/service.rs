@@ -45,65 +45,76 @@ impl VaraProxyService {
 		}
 	}
 
+	fn register_job(
+		payload: RegisterJobUserInput,
+		result_delivery_fee: u128,
+	) -> Result<RegisterJobPayloadV1, ProxyError> {
+		// Increment job identifier
+		let job_id = Storage::get_and_increase_next_job_id();
+
+		// Calculate the number of executions that fit the job schedule
+		let start_time = payload.job_registration.schedule.start_time;
+		let end_time = payload.job_registration.schedule.end_time;
+		let interval = payload.job_registration.schedule.interval;
+		if interval == 0 {
+			return Err(ProxyError::Verbose("INTERVAL_CANNNOT_BE_ZERO".to_string()));
+		}
+		let execution_count = ((end_time - start_time - 1) / interval) + 1;
+
+		// Calculate the fee required for all job executions
+		let slots = payload.job_registration.extra.slots;
+		let expected_fulfillment_fee = payload.expected_fulfillment_fee;
+		let expected_fee = ((slots as u128) * execution_count as u128) * expected_fulfillment_fee;
+
+		// Calculate the total reward required to pay all executions
+		let reward_per_execution = payload.job_registration.extra.reward + result_delivery_fee;
+		let maximum_reward = (slots as u128) * (execution_count as u128) * reward_per_execution;
+
+		// Get exchange price
+		let cost: u128 = Storage::config().exchange_ratio.exchange_price(maximum_reward);
+
+		// Validate job registration payment
+		let amount = msg::value();
+		if amount < expected_fee + cost {
+			return Err(ProxyError::Verbose("AMOUNT_CANNOT_COVER_JOB_COSTS".to_string()));
+		}
+
+		let info = JobInformationV1 {
+			status: JobStatus::Open,
+			creator: msg::source(),
+			destination: payload.destination,
+			processors: Vec::new(),
+			expected_fulfillment_fee,
+			remaining_fee: expected_fee,
+			maximum_reward,
+			slots,
+			schedule: payload.job_registration.schedule,
+		};
+
+		Storage::job_info().insert(job_id, (Version::V1 as u16, info.encode()));
+
+		Ok(RegisterJobPayloadV1 { job_id, job_registration: payload.job_registration })
+	}
+
 	async fn do_send_actions(actions: Vec<UserAction>) -> Result<(), ProxyError> {
 		let caller = msg::source();
 
 		for action in actions {
 			let outgoing_action = match action {
 				UserAction::RegisterJob(payload) => {
-					// Increment job identifier
-					let job_id = Storage::get_and_increase_next_job_id();
-
-					// Calculate the number of executions that fit the job schedule
-					let start_time = payload.job_registration.schedule.start_time;
-					let end_time = payload.job_registration.schedule.end_time;
-					let interval = payload.job_registration.schedule.interval;
-					if interval == 0 {
-						return Err(ProxyError::Verbose("INTERVAL_CANNNOT_BE_ZERO".to_string()));
-					}
-					let execution_count = ((end_time - start_time - 1) / interval) + 1;
-
-					// Calculate the fee required for all job executions
-					let slots = payload.job_registration.extra.slots;
-					let expected_fulfillment_fee = payload.expected_fulfillment_fee;
-					let expected_fee =
-						((slots as u128) * execution_count as u128) * expected_fulfillment_fee;
-
-					// Calculate the total reward required to pay all executions
-					let reward_per_execution = payload.job_registration.extra.reward;
-					let maximum_reward =
-						(slots as u128) * (execution_count as u128) * reward_per_execution;
-
-					// Get exchange price
-					let cost: u128 =
-						Storage::config().exchange_ratio.exchange_price(maximum_reward);
-
-					// Validate job registration payment
-					let amount = msg::value();
-					if amount < expected_fee + cost {
-						return Err(ProxyError::Verbose(
-							"AMOUNT_CANNOT_COVER_JOB_COSTS".to_string(),
-						));
-					}
-
-					let info = JobInformationV1 {
-						status: JobStatus::Open,
-						creator: caller,
-						destination: payload.destination,
-						processors: Vec::new(),
-						expected_fulfillment_fee,
-						remaining_fee: expected_fee,
-						maximum_reward,
-						slots,
-						schedule: payload.job_registration.schedule,
+					OutgoingActionPayloadV1::RegisterJob(Self::register_job(payload, 0)?)
+				},
+				UserAction::RegisterJobWithOptions(payload, options) => {
+					// Results delivered back to the proxy are paid out of the job reward
+					let result_delivery_fee = if options.deliver_results_to_origin {
+						Storage::config().result_delivery_fee
+					} else {
+						0
 					};
-
-					Storage::job_info().insert(job_id, (Version::V1 as u16, info.encode()));
-
-					OutgoingActionPayloadV1::RegisterJob(RegisterJobPayloadV1 {
-						job_id,
-						job_registration: payload.job_registration,
-					})
+					OutgoingActionPayloadV1::RegisterJobWithOptions(
+						Self::register_job(payload, result_delivery_fee)?,
+						options,
+					)
 				},
 				UserAction::DeregisterJob(job_id) => {
 					match JobInformation::from_id(job_id)? {
@@ -257,6 +268,14 @@ impl VaraProxyService {
 				// Intentionally do nothing
 				Ok(())
 			},
+			VersionedIncomingActionPayload::V1(IncomingActionPayloadV1::ExecutionResult(
+				payload,
+			)) => {
+				// Only the latest result is kept, consumers query it by job id
+				Storage::job_results().insert(payload.job_id, payload.result);
+
+				Ok(())
+			},
 		}?;
 
 		Ok(())
@@ -319,6 +338,7 @@ impl VaraProxyService {
 				ConfigureArgument::PayloadVersion(version) => config.payload_version = version,
 				ConfigureArgument::MaxMessageBytes(max_size) => config.max_message_bytes = max_size,
 				ConfigureArgument::ExchangeRatio(ratio) => config.exchange_ratio = ratio,
+				ConfigureArgument::ResultDeliveryFee(fee) => config.result_delivery_fee = fee,
 			}
 		}
 	}
@@ -354,4 +374,8 @@ impl VaraProxyService {
 	pub fn next_job_id(&self) -> u128 {
 		Storage::next_job_id()
 	}
+
+	pub fn job_result(&self, job_id: u128) -> Option<ExecutionResultV1> {
+		Storage::job_results().get(&job_id).cloned()
+	}
 }
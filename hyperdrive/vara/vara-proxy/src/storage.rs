@@ -12,6 +12,7 @@ pub struct Storage {
 	next_outgoing_action_id: u64,
 	next_job_id: u128,
 	job_info: HashMap<u128, (u16, Vec<u8>)>,
+	job_results: HashMap<u128, ExecutionResultV1>,
 }
 
 impl Storage {
@@ -53,6 +54,11 @@ impl Storage {
 		&mut storage.job_info
 	}
 
+	pub fn job_results() -> &'static mut HashMap<u128, ExecutionResultV1> {
+		let storage = Self::get_mut();
+		&mut storage.job_results
+	}
+
 	pub fn get_job(job_id: u128) -> Result<(Version, Vec<u8>), ProxyError> {
 		if let Some((version, job_bytes)) = Self::job_info().get(&job_id) {
 			match version {
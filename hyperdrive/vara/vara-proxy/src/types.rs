@@ -33,6 +33,7 @@ pub enum UserAction {
 	FinalizeJob(Vec<u128>),
 	SetJobEnvironment(SetJobEnvironmentUserInput),
 	Noop,
+	RegisterJobWithOptions(RegisterJobUserInput, RegisterJobOptionsV1),
 }
 
 #[derive(Clone, Eq, PartialEq, Encode, Decode, TypeInfo)]
@@ -103,6 +104,7 @@ pub enum ConfigureArgument {
 	PayloadVersion(u16),
 	MaxMessageBytes(u16),
 	ExchangeRatio(ExchangeRatio),
+	ResultDeliveryFee(u128),
 }
 
 #[derive(Debug, Clone, Encode, Decode, TypeInfo, Default)]
@@ -142,4 +144,6 @@ pub struct Config {
 	pub max_message_bytes: u16,
 	/// Exchange ratio ( AZERO / ACU )
 	pub exchange_ratio: ExchangeRatio,
+	/// Fee (in ACU) reserved per execution for delivering its result back to the proxy
+	pub result_delivery_fee: u128,
 }
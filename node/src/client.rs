@@ -20,7 +20,7 @@ use std::sync::Arc;
 pub use acurast_runtime_common::types::{AccountId, Balance, BlockNumber, Hash, Nonce};
 use acurast_runtime_common::{
 	opaque::{Block, Header},
-	types::AuraId,
+	types::{AuraId, MarketplaceRegistrationExtra, MaxAllowedSources},
 };
 use sc_client_api::{KeysIter, MerkleValue, PairsIter};
 use sp_consensus::BlockStatus;
@@ -49,6 +49,13 @@ pub trait RuntimeApiCollection:
 	+ sp_consensus_aura::AuraApi<Block, AuraId>
 	+ cumulus_primitives_core::CollectCollationInfo<Block>
 	+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
+	+ pallet_acurast_marketplace::MarketplaceRuntimeApi<
+		Block,
+		Balance,
+		AccountId,
+		MarketplaceRegistrationExtra,
+		MaxAllowedSources,
+	>
 {
 }
 
@@ -64,6 +71,13 @@ impl<Api> RuntimeApiCollection for Api where
 		+ sp_consensus_aura::AuraApi<Block, AuraId>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ cumulus_primitives_aura::AuraUnincludedSegmentApi<Block>
+		+ pallet_acurast_marketplace::MarketplaceRuntimeApi<
+			Block,
+			Balance,
+			AccountId,
+			MarketplaceRegistrationExtra,
+			MaxAllowedSources,
+		>
 {
 }
 
@@ -15,7 +15,7 @@ use sp_blockchain::{Error as BlockChainError, HeaderBackend, HeaderMetadata};
 
 use acurast_runtime_common::{
 	opaque::Block,
-	types::{AccountId, Balance, Nonce},
+	types::{AccountId, Balance, MarketplaceRegistrationExtra, MaxAllowedSources, Nonce},
 };
 
 /// A type representing all RPC extensions.
@@ -44,8 +44,16 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: pallet_acurast_marketplace::MarketplaceRuntimeApi<
+		Block,
+		Balance,
+		AccountId,
+		MarketplaceRegistrationExtra,
+		MaxAllowedSources,
+	>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use pallet_acurast_marketplace::rpc::{Marketplace, MarketplaceApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -54,5 +62,12 @@ where
 
 	module.merge(System::new(client.clone(), pool).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(
+		Marketplace::<
+			_,
+			(Block, Balance, AccountId, MarketplaceRegistrationExtra, MaxAllowedSources),
+		>::new(client.clone())
+		.into_rpc(),
+	)?;
 	Ok(module)
 }
@@ -1,5 +1,10 @@
 pub mod asn;
 pub mod error;
+pub mod tdx;
+mod verifier;
+
+pub use tdx::{IntelSgxRootCa, RootsOfTrust, TdxQuoteVerifier};
+pub use verifier::*;
 
 use asn::*;
 use asn1::{oid, BitString, ObjectIdentifier, ParseError, SequenceOf};
@@ -12,7 +17,8 @@ use p256::ecdsa::{signature::Verifier, VerifyingKey};
 use sha2::Digest;
 use sp_std::prelude::*;
 
-pub const CHAIN_MAX_LENGTH: u32 = 5;
+/// Remotely provisioned (RKP) chains can carry more intermediates than factory provisioned ones.
+pub const CHAIN_MAX_LENGTH: u32 = 6;
 pub const CERT_MAX_LENGTH: u32 = 3000;
 pub type CertificateInput = BoundedVec<u8, ConstU32<CERT_MAX_LENGTH>>;
 pub type CertificateChainInput = BoundedVec<CertificateInput, ConstU32<CHAIN_MAX_LENGTH>>;
@@ -265,16 +271,33 @@ fn parse_rsa_pbk(data: &[u8]) -> Result<RSAPbk, ParseError> {
 /// - the chain starts with a self-signed certificate at index 0 that matches one of the known [TRUSTED_ROOT_CERTS]
 /// - that the root's contained public key signs the next certificate in the chain
 /// - the next certificate's public key signs the next one and so on...
+///
+/// The root can be omitted, in which case the first certificate has to be signed by one of the trusted roots.
+/// This applies to factory provisioned as well as remotely provisioned (RKP) chains.
 pub fn validate_certificate_chain(
 	chain: &CertificateChainInput,
 ) -> Result<(Vec<CertificateId>, TBSCertificate<'_>, PublicKey), ValidationError> {
-	let google_root_pub_key =
-		PublicKey::parse(&asn1::parse_single::<SubjectPublicKeyInfo>(GOOGLE_ROOT_PUB_KEY)?)?;
-	let google_p384_root_pub_key =
-		PublicKey::parse(&asn1::parse_single::<SubjectPublicKeyInfo>(GOOGLE_P384_ROOT_PUB_KEY)?)?;
-	let apple_root_pub_key =
-		PublicKey::parse(&asn1::parse_single::<SubjectPublicKeyInfo>(APPLE_ROOT_PUB_KEY)?)?;
-	let trusted_roots = &[google_root_pub_key, google_p384_root_pub_key, apple_root_pub_key];
+	let trusted_roots = parse_root_public_keys(&[
+		GOOGLE_ROOT_PUB_KEY,
+		GOOGLE_P384_ROOT_PUB_KEY,
+		APPLE_ROOT_PUB_KEY,
+	])?;
+	validate_certificate_chain_with_roots(chain, &trusted_roots)
+}
+
+/// Parses DER encoded `SubjectPublicKeyInfo`s of root certificates.
+pub fn parse_root_public_keys(encoded: &[&[u8]]) -> Result<Vec<PublicKey>, ValidationError> {
+	encoded
+		.iter()
+		.map(|key| PublicKey::parse(&asn1::parse_single::<SubjectPublicKeyInfo>(key)?))
+		.collect()
+}
+
+/// Validates the chain like [validate_certificate_chain] with the provided `trusted_roots` instead of the known roots.
+pub fn validate_certificate_chain_with_roots<'a>(
+	chain: &'a CertificateChainInput,
+	trusted_roots: &[PublicKey],
+) -> Result<(Vec<CertificateId>, TBSCertificate<'a>, PublicKey), ValidationError> {
 	let mut cert_ids = Vec::<CertificateId>::new();
 	let fold_result = chain.iter().try_fold::<_, _, Result<_, ValidationError>>(
 		(Option::<PublicKey>::None, Option::<Certificate>::None),
@@ -341,10 +364,15 @@ mod tests {
 	use crate::{
 		asn::ParsedAttestation,
 		attestation::{error::ValidationError, extract_attestation},
-		BoundedKeyDescription,
+		AttestationFormat, AttestationReport, AttestationSecurityLevel, AttestationSummary,
+		BoundedKeyDescription, VerifiedBootState,
 	};
 
-	use super::{validate_certificate_chain, CertificateChainInput, CertificateInput};
+	use super::{
+		parse_root_public_keys, validate_certificate_chain, validate_certificate_chain_with_roots,
+		AttestationVerifier, AttestationVerifiers, CertificateChainInput, CertificateInput,
+		PublicKey, VerifiedReport, APPLE_ROOT_PUB_KEY, CHAIN_MAX_LENGTH, GOOGLE_ROOT_PUB_KEY,
+	};
 
 	pub fn decode_certificate_chain(chain: &[&str]) -> CertificateChainInput {
 		let decoded = chain
@@ -418,14 +446,7 @@ mod tests {
 
 	#[test]
 	fn test_solana_seeker_chain() -> Result<(), Error> {
-		let decoded_chain = [
-            hex_literal::hex!("3082051c30820304a003020102020900d50ff25ba3f2d6b3300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3139313132323230333735385a170d3334313131383230333735385a301b31193017060355040513106639323030396538353362366230343530820222300d06092a864886f70d01010105000382020f003082020a0282020100afb6c7822bb1a701ec2bb42e8bcc541663abef982f32c77f7531030c97524b1b5fe809fbc72aa9451f743cbd9a6f1335744aa55e77f6b6ac3535ee17c25e639517dd9c92e6374a53cbfe258f8ffbb6fd129378a22a4ca99c452d47a59f3201f44197ca1ccd7e762fb2f53151b6feb2fffd2b6fe4fe5bc6bd9ec34bfe08239daafceb8eb5a8ed2b3acd9c5e3a7790e1b51442793159859811ad9eb2a96bbdd7a57c93a91c41fccd27d67fd6f671aa0b815261ad384fa37944864604ddb3d8c4f920a19b1656c2f14ad6d03c56ec060899041c1ed1a5fe6d3440b556bad1d0a152589c53e55d370762f0122eef91861b1b0e6c4c80927499c0e9bec0b83e3bc1f93c72c049604bbd2f1345e62c3f8e26dbec06c94766f3c128239d4f4312fad8123887e06becf567583bf8355a81feeabaf99a83c8df3e2a322afc672bf120b135158b6821ceaf309b6eee77f98833b018daa10e451f06a374d50781f359082966bb778b9308942698e74e0bcd24628a01c2cc03e51f0b3e5b4ac1e4df9eaf9ff6a492a77c1483882885015b422ce67b80b88c9b48e13b607ab545c723ff8c44f8f2d368b9f6520d31145ebf9e862ad71df6a3bfd2450959d653740d97a12f368b13ef66d5d0a54a6e2f5d9a6fef446832bc67844725861f093dd0e6f3405da89643ef0f4d69b6420051fdb93049673e36950580d3cdf4fbd08bc58483952600630203010001a3633061301d0603551d0e041604143661e1007c880509518b446c47ff1a4cc9ea4f12301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff040403020204300d06092a864886f70d01010b050003820201004e31a05cf28ba65dbdafa1ced70969ee5ca84104added8a306cf7f6dee50375d745ed992cb0242cce72dc9eed51191fe5ad52bad7dd3b25c099e13a491a3cdd487a5acce8766324c4ae46338246ae7b78a418acbb98a05c4c9d696eeaab609d0ba0ce1a31be98490df3f4c0ea9ddc9e82ffb0fcb3e9ebdd8cb952789f2b1411fac56c886426eb7296042735da50e11ac715f1818cf9fdc4e254a3763351b6a2440150861263a6e310be1a50de5c7e8ee880fdd4be5884a37128d18830bb3476bf4291e82d5c66a6494939e08480bfbc00f7d8a74d43e73737ebe5d8e4ec515302d4689692780dc7538ed7e9175be6139e74d43ad388b3050ffd5a9de5262000898c01f63c53dfe22209108fa4f65ba16c49ccbde0837d7c5844d54b7398ba0122e505b155c9313cfe26e72d87e22aa1616e6bdbf547ddff93df29e35a63b455fe1fc0ec95581f3f4f7bbe3bb828396a37ae3157582bc3764b9780a239efc0f75a1e2e6d941ceabac27ddeb01e2bd8421029bea34d51aee6c60271d5a95ebd00515a9c0013dd80bf87eea260b81c34f688e6eb1348af0d8ea1cac32acb9d93fa24aff030a84c8f2b0f569cc95080b20ac35ace0c6d8dbd4f6847719519d32450166eb4bf15b859044501adeaf436382c34b15e3b54c92e61b69c2bfc7264589172b3c93dbe35ce06d08fd5c01322ca0877b1d12743af1fad5940ea1bc02dd891c").to_vec().try_into().unwrap(),
-            hex_literal::hex!("3082038030820168a003020102020a0388266760658996860f300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3232303132363232353032305a170d3337303132323232353032305a302931133011060355040a130a476f6f676c65204c4c43311230100603550403130944726f6964204341323076301006072a8648ce3d020106052b8104002203620004fedfb8008e39e03f29337d99504a6e692d1ec0b8c53fd1013a7085e0a933da3a9c0c40a9d1f8f2df801a4c28099e919d08b214deefd65c1b37a4410280cb92f515522aa8f9f38c1d6e97318896a16564b31ea3cad4d9f92d961d408a4b27d714a3663064301d0603551d0e04160414399807063a33129ef514063a80410c7180ce1aad301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f1230120603551d130101ff040830060101ff020102300e0603551d0f0101ff040403020106300d06092a864886f70d01010b050003820201003d053b9f20c1641be913a9e1cee190ec108968824c6d9477d370c3970a96c314f189593a4f275e7e4ec007ee153d352a071b6aed423431025fba80d6f27243d1c1b28a9aedf9a2d1c7f58ff45106fbc0b86be0df3472d3e39c930236f37db7f7d97ff3514c4a14be19ccd359f84b258fce6e45411646a7442a43844fd2c4d1cbff99c9cd8687f0e76300ab9f9c3f66705c98bf38e2c5b70f10ee667a51b093b29f0b1f66892a55b81ea637535da02b128bde99610181959b04f282dd6b37240b2813be9dacefb1ad22cc32c0e1dfdc711adfd3909bc07c836707732e2ee642a815e48f23dce8ea3c7e5836f512bf202994f5bbb96bc025be25442ab986c61cbcd20c8b117c6874b138e8447fece290f7ef539802490f307560b81644f22bf9bafd23e0807037f78bca841bd63981bff744514f5c79777fb3c6f9fbc93b3d8858703300d28c8541cbeb84ba166eacbb06b636e62584639c47e84b0716b7b9be9d6cbb5ed1766c5bcb79edee65f3d4dc4141d037accfa471995f70af6c1d33554c8dbdd16cbd212fbcff16a5aed6a5a383c0e84c287f25f2d7c5c8f4075d36cef9e0a3dff3fc8031b55cff6682966d5117bffbbc1d1fc7779098b138592d354acaac5a70ac1c249bca3748688c8a045421001b482312cd60b170fb49e703b0c153f3357bd81604b87c773d711c5a3e4e7e5e758698d5b632865181294286e0cf25").to_vec().try_into().unwrap(),
-            hex_literal::hex!("308201d63082015ca003020102021335772a634a0c9d3bc9466901d17cffad747726300a06082a8648ce3d040303302931133011060355040a130a476f6f676c65204c4c43311230100603550403130944726f696420434132301e170d3235303732323036353032305a170d3235303933303036353031395a302931133011060355040a130a476f6f676c65204c4c43311230100603550403130944726f6964204341333059301306072a8648ce3d020106082a8648ce3d03010703420004fabf9ee100e9e3c72017e407feb4da4f1a7c76e10edac856b18b87633071522fc612043b46cd706a39faa3120ee1c706722afa5303639893c9e9bcc18760355fa3633061300e0603551d0f0101ff040403020204300f0603551d130101ff040530030101ff301d0603551d0e04160414b4c77197661ea2bc8f9eaa483f1425effb9b71e3301f0603551d23041830168014399807063a33129ef514063a80410c7180ce1aad300a06082a8648ce3d0403030368003065023016e32066adfe2ff15de40ee7966cbe32953f8f56f5e4fd60204a3a8a7334b82c00552d789963b93e7315143fd1f70ab7023100b0af29dfe98a1bc46fe834858afc840e3d5e321856f3585e61750a3426910f78ea62afd1f731788f28ee243c223d00a9").to_vec().try_into().unwrap(),
-            hex_literal::hex!("308201f230820198a00302010202106b1a0d4882cf7b565006bd71a77f1159300a06082a8648ce3d040302302931133011060355040a130a476f6f676c65204c4c43311230100603550403130944726f696420434133301e170d3235303831393130323934305a170d3235303931363132353835385a303f3129302706035504031320366231613064343838326366376235363530303662643731613737663131353931123010060355040a13095374726f6e67426f783059301306072a8648ce3d020106082a8648ce3d0301070342000409a73a592725d66795565da358102607a14b1a423f17a942981d7db5914d98f691860c02226093f5f502963e40422229a5db0247140150160cfd813d1d550915a3818b308188301d0603551d0e04160414311db33cf32f95bafc64804d5d887be37b49f9eb301f0603551d23041830168014b4c77197661ea2bc8f9eaa483f1425effb9b71e3300f0603551d130101ff040530030101ff300e0603551d0f0101ff0404030202043025060a2b06010401d67902011e0417a201080372536f6c616e61204d6f62696c6520496e632e300a06082a8648ce3d0403020348003045022100c6b1f86dedcaf4f8bc9dd669869c2d4ffb3c991b754a2da2a8b876c6aeedde2c02205ee0cef4add14256e4d9470a2d7979a1e8421c9ee531636b2293630d88b14442").to_vec().try_into().unwrap(),
-            hex_literal::hex!("308202bf30820265a003020102020101300a06082a8648ce3d040302303f3129302706035504031320366231613064343838326366376235363530303662643731613737663131353931123010060355040a13095374726f6e67426f78301e170d3730303130313030303030305a170d3438303130313030303030305a301f311d301b06035504031314416e64726f6964204b657973746f7265204b65793059301306072a8648ce3d020106082a8648ce3d03010703420004525fd90b61574ff43b0a9f8833674e0063e52f07fdbaa6e91810f92a4680d2c90634e7c527077bd8575d2a7b66a3eb6cf8771fee4f9663f5f41ba0d861dc6787a38201703082016c30820158060a2b06010401d67902011104820148308201440202012c0a01020202012c0a01020420dc1d47687c0142ce02b1a7c3240d689269fc365bd6c49aa7ab3643a6e730809504003069bf853d0802060198cc525cd1bf85455904573055312f302d0428636f6d2e616375726173742e61747465737465642e6578656375746f722e7362732e63616e61727902015331220420e095733f011ae6934a02d65a0945fcf24c16af7598c1c23405dcc4f3cb9ee5bc3081a4a1083106020103020102a203020103a30402020100a5053103020100aa03020101bf8377020500bf853e03020100bf85404c304a0420eb63f1a60094029d2e5e3968dadaff3407fb4280394869cddbb3e64bf17ae37b0101ff0a010004207b8301dc8ef68779083c58875fdbc71aa8ba5bd0309160d85bc6c21a848085efbf85410502030249f0bf854205020303170abf854e0602040134ffedbf854f0602040134d815300e0603551d0f010101040403020780300a06082a8648ce3d0403020348003045022100e6f8fbaacf03b67b6f2f514cac6ba0f408762670e1bc8154748876b420775a5402204d44d0ba2bc4477442bcb63e54877c9cc361b65e58753f0440c43c2fdd71ae19").to_vec().try_into().unwrap(),
-        ].to_vec().try_into().unwrap();
-		let (_, cert, _) = validate_certificate_chain(&decoded_chain)?;
+		let (_, cert, _) = validate_certificate_chain(&seeker_rkp_chain())?;
 		_ = extract_attestation(cert.extensions)?;
 		Ok(())
 	}
@@ -630,15 +651,174 @@ mod tests {
 
 	#[test]
 	fn test_validate_cert_chain() -> Result<(), ValidationError> {
-		let decoded_chain = [
+		let (_, cert, _) = validate_certificate_chain(&key_mint_v400_chain())?;
+		_ = extract_attestation(cert.extensions)?;
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_cert_chain_v400() -> Result<(), ValidationError> {
+		let (cert_ids, cert, _) = validate_certificate_chain(&key_mint_v400_chain())?;
+		assert_eq!(cert_ids.len(), 4);
+		let ParsedAttestation::KeyDescription(key_description) =
+			extract_attestation(cert.extensions)?
+		else {
+			panic!("expected a key description");
+		};
+		assert_eq!(key_description.attestation_version, 400);
+		let key_description: BoundedKeyDescription = key_description.try_into().unwrap();
+		assert_eq!(key_description.attestation_security_level, AttestationSecurityLevel::StrongBox);
+		assert_eq!(
+			key_description.software_enforced.module_hash.unwrap().to_vec(),
+			hex_literal::hex!("707084d95ae0c59405e4fbd08aa10f4b39022583813cb62def245921da3cf2d9")
+				.to_vec()
+		);
+		assert_eq!(key_description.tee_enforced.attestation_id_second_imei, None);
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_cert_chain_without_root() -> Result<(), ValidationError> {
+		let chain = key_mint_v400_chain();
+		let (cert_ids, _, _) =
+			validate_certificate_chain(&chain[1..].to_vec().try_into().unwrap())?;
+		assert_eq!(cert_ids.len(), 3);
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_rkp_chain() -> Result<(), ValidationError> {
+		let chain = seeker_rkp_chain();
+		let (cert_ids, _, _) = validate_certificate_chain(&chain)?;
+		assert_eq!(cert_ids.len(), 5);
+		let (cert_ids, _, _) =
+			validate_certificate_chain(&chain[1..].to_vec().try_into().unwrap())?;
+		assert_eq!(cert_ids.len(), 4);
+		Ok(())
+	}
+
+	#[test]
+	fn test_validate_chain_of_max_length() -> Result<(), ValidationError> {
+		let trusted_roots = parse_root_public_keys(&[&hex_literal::hex!(
+			"3059301306072a8648ce3d020106082a8648ce3d0301070342000490060b2598725f4a52a142a69d5c7c65e214786ed1422f9a5d9c2d2d0d0314666e8673a975d0705681dae9272502ac30d0f1190d55c6c59df3b9bba256329942"
+		)])?;
+		let chain = six_certificate_chain();
+		let (cert_ids, _, _) = validate_certificate_chain_with_roots(&chain, &trusted_roots)?;
+		assert_eq!(cert_ids.len(), CHAIN_MAX_LENGTH as usize);
+		assert!(validate_certificate_chain(&chain).is_err());
+
+		let mut too_long = chain.to_vec();
+		too_long.push(chain[5].clone());
+		assert!(CertificateChainInput::try_from(too_long).is_err());
+		Ok(())
+	}
+
+	struct TestVerifier;
+
+	impl AttestationVerifier for TestVerifier {
+		const FORMAT: AttestationFormat = AttestationFormat(*b"testtee0");
+		const ROOTS_OF_TRUST: &'static [&'static [u8]] = &[GOOGLE_ROOT_PUB_KEY];
+
+		fn verify(report: &[u8], _signer: &PublicKey) -> Result<VerifiedReport, ValidationError> {
+			Ok(VerifiedReport {
+				report_data: report.to_vec(),
+				summary: AttestationSummary {
+					security_level: AttestationSecurityLevel::TrustedEnvironemnt,
+					verified_boot_state: VerifiedBootState::Verified,
+					measurement: vec![1; 48].try_into().unwrap(),
+				},
+			})
+		}
+	}
+
+	struct AppleRootVerifier;
+
+	impl AttestationVerifier for AppleRootVerifier {
+		const FORMAT: AttestationFormat = AttestationFormat(*b"testtee1");
+		const ROOTS_OF_TRUST: &'static [&'static [u8]] = &[APPLE_ROOT_PUB_KEY];
+
+		fn verify(_report: &[u8], _signer: &PublicKey) -> Result<VerifiedReport, ValidationError> {
+			Err(ValidationError::InvalidReport)
+		}
+	}
+
+	fn report(format: AttestationFormat) -> AttestationReport {
+		AttestationReport {
+			format,
+			report: b"report data".to_vec().try_into().unwrap(),
+			certificate_chain: key_mint_v400_chain(),
+		}
+	}
+
+	#[test]
+	fn test_verify_report() -> Result<(), ValidationError> {
+		let report = report(TestVerifier::FORMAT);
+		let (cert_ids, _, verified_report) =
+			<(AppleRootVerifier, TestVerifier) as AttestationVerifiers>::verify(&report)?;
+		assert_eq!(cert_ids.len(), 4);
+		assert_eq!(verified_report.report_data, b"report data".to_vec());
+		assert_eq!(verified_report.summary.verified_boot_state, VerifiedBootState::Verified);
+		Ok(())
+	}
+
+	#[test]
+	fn test_verify_report_unsupported_format() {
+		let report = report(AttestationFormat(*b"unknown0"));
+		assert!(matches!(
+			<(AppleRootVerifier, TestVerifier) as AttestationVerifiers>::verify(&report),
+			Err(ValidationError::UnsupportedAttestationFormat)
+		));
+		assert!(matches!(
+			<() as AttestationVerifiers>::verify(&report),
+			Err(ValidationError::UnsupportedAttestationFormat)
+		));
+	}
+
+	#[test]
+	fn test_verify_report_untrusted_root() {
+		let report = report(AppleRootVerifier::FORMAT);
+		assert!(matches!(
+			<(AppleRootVerifier, TestVerifier) as AttestationVerifiers>::verify(&report),
+			Err(ValidationError::InvalidSignature)
+		));
+	}
+
+	/// The StrongBox chain of [test_validate_cert_chain], attesting with KeyMint v400 through remotely provisioned intermediates.
+	fn key_mint_v400_chain() -> CertificateChainInput {
+		[
             hex_literal::hex!("3082051c30820304a003020102020900f1c172a699eaf51d300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3232303332303138303734385a170d3432303331353138303734385a301b31193017060355040513106639323030396538353362366230343530820222300d06092a864886f70d01010105000382020f003082020a0282020100afb6c7822bb1a701ec2bb42e8bcc541663abef982f32c77f7531030c97524b1b5fe809fbc72aa9451f743cbd9a6f1335744aa55e77f6b6ac3535ee17c25e639517dd9c92e6374a53cbfe258f8ffbb6fd129378a22a4ca99c452d47a59f3201f44197ca1ccd7e762fb2f53151b6feb2fffd2b6fe4fe5bc6bd9ec34bfe08239daafceb8eb5a8ed2b3acd9c5e3a7790e1b51442793159859811ad9eb2a96bbdd7a57c93a91c41fccd27d67fd6f671aa0b815261ad384fa37944864604ddb3d8c4f920a19b1656c2f14ad6d03c56ec060899041c1ed1a5fe6d3440b556bad1d0a152589c53e55d370762f0122eef91861b1b0e6c4c80927499c0e9bec0b83e3bc1f93c72c049604bbd2f1345e62c3f8e26dbec06c94766f3c128239d4f4312fad8123887e06becf567583bf8355a81feeabaf99a83c8df3e2a322afc672bf120b135158b6821ceaf309b6eee77f98833b018daa10e451f06a374d50781f359082966bb778b9308942698e74e0bcd24628a01c2cc03e51f0b3e5b4ac1e4df9eaf9ff6a492a77c1483882885015b422ce67b80b88c9b48e13b607ab545c723ff8c44f8f2d368b9f6520d31145ebf9e862ad71df6a3bfd2450959d653740d97a12f368b13ef66d5d0a54a6e2f5d9a6fef446832bc67844725861f093dd0e6f3405da89643ef0f4d69b6420051fdb93049673e36950580d3cdf4fbd08bc58483952600630203010001a3633061301d0603551d0e041604143661e1007c880509518b446c47ff1a4cc9ea4f12301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff040403020204300d06092a864886f70d01010b050003820201007c70ca939651dcf14faa0ab3a58371fbd7be2599a0ac6e8fdb2740b5ec912030b6f892faeab1766cd35537981fea00183fd6de4f77900e447011b35861a862025bf9ca31abf9ef87fdad93783c2d9996e7c65dbeec21d2691a23bd72d46188bb98ba5cb5d0971c5191841e91d260cd86b648186d96daea5b023d80003fcddcc8357ed5a3a44dfd510a9fe53343cabe6c58375d1162c2badf58eb95e19d71d931a122bffe64906e07169e600466bcc7a05d7fd20b28d47660227d182f35612d203f897097e104f6877279cf7ce796e286d67bfc3507717a2d832088404967eef34e0203de9c40a4d395a69ed9fc1ea978dd375fefda7a8e86780dcb3d77eb59859abe1799a287fc8b53c0e7bbd8d23d65cc12d6555a0afb089130c2117766f6b08d3c0635d224ee9c81c55d187eeca3f394719ec02abff133a8841467d3f34d7e1eee46c94e499ff129b37db4c06dc37ed9f1ddafbe75eafd859db26d7e24b5709fac980ffc9a70d241970a5d7656bc79a54c8ec17a9c19c881039ff732927b4ea7493aaf830507a2c80e10264967512ecdb1f8cacc1bb74dad2ad284161c7ebfe39381eff4e95fa31aca9358bb1face08d2ee03c1fefb3fa9504366a6a9e71e8bda238ee00be4cda648181a49014fa07f9bf534d41b8e0414f384894c119abdaa40d6b8cd9c039916e55dc525471f1e7c3521d6088365b183bc8771065e98542").to_vec().try_into().unwrap(),
             hex_literal::hex!("3082039a30820182a003020102021100dea90f73eec5583bdc82603511ed604a300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3234303931323231343631315a170d3334303931303231343631315a303f31123010060355040c0c095374726f6e67426f78312930270603550405132031363638663238336336646437393832353335623562356262616535383631653076301006072a8648ce3d020106052b8104002203620004a6b9ac66db7397f8701c2e366d21bb344d0a450a01d83baf197e7ae200b53264b99ccbb670a89729d57ccdbefe15e430aa6aaf3b3ce2a1fadebb7d20800da1586e62668b768597b3d957165dec2bedba1804aa9cde0f90b7da345fe5cc9b9caea3633061301d0603551d0e0416041446dc08cd396d3115819838b3e78c17076960aa88301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff040403020204300d06092a864886f70d01010b05000382020100aa7b7080d09865bc607aa9296eaef25c11f1c92444e54c5c46ad333250b6fa6d394d02db4d515c9c1a7198fbfb46066acd3216b9be13ad62f5a48514ab076664f957002ba1d4d696b46385afc4778a8435d50e431ea2b4a939348022c876b04d927bb3e6bce9573c9fd0dd7644329ed073a93c83d077b714338b0806383d3897097c589d2626d74f3e29f9c11b3d93d8732467054216f7bc6096728c1590bbf9a1ac8b99ecc50b732517da8aa0cc8c4e37258ddc09a667bfc31ca95c672d351a9d42bac1293d6d46935a35ff8cfc46e29d96eaef806bfa7d72830970caaac48de88398c361333f8b16f5d889675b590b58444553f1b83036c3076472c8f984400e128c0d272acdb8f8579db8c8f72d735a02fb3b57d97f65aed6dc46f2b313e193cd5e8cd6871846f20ff4f030fec44e08d69e31af74c368d1adc68eceb5a4da5c01e590cf0da14674e5a45c6e93c8afd2757eb71a702d854be23c9f8666c3e1f5c82beba7e1eabf5c408a440bf057f4f3e7580b76c87916e1d2a265922860527afbf944cd02f7181ec49c8a8c62415a865791ad452ec910ba8209ee8179047fef51389fb183ecdb27c991dd22a72aee913912b33d0adee5ed27f2abcd1c459122adf2ad7f1f097472418a06372734d7b1d7c794dc4e4c7f255487d218a99b17d51f5ae3b5acd5ef538d099e6c46f3a83a51d493863cc3863a5d521f2e7babac").to_vec().try_into().unwrap(),
             hex_literal::hex!("3082020130820186a003020102021100c0b7e9d4c786bd4b0d8665da013a5a44300a06082a8648ce3d040302303f31123010060355040c0c095374726f6e67426f7831293027060355040513203136363866323833633664643739383235333562356235626261653538363165301e170d3234303931323231343733345a170d3334303931303231343733345a303f31123010060355040c0c095374726f6e67426f78312930270603550405132065323234666366366436313831336662366339343164633064386334323561373059301306072a8648ce3d020106082a8648ce3d030107034200042db6dedfd916182ac9c737bc4d85b56542bc3c9d33cb93c93fe37e7d61357c4d50a19ef7b998b29a3ec49eba37bbde5f5193de5a85c987d994e317368952efeca3633061301d0603551d0e04160414399ffc0eb6cec89f2da3cd9decd25a43232cee5d301f0603551d2304183016801446dc08cd396d3115819838b3e78c17076960aa88300f0603551d130101ff040530030101ff300e0603551d0f0101ff040403020204300a06082a8648ce3d0403020369003066023100db26dddf1eafdae33fdf0cefebc271b3d7dddb4f1cf34c88e07cc9fd52009a05f04d5a37ad8bce09a47d3453377799e7023100f2fa2eaebd0c36b294ed7e4caa6d2007f6e8d673994f8944c5bfe445b9dc186c3d6293be775e928c7835e8cd31f35931").to_vec().try_into().unwrap(),
             hex_literal::hex!("308202e63082028ca003020102020101300a06082a8648ce3d040302303f31123010060355040c0c095374726f6e67426f7831293027060355040513206532323466636636643631383133666236633934316463306438633432356137301e170d3730303130313030303030305a170d3438303130313030303030305a301f311d301b06035504031314416e64726f6964204b657973746f7265204b65793059301306072a8648ce3d020106082a8648ce3d03010703420004c1b3588cd513a02aabd138ac2613e47d17f710ea66cbbd244f2714e6b8d97df0df3b2e8eba9a80c95862455d1875f2cfa6cf4fbd9c9e4c8023614ee0566f21bda382019730820193300e0603551d0f0101ff0404030207803082017f060a2b06010401d6790201110482016f3082016b020201900a0102020201900a010204200000a27e69e3df4779cb30b948137fae4b3f9db6e3d1f66bfeb339e4ff016522040030818fbf853d080206019e3e041472bf85455904573055312f302d0428636f6d2e616375726173742e61747465737465642e6578656375746f722e7362732e63616e61727902017631220420ea21af13f3b724c662f3da05247acc5a68a45331a90220f0d90a6024d7fa8f36bf8554220420707084d95ae0c59405e4fbd08aa10f4b39022583813cb62def245921da3cf2d93081a4a1083106020102020103a203020103a30402020100a5053103020100aa03020101bf8377020500bf853e03020100bf85404c304a0420ed6130258ca69dfae85401e3562bdac875f10e19ac726040d6beeeacf5dbc4630101ff0a0100042072116376011326b95f5c84c088d90135a816a07efc22144c1da650c21687bcb5bf8541050203027100bf854205020303176cbf854e06020401352635bf854f06020401352635300a06082a8648ce3d04030203480030450221008486009cced05170cb30e4ee43f0e593beb2ba5b380a0944a143ee0f72ab64a50220323792f788ab090587015e0ddefffd640e9ee102ec450acc1e18389b23267279").to_vec().try_into().unwrap(),
-        ].to_vec().try_into().unwrap();
-		let (_, cert, _) = validate_certificate_chain(&decoded_chain)?;
-		_ = extract_attestation(cert.extensions)?;
-		Ok(())
+        ].to_vec().try_into().unwrap()
+	}
+
+	/// The remotely provisioned (RKP) StrongBox chain of a Solana Seeker, issued through `Droid CA2` and `Droid CA3`, including the root.
+	fn seeker_rkp_chain() -> CertificateChainInput {
+		[
+            hex_literal::hex!("3082051c30820304a003020102020900d50ff25ba3f2d6b3300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3139313132323230333735385a170d3334313131383230333735385a301b31193017060355040513106639323030396538353362366230343530820222300d06092a864886f70d01010105000382020f003082020a0282020100afb6c7822bb1a701ec2bb42e8bcc541663abef982f32c77f7531030c97524b1b5fe809fbc72aa9451f743cbd9a6f1335744aa55e77f6b6ac3535ee17c25e639517dd9c92e6374a53cbfe258f8ffbb6fd129378a22a4ca99c452d47a59f3201f44197ca1ccd7e762fb2f53151b6feb2fffd2b6fe4fe5bc6bd9ec34bfe08239daafceb8eb5a8ed2b3acd9c5e3a7790e1b51442793159859811ad9eb2a96bbdd7a57c93a91c41fccd27d67fd6f671aa0b815261ad384fa37944864604ddb3d8c4f920a19b1656c2f14ad6d03c56ec060899041c1ed1a5fe6d3440b556bad1d0a152589c53e55d370762f0122eef91861b1b0e6c4c80927499c0e9bec0b83e3bc1f93c72c049604bbd2f1345e62c3f8e26dbec06c94766f3c128239d4f4312fad8123887e06becf567583bf8355a81feeabaf99a83c8df3e2a322afc672bf120b135158b6821ceaf309b6eee77f98833b018daa10e451f06a374d50781f359082966bb778b9308942698e74e0bcd24628a01c2cc03e51f0b3e5b4ac1e4df9eaf9ff6a492a77c1483882885015b422ce67b80b88c9b48e13b607ab545c723ff8c44f8f2d368b9f6520d31145ebf9e862ad71df6a3bfd2450959d653740d97a12f368b13ef66d5d0a54a6e2f5d9a6fef446832bc67844725861f093dd0e6f3405da89643ef0f4d69b6420051fdb93049673e36950580d3cdf4fbd08bc58483952600630203010001a3633061301d0603551d0e041604143661e1007c880509518b446c47ff1a4cc9ea4f12301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f12300f0603551d130101ff040530030101ff300e0603551d0f0101ff040403020204300d06092a864886f70d01010b050003820201004e31a05cf28ba65dbdafa1ced70969ee5ca84104added8a306cf7f6dee50375d745ed992cb0242cce72dc9eed51191fe5ad52bad7dd3b25c099e13a491a3cdd487a5acce8766324c4ae46338246ae7b78a418acbb98a05c4c9d696eeaab609d0ba0ce1a31be98490df3f4c0ea9ddc9e82ffb0fcb3e9ebdd8cb952789f2b1411fac56c886426eb7296042735da50e11ac715f1818cf9fdc4e254a3763351b6a2440150861263a6e310be1a50de5c7e8ee880fdd4be5884a37128d18830bb3476bf4291e82d5c66a6494939e08480bfbc00f7d8a74d43e73737ebe5d8e4ec515302d4689692780dc7538ed7e9175be6139e74d43ad388b3050ffd5a9de5262000898c01f63c53dfe22209108fa4f65ba16c49ccbde0837d7c5844d54b7398ba0122e505b155c9313cfe26e72d87e22aa1616e6bdbf547ddff93df29e35a63b455fe1fc0ec95581f3f4f7bbe3bb828396a37ae3157582bc3764b9780a239efc0f75a1e2e6d941ceabac27ddeb01e2bd8421029bea34d51aee6c60271d5a95ebd00515a9c0013dd80bf87eea260b81c34f688e6eb1348af0d8ea1cac32acb9d93fa24aff030a84c8f2b0f569cc95080b20ac35ace0c6d8dbd4f6847719519d32450166eb4bf15b859044501adeaf436382c34b15e3b54c92e61b69c2bfc7264589172b3c93dbe35ce06d08fd5c01322ca0877b1d12743af1fad5940ea1bc02dd891c").to_vec().try_into().unwrap(),
+            hex_literal::hex!("3082038030820168a003020102020a0388266760658996860f300d06092a864886f70d01010b0500301b311930170603550405131066393230303965383533623662303435301e170d3232303132363232353032305a170d3337303132323232353032305a302931133011060355040a130a476f6f676c65204c4c43311230100603550403130944726f6964204341323076301006072a8648ce3d020106052b8104002203620004fedfb8008e39e03f29337d99504a6e692d1ec0b8c53fd1013a7085e0a933da3a9c0c40a9d1f8f2df801a4c28099e919d08b214deefd65c1b37a4410280cb92f515522aa8f9f38c1d6e97318896a16564b31ea3cad4d9f92d961d408a4b27d714a3663064301d0603551d0e04160414399807063a33129ef514063a80410c7180ce1aad301f0603551d230418301680143661e1007c880509518b446c47ff1a4cc9ea4f1230120603551d130101ff040830060101ff020102300e0603551d0f0101ff040403020106300d06092a864886f70d01010b050003820201003d053b9f20c1641be913a9e1cee190ec108968824c6d9477d370c3970a96c314f189593a4f275e7e4ec007ee153d352a071b6aed423431025fba80d6f27243d1c1b28a9aedf9a2d1c7f58ff45106fbc0b86be0df3472d3e39c930236f37db7f7d97ff3514c4a14be19ccd359f84b258fce6e45411646a7442a43844fd2c4d1cbff99c9cd8687f0e76300ab9f9c3f66705c98bf38e2c5b70f10ee667a51b093b29f0b1f66892a55b81ea637535da02b128bde99610181959b04f282dd6b37240b2813be9dacefb1ad22cc32c0e1dfdc711adfd3909bc07c836707732e2ee642a815e48f23dce8ea3c7e5836f512bf202994f5bbb96bc025be25442ab986c61cbcd20c8b117c6874b138e8447fece290f7ef539802490f307560b81644f22bf9bafd23e0807037f78bca841bd63981bff744514f5c79777fb3c6f9fbc93b3d8858703300d28c8541cbeb84ba166eacbb06b636e62584639c47e84b0716b7b9be9d6cbb5ed1766c5bcb79edee65f3d4dc4141d037accfa471995f70af6c1d33554c8dbdd16cbd212fbcff16a5aed6a5a383c0e84c287f25f2d7c5c8f4075d36cef9e0a3dff3fc8031b55cff6682966d5117bffbbc1d1fc7779098b138592d354acaac5a70ac1c249bca3748688c8a045421001b482312cd60b170fb49e703b0c153f3357bd81604b87c773d711c5a3e4e7e5e758698d5b632865181294286e0cf25").to_vec().try_into().unwrap(),
+            hex_literal::hex!("308201d63082015ca003020102021335772a634a0c9d3bc9466901d17cffad747726300a06082a8648ce3d040303302931133011060355040a130a476f6f676c65204c4c43311230100603550403130944726f696420434132301e170d3235303732323036353032305a170d3235303933303036353031395a302931133011060355040a130a476f6f676c65204c4c43311230100603550403130944726f6964204341333059301306072a8648ce3d020106082a8648ce3d03010703420004fabf9ee100e9e3c72017e407feb4da4f1a7c76e10edac856b18b87633071522fc612043b46cd706a39faa3120ee1c706722afa5303639893c9e9bcc18760355fa3633061300e0603551d0f0101ff040403020204300f0603551d130101ff040530030101ff301d0603551d0e04160414b4c77197661ea2bc8f9eaa483f1425effb9b71e3301f0603551d23041830168014399807063a33129ef514063a80410c7180ce1aad300a06082a8648ce3d0403030368003065023016e32066adfe2ff15de40ee7966cbe32953f8f56f5e4fd60204a3a8a7334b82c00552d789963b93e7315143fd1f70ab7023100b0af29dfe98a1bc46fe834858afc840e3d5e321856f3585e61750a3426910f78ea62afd1f731788f28ee243c223d00a9").to_vec().try_into().unwrap(),
+            hex_literal::hex!("308201f230820198a00302010202106b1a0d4882cf7b565006bd71a77f1159300a06082a8648ce3d040302302931133011060355040a130a476f6f676c65204c4c43311230100603550403130944726f696420434133301e170d3235303831393130323934305a170d3235303931363132353835385a303f3129302706035504031320366231613064343838326366376235363530303662643731613737663131353931123010060355040a13095374726f6e67426f783059301306072a8648ce3d020106082a8648ce3d0301070342000409a73a592725d66795565da358102607a14b1a423f17a942981d7db5914d98f691860c02226093f5f502963e40422229a5db0247140150160cfd813d1d550915a3818b308188301d0603551d0e04160414311db33cf32f95bafc64804d5d887be37b49f9eb301f0603551d23041830168014b4c77197661ea2bc8f9eaa483f1425effb9b71e3300f0603551d130101ff040530030101ff300e0603551d0f0101ff0404030202043025060a2b06010401d67902011e0417a201080372536f6c616e61204d6f62696c6520496e632e300a06082a8648ce3d0403020348003045022100c6b1f86dedcaf4f8bc9dd669869c2d4ffb3c991b754a2da2a8b876c6aeedde2c02205ee0cef4add14256e4d9470a2d7979a1e8421c9ee531636b2293630d88b14442").to_vec().try_into().unwrap(),
+            hex_literal::hex!("308202bf30820265a003020102020101300a06082a8648ce3d040302303f3129302706035504031320366231613064343838326366376235363530303662643731613737663131353931123010060355040a13095374726f6e67426f78301e170d3730303130313030303030305a170d3438303130313030303030305a301f311d301b06035504031314416e64726f6964204b657973746f7265204b65793059301306072a8648ce3d020106082a8648ce3d03010703420004525fd90b61574ff43b0a9f8833674e0063e52f07fdbaa6e91810f92a4680d2c90634e7c527077bd8575d2a7b66a3eb6cf8771fee4f9663f5f41ba0d861dc6787a38201703082016c30820158060a2b06010401d67902011104820148308201440202012c0a01020202012c0a01020420dc1d47687c0142ce02b1a7c3240d689269fc365bd6c49aa7ab3643a6e730809504003069bf853d0802060198cc525cd1bf85455904573055312f302d0428636f6d2e616375726173742e61747465737465642e6578656375746f722e7362732e63616e61727902015331220420e095733f011ae6934a02d65a0945fcf24c16af7598c1c23405dcc4f3cb9ee5bc3081a4a1083106020103020102a203020103a30402020100a5053103020100aa03020101bf8377020500bf853e03020100bf85404c304a0420eb63f1a60094029d2e5e3968dadaff3407fb4280394869cddbb3e64bf17ae37b0101ff0a010004207b8301dc8ef68779083c58875fdbc71aa8ba5bd0309160d85bc6c21a848085efbf85410502030249f0bf854205020303170abf854e0602040134ffedbf854f0602040134d815300e0603551d0f010101040403020780300a06082a8648ce3d0403020348003045022100e6f8fbaacf03b67b6f2f514cac6ba0f408762670e1bc8154748876b420775a5402204d44d0ba2bc4477442bcb63e54877c9cc361b65e58753f0440c43c2fdd71ae19").to_vec().try_into().unwrap(),
+        ].to_vec().try_into().unwrap()
+	}
+
+	/// A synthetic chain of a self-signed P-256 root, four intermediates and a leaf, generated for testing only.
+	///
+	/// No real device ships a chain of [CHAIN_MAX_LENGTH] certificates yet, the root is not trusted by [validate_certificate_chain].
+	fn six_certificate_chain() -> CertificateChainInput {
+		[
+			hex_literal::hex!("308201293081d0a003020102020101300a06082a8648ce3d04030230143112301006035504030c095465737420526f6f74301e170d3235303130313030303030305a170d3435303130313030303030305a30143112301006035504030c095465737420526f6f743059301306072a8648ce3d020106082a8648ce3d0301070342000490060b2598725f4a52a142a69d5c7c65e214786ed1422f9a5d9c2d2d0d0314666e8673a975d0705681dae9272502ac30d0f1190d55c6c59df3b9bba256329942a3133011300f0603551d130101ff040530030101ff300a06082a8648ce3d0403020348003045022043553c3d209b97b8c776b319da4fb4d2670e07d3072e6c5250f4d856669d0c16022100fba8890be4a29e9a8522dfb27eb7fcf526526984c729b82550b0a53b33f56216").to_vec().try_into().unwrap(),
+			hex_literal::hex!("308201293081d0a003020102020102300a06082a8648ce3d04030230143112301006035504030c095465737420526f6f74301e170d3235303130313030303030305a170d3435303130313030303030305a30143112301006035504030c095465737420434120313059301306072a8648ce3d020106082a8648ce3d0301070342000486da47e87dead573f1b59481799466bed248e8b39172910ae25424023f7102f6cac351e8d7dd333fb92bf1f015c847332bbba98ac97bee21e5ee2a590919973fa3133011300f0603551d130101ff040530030101ff300a06082a8648ce3d0403020348003045022100c6f45346bc40ccc442eaefe9a3a1904ba92eda1dc18be8707c6b36ae63c1e25a0220454a15641d685844f2d5472d6190ca3ba15c606f4512dd028129103f3f9fca69").to_vec().try_into().unwrap(),
+			hex_literal::hex!("3082012a3081d0a003020102020103300a06082a8648ce3d04030230143112301006035504030c09546573742043412031301e170d3235303130313030303030305a170d3435303130313030303030305a30143112301006035504030c095465737420434120323059301306072a8648ce3d020106082a8648ce3d0301070342000465d42c1dafbe67ee096b20c238dbdd4e8361b49a240eaaadeb9319fee3fbbc946f80eea8cb676b5a87fa2eac49b8bfedb2dc73f131366fc4fc0e74b7ca0f80c8a3133011300f0603551d130101ff040530030101ff300a06082a8648ce3d04030203490030460221009ec40f170d5dbe46d9a59367e9292b55e6f409995a53cf920797ca1ee0684804022100bff34180fc8bcc69eab9c5159e4b0ffbc7649618145f04a27c99d4ef133e01dc").to_vec().try_into().unwrap(),
+			hex_literal::hex!("308201293081d0a003020102020104300a06082a8648ce3d04030230143112301006035504030c09546573742043412032301e170d3235303130313030303030305a170d3435303130313030303030305a30143112301006035504030c095465737420434120333059301306072a8648ce3d020106082a8648ce3d03010703420004681e283a38ad5d6c6b8186af6a00be3b7dd99cb50ce0baeb6a20a3cc76183b3843bc3108fee947d40075b2aa601800f0a72097d065d2e3497b7a4f4f4e2f0ea6a3133011300f0603551d130101ff040530030101ff300a06082a8648ce3d0403020348003045022100dcf991fd1801116dde15734987cf1126d8bb472b9783566f2b91dc0534f977d0022021cae1ce1c4e78407369bd78d03cec5df82327a72abc5bd98b39cbeec9f890ed").to_vec().try_into().unwrap(),
+			hex_literal::hex!("3082012a3081d0a003020102020105300a06082a8648ce3d04030230143112301006035504030c09546573742043412033301e170d3235303130313030303030305a170d3435303130313030303030305a30143112301006035504030c095465737420434120343059301306072a8648ce3d020106082a8648ce3d0301070342000429922c3f1e8e1a78e48997ecd58b805244143372fac79915d88388ddaa99a6937ae87e2f851425e5676e0eaea5a5d5c9eb8286c3b0af064180a3a13dc4cb9360a3133011300f0603551d130101ff040530030101ff300a06082a8648ce3d0403020349003046022100b470ee7dd0a8cf62ce704d3226f312fdd007e8ff3e17f9b8e8f35d066dca047802210087f05429235eaed5f6b7edd3da3e595e5ccb94bd4c859b018e64c4a28ed6f0c4").to_vec().try_into().unwrap(),
+			hex_literal::hex!("308201143081bba003020102020106300a06082a8648ce3d04030230143112301006035504030c09546573742043412034301e170d3235303130313030303030305a170d3435303130313030303030305a30143112301006035504030c0954657374204c6561663059301306072a8648ce3d020106082a8648ce3d03010703420004319a04767086c427510a0bcfb96c14c1b940c6762231ff6ef2156d69ef9e671f45fe96bffd6deb7f9853ff41b5e926a8b9dac12bf0e35e5849952e994b280ef8300a06082a8648ce3d04030203480030450220230d1e9dabbb924d7b05b981fd96d449aa5295027bc665af7cc1bb8afc33f86402210098c836a1cd6feb12cfbf4a2ccce1d9032c1ea97ddd5b8f368d85e95c236798a4").to_vec().try_into().unwrap(),
+		]
+		.to_vec()
+		.try_into()
+		.unwrap()
 	}
 
 	/// Synthetic per-schema parser tests. For each Android Keystore attestation
@@ -701,8 +881,8 @@ mod tests {
 
 		/// Wire-format `AuthorizationList` used to feed our selective parser.
 		/// Fields must be declared in ascending tag order (DER requirement).
-		/// Includes tags from the union of V1..V4..V100..V400 plus future tags
-		/// 723 / 724 / 9999 that our parser should silently ignore.
+		/// Includes tags from the union of V1..V4..V100..V400 plus the future
+		/// tag 9999 that our parser should silently ignore.
 		#[derive(Asn1Write, Default)]
 		struct AuthorizationListWire<'a> {
 			#[explicit(2)]
@@ -751,10 +931,10 @@ mod tests {
 			boot_patch_level: Option<i64>,
 			#[explicit(720)]
 			device_unique_attestation: Option<Null>,
-			/// Unknown to our parser (introduced in V200 — `attestationIdSecondImei`).
+			/// Introduced in V300 — `attestationIdSecondImei`.
 			#[explicit(723)]
 			attestation_id_second_imei: Option<&'a [u8]>,
-			/// Unknown to our parser (introduced in V400 — `moduleHash`).
+			/// Introduced in V400 — `moduleHash`.
 			#[explicit(724)]
 			module_hash: Option<&'a [u8]>,
 			/// Future tag Google has not assigned. Forward-compat smoke field.
@@ -1007,6 +1187,10 @@ mod tests {
 			let bkd = parse_kd(&bytes);
 			assert_modern_baseline(&bkd.tee_enforced);
 			assert_eq!(bkd.tee_enforced.usage_count_limit, Some(7));
+			assert_eq!(
+				bkd.tee_enforced.attestation_id_second_imei.map(|v| v.to_vec()),
+				Some(b"second-imei".to_vec())
+			);
 		}
 
 		#[test]
@@ -1017,6 +1201,11 @@ mod tests {
 			let bytes = asn1::write_single(&modern_kd(300, al)).unwrap();
 			let bkd = parse_kd(&bytes);
 			assert_modern_baseline(&bkd.tee_enforced);
+			assert_eq!(
+				bkd.tee_enforced.attestation_id_second_imei.map(|v| v.to_vec()),
+				Some(b"second-imei".to_vec())
+			);
+			assert_eq!(bkd.tee_enforced.module_hash, None);
 		}
 
 		#[test]
@@ -1028,6 +1217,23 @@ mod tests {
 			let bytes = asn1::write_single(&modern_kd(400, al)).unwrap();
 			let bkd = parse_kd(&bytes);
 			assert_modern_baseline(&bkd.tee_enforced);
+			assert_eq!(
+				bkd.tee_enforced.attestation_id_second_imei.map(|v| v.to_vec()),
+				Some(b"second-imei".to_vec())
+			);
+			assert_eq!(
+				bkd.tee_enforced.module_hash.map(|v| v.to_vec()),
+				Some(b"module-hash-bytes".to_vec())
+			);
+		}
+
+		#[test]
+		fn test_parse_schema_rejects_oversized_module_hash() {
+			let mut al = modern_authlist();
+			al.module_hash = Some(&[0u8; 33]);
+			let bytes = asn1::write_single(&modern_kd(400, al)).unwrap();
+			let kd = asn1::parse_single::<KeyDescription>(&bytes).expect("parse KeyDescription");
+			assert!(BoundedKeyDescription::try_from(kd).is_err());
 		}
 
 		#[test]
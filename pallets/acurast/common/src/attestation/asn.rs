@@ -460,6 +460,8 @@ pub struct AuthorizationList<'a> {
 	pub vendor_patch_level: Option<i64>,
 	pub boot_patch_level: Option<i64>,
 	pub device_unique_attestation: Option<Null>,
+	pub attestation_id_second_imei: Option<&'a [u8]>,
+	pub module_hash: Option<&'a [u8]>,
 }
 
 impl<'a> SimpleAsn1Readable<'a> for AuthorizationList<'a> {
@@ -510,6 +512,8 @@ impl<'a> SimpleAsn1Readable<'a> for AuthorizationList<'a> {
 			let vendor_patch_level_tag = Tag::from_bytes(&[0xBF, 0x85, 0x4E, 0x00])?.0;
 			let boot_patch_level_tag = Tag::from_bytes(&[0xBF, 0x85, 0x4F, 0x00])?.0;
 			let device_unique_attestation_tag = Tag::from_bytes(&[0xBF, 0x85, 0x50, 0x00])?.0;
+			let attestation_id_second_imei_tag = Tag::from_bytes(&[0xBF, 0x85, 0x53, 0x00])?.0;
+			let module_hash_tag = Tag::from_bytes(&[0xBF, 0x85, 0x54, 0x00])?.0;
 			let tlvs = try_parse_tags(
 				parser,
 				&[
@@ -553,6 +557,8 @@ impl<'a> SimpleAsn1Readable<'a> for AuthorizationList<'a> {
 					vendor_patch_level_tag,
 					boot_patch_level_tag,
 					device_unique_attestation_tag,
+					attestation_id_second_imei_tag,
+					module_hash_tag,
 				],
 			)?;
 			let pick = |tag: Tag| tlvs.iter().find(|tlv| tlv.tag() == tag);
@@ -717,6 +723,14 @@ impl<'a> SimpleAsn1Readable<'a> for AuthorizationList<'a> {
 					.map(Tlv::parse::<Explicit<'a, _, 720>>)
 					.transpose()?
 					.map(Explicit::into_inner),
+				attestation_id_second_imei: pick(attestation_id_second_imei_tag)
+					.map(Tlv::parse::<Explicit<'a, _, 723>>)
+					.transpose()?
+					.map(Explicit::into_inner),
+				module_hash: pick(module_hash_tag)
+					.map(Tlv::parse::<Explicit<'a, _, 724>>)
+					.transpose()?
+					.map(Explicit::into_inner),
 			})
 		})
 	}
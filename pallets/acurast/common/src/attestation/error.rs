@@ -50,6 +50,12 @@ pub enum ValidationError {
 	/// field in the sequence
 	/// [Certificate](https://www.rfc-editor.org/rfc/rfc5280#section-4.1.1.2).
 	SignatureMismatch,
+	/// No verifier is registered for the attestation report's format
+	UnsupportedAttestationFormat,
+	/// The attestation report is malformed or its signature is invalid
+	InvalidReport,
+	/// The enclave certifying the attestation report does not match the expected identity or is outdated
+	UntrustedQuotingEnclave,
 }
 
 impl From<ParseError> for ValidationError {
@@ -0,0 +1,340 @@
+use core::marker::PhantomData;
+
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use sha2::{Digest, Sha256};
+use sp_std::prelude::*;
+
+use super::{error::ValidationError, AttestationVerifier, ECDSACurve, PublicKey, VerifiedReport};
+use crate::{AttestationFormat, AttestationSecurityLevel, AttestationSummary, VerifiedBootState};
+
+const QUOTE_VERSION: u16 = 4;
+const ATTESTATION_KEY_TYPE_ECDSA_P256: u16 = 2;
+const TEE_TYPE_TDX: u32 = 0x81;
+const QE_REPORT_CERTIFICATION_DATA: u16 = 6;
+
+const HEADER_LENGTH: usize = 48;
+const BODY_LENGTH: usize = 584;
+const QE_REPORT_LENGTH: usize = 384;
+const SIGNATURE_LENGTH: usize = 64;
+const ATTESTATION_KEY_LENGTH: usize = 64;
+
+/// Offsets within the TD quote body.
+const TD_ATTRIBUTES_OFFSET: usize = 120;
+const MR_TD_OFFSET: usize = 136;
+const MR_TD_LENGTH: usize = 48;
+const REPORT_DATA_OFFSET: usize = 520;
+/// Offsets within the QE report.
+const QE_MR_SIGNER_OFFSET: usize = 128;
+const QE_MR_SIGNER_LENGTH: usize = 32;
+const QE_ISV_PROD_ID_OFFSET: usize = 256;
+const QE_ISV_SVN_OFFSET: usize = 258;
+const QE_REPORT_DATA_OFFSET: usize = 320;
+/// The debug flag in the first byte of the TD attributes.
+const TD_ATTRIBUTES_DEBUG: u8 = 0x01;
+
+/// The roots of trust of an [AttestationVerifier] generic over them.
+pub trait RootsOfTrust {
+	/// The DER encoded `SubjectPublicKeyInfo`s of the roots of trust.
+	const ROOTS_OF_TRUST: &'static [&'static [u8]];
+}
+
+/// The Intel SGX root CA, the root of the PCK certificate chains of TDX platforms.
+pub struct IntelSgxRootCa;
+
+impl RootsOfTrust for IntelSgxRootCa {
+	const ROOTS_OF_TRUST: &'static [&'static [u8]] = &[INTEL_SGX_ROOT_PUB_KEY];
+}
+
+const INTEL_SGX_ROOT_PUB_KEY: &[u8] = include_bytes!("../__root_key__/intel-sgx-root-public.key");
+
+/// The identity of Intel's TDX quoting enclave, as published in the QE identity of Intel's provisioning certification service
+/// (`/tdx/certification/v4/qe/identity`).
+const TD_QE_MR_SIGNER: [u8; QE_MR_SIGNER_LENGTH] = [
+	0xdc, 0x9e, 0x2a, 0x7c, 0x6f, 0x94, 0x8f, 0x17, 0x47, 0x4e, 0x34, 0xa7, 0xfc, 0x43, 0xed, 0x03,
+	0x0f, 0x7c, 0x15, 0x63, 0xf1, 0xba, 0xbd, 0xdf, 0x63, 0x40, 0xc8, 0x2e, 0x0e, 0x54, 0xa8, 0xc5,
+];
+const TD_QE_ISV_PROD_ID: u16 = 2;
+/// The lowest `ISVSVN` of the QE identity's TCB levels with status `UpToDate`.
+///
+/// Has to follow the QE identity whenever Intel issues a TCB recovery for the quoting enclave.
+const TD_QE_MIN_ISV_SVN: u16 = 4;
+
+/// Verifies Intel TDX quotes of version 4 signed with ECDSA P-256.
+///
+/// A quote is signed by an attestation key, which is certified by the quoting enclave (QE): the QE report binds the hash
+/// of the attestation key and is signed by the provisioning certification key (PCK). The PCK certificate chain, starting from
+/// the Intel SGX root CA, is submitted in DER form as the report's certificate chain.
+///
+/// The QE report has to match the identity of Intel's TDX quoting enclave (`MRSIGNER`, `ISVPRODID`) with an `ISVSVN` that is up to date.
+///
+/// The summary's measurement is `MRTD`, the boot state is only [VerifiedBootState::Verified] for trust domains not in debug mode.
+/// The TCB status of the platform, encoded in the SGX extensions of the PCK certificate, is not evaluated against Intel's TCB info yet,
+/// so the security level is reported as [AttestationSecurityLevel::Unknown] and barriers requiring a trusted environment reject the quotes.
+pub struct TdxQuoteVerifier<R = IntelSgxRootCa>(PhantomData<R>);
+
+impl<R: RootsOfTrust> AttestationVerifier for TdxQuoteVerifier<R> {
+	const FORMAT: AttestationFormat = AttestationFormat(*b"inteltdx");
+	const ROOTS_OF_TRUST: &'static [&'static [u8]] = R::ROOTS_OF_TRUST;
+
+	fn verify(report: &[u8], signer: &PublicKey) -> Result<VerifiedReport, ValidationError> {
+		let PublicKey::ECDSA(ECDSACurve::CurveP256(pck)) = signer else {
+			return Err(ValidationError::UnsupportedPublicKeyAlgorithm);
+		};
+
+		let mut reader = Reader(report);
+		let header = reader.take(HEADER_LENGTH)?;
+		if u16::from_le_bytes([header[0], header[1]]) != QUOTE_VERSION
+			|| u16::from_le_bytes([header[2], header[3]]) != ATTESTATION_KEY_TYPE_ECDSA_P256
+			|| u32::from_le_bytes([header[4], header[5], header[6], header[7]]) != TEE_TYPE_TDX
+		{
+			return Err(ValidationError::InvalidReport);
+		}
+		let body = reader.take(BODY_LENGTH)?;
+		let signed_quote = &report[..HEADER_LENGTH + BODY_LENGTH];
+
+		let mut signature_data = Reader(reader.take_u32_prefixed()?);
+		let quote_signature = signature_data.take(SIGNATURE_LENGTH)?;
+		let attestation_key = signature_data.take(ATTESTATION_KEY_LENGTH)?;
+		if signature_data.u16()? != QE_REPORT_CERTIFICATION_DATA {
+			return Err(ValidationError::InvalidReport);
+		}
+		let mut certification_data = Reader(signature_data.take_u32_prefixed()?);
+		let qe_report = certification_data.take(QE_REPORT_LENGTH)?;
+		let qe_report_signature = certification_data.take(SIGNATURE_LENGTH)?;
+		let qe_auth_data = certification_data.take_u16_prefixed()?;
+		// the remaining PCK certificate chain is submitted separately and already validated
+
+		// the PCK certifies the QE report ...
+		verify_p256(pck, qe_report, qe_report_signature)?;
+		// ... of Intel's quoting enclave ...
+		check_qe_identity(qe_report)?;
+		// ... which binds the attestation key ...
+		let mut hasher = Sha256::new();
+		hasher.update(attestation_key);
+		hasher.update(qe_auth_data);
+		let qe_report_data = &qe_report[QE_REPORT_DATA_OFFSET..];
+		if qe_report_data[..32] != hasher.finalize()[..]
+			|| qe_report_data[32..].iter().any(|b| *b != 0)
+		{
+			return Err(ValidationError::InvalidReport);
+		}
+		// ... which signs the quote
+		let mut encoded_attestation_key = [4u8; 1 + ATTESTATION_KEY_LENGTH];
+		encoded_attestation_key[1..].copy_from_slice(attestation_key);
+		let attestation_key = VerifyingKey::from_sec1_bytes(&encoded_attestation_key)
+			.map_err(|_| ValidationError::InvalidReport)?;
+		verify_p256(&attestation_key, signed_quote, quote_signature)?;
+
+		let debug = body[TD_ATTRIBUTES_OFFSET] & TD_ATTRIBUTES_DEBUG != 0;
+		Ok(VerifiedReport {
+			report_data: body[REPORT_DATA_OFFSET..].to_vec(),
+			summary: AttestationSummary {
+				security_level: AttestationSecurityLevel::Unknown,
+				verified_boot_state: if debug {
+					VerifiedBootState::Unverified
+				} else {
+					VerifiedBootState::Verified
+				},
+				measurement: body[MR_TD_OFFSET..MR_TD_OFFSET + MR_TD_LENGTH]
+					.to_vec()
+					.try_into()
+					.map_err(|_| ValidationError::InvalidReport)?,
+			},
+		})
+	}
+}
+
+/// Checks that the QE report was issued by an up to date version of Intel's TDX quoting enclave.
+fn check_qe_identity(qe_report: &[u8]) -> Result<(), ValidationError> {
+	let mr_signer = &qe_report[QE_MR_SIGNER_OFFSET..QE_MR_SIGNER_OFFSET + QE_MR_SIGNER_LENGTH];
+	let isv_prod_id = u16::from_le_bytes([
+		qe_report[QE_ISV_PROD_ID_OFFSET],
+		qe_report[QE_ISV_PROD_ID_OFFSET + 1],
+	]);
+	let isv_svn =
+		u16::from_le_bytes([qe_report[QE_ISV_SVN_OFFSET], qe_report[QE_ISV_SVN_OFFSET + 1]]);
+	if mr_signer != TD_QE_MR_SIGNER
+		|| isv_prod_id != TD_QE_ISV_PROD_ID
+		|| isv_svn < TD_QE_MIN_ISV_SVN
+	{
+		return Err(ValidationError::UntrustedQuotingEnclave);
+	}
+	Ok(())
+}
+
+/// Verifies a raw `r || s` ECDSA P-256 signature with SHA-256.
+fn verify_p256(
+	key: &VerifyingKey,
+	message: &[u8],
+	signature: &[u8],
+) -> Result<(), ValidationError> {
+	let signature = Signature::try_from(signature).map_err(|_| ValidationError::InvalidReport)?;
+	key.verify(message, &signature).map_err(|_| ValidationError::InvalidReport)
+}
+
+/// Reads the little endian encoded fields of a quote.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+	fn take(&mut self, length: usize) -> Result<&'a [u8], ValidationError> {
+		if self.0.len() < length {
+			return Err(ValidationError::InvalidReport);
+		}
+		let (taken, rest) = self.0.split_at(length);
+		self.0 = rest;
+		Ok(taken)
+	}
+
+	fn u16(&mut self) -> Result<u16, ValidationError> {
+		let bytes = self.take(2)?;
+		Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+	}
+
+	fn take_u16_prefixed(&mut self) -> Result<&'a [u8], ValidationError> {
+		let length = self.u16()?;
+		self.take(length as usize)
+	}
+
+	fn take_u32_prefixed(&mut self) -> Result<&'a [u8], ValidationError> {
+		let bytes = self.take(4)?;
+		let length = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+		self.take(length as usize)
+	}
+}
+
+/// Synthetic TDX quotes for tests and benchmarks.
+///
+/// The certificate chain is issued by a test root, not by Intel, so the quotes are only accepted by `TdxQuoteVerifier<TestTdxRoot>`.
+/// The quotes follow the layout of real quotes but carry made up measurements and QE authentication data. The QE report carries
+/// the identity of Intel's TDX quoting enclave, except for [UNKNOWN_QE_QUOTE] and [OUTDATED_QE_QUOTE].
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod fixtures {
+	use sp_std::prelude::*;
+
+	use super::{AttestationVerifier, RootsOfTrust, TdxQuoteVerifier};
+	use crate::{AttestationReport, CertificateChainInput};
+
+	/// The test root issuing [certificate_chain].
+	pub struct TestTdxRoot;
+
+	impl RootsOfTrust for TestTdxRoot {
+		const ROOTS_OF_TRUST: &'static [&'static [u8]] = &[TEST_ROOT_PUB_KEY];
+	}
+
+	/// The nonce bound to the report data of [QUOTE] and [DEBUG_QUOTE], followed by 32 zero bytes.
+	pub const NONCE: [u8; 32] =
+		hex_literal::hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f");
+	/// The `MRTD` of [QUOTE] and [DEBUG_QUOTE].
+	pub const MR_TD: [u8; 48] = [0x33; 48];
+
+	const TEST_ROOT_PUB_KEY: &[u8] = &hex_literal::hex!("3059301306072a8648ce3d020106082a8648ce3d0301070342000432fe00c3a62231b977724d1cbb9fc5f01726393a6fde9369c885f10147324cbbbc043f4fc53dfe002459e9bb615b1155f5e67b10d6a9e3b0ec0963839c68b959");
+	const TEST_ROOT_CERT: &[u8] = &hex_literal::hex!("308201673082010ca003020102020101300a06082a8648ce3d04030230323119301706035504030c10546573742054445820526f6f7420434131153013060355040a0c0c416375726173742054657374301e170d3230303130313030303030305a170d3439313233313030303030305a30323119301706035504030c10546573742054445820526f6f7420434131153013060355040a0c0c4163757261737420546573743059301306072a8648ce3d020106082a8648ce3d0301070342000432fe00c3a62231b977724d1cbb9fc5f01726393a6fde9369c885f10147324cbbbc043f4fc53dfe002459e9bb615b1155f5e67b10d6a9e3b0ec0963839c68b959a3133011300f0603551d130101ff040530030101ff300a06082a8648ce3d04030203490030460221009f423a2c45d5f9ddfc335ca6c18118b246b16c2acdd1ea96ac51326170349c04022100ed46b53e49d1a1216ef4a17411ac731a0c29a1a841a0f6fbaaab83989bd600c8");
+	const TEST_PLATFORM_CA_CERT: &[u8] = &hex_literal::hex!("3082016f30820114a003020102020102300a06082a8648ce3d04030230323119301706035504030c10546573742054445820526f6f7420434131153013060355040a0c0c416375726173742054657374301e170d3230303130313030303030305a170d3439313233313030303030305a303a3121301f06035504030c1854657374205444582050434b20506c6174666f726d20434131153013060355040a0c0c4163757261737420546573743059301306072a8648ce3d020106082a8648ce3d030107034200041a6c4c8664dc488717530dd37a49249ba3051d3bd04b03f742f234b466c7035d32e9a30ced96f07d2adb467a09c31ce335c0376e41a9d01c2c3b0188528299f9a3133011300f0603551d130101ff040530030101ff300a06082a8648ce3d0403020349003046022100af816e65acf58960c912b47efaf954a78ab6b9d7175725f4740d298cc5b020f8022100f52d746f1fd313fdd1dd3466dc43e871efbbc9a6a0375054feb4d1758881b975");
+	const TEST_PCK_CERT: &[u8] = &hex_literal::hex!("3082017230820119a003020102020103300a06082a8648ce3d040302303a3121301f06035504030c1854657374205444582050434b20506c6174666f726d20434131153013060355040a0c0c416375726173742054657374301e170d3230303130313030303030305a170d3439313233313030303030305a303a3121301f06035504030c1854657374205444582050434b20436572746966696361746531153013060355040a0c0c4163757261737420546573743059301306072a8648ce3d020106082a8648ce3d030107034200043821bd6500c81612bacda11e909cf8343dd0618b73c9d6be2a372b0b77ecde1a634022c3ff846226ac09065eb33cdc6380e92bd2a8a1cf903fc83748ea8bed09a310300e300c0603551d130101ff04023000300a06082a8648ce3d040302034700304402207b21edb385499c73b3eb96da08e4a24f58a784924f602f85dcd22d4bb1356f7e02207477d1e89f17c084893752d3c4d8548b4328baa6cdcaf121325bd3ed70dab35d");
+	/// A quote of a trust domain with the debug flag unset.
+	pub const QUOTE: &[u8] = &hex_literal::hex!("04000200810000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222000000000000000000000000000000000000000000000000333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f00000000000000000000000000000000000000000000000000000000000000006e020000cb4fe4f47b4d44183bb8be2f4e455cf45965512c09c8e21753affaa7d46fa97ec24cc70bc9e6d197e6d3700b3d157b93db223a32b88419a76b070f0a43e1deb95eb85f9c94bb3944c78ef9d95c41ddd32fefae2c8434db4d493278f21e3cd3cd3bdad402eb7142ca39fc65850240c7c1dc5c74b83f34e67dbb4d99b94cbe84760600e80100005555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555dc9e2a7c6f948f17474e34a7fc43ed030f7c1563f1babddf6340c82e0e54a8c555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555502000400555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555d01c81606283dfa6eae30cdcb3c03ac616d07dd12aa2c5d98bbedcce8d980e9500000000000000000000000000000000000000000000000000000000000000002f7255f432f7490e9a79522baa720117cecda280f3d1b6ba9082922ad5cdd5f386abf649e6830c0a2817885c13d0da08d1a1a77e8c54066e8bc66baff40ea87820004444444444444444444444444444444444444444444444444444444444444444050000000000");
+	/// A quote like [QUOTE], but of a trust domain in debug mode.
+	pub const DEBUG_QUOTE: &[u8] = &hex_literal::hex!("04000200810000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222000000000000000001000000000000000000000000000000333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f00000000000000000000000000000000000000000000000000000000000000006e0200008d60e2ab36100cc6c4cb2e78aff4c461635b640e8451231ea6cc9a29923563c74cc74819485e1e84fa8b65e308eb30cac4233de198dfed51a179ffdb94f181605eb85f9c94bb3944c78ef9d95c41ddd32fefae2c8434db4d493278f21e3cd3cd3bdad402eb7142ca39fc65850240c7c1dc5c74b83f34e67dbb4d99b94cbe84760600e80100005555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555dc9e2a7c6f948f17474e34a7fc43ed030f7c1563f1babddf6340c82e0e54a8c555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555502000400555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555d01c81606283dfa6eae30cdcb3c03ac616d07dd12aa2c5d98bbedcce8d980e950000000000000000000000000000000000000000000000000000000000000000761500e114efe1773e139cd01b1d983271a1fcd787a7a62eff1f68be44a8070e8c089e56bb575b52921f5120de1f499df481342fbd6d73b2fb09912fa64b76f220004444444444444444444444444444444444444444444444444444444444444444050000000000");
+
+	/// A quote like [QUOTE], but certified by a quoting enclave with a foreign `MRSIGNER`.
+	pub const UNKNOWN_QE_QUOTE: &[u8] = &hex_literal::hex!("04000200810000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222000000000000000000000000000000000000000000000000333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f00000000000000000000000000000000000000000000000000000000000000006e0200002c2a20d683e3a0ae4fc2b06377fffad2480cfc04a731a0412f116fa5c69ccab721348e6e837007c8a2c89e51b04120b046e18403c485b10ae3902dd9c504d5ae5eb85f9c94bb3944c78ef9d95c41ddd32fefae2c8434db4d493278f21e3cd3cd3bdad402eb7142ca39fc65850240c7c1dc5c74b83f34e67dbb4d99b94cbe84760600e80100005555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555502000400555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555d01c81606283dfa6eae30cdcb3c03ac616d07dd12aa2c5d98bbedcce8d980e950000000000000000000000000000000000000000000000000000000000000000af00ee3ccba6ac81e1567654927e00fabf1b8818a2c1693dd07a78426158c08873a04e1c3f1587f872192f25fbd22209cf813cb7296f0efb055ce4a0801f974020004444444444444444444444444444444444444444444444444444444444444444050000000000");
+	/// A quote like [QUOTE], but certified by a quoting enclave with an outdated `ISVSVN`.
+	pub const OUTDATED_QE_QUOTE: &[u8] = &hex_literal::hex!("04000200810000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222000000000000000000000000000000000000000000000000333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f00000000000000000000000000000000000000000000000000000000000000006e0200008e7a7a59019b8af6f15f23edfc9d11bfb63002567e87d14818b662621411ceba7c5edab6ed8ba059b116f5bc23d19f94ea5ca69f1263a039e31a04d913b25b3f5eb85f9c94bb3944c78ef9d95c41ddd32fefae2c8434db4d493278f21e3cd3cd3bdad402eb7142ca39fc65850240c7c1dc5c74b83f34e67dbb4d99b94cbe84760600e80100005555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555dc9e2a7c6f948f17474e34a7fc43ed030f7c1563f1babddf6340c82e0e54a8c555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555502000300555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555d01c81606283dfa6eae30cdcb3c03ac616d07dd12aa2c5d98bbedcce8d980e950000000000000000000000000000000000000000000000000000000000000000a35725d66a2acef2ab6191d0cce2302897cb20f946fe7318e14b37d4b72e4c43656636279765ca448088c462b377416b3c9ddd472d0f93b51ea8e8d0fab73cab20004444444444444444444444444444444444444444444444444444444444444444050000000000");
+
+	/// The DER encoded chain of the test root, a platform CA and the PCK certificate.
+	pub fn certificate_chain() -> CertificateChainInput {
+		vec![
+			TEST_ROOT_CERT.to_vec().try_into().unwrap(),
+			TEST_PLATFORM_CA_CERT.to_vec().try_into().unwrap(),
+			TEST_PCK_CERT.to_vec().try_into().unwrap(),
+		]
+		.try_into()
+		.unwrap()
+	}
+
+	/// Returns an [AttestationReport] of `quote` with [certificate_chain].
+	pub fn report(quote: &[u8]) -> AttestationReport {
+		AttestationReport {
+			format: TdxQuoteVerifier::<TestTdxRoot>::FORMAT,
+			report: quote.to_vec().try_into().unwrap(),
+			certificate_chain: certificate_chain(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{fixtures::*, *};
+	use crate::{attestation::verify_report, AttestationReport};
+
+	fn verify(report: &AttestationReport) -> Result<VerifiedReport, ValidationError> {
+		verify_report::<TdxQuoteVerifier<TestTdxRoot>>(report).map(|(_, _, verified)| verified)
+	}
+
+	#[test]
+	fn test_verify_quote() -> Result<(), ValidationError> {
+		let (cert_ids, _, verified) =
+			verify_report::<TdxQuoteVerifier<TestTdxRoot>>(&report(QUOTE))?;
+		assert_eq!(cert_ids.len(), 3);
+		assert_eq!(verified.report_data.len(), 64);
+		assert!(verified.report_data.starts_with(&NONCE));
+		assert_eq!(verified.summary.measurement.to_vec(), MR_TD.to_vec());
+		assert_eq!(verified.summary.verified_boot_state, VerifiedBootState::Verified);
+		// the platform's TCB status is not evaluated yet
+		assert_eq!(verified.summary.security_level, AttestationSecurityLevel::Unknown);
+		Ok(())
+	}
+
+	#[test]
+	fn test_verify_quote_untrusted_quoting_enclave() {
+		for quote in [UNKNOWN_QE_QUOTE, OUTDATED_QE_QUOTE] {
+			assert!(matches!(
+				verify(&report(quote)),
+				Err(ValidationError::UntrustedQuotingEnclave)
+			));
+		}
+	}
+
+	#[test]
+	fn test_verify_debug_quote() -> Result<(), ValidationError> {
+		let verified = verify(&report(DEBUG_QUOTE))?;
+		assert_eq!(verified.summary.verified_boot_state, VerifiedBootState::Unverified);
+		Ok(())
+	}
+
+	#[test]
+	fn test_verify_quote_untrusted_root() {
+		assert!(matches!(
+			verify_report::<TdxQuoteVerifier>(&report(QUOTE)),
+			Err(ValidationError::InvalidSignature)
+		));
+	}
+
+	#[test]
+	fn test_verify_tampered_quote() {
+		// report data, MRTD, attestation key and QE report
+		for offset in [HEADER_LENGTH + REPORT_DATA_OFFSET, HEADER_LENGTH + MR_TD_OFFSET, 700, 800] {
+			let mut quote = QUOTE.to_vec();
+			quote[offset] ^= 1;
+			assert!(
+				matches!(verify(&report(&quote)), Err(ValidationError::InvalidReport)),
+				"tampering at {offset} got accepted"
+			);
+		}
+	}
+
+	#[test]
+	fn test_verify_malformed_quote() {
+		for length in [0, HEADER_LENGTH, HEADER_LENGTH + BODY_LENGTH + 4, QUOTE.len() - 1] {
+			assert!(matches!(
+				verify(&report(&QUOTE[..length])),
+				Err(ValidationError::InvalidReport)
+			));
+		}
+
+		// SGX quotes are not accepted
+		let mut quote = QUOTE.to_vec();
+		quote[4] = 0;
+		assert!(matches!(verify(&report(&quote)), Err(ValidationError::InvalidReport)));
+	}
+}
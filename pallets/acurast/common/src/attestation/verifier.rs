@@ -0,0 +1,84 @@
+use sp_std::prelude::*;
+
+use super::{
+	asn::TBSCertificate, error::ValidationError, parse_root_public_keys,
+	validate_certificate_chain_with_roots, CertificateId, PublicKey,
+};
+use crate::{AttestationFormat, AttestationReport, AttestationSummary};
+
+/// Verifies attestation reports of one [AttestationFormat], e.g. AMD SEV-SNP attestation reports or Intel TDX quotes.
+pub trait AttestationVerifier {
+	/// The format of the reports verified.
+	const FORMAT: AttestationFormat;
+	/// The DER encoded `SubjectPublicKeyInfo`s of the roots of trust, one of them has to sign the report's certificate chain.
+	const ROOTS_OF_TRUST: &'static [&'static [u8]];
+
+	/// Verifies that `report` is signed by `signer`, the public key of the last certificate of the already validated chain,
+	/// and maps it into a [VerifiedReport].
+	fn verify(report: &[u8], signer: &PublicKey) -> Result<VerifiedReport, ValidationError>;
+}
+
+/// The result of verifying an attestation report.
+pub struct VerifiedReport {
+	/// The data bound to the report by the attested environment.
+	pub report_data: Vec<u8>,
+	pub summary: AttestationSummary,
+}
+
+/// A registry of [AttestationVerifier]s, implemented for `()` and for tuples of verifiers with distinct formats.
+pub trait AttestationVerifiers {
+	/// Verifies the report with the registered verifier of its format.
+	///
+	/// Returns the IDs of the certificates on the report's chain, the last certificate and the [VerifiedReport].
+	fn verify(
+		report: &AttestationReport,
+	) -> Result<(Vec<CertificateId>, TBSCertificate<'_>, VerifiedReport), ValidationError>;
+}
+
+impl AttestationVerifiers for () {
+	fn verify(
+		_report: &AttestationReport,
+	) -> Result<(Vec<CertificateId>, TBSCertificate<'_>, VerifiedReport), ValidationError> {
+		Err(ValidationError::UnsupportedAttestationFormat)
+	}
+}
+
+/// Verifies the report's certificate chain against the roots of trust of `V` and the report with `V`.
+pub fn verify_report<V: AttestationVerifier>(
+	report: &AttestationReport,
+) -> Result<(Vec<CertificateId>, TBSCertificate<'_>, VerifiedReport), ValidationError> {
+	if report.format != V::FORMAT {
+		return Err(ValidationError::UnsupportedAttestationFormat);
+	}
+	let trusted_roots = parse_root_public_keys(V::ROOTS_OF_TRUST)?;
+	let (cert_ids, cert, signer) =
+		validate_certificate_chain_with_roots(&report.certificate_chain, &trusted_roots)?;
+	let verified_report = V::verify(&report.report, &signer)?;
+	Ok((cert_ids, cert, verified_report))
+}
+
+macro_rules! impl_attestation_verifiers {
+	($($verifier:ident),+) => {
+		impl<$($verifier: AttestationVerifier),+> AttestationVerifiers for ($($verifier,)+) {
+			fn verify(
+				report: &AttestationReport,
+			) -> Result<(Vec<CertificateId>, TBSCertificate<'_>, VerifiedReport), ValidationError> {
+				$(
+					if report.format == $verifier::FORMAT {
+						return verify_report::<$verifier>(report);
+					}
+				)+
+				Err(ValidationError::UnsupportedAttestationFormat)
+			}
+		}
+	};
+}
+
+impl_attestation_verifiers!(A);
+impl_attestation_verifiers!(A, B);
+impl_attestation_verifiers!(A, B, C);
+impl_attestation_verifiers!(A, B, C, D);
+impl_attestation_verifiers!(A, B, C, D, E);
+impl_attestation_verifiers!(A, B, C, D, E, F);
+impl_attestation_verifiers!(A, B, C, D, E, F, G);
+impl_attestation_verifiers!(A, B, C, D, E, F, G, H);
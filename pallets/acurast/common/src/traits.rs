@@ -4,6 +4,7 @@ use frame_support::{
 		fungible::{Balanced, Imbalance, Inspect},
 		Get, IsType,
 	},
+	weights::Weight,
 };
 use sp_std::{fmt, prelude::*};
 
@@ -86,6 +87,11 @@ pub trait AttestationValidator<AccountId> {
 		attestation_chain: AttestationChain,
 		account: AccountId,
 	) -> DispatchResult;
+
+	/// The weight of [Self::validate] and [Self::validate_and_store] not covered by the benchmarks of the calling pallet.
+	fn validate_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub trait IsFundableCall<Call> {
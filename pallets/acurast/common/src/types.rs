@@ -211,6 +211,37 @@ pub type AllowedSourcesUpdate<AccountId> = ListUpdate<AccountId>;
 /// Structure used to updated the certificate recovation list.
 pub type CertificateRevocationListUpdate = ListUpdate<SerialNumber>;
 
+pub const ATTESTATION_ALLOWLIST_VALUE_MAX_LENGTH: u32 = 128;
+
+pub type AttestationAllowlistValue =
+	BoundedVec<u8, ConstU32<ATTESTATION_ALLOWLIST_VALUE_MAX_LENGTH>>;
+
+/// An entry of the attestation allowlist, identifying an attested processor app on its platform.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+)]
+pub enum AttestationAllowlistEntry {
+	/// Package name of an Android app.
+	PackageName(AttestationAllowlistValue),
+	/// Digest of an Android app's signing certificate.
+	SignatureDigest(AttestationAllowlistValue),
+	/// Bundle ID of an iOS app.
+	BundleId(AttestationAllowlistValue),
+	/// Measurement of an environment attested with an [AttestationReport].
+	Measurement(AttestationAllowlistValue),
+}
+
+/// Structure used to update the attestation allowlist.
+pub type AttestationAllowlistUpdate = ListUpdate<AttestationAllowlistEntry>;
+
 /// Structure representing a job registration.
 #[derive(
 	RuntimeDebug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq,
@@ -597,7 +628,9 @@ impl MinMetric {
 
 pub type MinMetrics = BoundedVec<MinMetric, ConstU32<METRICS_MAX_LENGTH>>;
 
-#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq)]
+#[derive(
+	RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq, Serialize, Deserialize,
+)]
 pub struct CU32<const T: u32>;
 impl<const T: u32> Get<u32> for CU32<T> {
 	fn get() -> u32 {
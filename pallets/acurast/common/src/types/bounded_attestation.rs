@@ -24,6 +24,9 @@ pub(crate) const MGF_DIGEST_MAX_LENGTH: u32 = 32;
 pub(crate) const VERIFIED_BOOT_KEY_MAX_LENGTH: u32 = 32;
 pub(crate) const VERIFIED_BOOT_HASH_MAX_LENGTH: u32 = 32;
 pub(crate) const ATTESTATION_ID_MAX_LENGTH: u32 = 256;
+pub(crate) const MODULE_HASH_MAX_LENGTH: u32 = 32;
+pub(crate) const REPORT_MAX_LENGTH: u32 = 8192;
+pub(crate) const MEASUREMENT_MAX_LENGTH: u32 = 64;
 pub(crate) const BOUNDED_SET_PROPERTY: u32 = 16;
 pub(crate) const PACKAGE_NAME_MAX_LENGTH: u32 = 128;
 pub(crate) const SIGNATURE_DIGEST_SET_MAX_LENGTH: u32 = 16;
@@ -35,12 +38,15 @@ pub type MgfDigest = BoundedVec<u8, ConstU32<MGF_DIGEST_MAX_LENGTH>>;
 pub type VerifiedBootKey = BoundedVec<u8, ConstU32<VERIFIED_BOOT_KEY_MAX_LENGTH>>;
 pub type VerifiedBootHash = BoundedVec<u8, ConstU32<VERIFIED_BOOT_HASH_MAX_LENGTH>>;
 pub type AttestationIdProperty = BoundedVec<u8, ConstU32<ATTESTATION_ID_MAX_LENGTH>>;
+pub type ModuleHash = BoundedVec<u8, ConstU32<MODULE_HASH_MAX_LENGTH>>;
 pub type CertId = (IssuerName, SerialNumber);
 pub type ValidatingCertIds = BoundedVec<CertId, ConstU32<CHAIN_MAX_LENGTH>>;
 pub type BoundedSetProperty = BoundedVec<CertId, ConstU32<BOUNDED_SET_PROPERTY>>;
 pub type PackageName = BoundedVec<u8, ConstU32<PACKAGE_NAME_MAX_LENGTH>>;
 pub type SignatureDigestSet = BoundedVec<Digest, ConstU32<SIGNATURE_DIGEST_SET_MAX_LENGTH>>;
 pub type PackageInfoSet = BoundedVec<BoundedAttestationPackageInfo, ConstU32<16>>;
+pub type ReportInput = BoundedVec<u8, ConstU32<REPORT_MAX_LENGTH>>;
+pub type Measurement = BoundedVec<u8, ConstU32<MEASUREMENT_MAX_LENGTH>>;
 
 /// Structure representing a submitted attestation chain.
 #[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
@@ -49,6 +55,34 @@ pub struct AttestationChain {
 	pub certificate_chain: CertificateChainInput,
 }
 
+/// Identifies the format of an [AttestationReport], such as an AMD SEV-SNP attestation report or an Intel TDX quote.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Serialize,
+	Deserialize,
+)]
+pub struct AttestationFormat(pub [u8; 8]);
+
+/// Structure representing a submitted TEE attestation report.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct AttestationReport {
+	/// The format of the report, selecting the [crate::AttestationVerifier] verifying it.
+	pub format: AttestationFormat,
+	/// The report as produced by the TEE.
+	pub report: ReportInput,
+	/// An ordered array of [CertificateInput]s describing a valid chain from the verifier's root of trust to the certificate of the key signing the report.
+	pub certificate_chain: CertificateChainInput,
+}
+
 /// Structure representing a stored attestation.
 #[derive(
 	RuntimeDebug,
@@ -87,6 +121,69 @@ pub struct AttestationValidity {
 	pub not_after: u64,
 }
 
+/// Structure representing a verified TEE attestation report.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Serialize,
+	Deserialize,
+)]
+pub struct BoundedAttestationReport {
+	pub format: AttestationFormat,
+	pub summary: AttestationSummary,
+}
+
+/// The normalized summary of an attestation report, mapped by the [crate::AttestationVerifier] of its format.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	Serialize,
+	Deserialize,
+)]
+pub struct AttestationSummary {
+	pub security_level: AttestationSecurityLevel,
+	pub verified_boot_state: VerifiedBootState,
+	/// The measurement of the attested environment, e.g. the launch digest of a confidential VM.
+	pub measurement: Measurement,
+}
+
+/// Structure tracking the submissions of a processor's attestations, kept when the attestation is refreshed or expires.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Serialize,
+	Deserialize,
+)]
+pub struct AttestationRecord {
+	/// The timestamp in milliseconds of the processor's first attestation.
+	pub first_attested_at: u64,
+	/// The timestamp in milliseconds of the processor's latest attestation, starting its re-attestation interval.
+	pub attested_at: u64,
+	/// Whether the expiry of the latest attestation was already announced.
+	pub expiring_notified: bool,
+}
+
 #[derive(
 	RuntimeDebug,
 	Encode,
@@ -103,6 +200,20 @@ pub struct AttestationValidity {
 pub enum BoundedAttestationContent {
 	KeyDescription(BoundedKeyDescription),
 	DeviceAttestation(BoundedDeviceAttestation),
+	Report(BoundedAttestationReport),
+}
+
+impl BoundedAttestationContent {
+	/// Returns the security level of the attested key or environment, `None` if the content does not provide one.
+	pub fn security_level(&self) -> Option<AttestationSecurityLevel> {
+		match self {
+			Self::KeyDescription(key_description) => {
+				Some(key_description.attestation_security_level.clone())
+			},
+			Self::DeviceAttestation(_) => None,
+			Self::Report(report) => Some(report.summary.security_level.clone()),
+		}
+	}
 }
 
 impl TryFrom<ParsedAttestation<'_>> for BoundedAttestationContent {
@@ -378,6 +489,8 @@ pub struct BoundedAuthorizationList {
 	pub vendor_patch_level: Option<u32>,
 	pub boot_patch_level: Option<u32>,
 	pub device_unique_attestation: Option<bool>,
+	pub attestation_id_second_imei: Option<AttestationIdProperty>,
+	pub module_hash: Option<ModuleHash>,
 }
 
 macro_rules! try_bound_set {
@@ -493,6 +606,16 @@ impl TryFrom<asn::AuthorizationList<'_>> for BoundedAuthorizationList {
 			vendor_patch_level: try_bound!(data.vendor_patch_level, u32)?,
 			boot_patch_level: try_bound!(data.boot_patch_level, u32)?,
 			device_unique_attestation: Some(data.device_unique_attestation.is_some()),
+			attestation_id_second_imei: data
+				.attestation_id_second_imei
+				.map(|v| AttestationIdProperty::try_from(v.to_vec()))
+				.map_or(Ok(None), |r| r.map(Some))
+				.map_err(|_| ())?,
+			module_hash: data
+				.module_hash
+				.map(|v| ModuleHash::try_from(v.to_vec()))
+				.map_or(Ok(None), |r| r.map(Some))
+				.map_err(|_| ())?,
 		})
 	}
 }
@@ -1,12 +1,12 @@
 #![allow(clippy::type_complexity)]
 
 use frame_benchmarking::{account, benchmarks, whitelist_account};
-use frame_support::{assert_ok, sp_runtime::traits::Get, BoundedVec};
+use frame_support::{assert_ok, sp_runtime::traits::Get, weights::WeightMeter, BoundedVec};
 use frame_system::RawOrigin;
 use hex_literal::hex;
 use sp_std::prelude::*;
 
-use acurast_common::{AttestationChain, JobRegistration, Script};
+use acurast_common::{AttestationChain, AttestationReport, JobRegistration, Script};
 
 use crate::{utils::validate_and_extract_attestation, Config, Pallet as Acurast};
 
@@ -22,6 +22,8 @@ pub const SCRIPT_BYTES: &[u8; 53] = b"ipfs://QmQgcdqZPsnnnuvBVQeQgTy3ccQknGbnRpH
 pub trait BenchmarkHelper<T: Config> {
 	fn registration_extra(instant_match: bool) -> T::RegistrationExtra;
 	fn funded_account(index: u32) -> T::AccountId;
+	/// Returns a report accepted by [Config::AttestationVerifiers] and the nonce its report data starts with.
+	fn attestation_report() -> (AttestationReport, [u8; 32]);
 }
 
 pub fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -161,6 +163,43 @@ benchmarks! {
 		).into());
 	}
 
+	submit_attestation_report {
+		set_timestamp::<T>(1657363915001);
+		let processor_account: T::AccountId = processor_account_id::<T>();
+		let (report, nonce) = T::BenchmarkHelper::attestation_report();
+		// the nonce of a precomputed report cannot be requested
+		<AttestationNonce<T>>::insert(&processor_account, nonce);
+	}: _(RawOrigin::Signed(processor_account.clone()), report)
+	verify {
+		assert_last_event::<T>(Event::AttestationStoredV2(
+			processor_account,
+		).into());
+	}
+
+	request_attestation_nonce {
+		let processor_account: T::AccountId = processor_account_id::<T>();
+	}: _(RawOrigin::Signed(processor_account.clone()))
+	verify {
+		assert!(<AttestationNonce<T>>::get(&processor_account).is_some());
+	}
+
+	check_attestation_expiry {
+		let x in 0 .. T::MaxAttestationExpiryChecks::get();
+		set_timestamp::<T>(1657363915001);
+		let attestation = validate_and_extract_attestation::<T>(&processor_account_id::<T>(), &attestation_chain()).unwrap();
+		for i in 0..x {
+			<StoredAttestation<T>>::insert(account::<T::AccountId>("processor", i, SEED), attestation.clone());
+		}
+		// every checked attestation is past its grace period and gets removed
+		set_timestamp::<T>(u64::MAX);
+		let mut meter = WeightMeter::new();
+	}: {
+		Acurast::<T>::check_attestation_expiry(&mut meter);
+	}
+	verify {
+		assert_eq!(<StoredAttestation<T>>::iter().count(), 0);
+	}
+
 	update_certificate_revocation_list {
 		let x in 1 .. T::MaxCertificateRevocationListUpdates::get();
 		set_timestamp::<T>(1000);
@@ -176,6 +215,23 @@ benchmarks! {
 		assert_last_event::<T>(Event::CertificateRevocationListUpdated.into());
 	}
 
+	update_attestation_allowlist {
+		let x in 1 .. T::MaxAttestationAllowlistUpdates::get();
+		let mut updates: Vec<AttestationAllowlistUpdate> = vec![];
+		for i in 0..x {
+			let mut package_name = b"com.acurast.attested.executor.".to_vec();
+			package_name.extend_from_slice(&i.to_be_bytes());
+			updates.push(AttestationAllowlistUpdate {
+				operation: ListUpdateOperation::Add,
+				item: AttestationAllowlistEntry::PackageName(package_name.try_into().unwrap()),
+			});
+		}
+		let updates: BoundedVec<AttestationAllowlistUpdate, T::MaxAttestationAllowlistUpdates> = updates.try_into().unwrap();
+	}: _(RawOrigin::Root, ProcessorType::Core, updates.clone())
+	verify {
+		assert_last_event::<T>(Event::AttestationAllowlistUpdated(ProcessorType::Core, updates).into());
+	}
+
 	set_environment {
 		let x in 1 .. T::MaxEnvVars::get();
 		set_timestamp::<T>(1000);
@@ -4,22 +4,28 @@ use frame_support::{
 	ensure,
 	sp_runtime::{BoundedVec, DispatchError},
 	traits::Get,
+	weights::{Weight, WeightMeter},
 };
+use parity_scale_codec::Encode;
 use sp_std::prelude::*;
 
 use acurast_common::{
-	is_valid_script, Attestation, AttestationChain, AttestationSecurityLevel, AttestationValidator,
-	EnsureAttested, JobId, JobIdSequence, Metrics, MinMetric, MinMetrics,
+	is_valid_script, Attestation, AttestationAllowlistEntry, AttestationChain, AttestationRecord,
+	AttestationReport, AttestationSecurityLevel, AttestationValidator, EnsureAttested, JobId,
+	JobIdSequence, Metrics, MinMetric, MinMetrics,
 };
 
 use crate::{
 	utils::{
-		ensure_not_expired, ensure_not_revoked, ensure_source_verified_and_security_level,
-		validate_and_extract_attestation,
+		attestation_refresh_deadline, ensure_not_expired, ensure_not_revoked,
+		ensure_source_verified_and_security_level, now, validate_and_extract_attestation,
+		validate_and_extract_attestation_report,
 	},
-	Config, EnvironmentFor, Error, Event, ExecutionEnvironment, JobHooks, JobRegistrationFor,
-	KeyAttestationBarrier, LocalJobIdSequence, Pallet, RequiredMinMetrics, StoredAttestation,
-	StoredJobRegistration,
+	AttestationAllowlist, AttestationAllowlistEntryCount, AttestationExpiryCursor,
+	AttestationNonce, CertificateAttestations, Config, EnvironmentFor, Error, Event,
+	ExecutionEnvironment, JobHooks, JobRegistrationFor, KeyAttestationBarrier, LocalJobIdSequence,
+	Pallet, PendingRevocations, ProcessorType, RequiredMinMetrics, StoredAttestation,
+	StoredAttestationRecord, StoredJobRegistration, WeightInfo,
 };
 
 impl<T: Config> Pallet<T> {
@@ -70,11 +76,18 @@ impl<T: Config> Pallet<T> {
 
 	pub fn deregister_for(job_id: JobId<T::AccountId>) -> DispatchResultWithPostInfo {
 		<T as Config>::JobHooks::deregister_hook(&job_id)?;
+		Self::remove_job_registration(job_id);
+		Ok(().into())
+	}
+
+	/// Removes a job registration without calling [`JobHooks::deregister_hook`].
+	///
+	/// Meant to be used by a [`Config::JobHooks`] implementation that already cleaned up its own state for the job.
+	pub fn remove_job_registration(job_id: JobId<T::AccountId>) {
 		Self::clear_environment_for(&job_id);
 		<StoredJobRegistration<T>>::remove(&job_id.0, job_id.1);
 		<RequiredMinMetrics<T>>::remove(&job_id);
 		Self::deposit_event(Event::JobRegistrationRemoved(job_id));
-		Ok(().into())
 	}
 
 	pub fn set_environment_for(
@@ -95,6 +108,188 @@ impl<T: Config> Pallet<T> {
 	pub fn clear_environment_for(job_id: &JobId<T::AccountId>) {
 		let _ = <ExecutionEnvironment<T>>::clear_prefix(job_id, T::MaxSlots::get(), None);
 	}
+
+	/// Returns the attestation allowlist entries of the given [ProcessorType]s.
+	pub fn attestation_allowlist_entries(
+		processor_types: &[ProcessorType],
+	) -> Vec<AttestationAllowlistEntry> {
+		processor_types
+			.iter()
+			.flat_map(|processor_type| <AttestationAllowlist<T>>::iter_key_prefix(processor_type))
+			.collect()
+	}
+
+	/// Inserts the [Config::InitialAttestationAllowlist] entries and returns the number of inserted entries.
+	///
+	/// Entries exceeding [Config::MaxAttestationAllowlistEntries] are skipped.
+	pub(crate) fn insert_initial_attestation_allowlist() -> u32 {
+		let mut inserted = 0u32;
+		for (processor_type, entry) in T::InitialAttestationAllowlist::get() {
+			if Self::add_attestation_allowlist_entry(processor_type, &entry).is_ok() {
+				inserted += 1;
+			}
+		}
+		inserted
+	}
+
+	/// Adds an entry to the attestation allowlist of `processor_type`, keeping [AttestationAllowlistEntryCount] up to date.
+	pub(crate) fn add_attestation_allowlist_entry(
+		processor_type: ProcessorType,
+		entry: &AttestationAllowlistEntry,
+	) -> Result<(), Error<T>> {
+		if <AttestationAllowlist<T>>::contains_key(processor_type, entry) {
+			return Ok(());
+		}
+		<AttestationAllowlistEntryCount<T>>::try_mutate(processor_type, |count| {
+			ensure!(
+				*count < T::MaxAttestationAllowlistEntries::get(),
+				Error::<T>::TooManyAttestationAllowlistEntries
+			);
+			*count += 1;
+			Ok::<(), Error<T>>(())
+		})?;
+		<AttestationAllowlist<T>>::insert(processor_type, entry, ());
+		Ok(())
+	}
+
+	/// Removes an entry from the attestation allowlist of `processor_type`, keeping [AttestationAllowlistEntryCount] up to date.
+	pub(crate) fn remove_attestation_allowlist_entry(
+		processor_type: ProcessorType,
+		entry: &AttestationAllowlistEntry,
+	) {
+		if <AttestationAllowlist<T>>::take(processor_type, entry).is_some() {
+			<AttestationAllowlistEntryCount<T>>::mutate(processor_type, |count| {
+				*count = count.saturating_sub(1)
+			});
+		}
+	}
+
+	/// Checks up to [Config::MaxAttestationExpiryChecks] stored attestations for expiry, continuing where the previous call stopped.
+	///
+	/// Fewer attestations are checked if the `meter` cannot cover their worst case weight, none if it cannot cover a single one.
+	/// Attestations past their refresh deadline are announced once with [Event::AttestationExpiring], attestations past the
+	/// [Config::ReattestationGracePeriod] are removed while their [AttestationRecord] is kept.
+	pub(crate) fn check_attestation_expiry(meter: &mut WeightMeter) {
+		let base_weight = <T as Config>::WeightInfo::check_attestation_expiry(0);
+		if !meter.can_consume(base_weight) {
+			return;
+		}
+		let check_weight =
+			<T as Config>::WeightInfo::check_attestation_expiry(1).saturating_sub(base_weight);
+		let max_checks = meter
+			.remaining()
+			.saturating_sub(base_weight)
+			.checked_div_per_component(&check_weight)
+			.unwrap_or(u64::MAX)
+			.min(T::MaxAttestationExpiryChecks::get() as u64) as usize;
+		if max_checks == 0 {
+			return;
+		}
+		let Ok(now) = now::<T>() else {
+			meter.consume(T::DbWeight::get().reads(1));
+			return;
+		};
+
+		let attestations: Vec<(T::AccountId, Attestation)> =
+			match <AttestationExpiryCursor<T>>::get() {
+				Some(cursor) => <StoredAttestation<T>>::iter_from(cursor.into_inner())
+					.take(max_checks)
+					.collect(),
+				None => <StoredAttestation<T>>::iter().take(max_checks).collect(),
+			};
+		meter.consume(<T as Config>::WeightInfo::check_attestation_expiry(
+			attestations.len() as u32
+		));
+		let cursor = if attestations.len() < max_checks {
+			None
+		} else {
+			attestations.last().and_then(|(account, _)| {
+				<StoredAttestation<T>>::hashed_key_for(account).try_into().ok()
+			})
+		};
+		<AttestationExpiryCursor<T>>::put(cursor);
+
+		for (account, attestation) in attestations {
+			let mut record = match <StoredAttestationRecord<T>>::get(&account) {
+				Some(record) => record,
+				None => {
+					// the attestation was submitted before re-attestation was enforced, start its interval now
+					let record = AttestationRecord {
+						first_attested_at: now,
+						attested_at: now,
+						expiring_notified: false,
+					};
+					<StoredAttestationRecord<T>>::insert(&account, record);
+					record
+				},
+			};
+			let deadline = attestation_refresh_deadline::<T>(&attestation, Some(&record));
+			let expires_at = deadline.saturating_add(T::ReattestationGracePeriod::get());
+			if now >= expires_at {
+				Self::remove_attestation(&account, &attestation);
+				Self::deposit_event(Event::AttestationExpired(account));
+			} else if now >= deadline && !record.expiring_notified {
+				record.expiring_notified = true;
+				<StoredAttestationRecord<T>>::insert(&account, record);
+				Self::deposit_event(Event::AttestationExpiring(account, expires_at));
+			}
+		}
+	}
+
+	/// Removes the stored attestations depending on [PendingRevocations], up to [Config::MaxRevocationPropagations] per call.
+	///
+	/// Every removed attestation is announced with [Event::AttestationRevoked] and passed to [JobHooks::attestation_revoked_hook].
+	pub(crate) fn propagate_revocations() -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		let mut remaining = T::MaxRevocationPropagations::get() as usize;
+		while remaining > 0 {
+			let Some(serial_number) = <PendingRevocations<T>>::iter_keys().next() else {
+				break;
+			};
+			let accounts: Vec<T::AccountId> =
+				<CertificateAttestations<T>>::iter_key_prefix(&serial_number)
+					.take(remaining)
+					.collect();
+			weight.saturating_accrue(T::DbWeight::get().reads(2));
+			if accounts.is_empty() {
+				<PendingRevocations<T>>::remove(&serial_number);
+				weight.saturating_accrue(T::DbWeight::get().writes(1));
+				remaining -= 1;
+				continue;
+			}
+			remaining -= accounts.len();
+			for account in accounts {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+				let Some(attestation) = <StoredAttestation<T>>::get(&account) else {
+					<CertificateAttestations<T>>::remove(&serial_number, &account);
+					weight.saturating_accrue(T::DbWeight::get().writes(1));
+					continue;
+				};
+				weight.saturating_accrue(Self::remove_attestation(&account, &attestation));
+				weight
+					.saturating_accrue(<T as Config>::JobHooks::attestation_revoked_hook(&account));
+				Self::deposit_event(Event::AttestationRevoked(account, serial_number.clone()));
+			}
+		}
+
+		weight
+	}
+
+	/// Removes the attestation of `account` together with its entries in [CertificateAttestations], keeping its [AttestationRecord].
+	fn remove_attestation(account: &T::AccountId, attestation: &Attestation) -> Weight {
+		<StoredAttestation<T>>::remove(account);
+		for (_, serial_number) in &attestation.cert_ids {
+			<CertificateAttestations<T>>::remove(serial_number, account);
+		}
+		T::DbWeight::get().writes(attestation.cert_ids.len() as u64 + 1)
+	}
+
+	/// Adds the certificates validating the attestation of `account` to [CertificateAttestations].
+	fn index_attestation(account: &T::AccountId, attestation: &Attestation) {
+		for (_, serial_number) in &attestation.cert_ids {
+			<CertificateAttestations<T>>::insert(serial_number, account, ());
+		}
+	}
 }
 
 impl<T: Config> EnsureAttested<T::AccountId> for Pallet<T> {
@@ -119,23 +314,76 @@ impl<T: Config> AttestationValidator<T::AccountId> for Pallet<T> {
 		);
 
 		let attestation = validate_and_extract_attestation::<T>(account, attestation_chain)?;
+		Self::ensure_attestation_accepted(account, &attestation)?;
+		Ok(attestation)
+	}
+
+	fn validate_and_store(
+		attestation_chain: AttestationChain,
+		account: T::AccountId,
+	) -> DispatchResult {
+		let attestation = Self::validate(&attestation_chain, &account)?;
+		Self::store_attestation(attestation, account)
+	}
+
+	fn validate_weight() -> Weight {
+		T::KeyAttestationBarrier::check_weight()
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Validates the attestation report submitted by `account` with the [Config::AttestationVerifiers] against the nonce requested by `account`.
+	pub(crate) fn validate_report(
+		report: &AttestationReport,
+		account: &T::AccountId,
+	) -> Result<Attestation, DispatchError> {
+		let nonce =
+			<AttestationNonce<T>>::get(account).ok_or(Error::<T>::AttestationNonceNotRequested)?;
+		let attestation = validate_and_extract_attestation_report::<T>(&nonce, report)?;
+		Self::ensure_attestation_accepted(account, &attestation)?;
+		Ok(attestation)
+	}
+
+	/// Returns a nonce for the next attestation report of `account`, unique per account and block.
+	pub(crate) fn next_attestation_nonce(account: &T::AccountId) -> [u8; 32] {
+		sp_io::hashing::blake2_256(
+			&(b"acurast-attestation-nonce", <frame_system::Pallet<T>>::parent_hash(), account)
+				.encode(),
+		)
+	}
 
-		if !T::KeyAttestationBarrier::accept_attestation_for_origin(account, &attestation) {
+	fn ensure_attestation_accepted(
+		account: &T::AccountId,
+		attestation: &Attestation,
+	) -> DispatchResult {
+		if !T::KeyAttestationBarrier::accept_attestation_for_origin(account, attestation) {
 			#[cfg(not(feature = "runtime-benchmarks"))]
 			return Err(Error::<T>::AttestationRejected.into());
 		}
 
-		ensure_not_expired::<T>(&attestation)?;
-		ensure_not_revoked::<T>(&attestation)?;
-		Ok(attestation)
+		ensure_not_expired::<T>(attestation)?;
+		ensure_not_revoked::<T>(attestation)?;
+		Ok(())
 	}
 
-	fn validate_and_store(
-		attestation_chain: AttestationChain,
+	/// Stores the validated attestation of `account`, replacing its previous attestation.
+	pub(crate) fn store_attestation(
+		attestation: Attestation,
 		account: T::AccountId,
 	) -> DispatchResult {
-		let attestation = Self::validate(&attestation_chain, &account)?;
+		let now = now::<T>()?;
+		if let Some(previous) = <StoredAttestation<T>>::get(&account) {
+			let _ = Self::remove_attestation(&account, &previous);
+		}
+		Self::index_attestation(&account, &attestation);
 		<StoredAttestation<T>>::insert(&account, attestation);
+		<StoredAttestationRecord<T>>::mutate(&account, |record| {
+			*record = Some(AttestationRecord {
+				first_attested_at: record.as_ref().map_or(now, |record| record.first_attested_at),
+				attested_at: now,
+				expiring_notified: false,
+			});
+		});
 		Self::deposit_event(Event::AttestationStoredV2(account));
 		Ok(())
 	}
@@ -40,6 +40,7 @@ pub mod pallet {
 		dispatch::DispatchResultWithPostInfo,
 		pallet_prelude::*,
 		traits::{EnsureOrigin, UnixTime},
+		weights::WeightMeter,
 		Blake2_128Concat, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
@@ -71,6 +72,8 @@ pub mod pallet {
 		type EnvValueMaxSize: Get<u32> + ParameterBound;
 		/// Barrier for submit_attestation extrinsic call.
 		type KeyAttestationBarrier: KeyAttestationBarrier<Self>;
+		/// The verifiers of the [AttestationReport] formats accepted by `submit_attestation_report`, e.g. a tuple of [AttestationVerifier]s.
+		type AttestationVerifiers: AttestationVerifiers;
 		/// Timestamp
 		type UnixTime: UnixTime;
 		/// Hooks used by tightly coupled subpallets.
@@ -81,6 +84,26 @@ pub mod pallet {
 		type MaxVersions: Get<u32> + ParameterBound;
 		/// Origin allowd to call update_* extrinsics
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The max number of updates in a single `update_attestation_allowlist` call.
+		#[pallet::constant]
+		type MaxAttestationAllowlistUpdates: Get<u32>;
+		/// The max number of attestation allowlist entries per [ProcessorType], bounding the entries loaded by the [Config::KeyAttestationBarrier].
+		#[pallet::constant]
+		type MaxAttestationAllowlistEntries: Get<u32>;
+		/// The attestation allowlist entries stored at genesis or when migrating an existing chain to the storage based allowlist.
+		type InitialAttestationAllowlist: Get<Vec<(ProcessorType, AttestationAllowlistEntry)>>;
+		/// The interval in milliseconds after which processors have to refresh their attestation by submitting a new one.
+		#[pallet::constant]
+		type ReattestationInterval: Get<u64>;
+		/// The period in milliseconds after an attestation's refresh deadline during which the attestation is still accepted.
+		#[pallet::constant]
+		type ReattestationGracePeriod: Get<u64>;
+		/// The max number of stored attestations checked for expiry per block, when the remaining block weight allows for it.
+		#[pallet::constant]
+		type MaxAttestationExpiryChecks: Get<u32>;
+		/// The max number of attestations invalidated per block when propagating certificate revocations.
+		#[pallet::constant]
+		type MaxRevocationPropagations: Get<u32>;
 		/// Weight Info for extrinsics. Needs to include weight of hooks called. The weights in this pallet or only correct when using the default hooks [()].
 		type WeightInfo: WeightInfo;
 
@@ -88,7 +111,7 @@ pub mod pallet {
 		type BenchmarkHelper: BenchmarkHelper<Self>;
 	}
 
-	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -129,6 +152,18 @@ pub mod pallet {
 	pub type StoredAttestation<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, Attestation>;
 
+	/// The [AttestationRecord]s of processors. They are stored by [AccountId] and kept when an attestation expires.
+	#[pallet::storage]
+	#[pallet::getter(fn stored_attestation_record)]
+	pub type StoredAttestationRecord<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, AttestationRecord>;
+
+	/// The raw storage key of the last [StoredAttestation] checked for expiry, `None` to start over with the first one.
+	#[pallet::storage]
+	#[pallet::getter(fn attestation_expiry_cursor)]
+	pub type AttestationExpiryCursor<T: Config> =
+		StorageValue<_, Option<BoundedVec<u8, ConstU32<80>>>, ValueQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn min_metrics)]
 	pub type RequiredMinMetrics<T: Config> =
@@ -140,11 +175,55 @@ pub mod pallet {
 	pub type StoredRevokedCertificate<T: Config> =
 		StorageMap<_, Blake2_128Concat, SerialNumber, ()>;
 
+	/// Reverse index of the certificates validating stored attestations as a map [SerialNumber] -> [AccountId] -> `()`.
+	#[pallet::storage]
+	#[pallet::getter(fn certificate_attestations)]
+	pub type CertificateAttestations<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, SerialNumber, Blake2_128Concat, T::AccountId, ()>;
+
+	/// Revoked certificates whose dependent attestations are still to be invalidated, as a map [SerialNumber] -> `()`.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_revocation)]
+	pub type PendingRevocations<T: Config> = StorageMap<_, Blake2_128Concat, SerialNumber, ()>;
+
+	/// The attestation allowlist as a map [ProcessorType] -> [AttestationAllowlistEntry] -> `()`, used by the [Config::KeyAttestationBarrier].
+	#[pallet::storage]
+	#[pallet::getter(fn attestation_allowlist)]
+	pub type AttestationAllowlist<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ProcessorType,
+		Blake2_128Concat,
+		AttestationAllowlistEntry,
+		(),
+	>;
+
+	/// The number of [AttestationAllowlist] entries per [ProcessorType], bounded by [Config::MaxAttestationAllowlistEntries].
+	#[pallet::storage]
+	#[pallet::getter(fn attestation_allowlist_entry_count)]
+	pub type AttestationAllowlistEntryCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, ProcessorType, u32, ValueQuery>;
+
+	/// The nonces requested with `request_attestation_nonce` as a map [AccountId] -> nonce, to be bound to the account's next attestation report.
+	#[pallet::storage]
+	#[pallet::getter(fn attestation_nonce)]
+	pub type AttestationNonce<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, [u8; 32]>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn v5_migration_state)]
 	pub type V5MigrationState<T: Config> =
 		StorageValue<_, Option<BoundedVec<u8, ConstU32<80>>>, ValueQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn v7_migration_state)]
+	pub type V7MigrationState<T: Config> =
+		StorageValue<_, Option<BoundedVec<u8, ConstU32<80>>>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn v8_migration_state)]
+	pub type V8MigrationState<T: Config> =
+		StorageValue<_, Option<BoundedVec<u8, ConstU32<80>>>, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	#[allow(clippy::large_enum_variant)]
@@ -179,6 +258,31 @@ pub mod pallet {
 		AttestationStoredV2(T::AccountId),
 		/// The execution environment has been updated. [job_id]
 		ExecutionEnvironmentsUpdatedV2(JobId<T::AccountId>),
+		/// The attestation allowlist has been updated. [processor_type, updates]
+		AttestationAllowlistUpdated(
+			ProcessorType,
+			BoundedVec<AttestationAllowlistUpdate, T::MaxAttestationAllowlistUpdates>,
+		),
+		/// An attestation passed its refresh deadline and expires at the end of the grace period unless refreshed. [who, expires_at]
+		AttestationExpiring(T::AccountId, u64),
+		/// An attestation expired and was removed. [who]
+		AttestationExpired(T::AccountId),
+		/// An attestation was removed since one of its certificates got revoked. [who, serial_number]
+		AttestationRevoked(T::AccountId, SerialNumber),
+		/// Migration started.
+		V7MigrationStarted,
+		/// Migration progressed. [migrations]
+		V7MigrationProgress(u32),
+		/// Migration completed.
+		V7MigrationCompleted,
+		/// Migration started.
+		V8MigrationStarted,
+		/// Migration progressed. [migrations]
+		V8MigrationProgress(u32),
+		/// Migration completed.
+		V8MigrationCompleted,
+		/// A nonce to bind to the report data of the next attestation report was requested. [who, nonce]
+		AttestationNonceRequested(T::AccountId, [u8; 32]),
 	}
 
 	#[pallet::error]
@@ -231,12 +335,64 @@ pub mod pallet {
 		JobHookFailed,
 		/// The min metrics list exceeded the max length.
 		TooManyMinMetrics,
+		/// The attestation is past its refresh deadline and grace period and has to be refreshed by submitting a new attestation.
+		ReattestationRequired,
+		/// No verifier is configured for the format of the submitted attestation report.
+		UnsupportedAttestationFormat,
+		/// The submitted attestation report is invalid.
+		AttestationReportVerificationFailed,
+		/// The data bound to the submitted attestation report does not start with the nonce requested by the source.
+		ReportDataDoesNotMatchNonce,
+		/// The attestation allowlist of the processor type already holds [Config::MaxAttestationAllowlistEntries] entries.
+		TooManyAttestationAllowlistEntries,
+		/// No nonce was requested with `request_attestation_nonce` before submitting the attestation report.
+		AttestationNonceNotRequested,
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		#[serde(skip)]
+		pub _phantom: PhantomData<T>,
+	}
+
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { _phantom: PhantomData }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			Pallet::<T>::insert_initial_attestation_allowlist();
+		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		fn on_initialize(_n: BlockNumberFor<T>) -> frame_support::weights::Weight {
-			crate::migration::migrate::<T>()
+			let weight =
+				crate::migration::migrate::<T>().saturating_add(T::DbWeight::get().reads(1));
+			// stored attestations cannot be decoded until they are migrated
+			if Self::on_chain_storage_version() < STORAGE_VERSION {
+				return weight;
+			}
+			weight.saturating_add(Self::propagate_revocations())
+		}
+
+		fn on_idle(
+			_n: BlockNumberFor<T>,
+			remaining_weight: frame_support::weights::Weight,
+		) -> frame_support::weights::Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			if meter.try_consume(T::DbWeight::get().reads(1)).is_err() {
+				return meter.consumed();
+			}
+			// stored attestations cannot be decoded until they are migrated
+			if Self::on_chain_storage_version() >= STORAGE_VERSION {
+				Self::check_attestation_expiry(&mut meter);
+			}
+			meter.consumed()
 		}
 	}
 
@@ -340,10 +496,11 @@ pub mod pallet {
 		/// - As input a list of binary certificates is expected.
 		/// - The list must be ordered, starting from one of the known [trusted root certificates](https://developer.android.com/training/articles/security-key-attestation#root_certificate).
 		/// - If the represented chain is valid, the [Attestation] details are stored. An existing attestion for signing account gets overwritten.
+		/// - Submitting a new attestation refreshes an expiring or expired attestation and restarts the [Config::ReattestationInterval].
 		///
 		/// Revocation: Each atttestation is stored with the unique IDs of the certificates on the chain proofing the attestation's validity.
 		#[pallet::call_index(5)]
-		#[pallet::weight(< T as Config >::WeightInfo::submit_attestation())]
+		#[pallet::weight(< T as Config >::WeightInfo::submit_attestation().saturating_add(T::KeyAttestationBarrier::check_weight()))]
 		pub fn submit_attestation(
 			origin: OriginFor<T>,
 			attestation_chain: AttestationChain,
@@ -358,6 +515,9 @@ pub mod pallet {
 		/// Updates the certificate revocation list by adding or removing a revoked certificate serial number. Attestations signed
 		/// by a revoked certificate will not be considered valid anymore. The `RevocationListUpdateBarrier` configured in [Config] can be used to
 		/// customize who can execute this action.
+		///
+		/// The stored attestations depending on a revoked certificate are removed in batches of [Config::MaxRevocationPropagations] per block,
+		/// removing a certificate from the list stops the propagation but does not restore already removed attestations.
 		#[pallet::weight(<T as Config>::WeightInfo::update_certificate_revocation_list(updates.len() as u32))]
 		#[pallet::call_index(6)]
 		pub fn update_certificate_revocation_list(
@@ -373,9 +533,16 @@ pub mod pallet {
 				match &update.operation {
 					ListUpdateOperation::Add => {
 						<StoredRevokedCertificate<T>>::insert(&update.item, ());
+						if <CertificateAttestations<T>>::iter_key_prefix(&update.item)
+							.next()
+							.is_some()
+						{
+							<PendingRevocations<T>>::insert(&update.item, ());
+						}
 					},
 					ListUpdateOperation::Remove => {
 						<StoredRevokedCertificate<T>>::remove(&update.item);
+						<PendingRevocations<T>>::remove(&update.item);
 					},
 				}
 			}
@@ -417,5 +584,67 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Updates the attestation allowlist of the given [ProcessorType] by adding or removing package names, signature digests, bundle IDs or measurements.
+		/// The allowlist is checked by the [Config::KeyAttestationBarrier] when accepting attestations and checking their processor type.
+		#[pallet::weight(<T as Config>::WeightInfo::update_attestation_allowlist(updates.len() as u32))]
+		#[pallet::call_index(10)]
+		pub fn update_attestation_allowlist(
+			origin: OriginFor<T>,
+			processor_type: ProcessorType,
+			updates: BoundedVec<AttestationAllowlistUpdate, T::MaxAttestationAllowlistUpdates>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			for update in &updates {
+				match &update.operation {
+					ListUpdateOperation::Add => {
+						Self::add_attestation_allowlist_entry(processor_type, &update.item)?;
+					},
+					ListUpdateOperation::Remove => {
+						Self::remove_attestation_allowlist_entry(processor_type, &update.item);
+					},
+				}
+			}
+			Self::deposit_event(Event::AttestationAllowlistUpdated(processor_type, updates));
+			Ok(().into())
+		}
+
+		/// Submits a TEE attestation report in one of the formats verified by [Config::AttestationVerifiers].
+		///
+		/// - The report's certificate chain has to start from a root of trust of the verifier of the report's format.
+		/// - The data bound to the report has to start with the nonce last requested by the signer with `request_attestation_nonce`.
+		///   The nonce is consumed, so that a report cannot be submitted twice.
+		/// - The stored attestation is treated like one submitted with `submit_attestation`, including revocation and re-attestation.
+		#[pallet::call_index(11)]
+		#[pallet::weight(< T as Config >::WeightInfo::submit_attestation_report().saturating_add(T::KeyAttestationBarrier::check_weight()))]
+		pub fn submit_attestation_report(
+			origin: OriginFor<T>,
+			report: AttestationReport,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let attestation = Self::validate_report(&report, &who)?;
+			<AttestationNonce<T>>::remove(&who);
+			Self::store_attestation(attestation, who)?;
+
+			Ok(().into())
+		}
+
+		/// Requests a nonce to bind to the report data of the next attestation report submitted with `submit_attestation_report`.
+		///
+		/// The nonce is derived from the parent block hash, so a report binding it was produced after the nonce got requested.
+		/// Requesting a new nonce replaces the previous one.
+		#[pallet::call_index(12)]
+		#[pallet::weight(< T as Config >::WeightInfo::request_attestation_nonce())]
+		pub fn request_attestation_nonce(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let nonce = Self::next_attestation_nonce(&who);
+			<AttestationNonce<T>>::insert(&who, nonce);
+			Self::deposit_event(Event::AttestationNonceRequested(who, nonce));
+
+			Ok(().into())
+		}
 	}
 }
@@ -1,4 +1,5 @@
 use frame_support::{
+	storage::unhashed,
 	traits::{GetStorageVersion, StorageVersion},
 	weights::{Weight, WeightMeter},
 	IterableStorageMap,
@@ -7,8 +8,9 @@ use sp_core::Get;
 
 use super::*;
 
-mod v4 {
-	use acurast_common::{AttestationValidity, BoundedKeyDescription, ValidatingCertIds};
+pub(crate) mod v4 {
+	use super::v6::BoundedKeyDescription;
+	use acurast_common::{AttestationValidity, ValidatingCertIds};
 	use frame_support::pallet_prelude::*;
 	use sp_std::prelude::*;
 
@@ -20,14 +22,177 @@ mod v4 {
 	}
 }
 
+pub(crate) mod v6 {
+	use acurast_common::{
+		AttestationIdProperty, AttestationSecurityLevel, AttestationValidity,
+		BoundedAttestationApplicationId, BoundedDeviceAttestation, BoundedRootOfTrust, Digest,
+		MgfDigest, Padding, Purpose, ValidatingCertIds,
+	};
+	use frame_support::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+	pub struct Attestation {
+		pub cert_ids: ValidatingCertIds,
+		pub content: BoundedAttestationContent,
+		pub validity: AttestationValidity,
+	}
+
+	#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+	#[allow(clippy::large_enum_variant)]
+	pub enum BoundedAttestationContent {
+		KeyDescription(BoundedKeyDescription),
+		DeviceAttestation(BoundedDeviceAttestation),
+	}
+
+	#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+	pub struct BoundedKeyDescription {
+		pub attestation_security_level: AttestationSecurityLevel,
+		pub key_mint_security_level: AttestationSecurityLevel,
+		pub software_enforced: BoundedAuthorizationList,
+		pub tee_enforced: BoundedAuthorizationList,
+	}
+
+	/// The authorization list before the KeyMint v300/v400 tags were added.
+	#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+	pub struct BoundedAuthorizationList {
+		pub purpose: Option<Purpose>,
+		pub algorithm: Option<u8>,
+		pub key_size: Option<u16>,
+		pub digest: Option<Digest>,
+		pub padding: Option<Padding>,
+		pub ec_curve: Option<u8>,
+		pub rsa_public_exponent: Option<u64>,
+		pub mgf_digest: Option<MgfDigest>,
+		pub rollback_resistance: Option<bool>,
+		pub early_boot_only: Option<bool>,
+		pub active_date_time: Option<u64>,
+		pub origination_expire_date_time: Option<u64>,
+		pub usage_expire_date_time: Option<u64>,
+		pub usage_count_limit: Option<u64>,
+		pub no_auth_required: bool,
+		pub user_auth_type: Option<u8>,
+		pub auth_timeout: Option<u32>,
+		pub allow_while_on_body: bool,
+		pub trusted_user_presence_required: Option<bool>,
+		pub trusted_confirmation_required: Option<bool>,
+		pub unlocked_device_required: Option<bool>,
+		pub all_applications: Option<bool>,
+		pub application_id: Option<AttestationIdProperty>,
+		pub creation_date_time: Option<u64>,
+		pub origin: Option<u8>,
+		pub root_of_trust: Option<BoundedRootOfTrust>,
+		pub os_version: Option<u32>,
+		pub os_patch_level: Option<u32>,
+		pub attestation_application_id: Option<BoundedAttestationApplicationId>,
+		pub attestation_id_brand: Option<AttestationIdProperty>,
+		pub attestation_id_device: Option<AttestationIdProperty>,
+		pub attestation_id_product: Option<AttestationIdProperty>,
+		pub attestation_id_serial: Option<AttestationIdProperty>,
+		pub attestation_id_imei: Option<AttestationIdProperty>,
+		pub attestation_id_meid: Option<AttestationIdProperty>,
+		pub attestation_id_manufacturer: Option<AttestationIdProperty>,
+		pub attestation_id_model: Option<AttestationIdProperty>,
+		pub vendor_patch_level: Option<u32>,
+		pub boot_patch_level: Option<u32>,
+		pub device_unique_attestation: Option<bool>,
+	}
+
+	impl Attestation {
+		pub fn migrate(self) -> acurast_common::Attestation {
+			acurast_common::Attestation {
+				cert_ids: self.cert_ids,
+				content: match self.content {
+					BoundedAttestationContent::KeyDescription(key_description) => {
+						acurast_common::BoundedAttestationContent::KeyDescription(
+							acurast_common::BoundedKeyDescription {
+								attestation_security_level: key_description
+									.attestation_security_level,
+								key_mint_security_level: key_description.key_mint_security_level,
+								software_enforced: key_description.software_enforced.migrate(),
+								tee_enforced: key_description.tee_enforced.migrate(),
+							},
+						)
+					},
+					BoundedAttestationContent::DeviceAttestation(device_attestation) => {
+						acurast_common::BoundedAttestationContent::DeviceAttestation(
+							device_attestation,
+						)
+					},
+				},
+				validity: self.validity,
+			}
+		}
+	}
+
+	impl BoundedAuthorizationList {
+		fn migrate(self) -> acurast_common::BoundedAuthorizationList {
+			acurast_common::BoundedAuthorizationList {
+				purpose: self.purpose,
+				algorithm: self.algorithm,
+				key_size: self.key_size,
+				digest: self.digest,
+				padding: self.padding,
+				ec_curve: self.ec_curve,
+				rsa_public_exponent: self.rsa_public_exponent,
+				mgf_digest: self.mgf_digest,
+				rollback_resistance: self.rollback_resistance,
+				early_boot_only: self.early_boot_only,
+				active_date_time: self.active_date_time,
+				origination_expire_date_time: self.origination_expire_date_time,
+				usage_expire_date_time: self.usage_expire_date_time,
+				usage_count_limit: self.usage_count_limit,
+				no_auth_required: self.no_auth_required,
+				user_auth_type: self.user_auth_type,
+				auth_timeout: self.auth_timeout,
+				allow_while_on_body: self.allow_while_on_body,
+				trusted_user_presence_required: self.trusted_user_presence_required,
+				trusted_confirmation_required: self.trusted_confirmation_required,
+				unlocked_device_required: self.unlocked_device_required,
+				all_applications: self.all_applications,
+				application_id: self.application_id,
+				creation_date_time: self.creation_date_time,
+				origin: self.origin,
+				root_of_trust: self.root_of_trust,
+				os_version: self.os_version,
+				os_patch_level: self.os_patch_level,
+				attestation_application_id: self.attestation_application_id,
+				attestation_id_brand: self.attestation_id_brand,
+				attestation_id_device: self.attestation_id_device,
+				attestation_id_product: self.attestation_id_product,
+				attestation_id_serial: self.attestation_id_serial,
+				attestation_id_imei: self.attestation_id_imei,
+				attestation_id_meid: self.attestation_id_meid,
+				attestation_id_manufacturer: self.attestation_id_manufacturer,
+				attestation_id_model: self.attestation_id_model,
+				vendor_patch_level: self.vendor_patch_level,
+				boot_patch_level: self.boot_patch_level,
+				device_unique_attestation: self.device_unique_attestation,
+				attestation_id_second_imei: None,
+				module_hash: None,
+			}
+		}
+	}
+}
+
 pub fn migrate<T: Config>() -> Weight {
-	let migrations: [(u16, &dyn Fn(Weight) -> Weight); 1] = [(5, &migrate_to_v5::<T>)];
+	let migrations: [(u16, &dyn Fn(Weight) -> Weight); 4] = [
+		(5, &migrate_to_v5::<T>),
+		(6, &migrate_to_v6::<T>),
+		(7, &migrate_to_v7::<T>),
+		(8, &migrate_to_v8::<T>),
+	];
 
 	let on_chain_version = Pallet::<T>::on_chain_storage_version();
 	let mut weight: Weight = T::DbWeight::get().reads(1);
 	for (i, f) in migrations.into_iter() {
 		if on_chain_version < StorageVersion::new(i) {
 			weight += f(weight);
+			// the v5, v7 and v8 migrations span multiple blocks, later migrations have to wait for them to complete
+			weight += T::DbWeight::get().reads(1);
+			if Pallet::<T>::on_chain_storage_version() < StorageVersion::new(i) {
+				break;
+			}
 		}
 	}
 
@@ -44,6 +209,10 @@ fn migrate_to_v5<T: Config>(weight: Weight) -> Weight {
 	if cursor.is_none() {
 		crate::Pallet::<T>::deposit_event(Event::<T>::V5MigrationStarted);
 	}
+	let mut keys = match &cursor {
+		Some(cursor) => StoredAttestation::<T>::iter_keys_from(cursor.clone().into_inner()),
+		None => StoredAttestation::<T>::iter_keys(),
+	};
 	let mut migrated_items: u32 = 0;
 	loop {
 		// check if current iteration would go over weight
@@ -52,26 +221,126 @@ fn migrate_to_v5<T: Config>(weight: Weight) -> Weight {
 			V5MigrationState::<T>::put(cursor);
 			break;
 		}
-		// Update storage
-		cursor = StoredAttestation::<T>::translate_next::<v4::Attestation, _>(
-			cursor.map(|v| v.to_vec()),
-			|_, old_value| {
-				Some(Attestation {
+		// Check if the migration is complete
+		let Some(account) = keys.next() else {
+			crate::Pallet::<T>::deposit_event(Event::<T>::V5MigrationProgress(migrated_items));
+			StorageVersion::new(5).put::<Pallet<T>>();
+			crate::Pallet::<T>::deposit_event(Event::<T>::V5MigrationCompleted);
+			V5MigrationState::<T>::kill();
+			break;
+		};
+		// Update storage, keeping the v6 layout that is migrated further by `migrate_to_v7`
+		let key = StoredAttestation::<T>::hashed_key_for(&account);
+		if let Some(old_value) = unhashed::get::<v4::Attestation>(&key) {
+			unhashed::put(
+				&key,
+				&v6::Attestation {
 					cert_ids: old_value.cert_ids,
-					content: BoundedAttestationContent::KeyDescription(old_value.key_description),
+					content: v6::BoundedAttestationContent::KeyDescription(
+						old_value.key_description,
+					),
 					validity: old_value.validity,
-				})
-			},
+				},
+			);
+		}
+		cursor = Some(key.try_into().unwrap());
+		migrated_items = migrated_items.saturating_add(1);
+	}
+
+	meter.consumed()
+}
+
+/// Moves the attestation allowlist from the runtime configuration to storage.
+fn migrate_to_v6<T: Config>(_weight: Weight) -> Weight {
+	let inserted = Pallet::<T>::insert_initial_attestation_allowlist();
+	StorageVersion::new(6).put::<Pallet<T>>();
+
+	T::DbWeight::get().writes(inserted as u64 + 1)
+}
+
+/// Adds the KeyMint v300/v400 authorization list tags to stored attestations.
+fn migrate_to_v7<T: Config>(weight: Weight) -> Weight {
+	let weights = T::BlockWeights::get();
+	let mut meter = WeightMeter::with_limit(
+		weights.max_block.saturating_sub(weights.base_block).saturating_sub(weight),
+	);
+	let mut cursor = V7MigrationState::<T>::get();
+	meter.consume(T::DbWeight::get().reads_writes(1, 2));
+	if cursor.is_none() {
+		crate::Pallet::<T>::deposit_event(Event::<T>::V7MigrationStarted);
+	}
+	let mut migrated_items: u32 = 0;
+	loop {
+		// check if current iteration would go over weight
+		if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+			crate::Pallet::<T>::deposit_event(Event::<T>::V7MigrationProgress(migrated_items));
+			V7MigrationState::<T>::put(cursor);
+			break;
+		}
+		// Update storage
+		cursor = StoredAttestation::<T>::translate_next::<v6::Attestation, _>(
+			cursor.map(|v| v.to_vec()),
+			|_, old_value| Some(old_value.migrate()),
 		)
 		.map(|cursor| cursor.try_into().unwrap());
 		// Check if the migration is complete
 		if cursor.is_none() {
-			crate::Pallet::<T>::deposit_event(Event::<T>::V5MigrationProgress(migrated_items));
-			STORAGE_VERSION.put::<Pallet<T>>();
-			crate::Pallet::<T>::deposit_event(Event::<T>::V5MigrationCompleted);
-			V5MigrationState::<T>::kill();
+			crate::Pallet::<T>::deposit_event(Event::<T>::V7MigrationProgress(migrated_items));
+			StorageVersion::new(7).put::<Pallet<T>>();
+			crate::Pallet::<T>::deposit_event(Event::<T>::V7MigrationCompleted);
+			V7MigrationState::<T>::kill();
+			break;
+		}
+		migrated_items = migrated_items.saturating_add(1);
+	}
+
+	meter.consumed()
+}
+
+/// Indexes the certificates validating stored attestations in [CertificateAttestations].
+///
+/// Certificates that were revoked before they got indexed are added to [PendingRevocations].
+fn migrate_to_v8<T: Config>(weight: Weight) -> Weight {
+	let weights = T::BlockWeights::get();
+	let mut meter = WeightMeter::with_limit(
+		weights.max_block.saturating_sub(weights.base_block).saturating_sub(weight),
+	);
+	let mut cursor = V8MigrationState::<T>::get();
+	meter.consume(T::DbWeight::get().reads_writes(1, 2));
+	if cursor.is_none() {
+		crate::Pallet::<T>::deposit_event(Event::<T>::V8MigrationStarted);
+	}
+	let mut iter = match &cursor {
+		Some(cursor) => StoredAttestation::<T>::iter_from(cursor.clone().into_inner()),
+		None => StoredAttestation::<T>::iter(),
+	};
+	let max_cert_ids = ValidatingCertIds::bound() as u64;
+	let mut migrated_items: u32 = 0;
+	loop {
+		// check if current iteration would go over weight
+		if meter
+			.try_consume(T::DbWeight::get().reads_writes(1 + max_cert_ids, 2 * max_cert_ids))
+			.is_err()
+		{
+			crate::Pallet::<T>::deposit_event(Event::<T>::V8MigrationProgress(migrated_items));
+			V8MigrationState::<T>::put(cursor);
+			break;
+		}
+		// Update storage
+		let Some((account, attestation)) = iter.next() else {
+			crate::Pallet::<T>::deposit_event(Event::<T>::V8MigrationProgress(migrated_items));
+			StorageVersion::new(8).put::<Pallet<T>>();
+			crate::Pallet::<T>::deposit_event(Event::<T>::V8MigrationCompleted);
+			V8MigrationState::<T>::kill();
 			break;
+		};
+		for (_, serial_number) in &attestation.cert_ids {
+			CertificateAttestations::<T>::insert(serial_number, &account, ());
+			if StoredRevokedCertificate::<T>::contains_key(serial_number) {
+				PendingRevocations::<T>::insert(serial_number, ());
+			}
 		}
+		cursor = Some(iter.last_raw_key().to_vec().try_into().unwrap());
 		migrated_items = migrated_items.saturating_add(1);
 	}
 
@@ -1,7 +1,11 @@
 #[cfg(feature = "runtime-benchmarks")]
 use crate::benchmarking::BenchmarkHelper;
 use crate::{AttestationChain, JobRegistration, Script, SerialNumber};
-use acurast_common::{AllowedSources, JobModules, Schedule, CU32};
+use acurast_common::{
+	error::ValidationError, tdx::fixtures::TestTdxRoot, AllowedSources, AttestationFormat,
+	AttestationReport, AttestationSecurityLevel, AttestationSummary, AttestationVerifier,
+	JobModules, PublicKey, Schedule, TdxQuoteVerifier, VerifiedBootState, VerifiedReport, CU32,
+};
 #[cfg(feature = "runtime-benchmarks")]
 use frame_support::traits::fungible;
 use frame_support::{
@@ -136,12 +140,20 @@ impl crate::Config for Test {
 	type RegistrationExtra = ();
 	type MaxAllowedSources = MaxAllowedSources;
 	type MaxCertificateRevocationListUpdates = ConstU32<10>;
+	type MaxAttestationAllowlistUpdates = ConstU32<10>;
+	type MaxAttestationAllowlistEntries = ConstU32<32>;
+	type InitialAttestationAllowlist = ();
+	type ReattestationInterval = ConstU64<2_592_000_000>;
+	type ReattestationGracePeriod = ConstU64<604_800_000>;
+	type MaxAttestationExpiryChecks = ConstU32<10>;
+	type MaxRevocationPropagations = ConstU32<10>;
 	type MaxSlots = CU32<64>;
 	type PalletId = AcurastPalletId;
 	type MaxEnvVars = CU32<10>;
 	type EnvKeyMaxSize = CU32<32>;
 	type EnvValueMaxSize = CU32<1024>;
 	type KeyAttestationBarrier = ();
+	type AttestationVerifiers = (TestAttestationVerifier, TdxQuoteVerifier<TestTdxRoot>);
 	type UnixTime = pallet_timestamp::Pallet<Test>;
 	type WeightInfo = crate::weights::WeightInfo<Test>;
 	type JobHooks = ();
@@ -152,6 +164,26 @@ impl crate::Config for Test {
 	type MaxVersions = CU32<2>;
 }
 
+/// Accepts reports in the test format signed by a key certified by the Google root, returning the report itself as report data.
+pub struct TestAttestationVerifier;
+
+impl AttestationVerifier for TestAttestationVerifier {
+	const FORMAT: AttestationFormat = AttestationFormat(*b"testtee0");
+	const ROOTS_OF_TRUST: &'static [&'static [u8]] =
+		&[include_bytes!("../common/src/__root_key__/google-public.key")];
+
+	fn verify(report: &[u8], _signer: &PublicKey) -> Result<VerifiedReport, ValidationError> {
+		Ok(VerifiedReport {
+			report_data: report.to_vec(),
+			summary: AttestationSummary {
+				security_level: AttestationSecurityLevel::TrustedEnvironemnt,
+				verified_boot_state: VerifiedBootState::Verified,
+				measurement: vec![1; 48].try_into().unwrap(),
+			},
+		})
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<T: crate::Config> BenchmarkHelper<T> for ()
 where
@@ -168,6 +200,13 @@ where
 
 		caller
 	}
+
+	fn attestation_report() -> (AttestationReport, [u8; 32]) {
+		(
+			acurast_common::tdx::fixtures::report(acurast_common::tdx::fixtures::QUOTE),
+			acurast_common::tdx::fixtures::NONCE,
+		)
+	}
 }
 
 pub fn events() -> Vec<RuntimeEvent> {
@@ -269,6 +308,14 @@ pub fn attestation_chain() -> AttestationChain {
 	}
 }
 
+pub fn attestation_report(format: AttestationFormat, report_data: Vec<u8>) -> AttestationReport {
+	AttestationReport {
+		format,
+		report: report_data.try_into().unwrap(),
+		certificate_chain: attestation_chain().certificate_chain,
+	}
+}
+
 pub fn invalid_attestation_chain_1() -> AttestationChain {
 	AttestationChain {
 		certificate_chain: vec![LEAF_CERT.to_vec().try_into().unwrap()].try_into().unwrap(),
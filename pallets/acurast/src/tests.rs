@@ -1,12 +1,29 @@
-use frame_support::{assert_err, assert_ok, BoundedVec};
+use frame_support::{
+	assert_err, assert_ok,
+	storage::unhashed,
+	traits::{Get, GetStorageVersion, Hooks, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
 use hex_literal::hex;
+use parity_scale_codec::{Decode, Encode};
 use sp_runtime::{bounded_vec, traits::BadOrigin, AccountId32};
 
-use acurast_common::{Environment, MinMetric, MultiOrigin};
+use acurast_common::{
+	tdx::fixtures as tdx, AttestationFormat, AttestationSecurityLevel, AttestationVerifier,
+	BoundedAttestationContent, Environment, MinMetric, MultiOrigin, VerifiedBootState,
+};
 
 use crate::{
-	mock::*, utils::validate_and_extract_attestation, AllowedSourcesUpdate, AttestationChain,
-	CertificateRevocationListUpdate, Error, ListUpdateOperation, SerialNumber,
+	migration,
+	mock::*,
+	utils::{
+		ensure_source_verified, ensure_source_verified_and_security_level,
+		validate_and_extract_attestation,
+	},
+	AllowedSourcesUpdate, AttestationAllowlistEntry, AttestationAllowlistUpdate, AttestationChain,
+	AttestationRecord, CertificateRevocationListUpdate, Error, ListUpdateOperation, ProcessorType,
+	SerialNumber, StoredAttestation, StoredAttestationRecord, WeightInfo,
 };
 
 #[test]
@@ -439,6 +456,261 @@ fn test_submit_attestation_failure_3() {
 	});
 }
 
+#[test]
+fn test_submit_attestation_report() {
+	ExtBuilder.build().execute_with(|| {
+		let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+		assert_ok!(Acurast::request_attestation_nonce(RuntimeOrigin::signed(
+			processor_account_id()
+		)));
+		let nonce = Acurast::attestation_nonce(processor_account_id()).unwrap();
+		let report = attestation_report(TestAttestationVerifier::FORMAT, nonce.to_vec());
+		assert_ok!(Acurast::submit_attestation_report(
+			RuntimeOrigin::signed(processor_account_id()),
+			report.clone()
+		));
+		// the nonce is consumed, the report cannot be submitted again
+		assert_eq!(None, Acurast::attestation_nonce(processor_account_id()));
+		assert_err!(
+			Acurast::submit_attestation_report(
+				RuntimeOrigin::signed(processor_account_id()),
+				report
+			),
+			Error::<Test>::AttestationNonceNotRequested
+		);
+
+		let attestation = Acurast::stored_attestation(processor_account_id()).unwrap();
+		assert_eq!(attestation.cert_ids.len(), 4);
+		let BoundedAttestationContent::Report(stored_report) = attestation.content else {
+			panic!("expected a report");
+		};
+		assert_eq!(stored_report.format, TestAttestationVerifier::FORMAT);
+		assert_eq!(stored_report.summary.measurement.to_vec(), vec![1; 48]);
+		assert!(Acurast::stored_attestation_record(processor_account_id()).is_some());
+
+		assert_ok!(ensure_source_verified_and_security_level::<Test>(
+			&processor_account_id(),
+			&[AttestationSecurityLevel::TrustedEnvironemnt]
+		));
+		assert_err!(
+			ensure_source_verified_and_security_level::<Test>(
+				&processor_account_id(),
+				&[AttestationSecurityLevel::StrongBox]
+			),
+			Error::<Test>::AttestationRejected
+		);
+
+		assert_eq!(
+			events(),
+			[
+				RuntimeEvent::Acurast(crate::Event::AttestationNonceRequested(
+					processor_account_id(),
+					nonce
+				)),
+				RuntimeEvent::Acurast(crate::Event::AttestationStoredV2(processor_account_id()))
+			]
+		);
+	});
+}
+
+#[test]
+fn test_submit_attestation_report_failure() {
+	ExtBuilder.build().execute_with(|| {
+		let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+
+		assert_err!(
+			Acurast::submit_attestation_report(
+				RuntimeOrigin::signed(processor_account_id()),
+				attestation_report(
+					TestAttestationVerifier::FORMAT,
+					processor_account_id().encode()
+				)
+			),
+			Error::<Test>::AttestationNonceNotRequested
+		);
+
+		assert_ok!(Acurast::request_attestation_nonce(RuntimeOrigin::signed(
+			processor_account_id()
+		)));
+		let nonce = Acurast::attestation_nonce(processor_account_id()).unwrap();
+
+		assert_err!(
+			Acurast::submit_attestation_report(
+				RuntimeOrigin::signed(processor_account_id()),
+				attestation_report(AttestationFormat(*b"unknown0"), nonce.to_vec())
+			),
+			Error::<Test>::UnsupportedAttestationFormat
+		);
+
+		// a report bound to the account instead of the nonce, or to the nonce of another account
+		assert_ok!(Acurast::request_attestation_nonce(RuntimeOrigin::signed(alice_account_id())));
+		let other_nonce = Acurast::attestation_nonce(alice_account_id()).unwrap();
+		assert_ne!(nonce, other_nonce);
+		for report_data in [processor_account_id().encode(), other_nonce.to_vec()] {
+			assert_err!(
+				Acurast::submit_attestation_report(
+					RuntimeOrigin::signed(processor_account_id()),
+					attestation_report(TestAttestationVerifier::FORMAT, report_data)
+				),
+				Error::<Test>::ReportDataDoesNotMatchNonce
+			);
+		}
+
+		let mut report = attestation_report(TestAttestationVerifier::FORMAT, nonce.to_vec());
+		report.certificate_chain = invalid_attestation_chain_2().certificate_chain;
+		assert_err!(
+			Acurast::submit_attestation_report(
+				RuntimeOrigin::signed(processor_account_id()),
+				report
+			),
+			Error::<Test>::CertificateChainValidationFailed
+		);
+
+		assert_eq!(None, Acurast::stored_attestation(processor_account_id()));
+	});
+}
+
+#[test]
+fn test_submit_tdx_attestation_report() {
+	ExtBuilder.build().execute_with(|| {
+		let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+		// the synthetic quotes are bound to a fixed nonce that cannot be requested
+		crate::AttestationNonce::<Test>::insert(processor_account_id(), tdx::NONCE);
+
+		let mut quote = tdx::QUOTE.to_vec();
+		quote[100] ^= 1;
+		assert_err!(
+			Acurast::submit_attestation_report(
+				RuntimeOrigin::signed(processor_account_id()),
+				tdx::report(&quote)
+			),
+			Error::<Test>::AttestationReportVerificationFailed
+		);
+
+		assert_ok!(Acurast::submit_attestation_report(
+			RuntimeOrigin::signed(processor_account_id()),
+			tdx::report(tdx::QUOTE)
+		));
+		let attestation = Acurast::stored_attestation(processor_account_id()).unwrap();
+		assert_eq!(attestation.cert_ids.len(), 3);
+		let BoundedAttestationContent::Report(stored_report) = attestation.content else {
+			panic!("expected a report");
+		};
+		assert_eq!(stored_report.format, AttestationFormat(*b"inteltdx"));
+		assert_eq!(stored_report.summary.measurement.to_vec(), tdx::MR_TD.to_vec());
+		assert_eq!(stored_report.summary.verified_boot_state, VerifiedBootState::Verified);
+		assert_eq!(stored_report.summary.security_level, AttestationSecurityLevel::Unknown);
+	});
+}
+
+#[test]
+fn test_reattestation() {
+	ExtBuilder.build().execute_with(|| {
+		let chain = attestation_chain();
+		let interval = <Test as crate::Config>::ReattestationInterval::get();
+		let grace_period = <Test as crate::Config>::ReattestationGracePeriod::get();
+		let attested_at = 1657363915001;
+		Timestamp::set_timestamp(attested_at);
+		assert_ok!(Acurast::submit_attestation(
+			RuntimeOrigin::signed(processor_account_id()),
+			chain.clone()
+		));
+		assert_eq!(
+			Some(AttestationRecord {
+				first_attested_at: attested_at,
+				attested_at,
+				expiring_notified: false
+			}),
+			Acurast::stored_attestation_record(processor_account_id())
+		);
+
+		// still valid during the grace period
+		Timestamp::set_timestamp(attested_at + interval);
+		assert_ok!(ensure_source_verified::<Test>(&processor_account_id()));
+		Acurast::on_idle(1, Weight::MAX);
+		Acurast::on_idle(1, Weight::MAX);
+		assert!(
+			Acurast::stored_attestation_record(processor_account_id())
+				.unwrap()
+				.expiring_notified
+		);
+
+		// expired after the grace period
+		Timestamp::set_timestamp(attested_at + interval + grace_period);
+		assert_err!(
+			ensure_source_verified::<Test>(&processor_account_id()),
+			Error::<Test>::ReattestationRequired
+		);
+		Acurast::on_idle(1, Weight::MAX);
+		assert_eq!(None, Acurast::stored_attestation(processor_account_id()));
+
+		// refreshing keeps the processor's first attestation
+		let refreshed_at = attested_at + interval + grace_period + 1;
+		Timestamp::set_timestamp(refreshed_at);
+		assert_ok!(Acurast::submit_attestation(
+			RuntimeOrigin::signed(processor_account_id()),
+			chain.clone()
+		));
+		assert_ok!(ensure_source_verified::<Test>(&processor_account_id()));
+		assert_eq!(
+			Some(AttestationRecord {
+				first_attested_at: attested_at,
+				attested_at: refreshed_at,
+				expiring_notified: false
+			}),
+			Acurast::stored_attestation_record(processor_account_id())
+		);
+
+		assert_eq!(
+			events(),
+			[
+				RuntimeEvent::Acurast(crate::Event::AttestationStoredV2(processor_account_id())),
+				RuntimeEvent::Acurast(crate::Event::AttestationExpiring(
+					processor_account_id(),
+					attested_at + interval + grace_period
+				)),
+				RuntimeEvent::Acurast(crate::Event::AttestationExpired(processor_account_id())),
+				RuntimeEvent::Acurast(crate::Event::AttestationStoredV2(processor_account_id())),
+			]
+		);
+	});
+}
+
+#[test]
+fn test_attestation_expiry_within_remaining_weight() {
+	ExtBuilder.build().execute_with(|| {
+		let interval = <Test as crate::Config>::ReattestationInterval::get();
+		let grace_period = <Test as crate::Config>::ReattestationGracePeriod::get();
+		let attested_at = 1657363915001;
+		Timestamp::set_timestamp(attested_at);
+		assert_ok!(Acurast::submit_attestation(
+			RuntimeOrigin::signed(processor_account_id()),
+			attestation_chain()
+		));
+		let attestation = Acurast::stored_attestation(processor_account_id()).unwrap();
+		let record = Acurast::stored_attestation_record(processor_account_id()).unwrap();
+		for account in [alice_account_id(), bob_account_id()] {
+			<StoredAttestation<Test>>::insert(&account, attestation.clone());
+			<StoredAttestationRecord<Test>>::insert(&account, record);
+		}
+		Timestamp::set_timestamp(attested_at + interval + grace_period);
+
+		// not even a single check fits
+		Acurast::on_idle(1, <Test as crate::Config>::WeightInfo::check_attestation_expiry(1) / 2);
+		assert_eq!(3, <StoredAttestation<Test>>::iter().count());
+
+		let weight = <Test as frame_system::Config>::DbWeight::get()
+			.reads(1)
+			.saturating_add(<Test as crate::Config>::WeightInfo::check_attestation_expiry(1));
+		assert_eq!(weight, Acurast::on_idle(1, weight));
+		assert_eq!(2, <StoredAttestation<Test>>::iter().count());
+
+		// the remaining attestations are checked starting after the first one
+		Acurast::on_idle(1, Weight::MAX);
+		assert_eq!(0, <StoredAttestation<Test>>::iter().count());
+	});
+}
+
 #[test]
 fn test_update_revocation_list() {
 	ExtBuilder.build().execute_with(|| {
@@ -484,6 +756,150 @@ fn test_update_revocation_list() {
 	});
 }
 
+#[test]
+fn test_update_attestation_allowlist() {
+	ExtBuilder.build().execute_with(|| {
+		let package_name = AttestationAllowlistEntry::PackageName(
+			b"com.acurast.attested.executor.mainnet".to_vec().try_into().unwrap(),
+		);
+		let signature_digest = AttestationAllowlistEntry::SignatureDigest(
+			hex!("ec70c2a4e072a0f586552a68357b23697c9d45f1e1257a8c4d29a25ac4982433")
+				.to_vec()
+				.try_into()
+				.unwrap(),
+		);
+		let updates_1 = vec![
+			AttestationAllowlistUpdate {
+				operation: ListUpdateOperation::Add,
+				item: package_name.clone(),
+			},
+			AttestationAllowlistUpdate {
+				operation: ListUpdateOperation::Add,
+				item: signature_digest.clone(),
+			},
+		];
+		assert_ok!(Acurast::update_attestation_allowlist(
+			RuntimeOrigin::root(),
+			ProcessorType::Core,
+			updates_1.clone().try_into().unwrap(),
+		));
+		assert_eq!(Some(()), Acurast::attestation_allowlist(ProcessorType::Core, &package_name));
+		assert_eq!(None, Acurast::attestation_allowlist(ProcessorType::Lite, &package_name));
+		assert_eq!(
+			2,
+			Acurast::attestation_allowlist_entries(&[ProcessorType::Core, ProcessorType::Lite])
+				.len()
+		);
+		assert_eq!(2, Acurast::attestation_allowlist_entry_count(ProcessorType::Core));
+		assert_eq!(0, Acurast::attestation_allowlist_entry_count(ProcessorType::Lite));
+
+		let updates_2 = vec![AttestationAllowlistUpdate {
+			operation: ListUpdateOperation::Remove,
+			item: package_name.clone(),
+		}];
+		assert_ok!(Acurast::update_attestation_allowlist(
+			RuntimeOrigin::root(),
+			ProcessorType::Core,
+			updates_2.clone().try_into().unwrap(),
+		));
+		assert_eq!(None, Acurast::attestation_allowlist(ProcessorType::Core, &package_name));
+		assert_eq!(
+			vec![signature_digest.clone()],
+			Acurast::attestation_allowlist_entries(&[ProcessorType::Core])
+		);
+		assert_eq!(1, Acurast::attestation_allowlist_entry_count(ProcessorType::Core));
+
+		assert_err!(
+			Acurast::update_attestation_allowlist(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProcessorType::Core,
+				updates_1.clone().try_into().unwrap(),
+			),
+			BadOrigin,
+		);
+		assert_eq!(None, Acurast::attestation_allowlist(ProcessorType::Core, &package_name));
+
+		assert_eq!(
+			events(),
+			[
+				RuntimeEvent::Acurast(crate::Event::AttestationAllowlistUpdated(
+					ProcessorType::Core,
+					updates_1.try_into().unwrap()
+				)),
+				RuntimeEvent::Acurast(crate::Event::AttestationAllowlistUpdated(
+					ProcessorType::Core,
+					updates_2.try_into().unwrap()
+				)),
+			]
+		);
+	});
+}
+
+#[test]
+fn test_update_attestation_allowlist_exceeds_max_entries() {
+	ExtBuilder.build().execute_with(|| {
+		let max_entries: u32 = <Test as crate::Config>::MaxAttestationAllowlistEntries::get();
+		let max_updates: u32 = <Test as crate::Config>::MaxAttestationAllowlistUpdates::get();
+		let entries: Vec<AttestationAllowlistEntry> = (0..=max_entries)
+			.map(|i| AttestationAllowlistEntry::Measurement(i.encode().try_into().unwrap()))
+			.collect();
+		let (allowed, exceeding) = entries.split_at(max_entries as usize);
+		for chunk in allowed.chunks(max_updates as usize) {
+			let updates: Vec<AttestationAllowlistUpdate> = chunk
+				.iter()
+				.map(|entry| AttestationAllowlistUpdate {
+					operation: ListUpdateOperation::Add,
+					item: entry.clone(),
+				})
+				.collect();
+			assert_ok!(Acurast::update_attestation_allowlist(
+				RuntimeOrigin::root(),
+				ProcessorType::Core,
+				updates.try_into().unwrap(),
+			));
+		}
+		assert_eq!(max_entries, Acurast::attestation_allowlist_entry_count(ProcessorType::Core));
+
+		// adding an already allowed entry does not count towards the limit
+		assert_ok!(Acurast::update_attestation_allowlist(
+			RuntimeOrigin::root(),
+			ProcessorType::Core,
+			vec![AttestationAllowlistUpdate {
+				operation: ListUpdateOperation::Add,
+				item: allowed[0].clone(),
+			}]
+			.try_into()
+			.unwrap(),
+		));
+		assert_err!(
+			Acurast::update_attestation_allowlist(
+				RuntimeOrigin::root(),
+				ProcessorType::Core,
+				vec![AttestationAllowlistUpdate {
+					operation: ListUpdateOperation::Add,
+					item: exceeding[0].clone(),
+				}]
+				.try_into()
+				.unwrap(),
+			),
+			Error::<Test>::TooManyAttestationAllowlistEntries
+		);
+
+		// the limit applies per processor type
+		assert_ok!(Acurast::update_attestation_allowlist(
+			RuntimeOrigin::root(),
+			ProcessorType::Lite,
+			vec![AttestationAllowlistUpdate {
+				operation: ListUpdateOperation::Add,
+				item: exceeding[0].clone(),
+			}]
+			.try_into()
+			.unwrap(),
+		));
+		assert_eq!(1, Acurast::attestation_allowlist_entry_count(ProcessorType::Lite));
+	});
+}
+
 #[test]
 fn test_update_revocation_list_submit_attestation() {
 	ExtBuilder.build().execute_with(|| {
@@ -553,6 +969,136 @@ fn test_update_revocation_list_assign_job() {
 	});
 }
 
+#[test]
+fn test_update_revocation_list_propagation() {
+	ExtBuilder.build().execute_with(|| {
+		let updates = vec![CertificateRevocationListUpdate {
+			operation: ListUpdateOperation::Add,
+			item: cert_serial_number(),
+		}];
+		let chain = attestation_chain();
+		let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+		assert_ok!(Acurast::submit_attestation(
+			RuntimeOrigin::signed(processor_account_id()),
+			chain.clone()
+		));
+		assert_eq!(
+			Some(()),
+			Acurast::certificate_attestations(cert_serial_number(), processor_account_id())
+		);
+
+		assert_ok!(Acurast::update_certificate_revocation_list(
+			RuntimeOrigin::root(),
+			updates.clone().try_into().unwrap(),
+		));
+		assert_eq!(Some(()), Acurast::pending_revocation(cert_serial_number()));
+		assert_err!(
+			ensure_source_verified::<Test>(&processor_account_id()),
+			Error::<Test>::RevokedCertificate
+		);
+
+		Acurast::propagate_revocations();
+		assert_eq!(None, Acurast::stored_attestation(processor_account_id()));
+		assert_eq!(
+			None,
+			Acurast::certificate_attestations(cert_serial_number(), processor_account_id())
+		);
+		assert_eq!(None, Acurast::pending_revocation(cert_serial_number()));
+		assert!(Acurast::stored_attestation_record(processor_account_id()).is_some());
+
+		assert_eq!(
+			events(),
+			[
+				RuntimeEvent::Acurast(crate::Event::AttestationStoredV2(processor_account_id())),
+				RuntimeEvent::Acurast(crate::Event::CertificateRevocationListUpdated),
+				RuntimeEvent::Acurast(crate::Event::AttestationRevoked(
+					processor_account_id(),
+					cert_serial_number()
+				)),
+			]
+		);
+	});
+}
+
+#[test]
+fn test_migrate_to_v8_indexes_certificates() {
+	ExtBuilder.build().execute_with(|| {
+		let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+		assert_ok!(Acurast::submit_attestation(
+			RuntimeOrigin::signed(processor_account_id()),
+			attestation_chain()
+		));
+		// simulate an attestation stored and revoked before certificates were indexed
+		let _ = crate::CertificateAttestations::<Test>::clear(u32::MAX, None);
+		crate::StoredRevokedCertificate::<Test>::insert(cert_serial_number(), ());
+		StorageVersion::new(7).put::<Acurast>();
+		let _ = events();
+
+		migration::migrate::<Test>();
+
+		assert_eq!(Acurast::on_chain_storage_version(), StorageVersion::new(8));
+		assert_eq!(
+			Some(()),
+			Acurast::certificate_attestations(cert_serial_number(), processor_account_id())
+		);
+		assert_eq!(Some(()), Acurast::pending_revocation(cert_serial_number()));
+		assert_eq!(None, Acurast::v8_migration_state());
+		assert_eq!(
+			events(),
+			[
+				RuntimeEvent::Acurast(crate::Event::V8MigrationStarted),
+				RuntimeEvent::Acurast(crate::Event::V8MigrationProgress(1)),
+				RuntimeEvent::Acurast(crate::Event::V8MigrationCompleted),
+			]
+		);
+	});
+}
+
+#[test]
+fn test_migrate_v4_attestation() {
+	ExtBuilder.build().execute_with(|| {
+		let _ = Timestamp::set(RuntimeOrigin::none(), 1657363915001);
+		assert_ok!(Acurast::submit_attestation(
+			RuntimeOrigin::signed(processor_account_id()),
+			attestation_chain()
+		));
+		let mut attestation = Acurast::stored_attestation(processor_account_id()).unwrap();
+		let BoundedAttestationContent::KeyDescription(key_description) = &mut attestation.content
+		else {
+			panic!("expected a key description");
+		};
+		// the tags added by the v7 migration are not part of the old layout
+		for list in [&mut key_description.software_enforced, &mut key_description.tee_enforced] {
+			list.attestation_id_second_imei = None;
+			list.module_hash = None;
+		}
+		// the new tags are appended, so the old layout is a prefix of the new one
+		let old_list = |list: &crate::BoundedAuthorizationList| {
+			migration::v6::BoundedAuthorizationList::decode(&mut list.encode().as_slice()).unwrap()
+		};
+		let old_attestation = migration::v4::Attestation {
+			cert_ids: attestation.cert_ids.clone(),
+			key_description: migration::v6::BoundedKeyDescription {
+				attestation_security_level: key_description.attestation_security_level.clone(),
+				key_mint_security_level: key_description.key_mint_security_level.clone(),
+				software_enforced: old_list(&key_description.software_enforced),
+				tee_enforced: old_list(&key_description.tee_enforced),
+			},
+			validity: attestation.validity.clone(),
+		};
+		unhashed::put(
+			&crate::StoredAttestation::<Test>::hashed_key_for(processor_account_id()),
+			&old_attestation,
+		);
+		StorageVersion::new(4).put::<Acurast>();
+
+		migration::migrate::<Test>();
+
+		assert_eq!(Acurast::on_chain_storage_version(), StorageVersion::new(8));
+		assert_eq!(Some(attestation), Acurast::stored_attestation(processor_account_id()));
+	});
+}
+
 #[test]
 fn test_set_environment() {
 	let registration = job_registration(
@@ -1,10 +1,21 @@
 use acurast_common::{Attestation, JobId};
-use frame_support::{dispatch::DispatchResultWithPostInfo, weights::Weight};
+use frame_support::{dispatch::DispatchResultWithPostInfo, pallet_prelude::*, weights::Weight};
 use sp_std::prelude::*;
 
 use crate::{AllowedSourcesUpdate, Config, Error, JobRegistrationFor};
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(
+	Debug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	PartialEq,
+	Eq,
+	Clone,
+	Copy,
+)]
 pub enum ProcessorType {
 	Core,
 	Lite,
@@ -17,6 +28,13 @@ pub trait KeyAttestationBarrier<T: Config> {
 		attestation: &Attestation,
 		processor_type: ProcessorType,
 	) -> bool;
+
+	/// The worst case weight of a single [Self::accept_attestation_for_origin] or [Self::check_attestation_is_of_type] call.
+	///
+	/// Not covered by the benchmarks of calls checking attestations, callers have to add it to their weights.
+	fn check_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 impl<T: Config> KeyAttestationBarrier<T> for () {
@@ -42,6 +60,10 @@ pub trait WeightInfo {
 	fn set_environment(x: u32) -> Weight;
 	fn set_environments(x: u32, y: u32) -> Weight;
 	fn register_with_min_metrics() -> Weight;
+	fn update_attestation_allowlist(x: u32) -> Weight;
+	fn submit_attestation_report() -> Weight;
+	fn request_attestation_nonce() -> Weight;
+	fn check_attestation_expiry(x: u32) -> Weight;
 }
 
 /// Allows to hook additional logic for various job related extrinsics.
@@ -58,6 +80,8 @@ pub trait JobHooks<T: Config> {
 		job_id: &JobId<<T as frame_system::Config>::AccountId>,
 		updates: &[AllowedSourcesUpdate<<T as frame_system::Config>::AccountId>],
 	) -> DispatchResultWithPostInfo;
+	/// Called when the attestation of a processor was removed due to a revoked certificate, returns the consumed weight.
+	fn attestation_revoked_hook(processor: &<T as frame_system::Config>::AccountId) -> Weight;
 }
 
 impl<T: Config> JobHooks<T> for () {
@@ -79,6 +103,9 @@ impl<T: Config> JobHooks<T> for () {
 	) -> DispatchResultWithPostInfo {
 		Ok(().into())
 	}
+	fn attestation_revoked_hook(_processor: &<T as frame_system::Config>::AccountId) -> Weight {
+		Weight::zero()
+	}
 }
 
 impl<T: Config> From<()> for Error<T> {
@@ -1,15 +1,20 @@
 use acurast_common::{
-	extract_attestation, validate_certificate_chain, AttestationSecurityLevel,
-	BoundedAttestationContent, ECDSACurve, PublicKey,
+	error::ValidationError, extract_attestation, validate_certificate_chain, AttestationReport,
+	AttestationSecurityLevel, AttestationVerifiers, BoundedAttestationContent,
+	BoundedAttestationReport, CertificateId, ECDSACurve, PublicKey,
+};
+use frame_support::{
+	ensure,
+	traits::{Get, UnixTime},
+	weights::Weight,
 };
-use frame_support::{ensure, traits::UnixTime};
 use parity_scale_codec::Encode;
 use sp_std::prelude::*;
 
 use crate::{
-	Attestation, AttestationChain, AttestationValidity, CertId, Config, Error, IssuerName,
-	KeyAttestationBarrier, ProcessorType, SerialNumber, StoredAttestation,
-	StoredRevokedCertificate, ValidatingCertIds,
+	Attestation, AttestationChain, AttestationRecord, AttestationValidity, CertId, Config, Error,
+	IssuerName, KeyAttestationBarrier, ProcessorType, SerialNumber, StoredAttestation,
+	StoredAttestationRecord, StoredRevokedCertificate, ValidatingCertIds,
 };
 
 /// Validates and returns an [Attestation] from the provided chain.
@@ -31,6 +36,52 @@ pub fn validate_and_extract_attestation<T: Config>(
 	let parsed_attestation = extract_attestation(cert.extensions)
 		.map_err(|_| Error::<T>::AttestationExtractionFailed)?;
 
+	Ok(Attestation {
+		cert_ids: bound_cert_ids::<T>(cert_ids)?,
+		content: parsed_attestation
+			.try_into()
+			.map_err(|_| Error::<T>::AttestationToBoundedTypeConversionFailed)?,
+		validity: attestation_validity,
+	})
+}
+
+/// Validates and returns an [Attestation] from the provided report using the [Config::AttestationVerifiers].
+///
+/// The data bound to the report has to start with `nonce`.
+pub fn validate_and_extract_attestation_report<T: Config>(
+	nonce: &[u8; 32],
+	report: &AttestationReport,
+) -> Result<Attestation, Error<T>> {
+	let (cert_ids, cert, verified_report) =
+		T::AttestationVerifiers::verify(report).map_err(|error| match error {
+			ValidationError::UnsupportedAttestationFormat => {
+				Error::<T>::UnsupportedAttestationFormat
+			},
+			ValidationError::InvalidReport | ValidationError::UntrustedQuotingEnclave => {
+				Error::<T>::AttestationReportVerificationFailed
+			},
+			_ => Error::<T>::CertificateChainValidationFailed,
+		})?;
+
+	ensure!(
+		verified_report.report_data.starts_with(nonce),
+		Error::<T>::ReportDataDoesNotMatchNonce
+	);
+
+	Ok(Attestation {
+		cert_ids: bound_cert_ids::<T>(cert_ids)?,
+		content: BoundedAttestationContent::Report(BoundedAttestationReport {
+			format: report.format,
+			summary: verified_report.summary,
+		}),
+		validity: AttestationValidity {
+			not_before: cert.validity.not_before.timestamp_millis(),
+			not_after: cert.validity.not_after.timestamp_millis(),
+		},
+	})
+}
+
+fn bound_cert_ids<T: Config>(cert_ids: Vec<CertificateId>) -> Result<ValidatingCertIds, Error<T>> {
 	let cert_ids_bounded = cert_ids
 		.into_iter()
 		.map(|cert_id| {
@@ -42,16 +93,14 @@ pub fn validate_and_extract_attestation<T: Config>(
 			Ok((iss_bounded, sn_bounded))
 		})
 		.collect::<Result<Vec<CertId>, Error<T>>>()?;
-	let cert_ids_bounded_vec = ValidatingCertIds::try_from(cert_ids_bounded)
-		.map_err(|_| Error::<T>::CannotGetCertificateId)?;
+	ValidatingCertIds::try_from(cert_ids_bounded).map_err(|_| Error::<T>::CannotGetCertificateId)
+}
 
-	Ok(Attestation {
-		cert_ids: cert_ids_bounded_vec,
-		content: parsed_attestation
-			.try_into()
-			.map_err(|_| Error::<T>::AttestationToBoundedTypeConversionFailed)?,
-		validity: attestation_validity,
-	})
+/// The worst case weight of the [Config::KeyAttestationBarrier] checks done by the `ensure_source_verified*` functions.
+///
+/// The barrier is not covered by the benchmarks of calls checking attestations, callers have to add this weight per checked source.
+pub fn ensure_source_verified_weight<T: Config>() -> Weight {
+	T::KeyAttestationBarrier::check_weight().saturating_mul(2)
 }
 
 /// Ensures that the provided account id has a valid (not expired and not revoked) key attestation.
@@ -76,8 +125,8 @@ pub fn ensure_source_verified_and_security_level<T: Config>(
 	valid_security_levels: &[AttestationSecurityLevel],
 ) -> Result<(), Error<T>> {
 	let attestation = check_attestation(account)?;
-	if let BoundedAttestationContent::KeyDescription(key_description) = attestation.content {
-		if !valid_security_levels.contains(&key_description.attestation_security_level) {
+	if let Some(security_level) = attestation.content.security_level() {
+		if !valid_security_levels.contains(&security_level) {
 			return Err(Error::<T>::AttestationRejected);
 		}
 	}
@@ -86,10 +135,7 @@ pub fn ensure_source_verified_and_security_level<T: Config>(
 
 /// Ensures the attestation is not expired.
 pub(crate) fn ensure_not_expired<T: Config>(attestation: &Attestation) -> Result<(), Error<T>> {
-	let now: u64 = T::UnixTime::now()
-		.as_millis()
-		.try_into()
-		.map_err(|_| Error::<T>::FailedTimestampConversion)?;
+	let now = now::<T>()?;
 
 	if now >= attestation.validity.not_after || now < attestation.validity.not_before {
 		return Err(Error::<T>::AttestationCertificateNotValid);
@@ -120,10 +166,58 @@ pub(crate) fn ensure_not_revoked<T: Config>(attestation: &Attestation) -> Result
 	Ok(())
 }
 
+/// Returns the timestamp in milliseconds by which the attestation has to be refreshed.
+///
+/// This is the earliest of the certificate's `not_after`, the key's usage expiry and the end of the [Config::ReattestationInterval]
+/// started by the latest submission. Attestations without an [AttestationRecord] were submitted before the interval was enforced
+/// and only expire with their certificate until the interval gets started for them.
+pub fn attestation_refresh_deadline<T: Config>(
+	attestation: &Attestation,
+	record: Option<&AttestationRecord>,
+) -> u64 {
+	let mut deadline = attestation.validity.not_after;
+	if let BoundedAttestationContent::KeyDescription(key_description) = &attestation.content {
+		if let Some(expire_date_time) = key_description
+			.tee_enforced
+			.usage_expire_date_time
+			.or(key_description.software_enforced.usage_expire_date_time)
+		{
+			deadline = deadline.min(expire_date_time);
+		}
+	}
+	if let Some(record) = record {
+		deadline = deadline.min(record.attested_at.saturating_add(T::ReattestationInterval::get()));
+	}
+	deadline
+}
+
+/// Ensures the attestation of the provided account is not past its refresh deadline and the [Config::ReattestationGracePeriod].
+pub(crate) fn ensure_not_expired_for_source<T: Config>(
+	account: &T::AccountId,
+	attestation: &Attestation,
+) -> Result<(), Error<T>> {
+	let record = <StoredAttestationRecord<T>>::get(account);
+	let expires_at = attestation_refresh_deadline::<T>(attestation, record.as_ref())
+		.saturating_add(T::ReattestationGracePeriod::get());
+	if now::<T>()? >= expires_at {
+		return Err(Error::<T>::ReattestationRequired);
+	}
+	Ok(())
+}
+
+/// Returns the current timestamp in milliseconds.
+pub(crate) fn now<T: Config>() -> Result<u64, Error<T>> {
+	T::UnixTime::now()
+		.as_millis()
+		.try_into()
+		.map_err(|_| Error::<T>::FailedTimestampConversion)
+}
+
 fn check_attestation<T: Config>(account: &T::AccountId) -> Result<Attestation, Error<T>> {
 	let attestation =
 		<StoredAttestation<T>>::get(account).ok_or(Error::<T>::FulfillSourceNotVerified)?;
 	ensure_not_revoked(&attestation)?;
+	ensure_not_expired_for_source(account, &attestation)?;
 	if !T::KeyAttestationBarrier::accept_attestation_for_origin(account, &attestation) {
 		return Err(Error::<T>::AttestationRejected);
 	}
@@ -105,26 +105,36 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Proof: Acurast StoredRevokedCertificate (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
 	/// Storage: Acurast StoredAttestation (r:0 w:1)
 	/// Proof: Acurast StoredAttestation (max_values: None, max_size: Some(11622), added: 14097, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredAttestationRecord (r:1 w:1)
+	/// Proof: Acurast StoredAttestationRecord (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
 	fn submit_attestation() -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `250`
 		//  Estimated: `12531`
 		// Minimum execution time: 9_665_000_000 picoseconds.
 		Weight::from_parts(9_709_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 12531))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: Acurast StoredRevokedCertificate (r:0 w:1)
 	/// Proof: Acurast StoredRevokedCertificate (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
-	fn update_certificate_revocation_list(_x: u32) -> Weight {
+	/// Storage: Acurast CertificateAttestations (r:1 w:0)
+	/// Proof: Acurast CertificateAttestations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// Storage: Acurast PendingRevocations (r:0 w:1)
+	/// Proof: Acurast PendingRevocations (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	fn update_certificate_revocation_list(x: u32) -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
 		// Minimum execution time: 9_000_000 picoseconds.
 		Weight::from_parts(10_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 0))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2560).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
 	}
 	/// Storage: Acurast StoredJobRegistration (r:1 w:0)
 	/// Proof: Acurast StoredJobRegistration (max_values: None, max_size: Some(34795), added: 37270, mode: MaxEncodedLen)
@@ -167,4 +177,68 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(9))
 	}
+	/// Storage: Acurast AttestationAllowlist (r:1 w:1)
+	/// Proof: Acurast AttestationAllowlist (max_values: None, max_size: Some(164), added: 2639, mode: MaxEncodedLen)
+	/// Storage: Acurast AttestationAllowlistEntryCount (r:1 w:1)
+	/// Proof: Acurast AttestationAllowlistEntryCount (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	/// The range of component `x` is `[1, 10]`.
+	fn update_attestation_allowlist(x: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(1_800_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 5135).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredRevokedCertificate (r:4 w:0)
+	/// Proof: Acurast StoredRevokedCertificate (max_values: None, max_size: Some(37), added: 2512, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredAttestation (r:0 w:1)
+	/// Proof: Acurast StoredAttestation (max_values: None, max_size: Some(11622), added: 14097, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredAttestationRecord (r:1 w:1)
+	/// Proof: Acurast StoredAttestationRecord (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Acurast AttestationNonce (r:1 w:1)
+	/// Proof: Acurast AttestationNonce (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn submit_attestation_report() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_715_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 15086))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: System ParentHash (r:1 w:0)
+	/// Proof: System ParentHash (max_values: Some(1), max_size: Some(32), added: 527, mode: MaxEncodedLen)
+	/// Storage: Acurast AttestationNonce (r:0 w:1)
+	/// Proof: Acurast AttestationNonce (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	fn request_attestation_nonce() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1517))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+	/// Storage: Acurast AttestationExpiryCursor (r:1 w:1)
+	/// Proof: Acurast AttestationExpiryCursor (max_values: Some(1), max_size: Some(83), added: 578, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredAttestation (r:51 w:50)
+	/// Proof: Acurast StoredAttestation (max_values: None, max_size: Some(11622), added: 14097, mode: MaxEncodedLen)
+	/// Storage: Acurast StoredAttestationRecord (r:50 w:50)
+	/// Proof: Acurast StoredAttestationRecord (max_values: None, max_size: Some(65), added: 2540, mode: MaxEncodedLen)
+	/// Storage: Acurast CertificateAttestations (r:0 w:300)
+	/// Proof: Acurast CertificateAttestations (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	/// The range of component `x` is `[0, 50]`.
+	fn check_attestation_expiry(x: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 15178))
+			.saturating_add(Weight::from_parts(31_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 16637).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(x.into())))
+	}
 }
@@ -1,6 +1,6 @@
 #![allow(clippy::type_complexity)]
 
-use acurast_common::{CommitmentIdProvider, PoolId, Slashable};
+use acurast_common::{CommitmentIdProvider, ManagerLookup, PoolId, Slashable};
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
@@ -2070,3 +2070,37 @@ where
 		}
 	}
 }
+
+/// Slashes the stake of the commitment backing the processor's manager.
+///
+/// Used for penalties on processors, e.g. missed SLAs in the marketplace, so that they are covered by the stake
+/// committed for the processor's compute rather than by unrelated funds of the processor's account.
+impl<T: Config<I>, I: 'static> Slashable<T::AccountId> for Pallet<T, I>
+where
+	BalanceFor<T, I>: From<u128>,
+{
+	type Currency = T::Currency;
+
+	fn slash(
+		processor: &T::AccountId,
+		amount: BalanceFor<T, I>,
+	) -> Option<Credit<T::AccountId, T::Currency>> {
+		let manager_id = T::ManagerProviderForEligibleProcessor::lookup_manager_id(processor)?;
+		let commitment_id = <BackingLookup<T, I>>::get(manager_id)?;
+		let committer = T::CommitmentIdProvider::owner_for(commitment_id).ok()?;
+		let stake = Self::commitments(commitment_id)?.stake?;
+
+		// do not slash more than the stake!
+		let to_slash = amount.min(stake.amount);
+		if to_slash.is_zero() {
+			return None;
+		}
+
+		// keep the stake if the funds cannot be slashed
+		frame_support::storage::with_storage_layer(|| -> Result<_, DispatchError> {
+			Self::decrease_committer_stake(&committer, commitment_id, to_slash)?;
+			Ok(Self::slash_for(&committer, to_slash)?)
+		})
+		.ok()
+	}
+}
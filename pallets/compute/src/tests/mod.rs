@@ -15,7 +15,9 @@ use crate::{
 	types::*,
 	Config, Cycle, Error, Event,
 };
-use acurast_common::{CommitmentIdProvider, ComputeHooks, ManagerIdProvider, ManagerLookup};
+use acurast_common::{
+	CommitmentIdProvider, ComputeHooks, ManagerIdProvider, ManagerLookup, Slashable,
+};
 
 fn commit_actions_2_processors() -> Vec<Action> {
 	vec![
@@ -2362,6 +2364,68 @@ fn test_commit_compute_with_slash() {
 	});
 }
 
+#[test]
+fn test_slash_processor_stake() {
+	use frame_support::traits::Imbalance;
+
+	ExtBuilder.build().execute_with(|| {
+		assert_ok!(Compute::enable_inflation(RuntimeOrigin::root()));
+		setup_balances();
+		create_pools();
+
+		let charlie = charlie_account_id();
+
+		// not backed by a commitment, nothing to slash
+		assert!(<Compute as Slashable<AccountId32>>::slash(&charlie, UNIT).is_none());
+
+		offer_accept_backing(charlie.clone());
+		let charlie_manager =
+			<Test as Config>::ManagerProviderForEligibleProcessor::lookup(&charlie).unwrap();
+
+		roll_to_block(10);
+		Compute::commit(&charlie, &charlie_manager, &[(2u8, 1000u128, 1u128)]);
+		roll_to_block(150);
+		Compute::commit(&charlie, &charlie_manager, &[(2u8, 4000u128, 1u128)]);
+		roll_to_block(202);
+
+		let commitment: sp_runtime::BoundedVec<ComputeCommitment, sp_core::ConstU32<30>> =
+			bounded_vec![ComputeCommitment {
+				pool_id: 2,
+				metric: FixedU128::from_rational(3200u128, 1u128),
+			},];
+		assert_ok!(Compute::commit_compute(
+			RuntimeOrigin::signed(charlie.clone()),
+			10 * UNIT,
+			36u64,
+			commitment,
+			Perbill::from_percent(10),
+			true,
+		));
+		let commitment_id =
+			<Test as Config>::CommitmentIdProvider::commitment_id_for(&charlie).unwrap();
+		let total_issuance = Balances::total_issuance();
+		let total_stake = Compute::total_stake();
+
+		// the processor's own account is slashed through the stake committed for its manager
+		let credit = <Compute as Slashable<AccountId32>>::slash(&charlie, 2 * UNIT).unwrap();
+		assert_eq!(credit.peek(), 2 * UNIT);
+		drop(credit);
+
+		let stake = Compute::commitments(commitment_id).unwrap().stake.unwrap();
+		assert_eq!(stake.amount, 8 * UNIT);
+		assert_eq!(Compute::total_stake(), total_stake - 2 * UNIT);
+		assert_eq!(Balances::total_issuance(), total_issuance - 2 * UNIT);
+
+		// never slashes more than the stake
+		let credit = <Compute as Slashable<AccountId32>>::slash(&charlie, 100 * UNIT).unwrap();
+		assert_eq!(credit.peek(), 8 * UNIT);
+		drop(credit);
+		let stake = Compute::commitments(commitment_id).unwrap().stake.unwrap();
+		assert_eq!(stake.amount, 0);
+		assert!(<Compute as Slashable<AccountId32>>::slash(&charlie, UNIT).is_none());
+	});
+}
+
 #[test]
 fn test_delegate_undelegate() {
 	ExtBuilder.build().execute_with(|| {
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @title Outgoing messages of the Hyperdrive IBC contract on Ethereum.
+/// @notice `pallet-acurast-hyperdrive-ibc` receives messages sent through this contract by verifying storage proofs of
+/// `outgoingMessages` (see `src/ethereum.rs`). The order of the state variables and the commitment are part of that
+/// protocol: `outgoingMessages` has to stay at slot 1 and `commitment` has to stay unchanged.
+contract HyperdriveIbc {
+    /// The number of messages sent, used to derive unique nonces. Slot 0.
+    uint256 public outgoingMessageCount;
+
+    /// The commitment of every outgoing message by its nonce. Slot 1.
+    mapping(bytes32 nonce => bytes32 commitment) public outgoingMessages;
+
+    event MessageSent(bytes32 indexed nonce, address indexed sender, bytes recipient, bytes payload);
+
+    /// @notice Sends `payload` to `recipient` on Acurast.
+    /// @param recipient The SCALE-encoded `Subject` of the recipient on Acurast.
+    /// @return nonce The nonce of the message, identifying it together with its sender.
+    function sendMessage(bytes calldata recipient, bytes calldata payload) external returns (bytes32 nonce) {
+        nonce = keccak256(abi.encode(block.chainid, address(this), outgoingMessageCount));
+        outgoingMessageCount += 1;
+        outgoingMessages[nonce] = commitment(senderSubject(msg.sender), nonce, recipient, payload);
+        emit MessageSent(nonce, msg.sender, recipient, payload);
+    }
+
+    /// @notice The SCALE encoding of `Subject::Ethereum(Layer::Contract(ContractCall { contract: sender, selector: None }))`,
+    /// the sender of a message on Acurast.
+    function senderSubject(address sender) public pure returns (bytes memory) {
+        return abi.encodePacked(uint8(3), uint8(1), sender, uint8(0));
+    }
+
+    /// @notice The commitment stored for a message, with `sender` and `recipient` as SCALE-encoded subjects.
+    function commitment(bytes memory sender, bytes32 nonce, bytes calldata recipient, bytes calldata payload)
+        public
+        pure
+        returns (bytes32)
+    {
+        return keccak256(abi.encode(sender, nonce, recipient, payload));
+    }
+}
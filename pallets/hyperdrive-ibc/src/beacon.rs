@@ -0,0 +1,472 @@
+//! A light client following the finality of the Ethereum beacon chain through sync committee signatures.
+//!
+//! Implements the verification of light client updates of the Ethereum consensus specs (`altair/light-client/sync-protocol.md`),
+//! requiring a supermajority of the sync committee and proving the execution state root in the finalized block body.
+
+use ark_bls12_381::{g2, Bls12_381, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{
+	hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+	pairing::Pairing,
+	AffineRepr, CurveGroup,
+};
+use ark_ff::{field_hashers::DefaultFieldHasher, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use sha2::{Digest, Sha256};
+use sp_core::H256;
+use sp_std::prelude::*;
+
+use crate::{
+	BeaconBlockHeader, BeaconFork, Error, EthereumBeaconConfig, EthereumFinalityUpdate,
+	SyncCommittee, SyncCommitteePublic, SyncCommitteeStoredPublic, SyncCommitteeStoredPublics,
+	SLOTS_PER_EPOCH, SLOTS_PER_SYNC_COMMITTEE_PERIOD, SYNC_COMMITTEE_SIZE,
+};
+
+/// The domain separation tag of signatures on the beacon chain.
+pub const SYNC_COMMITTEE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+const DOMAIN_SYNC_COMMITTEE: [u8; 4] = [7, 0, 0, 0];
+/// The generalized index of the execution state root in a beacon block body.
+///
+/// The execution payload is field 9 of the body (generalized index 25), the state root field 2 of its 17 fields.
+pub const EXECUTION_STATE_ROOT_GINDEX: u64 = 25 * 32 + 2;
+/// The generalized index of the execution block number in a beacon block body, see [`EXECUTION_STATE_ROOT_GINDEX`].
+pub const EXECUTION_BLOCK_NUMBER_GINDEX: u64 = 25 * 32 + 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LightClientError {
+	/// The slots of the update are not increasing or do not advance the finalized slot.
+	InvalidSlots,
+	/// Less than two thirds of the sync committee signed the attested header.
+	InsufficientParticipation,
+	/// A Merkle branch does not lead to the root it is proven against.
+	InvalidBranch,
+	/// A sync committee does not have [`SYNC_COMMITTEE_SIZE`] valid public keys.
+	InvalidSyncCommittee,
+	/// The sync committee's signature is not valid.
+	InvalidSignature,
+	/// The fork schedule has no fork active at a slot of the update.
+	UnknownFork,
+}
+
+impl<T, I> From<LightClientError> for Error<T, I> {
+	fn from(error: LightClientError) -> Self {
+		match error {
+			LightClientError::InvalidSlots => Error::InvalidBeaconSlots,
+			LightClientError::InsufficientParticipation => {
+				Error::InsufficientSyncCommitteeParticipation
+			},
+			LightClientError::InvalidBranch => Error::InvalidBeaconBranch,
+			LightClientError::InvalidSyncCommittee => Error::InvalidSyncCommittee,
+			LightClientError::InvalidSignature => Error::SyncCommitteeSignatureInvalid,
+			LightClientError::UnknownFork => Error::UnknownBeaconFork,
+		}
+	}
+}
+
+/// The period of the sync committee signing at `slot`.
+pub fn sync_committee_period(slot: u64) -> u64 {
+	slot / SLOTS_PER_SYNC_COMMITTEE_PERIOD
+}
+
+/// The epoch of `slot`.
+pub fn epoch(slot: u64) -> u64 {
+	slot / SLOTS_PER_EPOCH
+}
+
+/// The fork of `config` active at `epoch`, the last fork activated at or before it.
+pub fn fork_at(config: &EthereumBeaconConfig, epoch: u64) -> Option<&BeaconFork> {
+	config.forks.iter().rev().find(|fork| fork.epoch <= epoch)
+}
+
+/// Checks that the fork schedule of `config` is not empty and ordered by strictly ascending activation epoch.
+pub fn is_valid_fork_schedule(config: &EthereumBeaconConfig) -> bool {
+	!config.forks.is_empty() && config.forks.windows(2).all(|pair| pair[0].epoch < pair[1].epoch)
+}
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+	H256(Sha256::new().chain_update(left).chain_update(right).finalize().into())
+}
+
+/// The SSZ merkleization of `chunks`, padded with zero chunks to the next power of two.
+fn merkleize(mut chunks: Vec<H256>) -> H256 {
+	chunks.resize(chunks.len().next_power_of_two(), H256::zero());
+	while chunks.len() > 1 {
+		chunks = chunks.chunks(2).map(|pair| hash_pair(&pair[0], &pair[1])).collect();
+	}
+	chunks.first().copied().unwrap_or_default()
+}
+
+fn uint64_leaf(value: u64) -> H256 {
+	let mut leaf = [0u8; 32];
+	leaf[..8].copy_from_slice(&value.to_le_bytes());
+	H256(leaf)
+}
+
+/// The SSZ `hash_tree_root` of a beacon block header.
+pub fn header_root(header: &BeaconBlockHeader) -> H256 {
+	merkleize(vec![
+		uint64_leaf(header.slot),
+		uint64_leaf(header.proposer_index),
+		header.parent_root,
+		header.state_root,
+		header.body_root,
+	])
+}
+
+fn public_root(public: &SyncCommitteePublic) -> H256 {
+	let mut chunks = [0u8; 64];
+	chunks[..48].copy_from_slice(public);
+	H256(Sha256::digest(chunks).into())
+}
+
+/// The SSZ `hash_tree_root` of a sync committee.
+pub fn sync_committee_root(committee: &SyncCommittee) -> H256 {
+	let publics = merkleize(committee.pubkeys.iter().map(public_root).collect());
+	hash_pair(&publics, &public_root(&committee.aggregate_pubkey))
+}
+
+/// Checks that `branch` proves `leaf` at the generalized index `gindex` of the tree with `root`.
+pub fn is_valid_merkle_branch(leaf: H256, branch: &[H256], gindex: u64, root: H256) -> bool {
+	if gindex == 0 || branch.len() != gindex.ilog2() as usize {
+		return false;
+	}
+	let computed = branch.iter().enumerate().fold(leaf, |node, (depth, sibling)| {
+		if (gindex >> depth) & 1 == 1 {
+			hash_pair(sibling, &node)
+		} else {
+			hash_pair(&node, sibling)
+		}
+	});
+	computed == root
+}
+
+/// The root signed by the sync committee for `header` under the fork `version`.
+pub fn signing_root(
+	genesis_validators_root: &H256,
+	version: [u8; 4],
+	header: &BeaconBlockHeader,
+) -> H256 {
+	let mut fork_version = [0u8; 32];
+	fork_version[..4].copy_from_slice(&version);
+	let fork_data_root = hash_pair(&H256(fork_version), genesis_validators_root);
+	let mut domain = [0u8; 32];
+	domain[..4].copy_from_slice(&DOMAIN_SYNC_COMMITTEE);
+	domain[4..].copy_from_slice(&fork_data_root[..28]);
+	hash_pair(&header_root(header), &H256(domain))
+}
+
+fn hash_to_g2(message: &[u8]) -> Option<G2Affine> {
+	MapToCurveBasedHasher::<G2Projective, DefaultFieldHasher<Sha256, 128>, WBMap<g2::Config>>::new(
+		SYNC_COMMITTEE_DST,
+	)
+	.ok()?
+	.hash(message)
+	.ok()
+}
+
+/// Validates the public keys of a sync committee and converts them into their stored, uncompressed form.
+pub fn stored_publics(
+	committee: &SyncCommittee,
+) -> Result<SyncCommitteeStoredPublics, LightClientError> {
+	if committee.pubkeys.len() != SYNC_COMMITTEE_SIZE as usize {
+		return Err(LightClientError::InvalidSyncCommittee);
+	}
+	committee
+		.pubkeys
+		.iter()
+		.map(|public| {
+			// checks that the point is in the prime order subgroup
+			let point = G1Affine::deserialize_compressed(&public[..])
+				.map_err(|_| LightClientError::InvalidSyncCommittee)?;
+			if point.infinity {
+				return Err(LightClientError::InvalidSyncCommittee);
+			}
+			let mut stored = Vec::new();
+			point
+				.serialize_uncompressed(&mut stored)
+				.map_err(|_| LightClientError::InvalidSyncCommittee)?;
+			stored.try_into().map_err(|_| LightClientError::InvalidSyncCommittee)
+		})
+		.collect::<Result<Vec<SyncCommitteeStoredPublic>, _>>()?
+		.try_into()
+		.map_err(|_| LightClientError::InvalidSyncCommittee)
+}
+
+/// Verifies that a supermajority of `committee` signed the attested header of `update`.
+///
+/// The signature is verified under the fork of the slot before the signature slot, like the consensus specs do.
+fn verify_sync_aggregate(
+	config: &EthereumBeaconConfig,
+	committee: &[SyncCommitteeStoredPublic],
+	update: &EthereumFinalityUpdate,
+) -> Result<(), LightClientError> {
+	let bits = &update.sync_aggregate.sync_committee_bits;
+	let mut participants: u32 = 0;
+	let mut aggregate_public = G1Projective::zero();
+	for (index, public) in committee.iter().enumerate() {
+		if (bits[index / 8] >> (index % 8)) & 1 == 0 {
+			continue;
+		}
+		// stored public keys were validated when the committee got stored
+		let public = G1Affine::deserialize_uncompressed_unchecked(&public[..])
+			.map_err(|_| LightClientError::InvalidSyncCommittee)?;
+		aggregate_public += public;
+		participants += 1;
+	}
+	if participants * 3 < SYNC_COMMITTEE_SIZE * 2 {
+		return Err(LightClientError::InsufficientParticipation);
+	}
+
+	let signature =
+		G2Affine::deserialize_compressed(&update.sync_aggregate.sync_committee_signature[..])
+			.map_err(|_| LightClientError::InvalidSignature)?;
+	let fork = fork_at(config, epoch(update.signature_slot.max(1) - 1))
+		.ok_or(LightClientError::UnknownFork)?;
+	let message = hash_to_g2(
+		signing_root(&config.genesis_validators_root, fork.version, &update.attested_header)
+			.as_bytes(),
+	)
+	.ok_or(LightClientError::InvalidSignature)?;
+	// e(aggregate_public, H(message)) == e(g1, signature)
+	let is_valid = Bls12_381::multi_pairing(
+		[aggregate_public.into_affine(), -G1Affine::generator()],
+		[message, signature],
+	)
+	.is_zero();
+	is_valid.then_some(()).ok_or(LightClientError::InvalidSignature)
+}
+
+/// Verifies a finality update against the sync `committee` of its signature slot's period and the latest `finalized_slot`.
+///
+/// The generalized indices into the beacon state are taken from the fork of the attested header's epoch.
+/// Returns the validated public keys of the next sync committee if the update contains it.
+pub fn verify_finality_update(
+	config: &EthereumBeaconConfig,
+	committee: &[SyncCommitteeStoredPublic],
+	finalized_slot: u64,
+	update: &EthereumFinalityUpdate,
+) -> Result<Option<SyncCommitteeStoredPublics>, LightClientError> {
+	if update.signature_slot <= update.attested_header.slot
+		|| update.attested_header.slot < update.finalized_header.slot
+		|| update.finalized_header.slot <= finalized_slot
+	{
+		return Err(LightClientError::InvalidSlots);
+	}
+	let attested_fork =
+		fork_at(config, epoch(update.attested_header.slot)).ok_or(LightClientError::UnknownFork)?;
+
+	let is_finalized = is_valid_merkle_branch(
+		header_root(&update.finalized_header),
+		&update.finality_branch,
+		attested_fork.finalized_root_gindex,
+		update.attested_header.state_root,
+	);
+	let is_executed = is_valid_merkle_branch(
+		uint64_leaf(update.execution_block_number),
+		&update.execution_block_number_branch,
+		EXECUTION_BLOCK_NUMBER_GINDEX,
+		update.finalized_header.body_root,
+	) && is_valid_merkle_branch(
+		update.execution_state_root,
+		&update.execution_state_root_branch,
+		EXECUTION_STATE_ROOT_GINDEX,
+		update.finalized_header.body_root,
+	);
+	if !is_finalized || !is_executed {
+		return Err(LightClientError::InvalidBranch);
+	}
+
+	let next_committee = match &update.next_sync_committee {
+		Some(next) => {
+			if !is_valid_merkle_branch(
+				sync_committee_root(&next.committee),
+				&next.branch,
+				attested_fork.next_sync_committee_gindex,
+				update.attested_header.state_root,
+			) {
+				return Err(LightClientError::InvalidBranch);
+			}
+			Some(stored_publics(&next.committee)?)
+		},
+		None => None,
+	};
+
+	verify_sync_aggregate(config, committee, update)?;
+
+	Ok(next_committee)
+}
+
+/// Sync committees with known secret keys and finality updates signed by them, used in tests and benchmarks.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod fixtures {
+	use ark_bls12_381::Fr;
+	use sp_std::vec;
+
+	use super::*;
+	use crate::{ExecutionBranch, NextSyncCommitteeUpdate, SyncAggregate, EXECUTION_BRANCH_LENGTH};
+
+	/// The fork schedule of Ethereum mainnet since Altair, the first fork with sync committees.
+	pub fn config() -> EthereumBeaconConfig {
+		let fork = |epoch, version, finalized_root_gindex, next_sync_committee_gindex| BeaconFork {
+			epoch,
+			version,
+			finalized_root_gindex,
+			next_sync_committee_gindex,
+		};
+		EthereumBeaconConfig {
+			genesis_validators_root: H256(hex_literal::hex!(
+				"4b363db94e286120d76eb905340fdd4e54bfe9f06bf33ff6cf5ad27f511bfe95"
+			)),
+			forks: vec![
+				// Altair
+				fork(74240, [1, 0, 0, 0], 105, 55),
+				// Bellatrix
+				fork(144896, [2, 0, 0, 0], 105, 55),
+				// Capella
+				fork(194048, [3, 0, 0, 0], 105, 55),
+				// Deneb
+				fork(269568, [4, 0, 0, 0], 105, 55),
+				// Electra
+				fork(364032, [5, 0, 0, 0], 169, 87),
+				// Fulu
+				fork(411392, [6, 0, 0, 0], 169, 87),
+			]
+			.try_into()
+			.expect("less than BEACON_FORKS_MAX_LENGTH forks; qed"),
+		}
+	}
+
+	fn serialize<P: CanonicalSerialize>(point: P) -> Vec<u8> {
+		let mut bytes = Vec::new();
+		point
+			.serialize_compressed(&mut bytes)
+			.expect("serializing into a vector cannot fail; qed");
+		bytes
+	}
+
+	/// The sync committee whose members have the secret keys `seed + 1..=seed + SYNC_COMMITTEE_SIZE`.
+	pub fn sync_committee(seed: u64) -> SyncCommittee {
+		let generator = G1Affine::generator();
+		let mut public = generator * Fr::from(seed + 1);
+		let mut aggregate_public = G1Projective::zero();
+		let mut pubkeys = Vec::new();
+		for _ in 0..SYNC_COMMITTEE_SIZE {
+			aggregate_public += public;
+			pubkeys.push(serialize(public.into_affine()).try_into().expect("48 bytes; qed"));
+			public += generator;
+		}
+		SyncCommittee {
+			pubkeys: pubkeys.try_into().expect("SYNC_COMMITTEE_SIZE public keys; qed"),
+			aggregate_pubkey: serialize(aggregate_public.into_affine())
+				.try_into()
+				.expect("48 bytes; qed"),
+		}
+	}
+
+	/// A Merkle tree of the given leaves by generalized index, all other leaves are zero.
+	struct SparseTree(Vec<(u64, H256)>);
+
+	impl SparseTree {
+		fn node(&self, gindex: u64) -> H256 {
+			if let Some((_, leaf)) = self.0.iter().find(|(index, _)| *index == gindex) {
+				return *leaf;
+			}
+			let is_ancestor = |index: u64| index >> (index.ilog2() - gindex.ilog2()) == gindex;
+			if !self.0.iter().any(|(index, _)| *index > gindex && is_ancestor(*index)) {
+				return H256::zero();
+			}
+			hash_pair(&self.node(2 * gindex), &self.node(2 * gindex + 1))
+		}
+
+		fn branch(&self, mut gindex: u64) -> Vec<H256> {
+			let mut branch = Vec::new();
+			while gindex > 1 {
+				branch.push(self.node(gindex ^ 1));
+				gindex >>= 1;
+			}
+			branch
+		}
+	}
+
+	/// A finality update of the execution block finalized at `slot`, signed by all members of `sync_committee(seed)`.
+	///
+	/// The update is signed 33 slots later, `slot` should leave enough room to stay within its sync committee period.
+	/// Contains `sync_committee(next_seed)` as next sync committee if `next_seed` is given.
+	pub fn finality_update(
+		slot: u64,
+		seed: u64,
+		execution_block_number: u64,
+		execution_state_root: H256,
+		next_seed: Option<u64>,
+	) -> EthereumFinalityUpdate {
+		let config = config();
+		// the attested header is at the slot before the signature slot, both use its fork
+		let fork = fork_at(&config, epoch(slot + 32))
+			.expect("slot after the Altair fork; qed")
+			.clone();
+		let body = SparseTree(vec![
+			(EXECUTION_BLOCK_NUMBER_GINDEX, uint64_leaf(execution_block_number)),
+			(EXECUTION_STATE_ROOT_GINDEX, execution_state_root),
+		]);
+		let execution_branch = |gindex| -> ExecutionBranch {
+			let branch = body.branch(gindex);
+			debug_assert_eq!(branch.len(), EXECUTION_BRANCH_LENGTH as usize);
+			branch.try_into().expect("EXECUTION_BRANCH_LENGTH nodes; qed")
+		};
+		let finalized_header = BeaconBlockHeader {
+			slot,
+			proposer_index: 1,
+			parent_root: H256::repeat_byte(1),
+			state_root: H256::repeat_byte(2),
+			body_root: body.node(1),
+		};
+
+		let next_committee = next_seed.map(sync_committee);
+		let mut state = vec![(fork.finalized_root_gindex, header_root(&finalized_header))];
+		if let Some(committee) = &next_committee {
+			state.push((fork.next_sync_committee_gindex, sync_committee_root(committee)));
+		}
+		let state = SparseTree(state);
+		let attested_header = BeaconBlockHeader {
+			slot: slot + 32,
+			proposer_index: 2,
+			parent_root: H256::repeat_byte(3),
+			state_root: state.node(1),
+			body_root: H256::repeat_byte(4),
+		};
+
+		let secret = (1..=SYNC_COMMITTEE_SIZE as u64)
+			.fold(Fr::from(0u64), |secret, index| secret + Fr::from(seed + index));
+		let message = hash_to_g2(
+			signing_root(&config.genesis_validators_root, fork.version, &attested_header)
+				.as_bytes(),
+		)
+		.expect("valid DST; qed");
+
+		EthereumFinalityUpdate {
+			finality_branch: state
+				.branch(fork.finalized_root_gindex)
+				.try_into()
+				.expect("branch into the beacon state; qed"),
+			execution_block_number,
+			execution_state_root,
+			execution_block_number_branch: execution_branch(EXECUTION_BLOCK_NUMBER_GINDEX),
+			execution_state_root_branch: execution_branch(EXECUTION_STATE_ROOT_GINDEX),
+			next_sync_committee: next_committee.map(|committee| NextSyncCommitteeUpdate {
+				committee,
+				branch: state
+					.branch(fork.next_sync_committee_gindex)
+					.try_into()
+					.expect("branch into the beacon state; qed"),
+			}),
+			sync_aggregate: SyncAggregate {
+				sync_committee_bits: [u8::MAX; (SYNC_COMMITTEE_SIZE / 8) as usize],
+				sync_committee_signature: serialize((message * secret).into_affine())
+					.try_into()
+					.expect("96 bytes; qed"),
+			},
+			signature_slot: attested_header.slot + 1,
+			finalized_header,
+			attested_header,
+		}
+	}
+}
@@ -8,14 +8,25 @@ use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System, RawOrigin};
 use hex_literal::hex;
 use sp_std::prelude::*;
 
-use pallet_acurast::{AccountId20, ContractCall, Layer, MultiOrigin, ProxyAcurastChain, Subject};
+use pallet_acurast::{
+	AccountId20, ContractCall, Layer, MultiOrigin, ProxyAcurastChain, ProxyChain, Subject,
+};
 
 use crate::{
-	ActivityWindow, BalanceOf, Call, Config, HoldReason, IncomingMessageWithMetaFor,
-	IncomingMessages, IncomingMessagesLookup, MessageFor, MessageNonce, OraclePublicKeys,
-	OracleUpdate, OutgoingMessageWithMetaFor, OutgoingMessages, OutgoingMessagesLookup, Pallet,
-	Payload, Public, Signatures, SubjectFor, MESSAGES_CLEANUP_MAX_LENGTH,
-	ORACLE_UPDATES_MAX_LENGTH, PUBLIC_KEY_SERIALIZED_SIZE,
+	beacon::{self, fixtures as beacon_fixtures},
+	ethereum::{self, fixtures},
+	ActiveBondedOracles, ActivityWindow, AggregateAttestation, AggregateOracle, AggregateOracleSet,
+	AggregateOracles, AggregateSignatureVerifier, Attestation, BalanceOf, Call, Config,
+	DeliveryConfirmation, EquivocationProof, EthereumBeacon, EthereumIbc, EthereumStateRoots,
+	EthereumStateRootsBuffer, EthereumSyncCommittees, HoldReason, IncomingMessageSubmission,
+	IncomingMessageWithMetaFor, IncomingMessages, IncomingMessagesLookup, MessageFor, MessageNonce,
+	MessageProof, MessageVerifier, MessageVerifiers, OracleBond, OracleBonds, OraclePublicKeys,
+	OracleUpdate, OracleWeights, OutgoingMessageWithMetaFor, OutgoingMessages,
+	OutgoingMessagesLookup, Pallet, Payload, Public, Quorum, QuorumUpdate, SentMessageIds,
+	SentMessages, Signatures, SignedMessage, SubjectFor, AGGREGATE_ORACLES_MAX_LENGTH,
+	MESSAGES_CLEANUP_MAX_LENGTH, MESSAGE_BATCH_MAX_LENGTH, ORACLE_UPDATES_MAX_LENGTH,
+	ORACLE_WEIGHT_UPDATES_MAX_LENGTH, PROOF_NODES_MAX_LENGTH, PUBLIC_KEY_SERIALIZED_SIZE,
+	QUORUM_UPDATES_MAX_LENGTH, SLOTS_PER_SYNC_COMMITTEE_PERIOD, SYNC_COMMITTEE_SIZE,
 };
 
 fn set_block<T: Config<I>, I: 'static>(n: BlockNumberFor<T>) {
@@ -42,6 +53,52 @@ where
 	pubs
 }
 
+/// Returns `count` aggregate oracles with keys generated by the configured [`AggregateSignatureVerifier`].
+fn aggregate_oracles<T: Config<I>, I: 'static>(count: u32) -> Vec<AggregateOracle> {
+	(0..count)
+		.map(|i| {
+			let mut pk = [0u8; PUBLIC_KEY_SERIALIZED_SIZE];
+			pk[..4].copy_from_slice(&i.to_le_bytes());
+			let (aggregate_public, proof_of_possession) =
+				T::AggregateSignatureVerifier::generate_key(i);
+			AggregateOracle { public: pk.into(), aggregate_public, proof_of_possession }
+		})
+		.collect()
+}
+
+/// Seeds the aggregate oracle set with `count` active oracles and returns their attestation over `message`, signed by all of them.
+fn seed_aggregate_oracles<T: Config<I>, I: 'static>(
+	count: u32,
+	message: &[u8],
+) -> AggregateAttestation
+where
+	BlockNumberFor<T>: From<u32>,
+{
+	let now = System::<T>::block_number();
+	let oracles = aggregate_oracles::<T, I>(count);
+	for oracle in oracles.iter() {
+		OraclePublicKeys::<T, I>::insert(
+			oracle.public,
+			ActivityWindow {
+				start_block: now,
+				end_block: Some(now.saturating_add(10_000u32.into())),
+			},
+		);
+	}
+	let oracles: AggregateOracleSet = oracles.try_into().unwrap();
+	AggregateOracles::<T, I>::put(oracles);
+
+	let mut signers = vec![0u8; (count as usize + 7) / 8];
+	for index in 0..count as usize {
+		signers[index / 8] |= 1 << (index % 8);
+	}
+	let seeds: Vec<u32> = (0..count).collect();
+	AggregateAttestation {
+		signature: T::AggregateSignatureVerifier::sign(&seeds, message),
+		signers: signers.try_into().unwrap(),
+	}
+}
+
 fn seed_outgoing_message<T: Config<I>, I: 'static>(
 	sender: SubjectFor<T>,
 	payer: T::AccountId,
@@ -114,6 +171,29 @@ fn mint_to<T: Config<I>, I: 'static>(who: &T::AccountId, amount: BalanceOf<T, I>
 	_ = <<T as crate::Config<I>>::Currency as Mutate<T::AccountId>>::mint_into(who, amount);
 }
 
+fn seed_bonded_oracle<T: Config<I>, I: 'static>(
+	owner: &T::AccountId,
+	public: Public,
+	unbonding_until: Option<BlockNumberFor<T>>,
+) {
+	let amount = T::MinOracleBond::get();
+	mint_to::<T, I>(owner, amount.saturating_add(T::MinOracleBond::get()));
+	_ = T::Currency::hold(&HoldReason::<I>::OracleBond.into(), owner, amount);
+	let now = System::<T>::block_number();
+	OraclePublicKeys::<T, I>::insert(
+		public,
+		ActivityWindow { start_block: now, end_block: unbonding_until.map(|_| now) },
+	);
+	OracleBonds::<T, I>::insert(
+		public,
+		OracleBond { owner: owner.clone(), amount, unbonding_until },
+	);
+	OracleWeights::<T, I>::insert(public, 0);
+	if unbonding_until.is_none() {
+		ActiveBondedOracles::<T, I>::mutate(|count| *count += 1);
+	}
+}
+
 #[instance_benchmarks(
 	where
 		BlockNumberFor<T>: IsType<u32>,
@@ -273,4 +353,392 @@ mod benches {
 		#[extrinsic_call]
 		_(RawOrigin::Signed(caller), ids.try_into().unwrap());
 	}
+
+	#[benchmark]
+	fn bond_oracle() {
+		let caller: T::AccountId = whitelisted_caller();
+		let amount = T::MinOracleBond::get();
+		mint_to::<T, I>(&caller, amount.saturating_add(amount));
+		let public: Public = [1u8; PUBLIC_KEY_SERIALIZED_SIZE].into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), public, [0; 65].into(), amount);
+	}
+
+	#[benchmark]
+	fn unbond_oracle() {
+		let caller: T::AccountId = whitelisted_caller();
+		let public: Public = [1u8; PUBLIC_KEY_SERIALIZED_SIZE].into();
+		seed_bonded_oracle::<T, I>(&caller, public, None);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), public);
+	}
+
+	#[benchmark]
+	fn withdraw_oracle_bond() {
+		let caller: T::AccountId = whitelisted_caller();
+		let public: Public = [1u8; PUBLIC_KEY_SERIALIZED_SIZE].into();
+		set_block::<T, I>(1u32.into());
+		let until = System::<T>::block_number().saturating_add(T::OracleUnbondingPeriod::get());
+		seed_bonded_oracle::<T, I>(&caller, public, Some(until));
+		set_block::<T, I>(until);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), public);
+	}
+
+	#[benchmark]
+	fn report_equivocation() {
+		let caller: T::AccountId = whitelisted_caller();
+		let owner: T::AccountId = account("oracle", 0, 0);
+		let public: Public = [1u8; PUBLIC_KEY_SERIALIZED_SIZE].into();
+		seed_bonded_oracle::<T, I>(&owner, public, None);
+
+		// worst case: the message is checked against both the pending messages and the sent message hashes
+		let (sender, recipient) = default_subjects::<T, I>();
+		let nonce: MessageNonce = T::MessageIdHashing::hash_of(&b"nonce".as_slice());
+		let message = MessageFor::<T> {
+			id: Pallet::<T, I>::message_id(&sender, nonce),
+			sender,
+			nonce,
+			recipient,
+			payload: Payload::try_from(vec![0u8; crate::PAYLOAD_MAX_LENGTH as usize]).unwrap(),
+		};
+		// a different message was sent with the same id
+		SentMessageIds::<T, I>::insert(message.id, System::<T>::block_number());
+		SentMessages::<T, I>::insert(
+			message.id,
+			T::MessageIdHashing::hash_of(&b"sent".as_slice()),
+			(),
+		);
+		let proof = EquivocationProof::UnsentMessageDelivery(SignedMessage {
+			message,
+			relayer: Some(caller.clone()),
+			signature: [0; 65].into(),
+			batch_proof: None,
+		});
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), public, proof);
+	}
+
+	#[benchmark]
+	fn set_min_oracle_set_size() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, 3);
+	}
+
+	#[benchmark]
+	fn update_oracle_weights(n: Linear<1, ORACLE_WEIGHT_UPDATES_MAX_LENGTH>) {
+		let mut updates = vec![];
+		for i in 0..n {
+			let public: Public = [i as u8; PUBLIC_KEY_SERIALIZED_SIZE].into();
+			updates.push((public, Some(T::MaxOracleWeight::get())));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, updates.try_into().unwrap());
+	}
+
+	#[benchmark]
+	fn update_aggregate_oracles(n: Linear<1, AGGREGATE_ORACLES_MAX_LENGTH>) {
+		let oracles = aggregate_oracles::<T, I>(n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, oracles.try_into().unwrap());
+	}
+
+	#[benchmark]
+	fn receive_message_aggregated(s: Linear<1, AGGREGATE_ORACLES_MAX_LENGTH>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (recipient, sender) = default_subjects::<T, I>();
+		let relayer = MultiOrigin::Acurast(account::<T::AccountId>("relayer", 0, 0));
+
+		let nonce: MessageNonce = T::MessageIdHashing::hash_of(&b"nonce".as_slice());
+		let payload = b"incoming".to_vec();
+
+		let message = MessageFor::<T> {
+			id: Pallet::<T, I>::message_id(&sender, nonce),
+			sender: sender.clone(),
+			nonce,
+			recipient: recipient.clone(),
+			payload: payload.clone().try_into().unwrap(),
+		};
+		let attestation = seed_aggregate_oracles::<T, I>(
+			s.max(T::MinReceiptConfirmationSignatures::get()),
+			&Pallet::<T, I>::signed_payload(&message, None),
+		);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			sender,
+			nonce,
+			recipient,
+			payload.try_into().unwrap(),
+			relayer,
+			attestation,
+		);
+	}
+
+	#[benchmark]
+	fn confirm_message_delivery_aggregated(
+		s: Linear<1, AGGREGATE_ORACLES_MAX_LENGTH>,
+	) -> Result<(), BenchmarkError> {
+		let relayer: T::AccountId = whitelisted_caller();
+		let payer: T::AccountId = account("payer", 0, 0);
+
+		mint_to::<T, I>(&payer, 10_000_000_000_000u128.into());
+		T::Currency::hold(
+			&HoldReason::OutgoingMessageFee.into(),
+			&payer,
+			5_000_000_000_000u128.into(),
+		)?;
+
+		let (sender, recipient) = default_subjects::<T, I>();
+		let ttl = T::MinTTL::get().saturating_add(10u32.into());
+		let fee = T::MinFee::get();
+		let nonce: MessageNonce = T::MessageIdHashing::hash_of(&b"nonce".as_slice());
+		let payload = b"bench-msg".to_vec();
+
+		let msg = seed_outgoing_message::<T, I>(sender, payer, nonce, recipient, payload, ttl, fee);
+
+		let attestation = seed_aggregate_oracles::<T, I>(
+			s.max(T::MinDeliveryConfirmationSignatures::get()),
+			&Pallet::<T, I>::signed_payload(&msg.message, Some(&relayer)),
+		);
+		let id = msg.message.id;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(relayer), id, attestation);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn receive_messages(n: Linear<1, MESSAGE_BATCH_MAX_LENGTH>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (recipient, sender) = default_subjects::<T, I>();
+		let relayer = MultiOrigin::Acurast(account::<T::AccountId>("relayer", 0, 0));
+
+		let mut messages = vec![];
+		for i in 0..n {
+			messages.push(IncomingMessageSubmission {
+				sender: sender.clone(),
+				nonce: T::MessageIdHashing::hash(&i.to_le_bytes()),
+				recipient: recipient.clone(),
+				payload: b"incoming".to_vec().try_into().unwrap(),
+				relayer: relayer.clone(),
+				attestation: None,
+			});
+		}
+
+		let need = T::MinReceiptConfirmationSignatures::get() as u8;
+		let public_keys = seed_active_oracles::<T, I>(need.max(1));
+		let signatures: Signatures = public_keys
+			.into_iter()
+			.map(|p| ([0; 65].into(), p))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			messages.try_into().unwrap(),
+			Some(Attestation::Signatures(signatures)),
+		);
+	}
+
+	#[benchmark]
+	fn confirm_message_deliveries(
+		n: Linear<1, MESSAGE_BATCH_MAX_LENGTH>,
+	) -> Result<(), BenchmarkError> {
+		let relayer: T::AccountId = whitelisted_caller();
+		let payer: T::AccountId = account("payer", 0, 0);
+
+		mint_to::<T, I>(&payer, 10_000_000_000_000u128.into());
+		T::Currency::hold(
+			&HoldReason::OutgoingMessageFee.into(),
+			&payer,
+			5_000_000_000_000u128.into(),
+		)?;
+
+		let (sender, recipient) = default_subjects::<T, I>();
+		let ttl = T::MinTTL::get().saturating_add(10u32.into());
+		let fee = T::MinFee::get();
+
+		let mut confirmations = vec![];
+		for i in 0..n {
+			let msg = seed_outgoing_message::<T, I>(
+				sender.clone(),
+				payer.clone(),
+				T::MessageIdHashing::hash(&i.to_le_bytes()),
+				recipient.clone(),
+				b"bench-msg".to_vec(),
+				ttl,
+				fee,
+			);
+			confirmations.push(DeliveryConfirmation {
+				id: msg.message.id,
+				hash: Pallet::<T, I>::message_hash(&msg.message),
+				attestation: None,
+			});
+		}
+
+		let need = T::MinDeliveryConfirmationSignatures::get() as u8;
+		let public_keys = seed_active_oracles::<T, I>(need.max(1));
+		let signatures: Signatures = public_keys
+			.into_iter()
+			.map(|p| ([0; 65].into(), p))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(relayer),
+			confirmations.try_into().unwrap(),
+			Some(Attestation::Signatures(signatures)),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_quorums(n: Linear<1, QUORUM_UPDATES_MAX_LENGTH>) {
+		let quorum = Quorum { receipt: 5, delivery: 5 };
+		let mut updates = vec![];
+		for i in 0..n {
+			let recipient: SubjectFor<T> =
+				Subject::Acurast(Layer::Extrinsic(account::<T::AccountId>("recipient", i, 0)));
+			updates.push(QuorumUpdate::Recipient(recipient, Some(quorum)));
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, updates.try_into().unwrap());
+	}
+
+	#[benchmark]
+	fn update_message_verifier() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, ProxyChain::Ethereum, MessageVerifier::StateProof);
+	}
+
+	#[benchmark]
+	fn update_ethereum_ibc() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, Some(fixtures::layout()));
+	}
+
+	#[benchmark]
+	fn submit_ethereum_finality_update(c: Linear<0, 1>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let period = 1_000;
+
+		EthereumBeacon::<T, I>::put(beacon_fixtures::config());
+		EthereumSyncCommittees::<T, I>::insert(
+			period,
+			beacon::stored_publics(&beacon_fixtures::sync_committee(0)).unwrap(),
+		);
+		// an oldest state root to prune
+		EthereumStateRootsBuffer::<T, I>::insert(0, fixtures::BLOCK_NUMBER);
+		EthereumStateRoots::<T, I>::insert(fixtures::BLOCK_NUMBER, fixtures::STATE_ROOT);
+		// with `c`, the update rotates the sync committee, validating all public keys of the next one
+		let update = beacon_fixtures::finality_update(
+			period * SLOTS_PER_SYNC_COMMITTEE_PERIOD + 64,
+			0,
+			fixtures::BLOCK_NUMBER + 1,
+			fixtures::STATE_ROOT,
+			(c == 1).then_some(SYNC_COMMITTEE_SIZE as u64),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), Box::new(update));
+
+		assert!(EthereumStateRoots::<T, I>::get(fixtures::BLOCK_NUMBER).is_none());
+		assert_eq!(EthereumSyncCommittees::<T, I>::contains_key(period + 1), c == 1);
+	}
+
+	#[benchmark]
+	fn receive_message_with_proof(n: Linear<2, { 2 * PROOF_NODES_MAX_LENGTH }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let (recipient, sender) = default_subjects::<T, I>();
+		let relayer = MultiOrigin::Acurast(account::<T::AccountId>("relayer", 0, 0));
+		let payload: Payload = b"incoming".to_vec().try_into().unwrap();
+		let layout = fixtures::layout();
+
+		let message = MessageFor::<T> {
+			id: Pallet::<T, I>::message_id(&sender, fixtures::NONCE),
+			sender: sender.clone(),
+			nonce: fixtures::NONCE,
+			recipient: recipient.clone(),
+			payload: payload.clone(),
+		};
+		let (state_root, proof) = fixtures::full_branch_storage_proof(
+			layout.contract,
+			ethereum::mapping_slot(layout.messages_slot, fixtures::NONCE),
+			ethereum::message_commitment(&message),
+			n,
+		);
+
+		MessageVerifiers::<T, I>::insert(ProxyChain::Ethereum, MessageVerifier::StateProof);
+		EthereumIbc::<T, I>::put(layout);
+		EthereumStateRoots::<T, I>::insert(fixtures::BLOCK_NUMBER, state_root);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			sender,
+			fixtures::NONCE,
+			recipient,
+			payload,
+			relayer,
+			MessageProof::EthereumStorage(proof),
+		);
+
+		assert!(IncomingMessages::<T, I>::contains_key(message.id));
+	}
+
+	#[benchmark]
+	fn check_aggregate_signature(s: Linear<1, AGGREGATE_ORACLES_MAX_LENGTH>) {
+		let message = b"bench-msg".to_vec();
+		let attestation = seed_aggregate_oracles::<T, I>(s, &message);
+
+		#[block]
+		{
+			assert!(Pallet::<T, I>::check_aggregate_signature(&message, attestation, 0).is_ok());
+		}
+	}
+
+	#[benchmark]
+	fn update_ethereum_light_client(c: Linear<0, 1>) {
+		let sync_committee = (c == 1).then(|| (1_000, beacon_fixtures::sync_committee(0)));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, beacon_fixtures::config(), sync_committee);
+	}
+
+	#[benchmark]
+	fn clean_sent_messages(x: Linear<1, MESSAGES_CLEANUP_MAX_LENGTH>) {
+		let caller: T::AccountId = whitelisted_caller();
+		set_block::<T, I>(10_000u32.into());
+		let prunable_after = System::<T>::block_number().saturating_sub(1u32.into());
+
+		let mut ids = vec![];
+		for i in 0..x {
+			let id = T::MessageIdHashing::hash(&i.to_le_bytes());
+			SentMessageIds::<T, I>::insert(id, prunable_after);
+			// worst case: the id was reused after the TTL of its first message
+			SentMessages::<T, I>::insert(id, T::MessageIdHashing::hash(b"first"), ());
+			SentMessages::<T, I>::insert(id, T::MessageIdHashing::hash(b"second"), ());
+			ids.push(id);
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), ids.clone().try_into().unwrap());
+
+		assert!(ids.iter().all(|id| !SentMessageIds::<T, I>::contains_key(id)));
+	}
 }
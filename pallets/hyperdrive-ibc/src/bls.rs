@@ -0,0 +1,130 @@
+//! BLS signatures over BLS12-381 with public keys in G2 and signatures in G1.
+//!
+//! Follows the proof of possession scheme of the IETF BLS signature draft: every oracle proves ownership of its
+//! public key by signing it under a separate domain, which prevents rogue key attacks on aggregated public keys.
+
+use ark_bls12_381::{g1, Bls12_381, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{
+	hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+	pairing::Pairing,
+	AffineRepr, CurveGroup,
+};
+use ark_ff::{field_hashers::DefaultFieldHasher, Zero};
+use ark_serialize::CanonicalDeserialize;
+use sha2::Sha256;
+
+use crate::{AggregatePublic, AggregateSignature, AggregateSignatureVerifier};
+
+/// The domain separation tag for signatures over messages.
+pub const SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+/// The domain separation tag for proofs of possession.
+pub const PROOF_OF_POSSESSION_DST: &[u8] = b"BLS_POP_BLS12381G1_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Verifies aggregated BLS signatures with compressed 96 byte public keys and compressed 48 byte signatures.
+pub struct BlsVerifier;
+
+impl AggregateSignatureVerifier for BlsVerifier {
+	fn verify(message: &[u8], signers: &[AggregatePublic], signature: &AggregateSignature) -> bool {
+		if signers.is_empty() {
+			return false;
+		}
+		let Some(aggregate_public) =
+			signers.iter().try_fold(G2Projective::zero(), |aggregate, public| {
+				Some(aggregate + public_key(public)?)
+			})
+		else {
+			return false;
+		};
+		let Some(signature) = signature_point(signature) else {
+			return false;
+		};
+		verify_point(SIGNATURE_DST, message, aggregate_public.into_affine(), signature)
+	}
+
+	fn verify_proof_of_possession(public: &AggregatePublic, proof: &AggregateSignature) -> bool {
+		let (Some(public_point), Some(proof)) = (public_key(public), signature_point(proof)) else {
+			return false;
+		};
+		verify_point(PROOF_OF_POSSESSION_DST, public, public_point, proof)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn generate_key(seed: u32) -> (AggregatePublic, AggregateSignature) {
+		generate_key(seed)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn sign(seeds: &[u32], message: &[u8]) -> AggregateSignature {
+		sign(seeds, message)
+	}
+}
+
+fn hash_to_g1(dst: &[u8], message: &[u8]) -> Option<G1Affine> {
+	MapToCurveBasedHasher::<G1Projective, DefaultFieldHasher<Sha256, 128>, WBMap<g1::Config>>::new(
+		dst,
+	)
+	.ok()?
+	.hash(message)
+	.ok()
+}
+
+/// Decodes a compressed public key, rejecting points outside the prime order subgroup and the identity.
+fn public_key(bytes: &[u8]) -> Option<G2Affine> {
+	let public = G2Affine::deserialize_compressed(bytes).ok()?;
+	// the identity would make any signature valid for an aggregate containing it
+	(!public.infinity).then_some(public)
+}
+
+/// Decodes a compressed signature, rejecting points outside the prime order subgroup.
+fn signature_point(bytes: &[u8]) -> Option<G1Affine> {
+	G1Affine::deserialize_compressed(bytes).ok()
+}
+
+/// Checks `e(signature, g2) == e(H(message), public)`.
+fn verify_point(dst: &[u8], message: &[u8], public: G2Affine, signature: G1Affine) -> bool {
+	let Some(hash) = hash_to_g1(dst, message) else {
+		return false;
+	};
+	Bls12_381::multi_pairing([signature, -hash], [G2Affine::generator(), public]).is_zero()
+}
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+fn secret_key(seed: u32) -> ark_bls12_381::Fr {
+	// deterministic keys for tests and benchmarks only
+	ark_bls12_381::Fr::from(seed as u64 + 1)
+}
+
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+fn serialize<P: ark_serialize::CanonicalSerialize>(point: P) -> sp_std::vec::Vec<u8> {
+	let mut bytes = sp_std::vec::Vec::new();
+	point
+		.serialize_compressed(&mut bytes)
+		.expect("serializing into a vector cannot fail; qed");
+	bytes
+}
+
+/// Returns the public key derived from `seed` and its proof of possession.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub fn generate_key(seed: u32) -> (AggregatePublic, AggregateSignature) {
+	let secret = secret_key(seed);
+	let public = serialize((G2Affine::generator() * secret).into_affine());
+	let proof = hash_to_g1(PROOF_OF_POSSESSION_DST, &public).expect("valid DST; qed") * secret;
+	(
+		public.try_into().expect("compressed G2 points have 96 bytes; qed"),
+		serialize(proof.into_affine())
+			.try_into()
+			.expect("compressed G1 points have 48 bytes; qed"),
+	)
+}
+
+/// Returns the signature over `message` aggregated from the keys derived from `seeds`.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub fn sign(seeds: &[u32], message: &[u8]) -> AggregateSignature {
+	let hash = hash_to_g1(SIGNATURE_DST, message).expect("valid DST; qed");
+	let signature = seeds
+		.iter()
+		.fold(G1Projective::zero(), |signature, seed| signature + hash * secret_key(*seed));
+	serialize(signature.into_affine())
+		.try_into()
+		.expect("compressed G1 points have 48 bytes; qed")
+}
@@ -0,0 +1,376 @@
+//! Verification of Ethereum Merkle-Patricia storage proofs, as returned by `eth_getProof`.
+
+use core::marker::PhantomData;
+
+use pallet_acurast::ProxyChain;
+use parity_scale_codec::Encode;
+use rlp::Rlp;
+use sp_core::{H160, H256};
+use sp_runtime::traits::{Hash, Keccak256};
+use sp_std::prelude::*;
+
+use crate::{Config, EthereumStateRoots, Message, MessageProof, MessageProofVerifier, Pallet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofError {
+	/// The proof ended before reaching the key's leaf.
+	MissingNode,
+	/// A node does not hash to the reference of its parent.
+	InvalidNodeHash,
+	/// A node is not valid RLP or has an unexpected structure.
+	InvalidNode,
+	/// The proven account is not a valid RLP-encoded account.
+	InvalidAccount,
+	/// The proven storage value is not a valid RLP-encoded word.
+	InvalidValue,
+}
+
+impl From<rlp::DecoderError> for ProofError {
+	fn from(_: rlp::DecoderError) -> Self {
+		ProofError::InvalidNode
+	}
+}
+
+/// How a trie node references its child.
+enum NodeRef {
+	Hash(H256),
+	/// Nodes shorter than 32 bytes are embedded in their parent instead of being referenced by hash.
+	Inline(Vec<u8>),
+}
+
+impl NodeRef {
+	/// Reads the reference to a child, `None` if the child is empty.
+	fn from_rlp(rlp: &Rlp) -> Result<Option<Self>, ProofError> {
+		if rlp.is_list() {
+			return Ok(Some(NodeRef::Inline(rlp.as_raw().to_vec())));
+		}
+		match rlp.data()? {
+			[] => Ok(None),
+			hash if hash.len() == 32 => Ok(Some(NodeRef::Hash(H256::from_slice(hash)))),
+			_ => Err(ProofError::InvalidNode),
+		}
+	}
+}
+
+fn to_nibbles(bytes: &[u8]) -> Vec<u8> {
+	bytes.iter().flat_map(|byte| [byte >> 4, byte & 0x0f]).collect()
+}
+
+/// Decodes a hex-prefix encoded path into its nibbles and whether it belongs to a leaf.
+fn decode_path(encoded: &[u8]) -> Result<(Vec<u8>, bool), ProofError> {
+	let first = *encoded.first().ok_or(ProofError::InvalidNode)?;
+	let flag = first >> 4;
+	if flag > 3 {
+		Err(ProofError::InvalidNode)?;
+	}
+	let mut nibbles = to_nibbles(&encoded[1..]);
+	if flag % 2 == 1 {
+		nibbles.insert(0, first & 0x0f);
+	} else if first & 0x0f != 0 {
+		Err(ProofError::InvalidNode)?;
+	}
+	Ok((nibbles, flag >= 2))
+}
+
+/// Verifies a proof of `key` in the secure trie (keys are hashed with keccak) with `root`.
+///
+/// Returns the value stored under `key`, or `None` if the proof shows that there is no such key.
+pub fn verify_proof<N: AsRef<[u8]>>(
+	root: H256,
+	key: &[u8],
+	proof: &[N],
+) -> Result<Option<Vec<u8>>, ProofError> {
+	let path = to_nibbles(Keccak256::hash(key).as_bytes());
+	let mut path = path.as_slice();
+	let mut nodes = proof.iter();
+	let mut next = NodeRef::Hash(root);
+
+	loop {
+		let node = match next {
+			NodeRef::Hash(hash) => {
+				let node = nodes.next().ok_or(ProofError::MissingNode)?.as_ref();
+				if Keccak256::hash(node) != hash {
+					Err(ProofError::InvalidNodeHash)?;
+				}
+				node.to_vec()
+			},
+			NodeRef::Inline(node) => node,
+		};
+		let rlp = Rlp::new(&node);
+
+		let child = match rlp.item_count()? {
+			// branch node
+			17 => {
+				let Some((index, rest)) = path.split_first() else {
+					// keys in secure tries all have the same length and never end at a branch
+					return Err(ProofError::InvalidNode);
+				};
+				path = rest;
+				rlp.at(*index as usize)?
+			},
+			// leaf or extension node
+			2 => {
+				let (partial, is_leaf) = decode_path(rlp.at(0)?.data()?)?;
+				if is_leaf {
+					if path != partial.as_slice() {
+						return Ok(None);
+					}
+					return Ok(Some(rlp.at(1)?.data()?.to_vec()));
+				}
+				let Some(rest) = path.strip_prefix(partial.as_slice()) else {
+					return Ok(None);
+				};
+				path = rest;
+				rlp.at(1)?
+			},
+			_ => Err(ProofError::InvalidNode)?,
+		};
+
+		match NodeRef::from_rlp(&child)? {
+			Some(child) => next = child,
+			None => return Ok(None),
+		}
+	}
+}
+
+/// Verifies a storage proof for `slot` of the contract at `address` against the state root of a block.
+///
+/// Returns the (left-padded) word stored in the slot. Slots that were never written or the storage of non-existing accounts are proven to be zero.
+pub fn verify_storage_proof<N: AsRef<[u8]>>(
+	state_root: H256,
+	address: H160,
+	account_proof: &[N],
+	slot: H256,
+	storage_proof: &[N],
+) -> Result<H256, ProofError> {
+	let Some(account) = verify_proof(state_root, address.as_bytes(), account_proof)? else {
+		return Ok(H256::zero());
+	};
+	// accounts are encoded as `[nonce, balance, storage_root, code_hash]`
+	let account = Rlp::new(&account);
+	if account.item_count().map_err(|_| ProofError::InvalidAccount)? != 4 {
+		Err(ProofError::InvalidAccount)?;
+	}
+	let storage_root = account
+		.at(2)
+		.and_then(|root| root.data())
+		.map_err(|_| ProofError::InvalidAccount)?;
+	if storage_root.len() != 32 {
+		Err(ProofError::InvalidAccount)?;
+	}
+
+	let Some(value) = verify_proof(H256::from_slice(storage_root), slot.as_bytes(), storage_proof)?
+	else {
+		return Ok(H256::zero());
+	};
+	// values are stored as RLP-encoded words without leading zeros
+	let value = Rlp::new(&value).data().map_err(|_| ProofError::InvalidValue)?;
+	if value.len() > 32 {
+		Err(ProofError::InvalidValue)?;
+	}
+	let mut word = [0u8; 32];
+	word[32 - value.len()..].copy_from_slice(value);
+	Ok(H256(word))
+}
+
+/// The storage slot of the entry for `key` in a Solidity `mapping(bytes32 => bytes32)` at `slot`.
+pub fn mapping_slot(slot: H256, key: H256) -> H256 {
+	Keccak256::hash(&[key.as_bytes(), slot.as_bytes()].concat())
+}
+
+fn abi_word(value: usize) -> [u8; 32] {
+	let mut word = [0u8; 32];
+	word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+	word
+}
+
+/// Appends the ABI encoding of a `bytes` value: its length followed by the data, right-padded to a multiple of 32 bytes.
+fn abi_encode_bytes(encoded: &mut Vec<u8>, bytes: &[u8]) {
+	encoded.extend_from_slice(&abi_word(bytes.len()));
+	encoded.extend_from_slice(bytes);
+	encoded.resize(encoded.len() + (32 - bytes.len() % 32) % 32, 0);
+}
+
+/// The commitment the Ethereum IBC contract stores for an outgoing message, see `commitment` in `contracts/HyperdriveIbc.sol`.
+///
+/// It is the keccak hash of `abi.encode(bytes sender, bytes32 nonce, bytes recipient, bytes payload)` with sender and
+/// recipient as SCALE-encoded subjects. The message id is derived from sender and nonce on Acurast and not committed.
+pub fn message_commitment<AccountId: Encode>(message: &Message<AccountId, AccountId>) -> H256 {
+	const HEAD_LENGTH: usize = 4 * 32;
+
+	let sender = message.sender.encode();
+	let recipient = message.recipient.encode();
+	// the dynamic values follow the head in order, which holds their offsets
+	let mut tail = Vec::new();
+	let mut offsets = [0usize; 3];
+	for (offset, bytes) in
+		offsets.iter_mut().zip([&sender[..], &recipient[..], &message.payload[..]])
+	{
+		*offset = HEAD_LENGTH + tail.len();
+		abi_encode_bytes(&mut tail, bytes);
+	}
+
+	let mut encoded = Vec::with_capacity(HEAD_LENGTH + tail.len());
+	encoded.extend_from_slice(&abi_word(offsets[0]));
+	encoded.extend_from_slice(message.nonce.as_bytes());
+	encoded.extend_from_slice(&abi_word(offsets[1]));
+	encoded.extend_from_slice(&abi_word(offsets[2]));
+	encoded.extend_from_slice(&tail);
+	Keccak256::hash(&encoded)
+}
+
+/// Verifies [`MessageProof::EthereumStorage`] proofs against the state roots in [`EthereumStateRoots`].
+///
+/// The message is proven by the commitment stored for its nonce in the `outgoingMessages` mapping of the [`crate::EthereumIbc`]
+/// contract, whose storage is defined by `contracts/HyperdriveIbc.sol`.
+pub struct EthereumStorageProofVerifier<T, I>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> MessageProofVerifier<T::AccountId>
+	for EthereumStorageProofVerifier<T, I>
+{
+	fn verify(message: &Message<T::AccountId, T::AccountId>, proof: &MessageProof) -> bool {
+		let MessageProof::EthereumStorage(proof) = proof;
+		if ProxyChain::from(&message.sender) != ProxyChain::Ethereum {
+			return false;
+		}
+		let (Some(layout), Some(state_root)) =
+			(Pallet::<T, I>::ethereum_ibc(), <EthereumStateRoots<T, I>>::get(proof.block_number))
+		else {
+			return false;
+		};
+
+		verify_storage_proof(
+			state_root,
+			layout.contract,
+			&proof.account_proof[..],
+			mapping_slot(layout.messages_slot, message.nonce),
+			&proof.storage_proof[..],
+		)
+		.is_ok_and(|value| value == message_commitment(message))
+	}
+}
+
+/// A proof of the message with [`fixtures::NONCE`] stored by the IBC contract at [`fixtures::CONTRACT`], used in tests, and
+/// proofs of any number of nodes, used in benchmarks.
+///
+/// The message is the one sent in `encodes_specific_message_correctly`. The tries are built locally with the storage of
+/// `contracts/HyperdriveIbc.sol` after it sent six messages, next to a few other accounts. They are not taken from
+/// `eth_getProof` against a deployed contract.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub(crate) mod fixtures {
+	use hex_literal::hex;
+	use rlp::RlpStream;
+	use sp_core::{H160, H256};
+	use sp_runtime::traits::{Hash, Keccak256};
+	use sp_std::prelude::*;
+
+	use super::to_nibbles;
+	use crate::{EthereumIbcLayout, EthereumStorageProof, ProofNodes};
+
+	pub const BLOCK_NUMBER: u64 = 21_000_000;
+	pub const STATE_ROOT: H256 =
+		H256(hex!("aafb459e313aff6b60aae5a115c88714441f2afc872c6fde688387873663417c"));
+	pub const CONTRACT: H160 = H160(hex!("7f44ad0fd6c15cfba6f417c33924c8cf0c751d23"));
+	pub const NONCE: H256 =
+		H256(hex!("e8e77626586f73b955364c7b4bbf0bb7f7685ebd40e852b164633a4acbd3244c"));
+	#[cfg(test)]
+	pub const COMMITMENT: H256 =
+		H256(hex!("f40a7ecd13746f9f19242f626e313c640886d9ff13cfbf2622119acab9fd4055"));
+	#[cfg(test)]
+	pub const ACCOUNT_PROOF: [&[u8]; 3] = [
+		&hex!("f8d1a05a5cd3b1b3326c1ed24d01967e247eacd8dc85959faa870a8b6b2b190670c8658080808080a0aadb07892af07d6c7add1c06eecaba136fc07580296e5ae9eb7b838f059b565c8080a0fb673bdc656bf03e11514f0818fe12a843af7655a841cd575027953db98e8b61a03f4fa4680e75180deea04a9805e155020667cd7c874689dcd8a9538db6e3a9f9a078a6628d55604a783b0a8fa1794e51fa6b24855f11e0947fa1295eb6e7fbfad48080a07a72143e7465ecd347cc78cbb67bb7bdeeae51c4d4fc618a9f4005c5e0b820f98080"),
+		&hex!("f851808080808080808080a021a6004b1937a0616f5360563511e212fb32df67a3218299a69fd1080c60490380a0f2c336366753ad740b36013fdc66789e35a0da472872acffcdea3a94370056608080808080"),
+		&hex!("f869a0200e47388945a5bd80d4e63292c18e5bf19ada110b0b29b65dd5e0b701333bc9b846f8440180a0c795d2111f25af3f035154ba65c1df42ea6bae7098b480876e115921dff6aeeda02dc081a8d6d4714c79b5abd2e9b08c3a33b4ef1dcf946ef8b8cf6c495014f47b"),
+	];
+	#[cfg(test)]
+	pub const STORAGE_PROOF: [&[u8]; 2] = [
+		&hex!("f8d180a04cfbd8ab9c6b486cba783af7813210b7e559a108fc245917ee7a35e00795c283a05dce1b049e5a53760dd9b592e8837c6e6e74ef29af8ffd3ec7020220b75da5d880a0c640751739b60e4a129f3d0cfee6eadf7d3860d593728da1b13d5eb908d049c88080a0b5836b43109583fc569d57f9d371d648ec8316b47efe470789a2f4db792ae69080a0e9a0d97bb77f9f964f1709e7a658b0728bce2008d4efd8c8adf958af6828d6898080a013d2a0a800d6c1c42a62616a109a91df72e3dc72854b4cc1b8afc5b3e3c3f0bb80808080"),
+		&hex!("f843a039570f2bfc91c3a254d3825556fb3a318c9aa555e294994cfe4bcc9c31f43c63a1a0f40a7ecd13746f9f19242f626e313c640886d9ff13cfbf2622119acab9fd4055"),
+	];
+
+	pub fn layout() -> EthereumIbcLayout {
+		EthereumIbcLayout { contract: CONTRACT, messages_slot: H256::from_low_u64_be(1) }
+	}
+
+	/// The hex-prefix encoding of the path of a leaf.
+	fn leaf_path(nibbles: &[u8]) -> Vec<u8> {
+		let (first, rest) = match nibbles.split_first() {
+			Some((first, rest)) if nibbles.len() % 2 == 1 => (0x30 | first, rest),
+			_ => (0x20, nibbles),
+		};
+		[first]
+			.into_iter()
+			.chain(rest.chunks(2).map(|pair| pair[0] << 4 | pair[1]))
+			.collect()
+	}
+
+	/// A proof of `value` under `key` in a secure trie, with `depth - 1` branch nodes of 16 children above the leaf.
+	///
+	/// Returns the root of the trie and the proof.
+	fn full_branch_proof(key: &[u8], value: Vec<u8>, depth: u32) -> (H256, Vec<Vec<u8>>) {
+		let path = to_nibbles(Keccak256::hash(key).as_bytes());
+		let (branch_path, remaining_path) = path.split_at(depth.saturating_sub(1) as usize);
+
+		let mut leaf = RlpStream::new_list(2);
+		leaf.append(&leaf_path(remaining_path)).append(&value);
+		let mut nodes = vec![leaf.out().to_vec()];
+		for nibble in branch_path.iter().rev() {
+			let child = Keccak256::hash(nodes.last().expect("at least the leaf; qed"));
+			let mut branch = RlpStream::new_list(17);
+			for index in 0..16u8 {
+				let reference = if index == *nibble { child } else { H256::repeat_byte(index + 1) };
+				branch.append(&reference.as_bytes().to_vec());
+			}
+			branch.append_empty_data();
+			nodes.push(branch.out().to_vec());
+		}
+		nodes.reverse();
+
+		(Keccak256::hash(&nodes[0]), nodes)
+	}
+
+	/// A storage proof with `nodes` trie nodes, split between the account and the storage proof, proving `value` in `slot` of `contract`.
+	///
+	/// All nodes but the leaves are branch nodes with 16 children, the largest nodes of valid proofs. Returns the state root
+	/// the proof is verified against. Used to benchmark proofs by their number of nodes.
+	pub fn full_branch_storage_proof(
+		contract: H160,
+		slot: H256,
+		value: H256,
+		nodes: u32,
+	) -> (H256, EthereumStorageProof) {
+		let storage_depth = nodes / 2;
+		let account_depth = nodes - storage_depth;
+
+		let word = value.as_bytes();
+		let word = word[word.iter().take_while(|byte| **byte == 0).count()..].to_vec();
+		let (storage_root, storage_proof) =
+			full_branch_proof(slot.as_bytes(), rlp::encode(&word).to_vec(), storage_depth);
+
+		let mut account = RlpStream::new_list(4);
+		account
+			.append(&1u64)
+			.append(&0u64)
+			.append(&storage_root.as_bytes().to_vec())
+			.append(&Keccak256::hash(&[]).as_bytes().to_vec());
+		let (state_root, account_proof) =
+			full_branch_proof(contract.as_bytes(), account.out().to_vec(), account_depth);
+
+		let nodes = |nodes: Vec<Vec<u8>>| -> ProofNodes {
+			nodes
+				.into_iter()
+				.map(|node| node.try_into().unwrap())
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap()
+		};
+		(
+			state_root,
+			EthereumStorageProof {
+				block_number: BLOCK_NUMBER,
+				account_proof: nodes(account_proof),
+				storage_proof: nodes(storage_proof),
+			},
+		)
+	}
+}
@@ -10,9 +10,14 @@ pub use types::*;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 #[cfg(test)]
+mod mock;
+#[cfg(test)]
 mod tests;
 mod traits;
 
+pub mod beacon;
+pub mod bls;
+pub mod ethereum;
 mod types;
 pub mod weights;
 
@@ -24,6 +29,7 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::PostDispatchInfo,
 		pallet_prelude::*,
+		storage::with_storage_layer,
 		traits::{
 			fungible::{
 				hold::Mutate as HoldMutateFungible, Inspect as InspectFungible,
@@ -36,7 +42,11 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use sp_arithmetic::traits::{Saturating, Zero};
-	use sp_runtime::traits::{Hash, Verify};
+	use sp_core::H256;
+	use sp_runtime::{
+		traits::{Hash, Verify},
+		Perbill,
+	};
 	use sp_std::{prelude::*, vec};
 
 	use pallet_acurast::{
@@ -81,6 +91,33 @@ pub mod pallet {
 		/// The proxy chain identifier for this runtime (e.g., Acurast, AcurastCanary).
 		#[pallet::constant]
 		type SelfChain: Get<ProxyAcurastChain>;
+		/// The minimum stake an oracle has to bond to register itself.
+		///
+		/// Bonded oracles only count towards quorums once approved by governance, see [`Pallet::bond_oracle`].
+		#[pallet::constant]
+		type MinOracleBond: Get<BalanceOf<Self, I>>;
+		/// The number of blocks an unbonding oracle stays slashable before its bond can be withdrawn.
+		#[pallet::constant]
+		type OracleUnbondingPeriod: Get<BlockNumberFor<Self>>;
+		/// Verifies aggregated attestations, e.g. [`bls::BlsVerifier`]; `()` disables them.
+		type AggregateSignatureVerifier: AggregateSignatureVerifier;
+		/// Verifies the proofs of messages from proxy chains using [`MessageVerifier::StateProof`]; `()` rejects all proofs.
+		type MessageProofVerifier: MessageProofVerifier<Self::AccountId>;
+		/// The share of a slashed bond paid to the reporter of the equivocation. The rest is burned.
+		#[pallet::constant]
+		type EquivocationBounty: Get<Perbill>;
+		/// The number of the latest finalized Ethereum state roots kept in [`EthereumStateRoots`].
+		#[pallet::constant]
+		type MaxEthereumStateRoots: Get<u32>;
+		/// The number of blocks after its TTL a sent message stays in [`SentMessages`], bounding how long its delivery
+		/// confirmations can be checked in equivocation reports.
+		#[pallet::constant]
+		type SentMessageRetention: Get<BlockNumberFor<Self>>;
+		/// The highest weight an oracle can be given in [`OracleWeights`].
+		///
+		/// Keeping it below the minimum confirmation signatures ensures no single oracle meets a quorum on its own.
+		#[pallet::constant]
+		type MaxOracleWeight: Get<u32>;
 
 		type WeightInfo: WeightInfo;
 	}
@@ -104,9 +141,66 @@ pub mod pallet {
 		},
 		MessageProcessed {
 			message: IncomingMessageWithMetaFor<T>,
+			attested_weight: u32,
 		},
 		MessageProcessedWithErrors {
 			message: IncomingMessageWithMetaFor<T>,
+			attested_weight: u32,
+		},
+		OracleBonded {
+			public: Public,
+			owner: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		OracleUnbonding {
+			public: Public,
+			until: BlockNumberFor<T>,
+		},
+		OracleBondWithdrawn {
+			public: Public,
+			owner: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		OracleSlashed {
+			public: Public,
+			owner: T::AccountId,
+			amount: BalanceOf<T, I>,
+			reporter: T::AccountId,
+			bounty: BalanceOf<T, I>,
+		},
+		MinOracleSetSizeUpdated {
+			size: u32,
+		},
+		OracleWeightsUpdated {
+			updates: Vec<(Public, Option<u32>)>,
+		},
+		QuorumsUpdated {
+			updates: Vec<QuorumUpdateFor<T>>,
+		},
+		AggregateOraclesUpdated {
+			count: u32,
+		},
+		BatchItemFailed {
+			index: u32,
+			error: DispatchError,
+		},
+		MessageVerifierUpdated {
+			chain: ProxyChain,
+			verifier: MessageVerifier,
+		},
+		EthereumIbcUpdated {
+			layout: Option<EthereumIbcLayout>,
+		},
+		EthereumLightClientUpdated {
+			config: EthereumBeaconConfig,
+		},
+		EthereumSyncCommitteeUpdated {
+			period: u64,
+		},
+		EthereumStateRootFinalized {
+			slot: u64,
+			block_number: u64,
+			state_root: H256,
 		},
 	}
 
@@ -153,6 +247,109 @@ pub mod pallet {
 	pub type IncomingMessagesLookup<T: Config<I>, I: 'static = ()> =
 		StorageDoubleMap<_, Blake2_128Concat, SubjectFor<T>, Blake2_128Concat, MessageId, ()>;
 
+	/// The bonds of oracles that registered permissionlessly, mapped by their public key.
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_bonds)]
+	pub type OracleBonds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, Public, OracleBondFor<T, I>>;
+
+	/// The number of bonded oracles that are not unbonding.
+	#[pallet::storage]
+	#[pallet::getter(fn active_bonded_oracles)]
+	pub type ActiveBondedOracles<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// The minimum number of active bonded oracles; unbonding below this size is refused.
+	#[pallet::storage]
+	#[pallet::getter(fn min_oracle_set_size)]
+	pub type MinOracleSetSize<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// Hashes of the messages sent with an id, used to prove delivery confirmations for unsent messages.
+	///
+	/// Keyed by the message hash next to its id, so messages replaced after their TTL stay recorded.
+	#[pallet::storage]
+	#[pallet::getter(fn sent_messages)]
+	pub type SentMessages<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Identity, MessageId, Identity, MessageHash, ()>;
+
+	/// The ids tracked in [`SentMessages`], mapped to the block after which they can be pruned.
+	///
+	/// Messages of ids that are not tracked, because they were sent before the tracking started or were pruned,
+	/// cannot be told apart from unsent ones and are never reported as such.
+	#[pallet::storage]
+	#[pallet::getter(fn sent_message_ids)]
+	pub type SentMessageIds<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, MessageId, BlockNumberFor<T>>;
+
+	/// The weights of oracles counted towards a quorum, defaulting to [`DEFAULT_ORACLE_WEIGHT`].
+	///
+	/// Bonded oracles start with a weight of `0` until governance approves them.
+	#[pallet::storage]
+	#[pallet::getter(fn oracle_weights)]
+	pub type OracleWeights<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, Public, u32>;
+
+	/// Quorum overrides for messages from or to a proxy chain.
+	#[pallet::storage]
+	#[pallet::getter(fn chain_quorums)]
+	pub type ChainQuorums<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProxyChain, Quorum>;
+
+	/// Quorum overrides for messages to a recipient, taking precedence over [`ChainQuorums`].
+	#[pallet::storage]
+	#[pallet::getter(fn recipient_quorums)]
+	pub type RecipientQuorums<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, SubjectFor<T>, Quorum>;
+
+	/// The oracles taking part in aggregated attestations, indexed by [`AggregateAttestation::signers`].
+	#[pallet::storage]
+	#[pallet::getter(fn aggregate_oracles)]
+	pub type AggregateOracles<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, AggregateOracleSet, ValueQuery>;
+
+	/// How messages from a proxy chain are verified, defaulting to [`MessageVerifier::Oracles`].
+	#[pallet::storage]
+	#[pallet::getter(fn message_verifier)]
+	pub type MessageVerifiers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, ProxyChain, MessageVerifier, ValueQuery>;
+
+	/// Where the Ethereum IBC contract stores outgoing messages, required to verify their storage proofs.
+	#[pallet::storage]
+	#[pallet::getter(fn ethereum_ibc)]
+	pub type EthereumIbc<T: Config<I>, I: 'static = ()> = StorageValue<_, EthereumIbcLayout>;
+
+	/// Finalized Ethereum state roots by execution block number, proven by the Ethereum light client.
+	///
+	/// Only the latest [`Config::MaxEthereumStateRoots`] are kept, see [`EthereumStateRootsBuffer`].
+	#[pallet::storage]
+	#[pallet::getter(fn ethereum_state_root)]
+	pub type EthereumStateRoots<T: Config<I>, I: 'static = ()> = StorageMap<_, Identity, u64, H256>;
+
+	/// The block numbers of the [`EthereumStateRoots`] as a ring buffer, used to prune the oldest state root.
+	#[pallet::storage]
+	pub type EthereumStateRootsBuffer<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u32, u64>;
+
+	/// The index in [`EthereumStateRootsBuffer`] the next finalized state root is stored at.
+	#[pallet::storage]
+	pub type EthereumStateRootsBufferIndex<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// The parameters of the Ethereum beacon chain followed by the light client.
+	#[pallet::storage]
+	#[pallet::getter(fn ethereum_beacon)]
+	pub type EthereumBeacon<T: Config<I>, I: 'static = ()> = StorageValue<_, EthereumBeaconConfig>;
+
+	/// The public keys of the Ethereum sync committees by sync committee period, kept for the current and next period.
+	#[pallet::storage]
+	pub type EthereumSyncCommittees<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u64, SyncCommitteeStoredPublics>;
+
+	/// The slot of the latest finalized beacon block header.
+	#[pallet::storage]
+	#[pallet::getter(fn ethereum_finalized_slot)]
+	pub type EthereumFinalizedSlot<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u64, ValueQuery>;
+
 	#[pallet::error]
 	pub enum Error<T, I = ()> {
 		TTLSmallerThanMinimum,
@@ -170,6 +367,37 @@ pub mod pallet {
 		PayloadLengthExceeded,
 		FeeTooLow,
 		SelfMessagingNotAllowed,
+		OracleAlreadyRegistered,
+		OracleBondTooLow,
+		InvalidOracleOwnershipProof,
+		CouldNotHoldOracleBond,
+		CouldNotReleaseOracleBond,
+		OracleNotBonded,
+		NotOracleOwner,
+		OracleAlreadyUnbonding,
+		OracleNotUnbonding,
+		UnbondingPeriodNotPassed,
+		MinOracleSetSizeReached,
+		InvalidEquivocationProof,
+		EquivocationSignatureInvalid,
+		MessageWasSent,
+		InvalidSignerBitmap,
+		AggregateSignatureInvalid,
+		BatchMessageMismatch,
+		UnexpectedAttestation,
+		MessageProofInvalid,
+		InvalidProofOfPossession,
+		EthereumLightClientNotInitialized,
+		InvalidBeaconSlots,
+		UnknownSyncCommittee,
+		InsufficientSyncCommitteeParticipation,
+		InvalidBeaconBranch,
+		InvalidSyncCommittee,
+		SyncCommitteeSignatureInvalid,
+		MessageNotTracked,
+		OracleWeightTooHigh,
+		UnknownBeaconFork,
+		InvalidBeaconForkSchedule,
 	}
 
 	/// A reason for the pallet placing a hold on funds.
@@ -177,6 +405,8 @@ pub mod pallet {
 	pub enum HoldReason<I: 'static = ()> {
 		#[codec(index = 0)]
 		OutgoingMessageFee,
+		#[codec(index = 1)]
+		OracleBond,
 	}
 
 	#[pallet::call]
@@ -260,38 +490,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			let message = Self::outgoing_messages(id).ok_or(Error::<T, I>::MessageNotFound)?;
-
-			let current_block = <frame_system::Pallet<T>>::block_number();
-
-			ensure!(message.ttl_block >= current_block, Error::<T, I>::DeliveryConfirmationOverdue);
-
-			Self::check_signatures(
-				&message.message,
-				// include the relayer's address in the payload validated for signature to avoid front-running on actual relayer that paid for tx on destination chain
-				Some(who.clone()),
-				signatures,
-				T::MinDeliveryConfirmationSignatures::get(),
-			)?;
-
-			T::Currency::transfer_on_hold(
-				&HoldReason::OutgoingMessageFee.into(),
-				&message.payer,
-				&who,
-				message.fee,
-				Precision::Exact,
-				Restriction::Free,
-				Fortitude::Polite,
+			Self::do_confirm_message_delivery(
+				who,
+				id,
+				AttestationSource::Message(Attestation::Signatures(signatures)),
 			)
-			.map_err(|_| Error::<T, I>::CouldNotReleaseHoldFee)?;
-
-			Self::deposit_event(Event::MessageDelivered { id });
-
-			// clear
-			<OutgoingMessages<T, I>>::remove(id);
-			<OutgoingMessagesLookup<T, I>>::remove(&message.message.sender, message.message.nonce);
-
-			Ok(())
 		}
 
 		#[pallet::call_index(3)]
@@ -336,49 +539,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let _who = ensure_signed(origin)?;
 
-			// Validate recipient matches the configured chain
-			ensure!(
-				ProxyChain::from(&recipient) == (&T::SelfChain::get()).into(),
-				Error::<T, I>::IncorrectRecipient
-			);
-
-			let id = Self::message_id(&sender, nonce);
-			ensure!(
-				<IncomingMessages<T, I>>::get(id).is_none(),
-				Error::<T, I>::MessageAlreadyReceived
-			);
-
-			let current_block = <frame_system::Pallet<T>>::block_number();
-
-			let message =
-				MessageFor::<T> { id, sender, nonce, recipient: recipient.clone(), payload };
-
-			Self::check_signatures(
-				&message.clone(),
-				None,
-				signatures,
-				T::MinReceiptConfirmationSignatures::get(),
-			)?;
-
-			let message_with_meta = IncomingMessageWithMetaFor::<T> {
-				message: message.clone(),
-				current_block,
+			Self::do_receive_message(
+				sender,
+				nonce,
+				recipient,
+				payload,
 				relayer,
-			};
-			<IncomingMessages<T, I>>::insert(id, message_with_meta.clone());
-			<IncomingMessagesLookup<T, I>>::insert(&recipient, id, ());
-
-			// don't fail extrinsic from here onwards
-			if let Err(e) = Self::process_message(message.clone()) {
-				log::warn!("Received message {:?} processed with errors: {:?}", message, e.error);
-				Self::deposit_event(Event::MessageProcessedWithErrors {
-					message: message_with_meta,
-				});
-			} else {
-				Self::deposit_event(Event::MessageProcessed { message: message_with_meta });
-			}
-
-			Ok(())
+				AttestationSource::Message(Attestation::Signatures(signatures)),
+			)
 		}
 
 		/// Cleans up incoming messages for which [`<T as Config<I>>::IncomingTTL`] passed.
@@ -411,145 +579,1072 @@ pub mod pallet {
 				Ok(().into())
 			}
 		}
-	}
 
-	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		pub fn check_signatures(
-			message: &MessageFor<T>,
-			relayer: Option<T::AccountId>,
-			signatures: Signatures,
-			min_signatures: u32,
-		) -> Result<(), Error<T, I>> {
+		/// Registers an oracle by bonding at least [`<T as Config<I>>::MinOracleBond`] from the caller.
+		///
+		/// A bonded oracle starts with a weight of `0`, so its signatures do not count towards any quorum until governance
+		/// approves it by setting its weight with [`Pallet::update_oracle_weights`]. Forging a message with a fresh nonce is
+		/// no equivocation and cannot be slashed, so a bond alone must never be enough to reach a quorum.
+		///
+		/// The `proof` is the oracle's signature over the caller's account, see [`Pallet::oracle_ownership_payload`],
+		/// preventing anyone from bonding for a public key they do not control.
+		#[pallet::call_index(6)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::bond_oracle())]
+		pub fn bond_oracle(
+			origin: OriginFor<T>,
+			public: Public,
+			proof: Signature,
+			amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(amount >= T::MinOracleBond::get(), Error::<T, I>::OracleBondTooLow);
 			ensure!(
-				signatures.len() >= min_signatures as usize,
-				Error::<T, I>::NotEnoughSignaturesProvided
+				!<OraclePublicKeys<T, I>>::contains_key(public)
+					&& !<OracleBonds<T, I>>::contains_key(public),
+				Error::<T, I>::OracleAlreadyRegistered
 			);
 
+			let is_valid = proof.verify(Self::oracle_ownership_payload(&who).as_slice(), &public);
+			#[cfg(not(feature = "runtime-benchmarks"))]
+			ensure!(is_valid, Error::<T, I>::InvalidOracleOwnershipProof);
+
+			T::Currency::hold(&HoldReason::OracleBond.into(), &who, amount)
+				.map_err(|_| Error::<T, I>::CouldNotHoldOracleBond)?;
+
 			let current_block = <frame_system::Pallet<T>>::block_number();
+			<OraclePublicKeys<T, I>>::insert(
+				public,
+				ActivityWindow { start_block: current_block, end_block: None },
+			);
+			<OracleBonds<T, I>>::insert(
+				public,
+				OracleBond { owner: who.clone(), amount, unbonding_until: None },
+			);
+			<OracleWeights<T, I>>::insert(public, 0);
+			<ActiveBondedOracles<T, I>>::mutate(|count| *count = count.saturating_add(1));
 
-			// we always deny invalid signatures but skip signatures for unknown public keys or known public keys outside activity window
-			// this allows for some flexibility while deprecating relayer oracles
-			let mut not_found: Vec<(
-				[u8; SIGNATURE_SERIALIZED_SIZE],
-				[u8; PUBLIC_KEY_SERIALIZED_SIZE],
-			)> = Default::default();
-			let mut outside_activity_window: Vec<(
-				[u8; SIGNATURE_SERIALIZED_SIZE],
-				[u8; PUBLIC_KEY_SERIALIZED_SIZE],
-			)> = Default::default();
-			let mut valid = 0;
-			let mut checked: BTreeSet<Public> = BTreeSet::new();
-			let full_message: Vec<u8> = if let Some(relayer) = &relayer {
-				(T::ParachainId::get(), message, relayer).encode()
-			} else {
-				(T::ParachainId::get(), message).encode()
-			};
-			signatures.into_iter().try_for_each(
-				|(signature, public)| -> Result<(), Error<T, I>> {
-					match <OraclePublicKeys<T, I>>::get(public) {
-						None => {
-							not_found.push((signature.0, public.0));
-						},
-						Some(activity_window) if !checked.contains(&public) => {
-							// valid window is defined inclusive start_block, exclusive end_block
-							if activity_window.start_block <= current_block
-								&& activity_window
-									.end_block
-									.map_or(true, |end_block| current_block < end_block)
-							{
-								let is_valid = signature.verify(full_message.as_slice(), &public);
-								#[cfg(not(feature = "runtime-benchmarks"))]
-								ensure!(is_valid, Error::<T, I>::SignatureInvalid);
-								valid += 1;
-								checked.insert(public);
-							} else {
-								outside_activity_window.push((signature.0, public.0));
-							}
-						},
-						_ => {},
-					}
+			Self::deposit_event(Event::OracleBonded { public, owner: who, amount });
 
-					Ok(())
-				},
-			)?;
+			Ok(())
+		}
 
-			if !not_found.len().is_zero() {
-				log::warn!(
-					"Some provided signatures from unknown public keys provided: {:?}",
-					not_found
-				);
-			}
-			if !outside_activity_window.len().is_zero() {
-				log::warn!("Some provided signatures were invalid since the oracles' public key are outside their activity window: {:?}", outside_activity_window);
-			}
+		/// Starts unbonding an oracle, ending its activity window immediately.
+		///
+		/// The bond stays slashable for [`<T as Config<I>>::OracleUnbondingPeriod`] blocks before it can be withdrawn.
+		#[pallet::call_index(7)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::unbond_oracle())]
+		pub fn unbond_oracle(origin: OriginFor<T>, public: Public) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-			if valid < min_signatures {
-				log::info!("check_signatures failed since not enough valid signatures remain. Unknown oracles: {:?}, inactive oracles: {:?}.", outside_activity_window, outside_activity_window);
-				Err(Error::<T, I>::NotEnoughSignaturesValid)?
-			}
+			let mut bond = Self::oracle_bonds(public).ok_or(Error::<T, I>::OracleNotBonded)?;
+			ensure!(bond.owner == who, Error::<T, I>::NotOracleOwner);
+			ensure!(bond.unbonding_until.is_none(), Error::<T, I>::OracleAlreadyUnbonding);
+
+			let active = Self::active_bonded_oracles();
+			ensure!(active > Self::min_oracle_set_size(), Error::<T, I>::MinOracleSetSizeReached);
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			let until = current_block.saturating_add(T::OracleUnbondingPeriod::get());
+			bond.unbonding_until = Some(until);
+			<OracleBonds<T, I>>::insert(public, bond);
+			<ActiveBondedOracles<T, I>>::set(active.saturating_sub(1));
+			<OraclePublicKeys<T, I>>::mutate(public, |activity_window| {
+				if let Some(activity_window) = activity_window {
+					activity_window.end_block = Some(current_block);
+				}
+			});
+
+			Self::deposit_event(Event::OracleUnbonding { public, until });
 
 			Ok(())
 		}
 
-		pub(crate) fn message_id(sender: &SubjectFor<T>, nonce: MessageNonce) -> MessageId {
-			T::MessageIdHashing::hash_of(&(sender, nonce))
-		}
+		/// Withdraws the bond of an oracle whose unbonding period passed and removes the oracle.
+		#[pallet::call_index(8)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::withdraw_oracle_bond())]
+		pub fn withdraw_oracle_bond(origin: OriginFor<T>, public: Public) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-		/// Sends a message by the given `sender` paid by a potentially different `payer`.
-		///
-		/// **NOTE**:
-		///
-		/// * This is an internal function but could be made available
-		/// to other pallets if the authorization for the passed `sender` has been ensured at the caller.
-		/// Be careful to not allow for unintended impersonation.
-		/// * _Exactly-once delivery_ is **not** guaranteed even the `nonce` serves as deduplication during ttl; While, after ttl passed and message fee cannot be claimed by relayer, a _different_ message with same nonce can be sent off, it cannot be guaranteed a relayer received the oracle signatures before and still submits first message to proxy.
-		fn do_send_message(
-			sender: &T::AccountId,
-			payer: &T::AccountId,
-			nonce: MessageNonce,
-			recipient: SubjectFor<T>,
-			payload: Vec<u8>,
-			ttl: BlockNumberFor<T>,
-			fee: BalanceOf<T, I>,
-		) -> Result<NewAndMaybeOldMessage<T, I>, Error<T, I>> {
-			ensure!(fee >= T::MinFee::get(), Error::<T, I>::FeeTooLow);
-			let current_block = <frame_system::Pallet<T>>::block_number();
+			let bond = Self::oracle_bonds(public).ok_or(Error::<T, I>::OracleNotBonded)?;
+			ensure!(bond.owner == who, Error::<T, I>::NotOracleOwner);
+			let until = bond.unbonding_until.ok_or(Error::<T, I>::OracleNotUnbonding)?;
 
-			let layer = Layer::Extrinsic(sender.clone());
-			let sender = match T::SelfChain::get() {
-				ProxyAcurastChain::Acurast => Subject::Acurast(layer),
-				ProxyAcurastChain::AcurastCanary => Subject::AcurastCanary(layer),
-			};
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			ensure!(until <= current_block, Error::<T, I>::UnbondingPeriodNotPassed);
 
-			// Prevent self-messaging: sender and recipient must be from different chains
-			ensure!(
-				ProxyChain::from(&sender) != ProxyChain::from(&recipient),
-				Error::<T, I>::SelfMessagingNotAllowed
-			);
+			T::Currency::release(
+				&HoldReason::OracleBond.into(),
+				&who,
+				bond.amount,
+				Precision::BestEffort,
+			)
+			.map_err(|_| Error::<T, I>::CouldNotReleaseOracleBond)?;
 
-			// look for duplicates
-			let id = Self::message_id(&sender, nonce);
-			let mut replaced_message: Option<OutgoingMessageWithMetaFor<T, I>> = None;
-			if let Some(message) = Self::outgoing_messages(id) {
-				// potential duplicate found: check for ttl
-				ensure!(
-					message.ttl_block < current_block,
-					Error::<T, I>::MessageWithSameNoncePending
-				);
-				// release previous fee
-				_ = T::Currency::release(
-					&HoldReason::OutgoingMessageFee.into(),
-					&message.payer,
-					message.fee,
-					Precision::Exact,
-				)
-				.map_err(|_| Error::<T, I>::CouldNotReleaseHoldFee)?;
-				replaced_message = Some(message);
-				// continue below and overwrite message
-			}
+			<OracleBonds<T, I>>::remove(public);
+			<OraclePublicKeys<T, I>>::remove(public);
+			<OracleWeights<T, I>>::remove(public);
 
-			// validate params
-			ensure!(ttl >= T::MinTTL::get(), Error::<T, I>::TTLSmallerThanMinimum);
+			Self::deposit_event(Event::OracleBondWithdrawn {
+				public,
+				owner: who,
+				amount: bond.amount,
+			});
+
+			Ok(())
+		}
+
+		/// Reports an equivocation of a bonded oracle, slashing its full bond.
+		///
+		/// Can be called by anyone. The caller receives [`<T as Config<I>>::EquivocationBounty`] of the slashed bond, the rest is burned.
+		/// Signatures over the root of a batch are accepted together with a [`MerkleProof`] of the message's inclusion.
+		#[pallet::call_index(9)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::report_equivocation())]
+		pub fn report_equivocation(
+			origin: OriginFor<T>,
+			public: Public,
+			proof: EquivocationProofFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let reporter = ensure_signed(origin)?;
+
+			let bond = Self::oracle_bonds(public).ok_or(Error::<T, I>::OracleNotBonded)?;
+			ensure!(proof.is_well_formed(), Error::<T, I>::InvalidEquivocationProof);
+
+			match &proof {
+				EquivocationProof::ConflictingMessages { first, second } => {
+					Self::ensure_signed_by(first, &public)?;
+					Self::ensure_signed_by(second, &public)?;
+				},
+				EquivocationProof::UnsentMessageDelivery(signed) => {
+					ensure!(
+						<SentMessageIds<T, I>>::contains_key(signed.message.id),
+						Error::<T, I>::MessageNotTracked
+					);
+					ensure!(!Self::was_sent(&signed.message), Error::<T, I>::MessageWasSent);
+					Self::ensure_signed_by(signed, &public)?;
+				},
+			}
+
+			Self::slash_oracle(public, bond, reporter)?;
+
+			Ok(Pays::No.into())
+		}
+
+		/// Updates the minimum number of active bonded oracles.
+		#[pallet::call_index(10)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::set_min_oracle_set_size())]
+		pub fn set_min_oracle_set_size(origin: OriginFor<T>, size: u32) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			<MinOracleSetSize<T, I>>::set(size);
+
+			Self::deposit_event(Event::MinOracleSetSizeUpdated { size });
+
+			Ok(())
+		}
+
+		/// Used to set or reset the weights oracles contribute to a quorum.
+		///
+		/// Weights are limited to [`<T as Config<I>>::MaxOracleWeight`]. Setting the weight of a bonded oracle approves it,
+		/// which should only happen while the bonds of every set of approved oracles able to reach a quorum exceed the
+		/// value a forged message could release.
+		#[pallet::call_index(11)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::update_oracle_weights(updates.len() as u32))]
+		pub fn update_oracle_weights(
+			origin: OriginFor<T>,
+			updates: OracleWeightUpdates,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let max = T::MaxOracleWeight::get();
+			ensure!(
+				updates.iter().all(|(_, weight)| weight.map_or(true, |weight| weight <= max)),
+				Error::<T, I>::OracleWeightTooHigh
+			);
+
+			for (public, weight) in updates.iter() {
+				<OracleWeights<T, I>>::set(public, *weight);
+			}
+
+			Self::deposit_event(Event::OracleWeightsUpdated { updates: updates.into_inner() });
+
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
+		}
+
+		/// Used to set or remove quorum overrides per proxy chain or recipient.
+		///
+		/// Overrides can only raise the quorum above the global minimum configured for the runtime.
+		#[pallet::call_index(12)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::update_quorums(updates.len() as u32))]
+		pub fn update_quorums(
+			origin: OriginFor<T>,
+			updates: QuorumUpdates<T>,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			for update in updates.iter() {
+				match update {
+					QuorumUpdate::Chain(chain, quorum) => {
+						<ChainQuorums<T, I>>::set(chain, *quorum);
+					},
+					QuorumUpdate::Recipient(recipient, quorum) => {
+						<RecipientQuorums<T, I>>::set(recipient, *quorum);
+					},
+				}
+			}
+
+			Self::deposit_event(Event::QuorumsUpdated { updates: updates.into_inner() });
+
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
+		}
+
+		/// Replaces the oracle set used for aggregated attestations.
+		///
+		/// Every oracle has to prove the possession of its aggregate key's secret.
+		/// Changing the order invalidates signer bitmaps of attestations created for the previous set.
+		#[pallet::call_index(13)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::update_aggregate_oracles(oracles.len() as u32))]
+		pub fn update_aggregate_oracles(
+			origin: OriginFor<T>,
+			oracles: AggregateOracleSet,
+		) -> DispatchResultWithPostInfo {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				oracles.iter().all(|oracle| {
+					T::AggregateSignatureVerifier::verify_proof_of_possession(
+						&oracle.aggregate_public,
+						&oracle.proof_of_possession,
+					)
+				}),
+				Error::<T, I>::InvalidProofOfPossession
+			);
+
+			let count = oracles.len() as u32;
+			<AggregateOracles<T, I>>::set(oracles);
+
+			Self::deposit_event(Event::AggregateOraclesUpdated { count });
+
+			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
+		}
+
+		/// Receives messages attested by an aggregated oracle signature.
+		#[pallet::call_index(14)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::receive_message_aggregated(attestation.signer_count()))]
+		pub fn receive_message_aggregated(
+			origin: OriginFor<T>,
+			sender: SubjectFor<T>,
+			nonce: MessageNonce,
+			recipient: SubjectFor<T>,
+			payload: Payload,
+			relayer: MultiOrigin<T::AccountId>,
+			attestation: AggregateAttestation,
+		) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+
+			Self::do_receive_message(
+				sender,
+				nonce,
+				recipient,
+				payload,
+				relayer,
+				AttestationSource::Message(Attestation::Aggregate(attestation)),
+			)
+		}
+
+		/// Confirms a message delivery attested by an aggregated oracle signature, claiming the message fee.
+		#[pallet::call_index(15)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::confirm_message_delivery_aggregated(attestation.signer_count()))]
+		pub fn confirm_message_delivery_aggregated(
+			origin: OriginFor<T>,
+			id: MessageId,
+			attestation: AggregateAttestation,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_confirm_message_delivery(
+				who,
+				id,
+				AttestationSource::Message(Attestation::Aggregate(attestation)),
+			)
+		}
+
+		/// Receives a batch of messages, each attested on its own or all by a `batch_attestation` over their [`merkle_root`].
+		///
+		/// Messages are processed independently: a failing message is reported in [`Event::BatchItemFailed`] without reverting the others.
+		#[pallet::call_index(16)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::receive_messages(messages.len() as u32).saturating_add(Pallet::<T, I>::aggregate_signatures_weight(batch_attestation.iter().chain(messages.iter().filter_map(|submission| submission.attestation.as_ref())))))]
+		pub fn receive_messages(
+			origin: OriginFor<T>,
+			messages: IncomingMessageBatch<T>,
+			batch_attestation: Option<Attestation>,
+		) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+
+			let hashes: Vec<MessageHash> = messages
+				.iter()
+				.map(|submission| {
+					Self::message_hash(&MessageFor::<T> {
+						id: Self::message_id(&submission.sender, submission.nonce),
+						sender: submission.sender.clone(),
+						nonce: submission.nonce,
+						recipient: submission.recipient.clone(),
+						payload: submission.payload.clone(),
+					})
+				})
+				.collect();
+			let batch_weight = batch_attestation
+				.map(|attestation| {
+					let root = merkle_root::<T::MessageIdHashing>(hashes.clone());
+					Self::check_batch_attestation(root, None, attestation, 0)
+				})
+				.transpose()?;
+
+			for (index, (submission, hash)) in messages.into_iter().zip(hashes).enumerate() {
+				let Some(attestation) =
+					Self::attestation_source(submission.attestation, hash, batch_weight)
+				else {
+					Self::deposit_event(Event::BatchItemFailed {
+						index: index as u32,
+						error: Error::<T, I>::NotEnoughSignaturesProvided.into(),
+					});
+					continue;
+				};
+				if let Err(error) = with_storage_layer(|| {
+					Self::do_receive_message(
+						submission.sender,
+						submission.nonce,
+						submission.recipient,
+						submission.payload,
+						submission.relayer,
+						attestation,
+					)
+				}) {
+					Self::deposit_event(Event::BatchItemFailed { index: index as u32, error });
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Confirms a batch of message deliveries, each attested on its own or all by a `batch_attestation` over the [`merkle_root`] of their hashes.
+		///
+		/// Confirmations are processed independently: a failing confirmation is reported in [`Event::BatchItemFailed`] without reverting the others.
+		#[pallet::call_index(17)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::confirm_message_deliveries(confirmations.len() as u32).saturating_add(Pallet::<T, I>::aggregate_signatures_weight(batch_attestation.iter().chain(confirmations.iter().filter_map(|confirmation| confirmation.attestation.as_ref())))))]
+		pub fn confirm_message_deliveries(
+			origin: OriginFor<T>,
+			confirmations: DeliveryConfirmationBatch,
+			batch_attestation: Option<Attestation>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let batch_weight = batch_attestation
+				.map(|attestation| {
+					let root = merkle_root::<T::MessageIdHashing>(
+						confirmations.iter().map(|confirmation| confirmation.hash).collect(),
+					);
+					// include the relayer's address like for single confirmations to avoid front-running
+					Self::check_batch_attestation(root, Some(who.clone()), attestation, 0)
+				})
+				.transpose()?;
+
+			for (index, confirmation) in confirmations.into_iter().enumerate() {
+				let Some(attestation) = Self::attestation_source(
+					confirmation.attestation,
+					confirmation.hash,
+					batch_weight,
+				) else {
+					Self::deposit_event(Event::BatchItemFailed {
+						index: index as u32,
+						error: Error::<T, I>::NotEnoughSignaturesProvided.into(),
+					});
+					continue;
+				};
+				if let Err(error) = with_storage_layer(|| {
+					Self::do_confirm_message_delivery(who.clone(), confirmation.id, attestation)
+				}) {
+					Self::deposit_event(Event::BatchItemFailed { index: index as u32, error });
+				}
+			}
+
+			Ok(())
+		}
+
+		/// Updates how messages from `chain` are verified.
+		#[pallet::call_index(18)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::update_message_verifier())]
+		pub fn update_message_verifier(
+			origin: OriginFor<T>,
+			chain: ProxyChain,
+			verifier: MessageVerifier,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			<MessageVerifiers<T, I>>::insert(chain, verifier);
+			Self::deposit_event(Event::MessageVerifierUpdated { chain, verifier });
+
+			Ok(())
+		}
+
+		/// Updates or (if `layout` is `None`) removes where the Ethereum IBC contract stores outgoing messages.
+		#[pallet::call_index(19)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::update_ethereum_ibc())]
+		pub fn update_ethereum_ibc(
+			origin: OriginFor<T>,
+			layout: Option<EthereumIbcLayout>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			<EthereumIbc<T, I>>::set(layout.clone());
+			Self::deposit_event(Event::EthereumIbcUpdated { layout });
+
+			Ok(())
+		}
+
+		/// Stores the state root of a finalized Ethereum execution block, proven by the signature of the sync committee.
+		///
+		/// Can be submitted by anyone, the update has to advance the finalized slot of the light client.
+		#[pallet::call_index(20)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::submit_ethereum_finality_update(update.next_sync_committee.is_some() as u32))]
+		pub fn submit_ethereum_finality_update(
+			origin: OriginFor<T>,
+			update: Box<EthereumFinalityUpdate>,
+		) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+
+			Self::do_submit_ethereum_finality_update(&update)
+		}
+
+		/// Receives a message proven to be stored in the state of the proxy chain it was sent from.
+		///
+		/// Only accepted for proxy chains using [`MessageVerifier::StateProof`].
+		#[pallet::call_index(21)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::receive_message_with_proof(proof.node_count()))]
+		pub fn receive_message_with_proof(
+			origin: OriginFor<T>,
+			sender: SubjectFor<T>,
+			nonce: MessageNonce,
+			recipient: SubjectFor<T>,
+			payload: Payload,
+			relayer: MultiOrigin<T::AccountId>,
+			proof: MessageProof,
+		) -> DispatchResult {
+			let _who = ensure_signed(origin)?;
+
+			Self::do_receive_message(
+				sender,
+				nonce,
+				recipient,
+				payload,
+				relayer,
+				AttestationSource::Proof(proof),
+			)
+		}
+
+		/// Updates the parameters of the Ethereum beacon chain followed by the light client, e.g. to schedule a fork.
+		///
+		/// Initializes the light client with a trusted `sync_committee` for a sync committee period if given.
+		#[pallet::call_index(22)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::update_ethereum_light_client(sync_committee.is_some() as u32))]
+		pub fn update_ethereum_light_client(
+			origin: OriginFor<T>,
+			config: EthereumBeaconConfig,
+			sync_committee: Option<(u64, SyncCommittee)>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+			ensure!(
+				beacon::is_valid_fork_schedule(&config),
+				Error::<T, I>::InvalidBeaconForkSchedule
+			);
+
+			if let Some((period, committee)) = sync_committee {
+				let publics = beacon::stored_publics(&committee).map_err(Error::<T, I>::from)?;
+				<EthereumSyncCommittees<T, I>>::insert(period, publics);
+				Self::deposit_event(Event::EthereumSyncCommitteeUpdated { period });
+			}
+			<EthereumBeacon<T, I>>::put(config.clone());
+			Self::deposit_event(Event::EthereumLightClientUpdated { config });
+
+			Ok(())
+		}
+
+		/// Prunes the [`SentMessages`] of ids for which [`<T as Config<I>>::SentMessageRetention`] passed after their TTL.
+		#[pallet::call_index(23)]
+		#[pallet::weight(< T as Config < I >>::WeightInfo::clean_sent_messages(ids.len() as u32))]
+		pub fn clean_sent_messages(
+			origin: OriginFor<T>,
+			ids: MessagesCleanup,
+		) -> DispatchResultWithPostInfo {
+			let _who = ensure_signed(origin)?;
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+
+			let l = ids.len();
+			let mut i = 0usize;
+			for id in ids.iter() {
+				let Some(prunable_after) = <SentMessageIds<T, I>>::get(id) else {
+					continue;
+				};
+				if prunable_after < current_block {
+					<SentMessageIds<T, I>>::remove(id);
+					let _ = <SentMessages<T, I>>::clear_prefix(id, u32::MAX, None);
+					i += 1;
+				}
+			}
+
+			if i == l {
+				Ok(Pays::No.into())
+			} else {
+				Ok(().into())
+			}
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		fn do_confirm_message_delivery(
+			who: T::AccountId,
+			id: MessageId,
+			attestation: AttestationSource,
+		) -> DispatchResult {
+			let message = Self::outgoing_messages(id).ok_or(Error::<T, I>::MessageNotFound)?;
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+
+			ensure!(message.ttl_block >= current_block, Error::<T, I>::DeliveryConfirmationOverdue);
+
+			let quorum = Self::delivery_quorum(&message.message);
+			match attestation {
+				AttestationSource::Message(attestation) => {
+					Self::check_attestation(
+						&message.message,
+						// include the relayer's address in the payload validated for signature to avoid front-running on actual relayer that paid for tx on destination chain
+						Some(who.clone()),
+						attestation,
+						quorum,
+					)?;
+				},
+				AttestationSource::Batch { hash, weight } => {
+					ensure!(
+						hash == Self::message_hash(&message.message),
+						Error::<T, I>::BatchMessageMismatch
+					);
+					ensure!(weight >= quorum, Error::<T, I>::NotEnoughSignaturesValid);
+				},
+				// deliveries to proxy chains are only attested by oracles
+				AttestationSource::Proof(_) => Err(Error::<T, I>::UnexpectedAttestation)?,
+			}
+
+			T::Currency::transfer_on_hold(
+				&HoldReason::OutgoingMessageFee.into(),
+				&message.payer,
+				&who,
+				message.fee,
+				Precision::Exact,
+				Restriction::Free,
+				Fortitude::Polite,
+			)
+			.map_err(|_| Error::<T, I>::CouldNotReleaseHoldFee)?;
+
+			Self::deposit_event(Event::MessageDelivered { id });
+
+			// clear
+			<OutgoingMessages<T, I>>::remove(id);
+			<OutgoingMessagesLookup<T, I>>::remove(&message.message.sender, message.message.nonce);
+
+			Ok(())
+		}
+
+		fn do_submit_ethereum_finality_update(update: &EthereumFinalityUpdate) -> DispatchResult {
+			let config =
+				Self::ethereum_beacon().ok_or(Error::<T, I>::EthereumLightClientNotInitialized)?;
+			let period = beacon::sync_committee_period(update.signature_slot);
+			let committee = <EthereumSyncCommittees<T, I>>::get(period)
+				.ok_or(Error::<T, I>::UnknownSyncCommittee)?;
+
+			let next_committee = beacon::verify_finality_update(
+				&config,
+				&committee,
+				Self::ethereum_finalized_slot(),
+				update,
+			)
+			.map_err(Error::<T, I>::from)?;
+
+			if let Some(publics) = next_committee {
+				let next_period = beacon::sync_committee_period(update.attested_header.slot) + 1;
+				<EthereumSyncCommittees<T, I>>::insert(next_period, publics);
+				// only the current and next sync committee are needed to follow the chain
+				<EthereumSyncCommittees<T, I>>::remove(next_period.saturating_sub(2));
+				Self::deposit_event(Event::EthereumSyncCommitteeUpdated { period: next_period });
+			}
+
+			<EthereumFinalizedSlot<T, I>>::put(update.finalized_header.slot);
+			Self::store_ethereum_state_root(
+				update.execution_block_number,
+				update.execution_state_root,
+			);
+			Self::deposit_event(Event::EthereumStateRootFinalized {
+				slot: update.finalized_header.slot,
+				block_number: update.execution_block_number,
+				state_root: update.execution_state_root,
+			});
+
+			Ok(())
+		}
+
+		/// Stores a finalized state root, replacing the oldest one once [`Config::MaxEthereumStateRoots`] are stored.
+		fn store_ethereum_state_root(block_number: u64, state_root: H256) {
+			let index = <EthereumStateRootsBufferIndex<T, I>>::get();
+			if let Some(oldest) = <EthereumStateRootsBuffer<T, I>>::get(index) {
+				<EthereumStateRoots<T, I>>::remove(oldest);
+			}
+			<EthereumStateRootsBuffer<T, I>>::insert(index, block_number);
+			<EthereumStateRoots<T, I>>::insert(block_number, state_root);
+			<EthereumStateRootsBufferIndex<T, I>>::put(
+				index.saturating_add(1) % T::MaxEthereumStateRoots::get().max(1),
+			);
+		}
+
+		fn do_receive_message(
+			sender: SubjectFor<T>,
+			nonce: MessageNonce,
+			recipient: SubjectFor<T>,
+			payload: Payload,
+			relayer: MultiOrigin<T::AccountId>,
+			attestation: AttestationSource,
+		) -> DispatchResult {
+			// Validate recipient matches the configured chain
+			ensure!(
+				ProxyChain::from(&recipient) == (&T::SelfChain::get()).into(),
+				Error::<T, I>::IncorrectRecipient
+			);
+
+			let id = Self::message_id(&sender, nonce);
+			ensure!(
+				<IncomingMessages<T, I>>::get(id).is_none(),
+				Error::<T, I>::MessageAlreadyReceived
+			);
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+
+			let message =
+				MessageFor::<T> { id, sender, nonce, recipient: recipient.clone(), payload };
+
+			let quorum = Self::receipt_quorum(&message);
+			let verifier = Self::message_verifier(ProxyChain::from(&message.sender));
+			// proven messages are not attested by oracles and report an attested weight of 0
+			let attested_weight = match (verifier, attestation) {
+				(MessageVerifier::Oracles, AttestationSource::Message(attestation)) => {
+					Self::check_attestation(&message, None, attestation, quorum)?
+				},
+				(MessageVerifier::Oracles, AttestationSource::Batch { hash, weight }) => {
+					ensure!(
+						hash == Self::message_hash(&message),
+						Error::<T, I>::BatchMessageMismatch
+					);
+					ensure!(weight >= quorum, Error::<T, I>::NotEnoughSignaturesValid);
+					weight
+				},
+				(MessageVerifier::StateProof, AttestationSource::Proof(proof)) => {
+					let is_valid = T::MessageProofVerifier::verify(&message, &proof);
+					#[cfg(not(feature = "runtime-benchmarks"))]
+					ensure!(is_valid, Error::<T, I>::MessageProofInvalid);
+					0
+				},
+				_ => Err(Error::<T, I>::UnexpectedAttestation)?,
+			};
+
+			let message_with_meta = IncomingMessageWithMetaFor::<T> {
+				message: message.clone(),
+				current_block,
+				relayer,
+			};
+			<IncomingMessages<T, I>>::insert(id, message_with_meta.clone());
+			<IncomingMessagesLookup<T, I>>::insert(&recipient, id, ());
+
+			// don't fail extrinsic from here onwards
+			if let Err(e) = Self::process_message(message.clone()) {
+				log::warn!("Received message {:?} processed with errors: {:?}", message, e.error);
+				Self::deposit_event(Event::MessageProcessedWithErrors {
+					message: message_with_meta,
+					attested_weight,
+				});
+			} else {
+				Self::deposit_event(Event::MessageProcessed {
+					message: message_with_meta,
+					attested_weight,
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Prefers a message's own attestation over the batch's, if any.
+		fn attestation_source(
+			attestation: Option<Attestation>,
+			hash: MessageHash,
+			batch_weight: Option<u32>,
+		) -> Option<AttestationSource> {
+			match (attestation, batch_weight) {
+				(Some(attestation), _) => Some(AttestationSource::Message(attestation)),
+				(None, Some(weight)) => Some(AttestationSource::Batch { hash, weight }),
+				(None, None) => None,
+			}
+		}
+
+		/// Checks an attestation over `message` and returns the total weight of its valid signers.
+		pub fn check_attestation(
+			message: &MessageFor<T>,
+			relayer: Option<T::AccountId>,
+			attestation: Attestation,
+			quorum: u32,
+		) -> Result<u32, Error<T, I>> {
+			let full_message = Self::signed_payload(message, relayer.as_ref());
+			Self::check_attestation_over(full_message.as_slice(), attestation, quorum)
+		}
+
+		/// Checks an attestation over the Merkle root of a batch, see [`merkle_root`], and returns the total weight of its valid signers.
+		pub fn check_batch_attestation(
+			root: MessageHash,
+			relayer: Option<T::AccountId>,
+			attestation: Attestation,
+			quorum: u32,
+		) -> Result<u32, Error<T, I>> {
+			let full_message = Self::batch_payload(root, relayer.as_ref());
+			Self::check_attestation_over(full_message.as_slice(), attestation, quorum)
+		}
+
+		fn check_attestation_over(
+			full_message: &[u8],
+			attestation: Attestation,
+			quorum: u32,
+		) -> Result<u32, Error<T, I>> {
+			match attestation {
+				Attestation::Signatures(signatures) => {
+					Self::check_signatures(full_message, signatures, quorum)
+				},
+				Attestation::Aggregate(aggregate) => {
+					Self::check_aggregate_signature(full_message, aggregate, quorum)
+				},
+			}
+		}
+
+		/// Checks the oracle signatures over `full_message` and returns the total weight of valid signatures.
+		///
+		/// Fails if the total weight does not reach `quorum`.
+		pub fn check_signatures(
+			full_message: &[u8],
+			signatures: Signatures,
+			quorum: u32,
+		) -> Result<u32, Error<T, I>> {
+			ensure!(
+				quorum.is_zero() || !signatures.is_empty(),
+				Error::<T, I>::NotEnoughSignaturesProvided
+			);
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+
+			// we always deny invalid signatures but skip signatures for unknown public keys or known public keys outside activity window
+			// this allows for some flexibility while deprecating relayer oracles
+			let mut not_found: Vec<(
+				[u8; SIGNATURE_SERIALIZED_SIZE],
+				[u8; PUBLIC_KEY_SERIALIZED_SIZE],
+			)> = Default::default();
+			let mut outside_activity_window: Vec<(
+				[u8; SIGNATURE_SERIALIZED_SIZE],
+				[u8; PUBLIC_KEY_SERIALIZED_SIZE],
+			)> = Default::default();
+			let mut valid: u32 = 0;
+			let mut checked: BTreeSet<Public> = BTreeSet::new();
+			signatures.into_iter().try_for_each(
+				|(signature, public)| -> Result<(), Error<T, I>> {
+					match <OraclePublicKeys<T, I>>::get(public) {
+						None => {
+							not_found.push((signature.0, public.0));
+						},
+						Some(activity_window) if !checked.contains(&public) => {
+							// valid window is defined inclusive start_block, exclusive end_block
+							if activity_window.is_active(&current_block) {
+								let is_valid = signature.verify(full_message, &public);
+								#[cfg(not(feature = "runtime-benchmarks"))]
+								ensure!(is_valid, Error::<T, I>::SignatureInvalid);
+								valid = valid.saturating_add(Self::oracle_weight(&public));
+								checked.insert(public);
+							} else {
+								outside_activity_window.push((signature.0, public.0));
+							}
+						},
+						_ => {},
+					}
+
+					Ok(())
+				},
+			)?;
+
+			if !not_found.len().is_zero() {
+				log::warn!(
+					"Some provided signatures from unknown public keys provided: {:?}",
+					not_found
+				);
+			}
+			if !outside_activity_window.len().is_zero() {
+				log::warn!("Some provided signatures were invalid since the oracles' public key are outside their activity window: {:?}", outside_activity_window);
+			}
+
+			if valid < quorum {
+				log::info!("check_signatures failed since not enough valid signatures remain. Unknown oracles: {:?}, inactive oracles: {:?}.", outside_activity_window, outside_activity_window);
+				Err(Error::<T, I>::NotEnoughSignaturesValid)?
+			}
+
+			Ok(valid)
+		}
+
+		/// Checks an aggregated signature over `full_message` and returns the total weight of its active signers.
+		///
+		/// Inactive or unknown signers must still be part of the aggregate but do not count towards the quorum.
+		pub fn check_aggregate_signature(
+			full_message: &[u8],
+			attestation: AggregateAttestation,
+			quorum: u32,
+		) -> Result<u32, Error<T, I>> {
+			let oracles = Self::aggregate_oracles();
+			ensure!(
+				!attestation.has_signers_beyond(oracles.len()),
+				Error::<T, I>::InvalidSignerBitmap
+			);
+
+			let current_block = <frame_system::Pallet<T>>::block_number();
+
+			let mut valid: u32 = 0;
+			let mut signers: Vec<AggregatePublic> = Vec::new();
+			for (index, oracle) in oracles.into_iter().enumerate() {
+				if !attestation.is_signer(index) {
+					continue;
+				}
+				if <OraclePublicKeys<T, I>>::get(oracle.public)
+					.is_some_and(|activity_window| activity_window.is_active(&current_block))
+				{
+					valid = valid.saturating_add(Self::oracle_weight(&oracle.public));
+				}
+				signers.push(oracle.aggregate_public);
+			}
+
+			// check the quorum first since it is cheaper than verifying the signature
+			if valid < quorum {
+				log::info!("check_aggregate_signature failed since the active signers' weight {:?} is below the quorum {:?}.", valid, quorum);
+				Err(Error::<T, I>::NotEnoughSignaturesValid)?
+			}
+
+			let is_valid = T::AggregateSignatureVerifier::verify(
+				full_message,
+				&signers,
+				&attestation.signature,
+			);
+			#[cfg(not(feature = "runtime-benchmarks"))]
+			ensure!(is_valid, Error::<T, I>::AggregateSignatureInvalid);
+
+			Ok(valid)
+		}
+
+		/// The weight of checking the aggregated signatures among `attestations`, which grows with their signer count.
+		pub fn aggregate_signatures_weight<'a>(
+			attestations: impl Iterator<Item = &'a Attestation>,
+		) -> Weight {
+			attestations.fold(Weight::zero(), |weight, attestation| match attestation {
+				Attestation::Signatures(_) => weight,
+				Attestation::Aggregate(aggregate) => weight.saturating_add(
+					T::WeightInfo::check_aggregate_signature(aggregate.signer_count()),
+				),
+			})
+		}
+
+		/// The weight an oracle's valid signature contributes to a quorum.
+		pub fn oracle_weight(public: &Public) -> u32 {
+			Self::oracle_weights(public).unwrap_or(DEFAULT_ORACLE_WEIGHT)
+		}
+
+		/// The quorum override for a route, preferring the recipient's over the proxy chain's.
+		pub fn route_quorum(chain: &ProxyChain, recipient: &SubjectFor<T>) -> Option<Quorum> {
+			Self::recipient_quorums(recipient).or_else(|| Self::chain_quorums(chain))
+		}
+
+		/// The weight required to receive `message`, sent from a proxy chain.
+		///
+		/// An override below [`<T as Config<I>>::MinReceiptConfirmationSignatures`] is raised to it, so overrides
+		/// can only make a route stricter.
+		pub fn receipt_quorum(message: &MessageFor<T>) -> u32 {
+			let min = T::MinReceiptConfirmationSignatures::get();
+			Self::route_quorum(&ProxyChain::from(&message.sender), &message.recipient)
+				.map_or(min, |quorum| quorum.receipt.max(min))
+		}
+
+		/// The weight required to confirm the delivery of `message`, sent to a proxy chain.
+		///
+		/// An override below [`<T as Config<I>>::MinDeliveryConfirmationSignatures`] is raised to it, so overrides
+		/// can only make a route stricter.
+		pub fn delivery_quorum(message: &MessageFor<T>) -> u32 {
+			let min = T::MinDeliveryConfirmationSignatures::get();
+			Self::route_quorum(&ProxyChain::from(&message.recipient), &message.recipient)
+				.map_or(min, |quorum| quorum.delivery.max(min))
+		}
+
+		pub(crate) fn message_id(sender: &SubjectFor<T>, nonce: MessageNonce) -> MessageId {
+			T::MessageIdHashing::hash_of(&(sender, nonce))
+		}
+
+		pub(crate) fn message_hash(message: &MessageFor<T>) -> MessageHash {
+			T::MessageIdHashing::hash_of(message)
+		}
+
+		/// The payload oracles sign, including the relayer's address for delivery confirmations.
+		pub fn signed_payload(message: &MessageFor<T>, relayer: Option<&T::AccountId>) -> Vec<u8> {
+			if let Some(relayer) = relayer {
+				(T::ParachainId::get(), message, relayer).encode()
+			} else {
+				(T::ParachainId::get(), message).encode()
+			}
+		}
+
+		/// The payload oracles sign to attest a whole batch, including the relayer's address for delivery confirmations.
+		pub fn batch_payload(root: MessageHash, relayer: Option<&T::AccountId>) -> Vec<u8> {
+			if let Some(relayer) = relayer {
+				(T::ParachainId::get(), b"hyperdrive-ibc-batch", root, relayer).encode()
+			} else {
+				(T::ParachainId::get(), b"hyperdrive-ibc-batch", root).encode()
+			}
+		}
+
+		/// The payload an oracle signs to prove that `owner` may bond for its public key.
+		pub fn oracle_ownership_payload(owner: &T::AccountId) -> Vec<u8> {
+			(T::ParachainId::get(), b"hyperdrive-ibc-oracle-bond", owner).encode()
+		}
+
+		fn ensure_signed_by(
+			signed: &SignedMessageFor<T>,
+			public: &Public,
+		) -> Result<(), Error<T, I>> {
+			let payload = match &signed.batch_proof {
+				None => Self::signed_payload(&signed.message, signed.relayer.as_ref()),
+				Some(proof) => {
+					let root = merkle_root_from_proof::<T::MessageIdHashing>(
+						Self::message_hash(&signed.message),
+						proof,
+					)
+					.ok_or(Error::<T, I>::InvalidEquivocationProof)?;
+					Self::batch_payload(root, signed.relayer.as_ref())
+				},
+			};
+			let is_valid = signed.signature.verify(payload.as_slice(), public);
+			#[cfg(not(feature = "runtime-benchmarks"))]
+			ensure!(is_valid, Error::<T, I>::EquivocationSignatureInvalid);
+			Ok(())
+		}
+
+		/// Whether `message` was sent from this chain, either still pending or recorded in [`SentMessages`].
+		fn was_sent(message: &MessageFor<T>) -> bool {
+			Self::outgoing_messages(message.id).is_some_and(|m| &m.message == message)
+				|| <SentMessages<T, I>>::contains_key(message.id, Self::message_hash(message))
+		}
+
+		fn slash_oracle(
+			public: Public,
+			bond: OracleBondFor<T, I>,
+			reporter: T::AccountId,
+		) -> DispatchResult {
+			let reason = HoldReason::OracleBond.into();
+			let bounty = T::EquivocationBounty::get() * bond.amount;
+			if !bounty.is_zero() {
+				T::Currency::transfer_on_hold(
+					&reason,
+					&bond.owner,
+					&reporter,
+					bounty,
+					Precision::BestEffort,
+					Restriction::Free,
+					Fortitude::Force,
+				)?;
+			}
+			T::Currency::burn_held(
+				&reason,
+				&bond.owner,
+				bond.amount.saturating_sub(bounty),
+				Precision::BestEffort,
+				Fortitude::Force,
+			)?;
+
+			<OracleBonds<T, I>>::remove(public);
+			<OraclePublicKeys<T, I>>::remove(public);
+			<OracleWeights<T, I>>::remove(public);
+			if bond.unbonding_until.is_none() {
+				<ActiveBondedOracles<T, I>>::mutate(|count| *count = count.saturating_sub(1));
+			}
+
+			Self::deposit_event(Event::OracleSlashed {
+				public,
+				owner: bond.owner,
+				amount: bond.amount,
+				reporter,
+				bounty,
+			});
+
+			Ok(())
+		}
+
+		/// Sends a message by the given `sender` paid by a potentially different `payer`.
+		///
+		/// **NOTE**:
+		///
+		/// * This is an internal function but could be made available
+		/// to other pallets if the authorization for the passed `sender` has been ensured at the caller.
+		/// Be careful to not allow for unintended impersonation.
+		/// * _Exactly-once delivery_ is **not** guaranteed even the `nonce` serves as deduplication during ttl; While, after ttl passed and message fee cannot be claimed by relayer, a _different_ message with same nonce can be sent off, it cannot be guaranteed a relayer received the oracle signatures before and still submits first message to proxy.
+		fn do_send_message(
+			sender: &T::AccountId,
+			payer: &T::AccountId,
+			nonce: MessageNonce,
+			recipient: SubjectFor<T>,
+			payload: Vec<u8>,
+			ttl: BlockNumberFor<T>,
+			fee: BalanceOf<T, I>,
+		) -> Result<NewAndMaybeOldMessage<T, I>, Error<T, I>> {
+			ensure!(fee >= T::MinFee::get(), Error::<T, I>::FeeTooLow);
+			let current_block = <frame_system::Pallet<T>>::block_number();
+
+			let layer = Layer::Extrinsic(sender.clone());
+			let sender = match T::SelfChain::get() {
+				ProxyAcurastChain::Acurast => Subject::Acurast(layer),
+				ProxyAcurastChain::AcurastCanary => Subject::AcurastCanary(layer),
+			};
+
+			// Prevent self-messaging: sender and recipient must be from different chains
+			ensure!(
+				ProxyChain::from(&sender) != ProxyChain::from(&recipient),
+				Error::<T, I>::SelfMessagingNotAllowed
+			);
+
+			// look for duplicates
+			let id = Self::message_id(&sender, nonce);
+			let mut replaced_message: Option<OutgoingMessageWithMetaFor<T, I>> = None;
+			if let Some(message) = Self::outgoing_messages(id) {
+				// potential duplicate found: check for ttl
+				ensure!(
+					message.ttl_block < current_block,
+					Error::<T, I>::MessageWithSameNoncePending
+				);
+				// release previous fee
+				_ = T::Currency::release(
+					&HoldReason::OutgoingMessageFee.into(),
+					&message.payer,
+					message.fee,
+					Precision::Exact,
+				)
+				.map_err(|_| Error::<T, I>::CouldNotReleaseHoldFee)?;
+				replaced_message = Some(message);
+				// continue below and overwrite message
+			}
+
+			// validate params
+			ensure!(ttl >= T::MinTTL::get(), Error::<T, I>::TTLSmallerThanMinimum);
 
 			let message = MessageFor::<T> {
 				id,
@@ -568,6 +1663,15 @@ pub mod pallet {
 			};
 			<OutgoingMessages<T, I>>::insert(id, &message_with_meta);
 			<OutgoingMessagesLookup<T, I>>::insert(&sender, nonce, id);
+			<SentMessages<T, I>>::insert(id, Self::message_hash(&message_with_meta.message), ());
+			if let Some(replaced_message) = &replaced_message {
+				// also covers messages sent before their id was tracked
+				<SentMessages<T, I>>::insert(id, Self::message_hash(&replaced_message.message), ());
+			}
+			<SentMessageIds<T, I>>::insert(
+				id,
+				message_with_meta.ttl_block.saturating_add(T::SentMessageRetention::get()),
+			);
 
 			T::Currency::hold(&HoldReason::OutgoingMessageFee.into(), payer, fee)
 				.map_err(|_| Error::<T, I>::CouldNotHoldFee)?;
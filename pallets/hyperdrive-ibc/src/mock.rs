@@ -0,0 +1,172 @@
+use cumulus_primitives_core::ParaId;
+use frame_support::{
+	derive_impl,
+	pallet_prelude::*,
+	parameter_types,
+	traits::{ConstU16, ConstU64},
+};
+use frame_system::{self as system, EnsureRoot};
+use pallet_acurast::{MessageBody, MessageProcessor, ProxyAcurastChain};
+use sp_core::{ecdsa, Pair, H256};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage, Perbill,
+};
+
+use crate::{ActivityWindow, OracleUpdate, OracleUpdates};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+
+pub const UNIT: Balance = 1_000_000_000_000;
+pub const INITIAL_BALANCE: Balance = 100 * UNIT;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
+		AcurastHyperdriveIbc: crate::{Pallet, Call, Storage, Event<T>, HoldReason},
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::ParaChainDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Nonce = u64;
+	type Hash = H256;
+	type Block = Block;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type DbWeight = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = ();
+	type MaxFreezes = ConstU32<0>;
+	type DoneSlashHandler = ();
+}
+
+parameter_types! {
+	pub const MinTTL: BlockNumber = 15;
+	pub const IncomingTTL: BlockNumber = 50;
+	pub const MinDeliveryConfirmationSignatures: u32 = 1;
+	pub const MinReceiptConfirmationSignatures: u32 = 1;
+	pub const MinFee: Balance = UNIT / 10;
+	pub const ParachainId: ParaId = ParaId::new(2000);
+	pub const SelfChain: ProxyAcurastChain = ProxyAcurastChain::Acurast;
+	pub const MinOracleBond: Balance = UNIT;
+	pub const OracleUnbondingPeriod: BlockNumber = 10;
+	pub const EquivocationBounty: Perbill = Perbill::from_percent(10);
+	pub const MaxEthereumStateRoots: u32 = 2;
+	pub const SentMessageRetention: BlockNumber = 20;
+	pub const MaxOracleWeight: u32 = 2;
+}
+
+impl crate::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MinTTL = MinTTL;
+	type IncomingTTL = IncomingTTL;
+	type MinDeliveryConfirmationSignatures = MinDeliveryConfirmationSignatures;
+	type MinReceiptConfirmationSignatures = MinReceiptConfirmationSignatures;
+	type MinFee = MinFee;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type MessageIdHashing = BlakeTwo256;
+	type MessageProcessor = AcceptingMessageProcessor;
+	type UpdateOrigin = EnsureRoot<Self::AccountId>;
+	type ParachainId = ParachainId;
+	type SelfChain = SelfChain;
+	type MinOracleBond = MinOracleBond;
+	type OracleUnbondingPeriod = OracleUnbondingPeriod;
+	type EquivocationBounty = EquivocationBounty;
+	type AggregateSignatureVerifier = crate::bls::BlsVerifier;
+	type MessageProofVerifier = ();
+	type MaxEthereumStateRoots = MaxEthereumStateRoots;
+	type SentMessageRetention = SentMessageRetention;
+	type MaxOracleWeight = MaxOracleWeight;
+	type WeightInfo = crate::weights::WeightInfo<Test>;
+}
+
+/// Accepts every incoming message without further processing.
+pub struct AcceptingMessageProcessor;
+impl MessageProcessor<AccountId, AccountId> for AcceptingMessageProcessor {
+	fn process(_message: impl MessageBody<AccountId, AccountId>) -> DispatchResultWithPostInfo {
+		Ok(().into())
+	}
+}
+
+pub fn alice_account_id() -> AccountId {
+	AccountId32::new([1u8; 32])
+}
+
+pub fn bob_account_id() -> AccountId {
+	AccountId32::new([2u8; 32])
+}
+
+/// The key pair of the oracle derived from `seed`.
+pub fn oracle(seed: u8) -> ecdsa::Pair {
+	ecdsa::Pair::from_seed(&[seed; 32])
+}
+
+/// Registers the `oracles` as active from genesis.
+pub fn register_oracles(oracles: &[ecdsa::Pair]) {
+	let updates: Vec<_> = oracles
+		.iter()
+		.map(|oracle| {
+			OracleUpdate::Add(oracle.public(), ActivityWindow { start_block: 0, end_block: None })
+		})
+		.collect();
+	assert!(AcurastHyperdriveIbc::update_oracles(
+		RuntimeOrigin::root(),
+		OracleUpdates::<Test>::try_from(updates).unwrap()
+	)
+	.is_ok());
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut storage = system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(alice_account_id(), INITIAL_BALANCE), (bob_account_id(), INITIAL_BALANCE)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+pub fn events() -> Vec<RuntimeEvent> {
+	let evt = System::events().into_iter().map(|evt| evt.event).collect::<Vec<_>>();
+
+	System::reset_events();
+
+	evt
+}
@@ -1,15 +1,34 @@
+use frame_support::{assert_err, assert_ok, traits::fungible::InspectHold};
 use hex_literal::hex;
 use sp_core::{
 	crypto::{AccountId32, Ss58Codec},
-	ecdsa::{Public, Signature},
-	ByteArray, Encode,
+	ecdsa::{self, Public, Signature},
+	ByteArray, Decode, Encode, Pair, H256,
+};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, Verify},
+	DispatchResult,
 };
-use sp_runtime::traits::Verify;
 
-use pallet_acurast::{AccountId20, ContractCall, Layer, Subject};
+use pallet_acurast::{AccountId20, ContractCall, Layer, MultiOrigin, ProxyChain, Subject};
 
 // Assuming these are in scope:
-use crate::{Message, MessageId, MessageNonce, Payload};
+use crate::{
+	beacon::{
+		self,
+		fixtures::{self as beacon_fixtures, finality_update},
+	},
+	bls::{self, BlsVerifier},
+	ethereum::{fixtures, mapping_slot, message_commitment, verify_storage_proof, ProofError},
+	merkle_root, merkle_root_from_proof,
+	mock::*,
+	AggregateAttestation, AggregatePublic, AggregateSignatureVerifier, Attestation,
+	EquivocationProof, Error, EthereumFinalityUpdate, EthereumSyncCommittees, Event, HoldReason,
+	IncomingMessageSubmission, IncomingMessageSubmissionFor, MerkleProof, Message, MessageFor,
+	MessageHash, MessageId, MessageNonce, MessageProof, MessagesCleanup, Payload, Quorum,
+	QuorumUpdate, Signatures, SignedMessage, SubjectFor, PROOF_NODES_MAX_LENGTH,
+	SLOTS_PER_SYNC_COMMITTEE_PERIOD, SYNC_COMMITTEE_SIZE,
+};
 
 #[test]
 fn encodes_specific_message_correctly() {
@@ -60,3 +79,914 @@ fn encodes_specific_message_correctly() {
 	.unwrap();
 	assert!(signature.verify(&message.encode()[..], &public));
 }
+
+#[test]
+fn equivocation_proof_requires_conflicting_messages() {
+	let sender: Subject<AccountId32, AccountId32> =
+		Subject::Acurast(Layer::Extrinsic(AccountId32::new([1u8; 32])));
+	let recipient: Subject<AccountId32, AccountId32> =
+		Subject::Ethereum(Layer::Contract(ContractCall {
+			contract: AccountId20([2u8; 20]),
+			selector: None,
+		}));
+	let message = |nonce: u8, payload: &[u8]| Message::<AccountId32, AccountId32> {
+		id: MessageId::repeat_byte(nonce),
+		sender: sender.clone(),
+		nonce: MessageNonce::repeat_byte(nonce),
+		recipient: recipient.clone(),
+		payload: Payload::try_from(payload.to_vec()).unwrap(),
+	};
+	let signed =
+		|message: Message<AccountId32, AccountId32>, relayer: Option<AccountId32>| SignedMessage {
+			message,
+			relayer,
+			signature: Signature::from_raw([0u8; 65]),
+			batch_proof: None,
+		};
+
+	// same (sender, nonce) but different payloads
+	assert!(EquivocationProof::ConflictingMessages {
+		first: signed(message(1, b"a"), None),
+		second: signed(message(1, b"b"), None),
+	}
+	.is_well_formed());
+	// the same message signed twice is no equivocation, even for different relayers
+	assert!(!EquivocationProof::ConflictingMessages {
+		first: signed(message(1, b"a"), Some(AccountId32::new([3u8; 32]))),
+		second: signed(message(1, b"a"), Some(AccountId32::new([4u8; 32]))),
+	}
+	.is_well_formed());
+	// different nonces do not conflict
+	assert!(!EquivocationProof::ConflictingMessages {
+		first: signed(message(1, b"a"), None),
+		second: signed(message(2, b"b"), None),
+	}
+	.is_well_formed());
+	// only delivery confirmations, which include the relayer, can prove an unsent message
+	assert!(EquivocationProof::UnsentMessageDelivery(signed(
+		message(1, b"a"),
+		Some(AccountId32::new([3u8; 32]))
+	))
+	.is_well_formed());
+	assert!(
+		!EquivocationProof::UnsentMessageDelivery(signed(message(1, b"a"), None)).is_well_formed()
+	);
+}
+
+#[test]
+fn aggregate_attestation_signer_bitmap() {
+	let attestation = AggregateAttestation {
+		signature: Default::default(),
+		signers: vec![0b0000_0101, 0b1000_0000].try_into().unwrap(),
+	};
+
+	let signers: Vec<usize> = (0..16).filter(|index| attestation.is_signer(*index)).collect();
+	assert_eq!(signers, vec![0, 2, 15]);
+	assert!(!attestation.is_signer(16));
+
+	assert!(!attestation.has_signers_beyond(16));
+	assert!(attestation.has_signers_beyond(15));
+	assert!(attestation.has_signers_beyond(3));
+	assert_eq!(attestation.signer_count(), 3);
+}
+
+// No reference vectors of the IETF ciphersuite are vendored, these tests check consistency of signing and verifying.
+#[test]
+fn verifies_aggregated_bls_signatures() {
+	let message = b"message";
+	let keys: Vec<AggregatePublic> = (0..3).map(|seed| bls::generate_key(seed).0).collect();
+	let signature = bls::sign(&[0, 1, 2], message);
+
+	assert!(BlsVerifier::verify(message, &keys, &signature));
+	assert!(!BlsVerifier::verify(b"other message", &keys, &signature));
+	// missing signer
+	assert!(!BlsVerifier::verify(message, &keys[..2], &signature));
+	// extra signer
+	assert!(!BlsVerifier::verify(message, &keys, &bls::sign(&[0, 1], message)));
+	assert!(!BlsVerifier::verify(message, &[], &signature));
+	assert!(!BlsVerifier::verify(message, &keys, &vec![0u8; 48].try_into().unwrap()));
+}
+
+#[test]
+fn verifies_bls_proofs_of_possession() {
+	let (public, proof) = bls::generate_key(0);
+	let (other_public, other_proof) = bls::generate_key(1);
+
+	assert!(BlsVerifier::verify_proof_of_possession(&public, &proof));
+	assert!(BlsVerifier::verify_proof_of_possession(&other_public, &other_proof));
+	assert!(!BlsVerifier::verify_proof_of_possession(&public, &other_proof));
+	// a signature over the public key under the message domain is no proof of possession
+	assert!(!BlsVerifier::verify_proof_of_possession(&public, &bls::sign(&[0], &public)));
+
+	// the compressed identity
+	let mut identity = vec![0u8; 96];
+	identity[0] = 0xc0;
+	assert!(!BlsVerifier::verify_proof_of_possession(&identity.try_into().unwrap(), &proof));
+	assert!(!BlsVerifier::verify_proof_of_possession(&vec![0xab; 96].try_into().unwrap(), &proof));
+}
+
+#[test]
+fn merkle_root_pairs_leaves_and_promotes_unpaired() {
+	let leaves: Vec<MessageId> = (0u8..3).map(MessageId::repeat_byte).collect();
+	let hash_leaf = |leaf: &MessageId| BlakeTwo256::hash(&[&[0u8][..], leaf.as_bytes()].concat());
+	let hash_pair = |left: &MessageId, right: &MessageId| {
+		BlakeTwo256::hash(&[&[1u8][..], left.as_bytes(), right.as_bytes()].concat())
+	};
+
+	assert_eq!(merkle_root::<BlakeTwo256>(vec![]), MessageId::zero());
+	assert_eq!(merkle_root::<BlakeTwo256>(leaves[..1].to_vec()), hash_leaf(&leaves[0]));
+	assert_eq!(
+		merkle_root::<BlakeTwo256>(leaves[..2].to_vec()),
+		hash_pair(&hash_leaf(&leaves[0]), &hash_leaf(&leaves[1]))
+	);
+	assert_eq!(
+		merkle_root::<BlakeTwo256>(leaves.clone()),
+		hash_pair(
+			&hash_pair(&hash_leaf(&leaves[0]), &hash_leaf(&leaves[1])),
+			&hash_leaf(&leaves[2])
+		)
+	);
+	// a root is no leaf of the tree it is the root of
+	assert_ne!(
+		merkle_root::<BlakeTwo256>(leaves[..2].to_vec()),
+		merkle_root::<BlakeTwo256>(vec![merkle_root::<BlakeTwo256>(leaves[..2].to_vec())])
+	);
+}
+
+#[test]
+fn merkle_proofs_lead_to_the_root() {
+	let leaves: Vec<MessageId> = (0u8..5).map(MessageId::repeat_byte).collect();
+	let root = merkle_root::<BlakeTwo256>(leaves.clone());
+	let hash_leaf = |leaf: &MessageId| BlakeTwo256::hash(&[&[0u8][..], leaf.as_bytes()].concat());
+	let hash_pair = |left: &MessageId, right: &MessageId| {
+		BlakeTwo256::hash(&[&[1u8][..], left.as_bytes(), right.as_bytes()].concat())
+	};
+	let proof = |leaf_index: u32, siblings: Vec<MessageId>| MerkleProof {
+		leaf_index,
+		leaf_count: 5,
+		siblings: siblings.try_into().unwrap(),
+	};
+	let (l0, l1, l2, l3, l4) = (
+		hash_leaf(&leaves[0]),
+		hash_leaf(&leaves[1]),
+		hash_leaf(&leaves[2]),
+		hash_leaf(&leaves[3]),
+		hash_leaf(&leaves[4]),
+	);
+
+	// the first leaf is paired in every layer
+	let first = proof(0, vec![l1, hash_pair(&l2, &l3), l4]);
+	assert_eq!(merkle_root_from_proof::<BlakeTwo256>(leaves[0], &first), Some(root));
+	// the last leaf is promoted until it is paired with the rest of the tree
+	let last = proof(4, vec![hash_pair(&hash_pair(&l0, &l1), &hash_pair(&l2, &l3))]);
+	assert_eq!(merkle_root_from_proof::<BlakeTwo256>(leaves[4], &last), Some(root));
+
+	// another leaf, another position, too many or too few siblings do not lead to the root
+	assert_ne!(merkle_root_from_proof::<BlakeTwo256>(leaves[1], &first), Some(root));
+	assert_ne!(
+		merkle_root_from_proof::<BlakeTwo256>(
+			leaves[0],
+			&proof(1, vec![l1, hash_pair(&l2, &l3), l4])
+		),
+		Some(root)
+	);
+	assert_eq!(
+		merkle_root_from_proof::<BlakeTwo256>(
+			leaves[0],
+			&proof(0, vec![l1, hash_pair(&l2, &l3), l4, l4])
+		),
+		None
+	);
+	assert_eq!(
+		merkle_root_from_proof::<BlakeTwo256>(leaves[0], &proof(0, vec![l1, hash_pair(&l2, &l3)])),
+		None
+	);
+	assert_eq!(merkle_root_from_proof::<BlakeTwo256>(leaves[0], &proof(5, vec![])), None);
+}
+
+fn ethereum_sender() -> SubjectFor<Test> {
+	Subject::Ethereum(Layer::Contract(ContractCall {
+		contract: AccountId20([2u8; 20]),
+		selector: None,
+	}))
+}
+
+fn incoming_submission(
+	nonce: u8,
+	recipient: SubjectFor<Test>,
+	payload: &[u8],
+) -> IncomingMessageSubmissionFor<Test> {
+	IncomingMessageSubmission {
+		sender: ethereum_sender(),
+		nonce: MessageNonce::repeat_byte(nonce),
+		recipient,
+		payload: Payload::try_from(payload.to_vec()).unwrap(),
+		relayer: MultiOrigin::Acurast(bob_account_id()),
+		attestation: None,
+	}
+}
+
+fn incoming_message(submission: &IncomingMessageSubmissionFor<Test>) -> MessageFor<Test> {
+	MessageFor::<Test> {
+		id: AcurastHyperdriveIbc::message_id(&submission.sender, submission.nonce),
+		sender: submission.sender.clone(),
+		nonce: submission.nonce,
+		recipient: submission.recipient.clone(),
+		payload: submission.payload.clone(),
+	}
+}
+
+fn sign(oracle: &ecdsa::Pair, payload: &[u8]) -> Attestation {
+	Attestation::Signatures(vec![(oracle.sign(payload), oracle.public())].try_into().unwrap())
+}
+
+#[test]
+fn receive_messages_applies_items_next_to_a_failing_one() {
+	new_test_ext().execute_with(|| {
+		let oracle = oracle(1);
+		register_oracles(&[oracle.clone()]);
+
+		let alice = Subject::Acurast(Layer::Extrinsic(alice_account_id()));
+		let submissions = vec![
+			incoming_submission(1, alice.clone(), b"first"),
+			// addressed to another chain
+			incoming_submission(2, ethereum_sender(), b"second"),
+			incoming_submission(3, alice, b"third"),
+		];
+		let messages: Vec<MessageFor<Test>> = submissions.iter().map(incoming_message).collect();
+		let root = merkle_root::<BlakeTwo256>(
+			messages.iter().map(AcurastHyperdriveIbc::message_hash).collect(),
+		);
+		let attestation = sign(&oracle, &AcurastHyperdriveIbc::batch_payload(root, None));
+
+		assert_ok!(AcurastHyperdriveIbc::receive_messages(
+			RuntimeOrigin::signed(bob_account_id()),
+			submissions.try_into().unwrap(),
+			Some(attestation),
+		));
+
+		assert!(AcurastHyperdriveIbc::incoming_messages(messages[0].id).is_some());
+		assert!(AcurastHyperdriveIbc::incoming_messages(messages[1].id).is_none());
+		assert!(AcurastHyperdriveIbc::incoming_messages(messages[2].id).is_some());
+		let events = events();
+		assert!(events.contains(&RuntimeEvent::AcurastHyperdriveIbc(Event::BatchItemFailed {
+			index: 1,
+			error: Error::<Test>::IncorrectRecipient.into(),
+		})));
+		assert_eq!(
+			events
+				.iter()
+				.filter(|event| matches!(
+					event,
+					RuntimeEvent::AcurastHyperdriveIbc(Event::MessageProcessed { .. })
+				))
+				.count(),
+			2
+		);
+	});
+}
+
+#[test]
+fn report_equivocation_over_batch_root() {
+	new_test_ext().execute_with(|| {
+		let oracle = oracle(1);
+		assert_ok!(AcurastHyperdriveIbc::bond_oracle(
+			RuntimeOrigin::signed(alice_account_id()),
+			oracle.public(),
+			oracle.sign(&AcurastHyperdriveIbc::oracle_ownership_payload(&alice_account_id())),
+			UNIT,
+		));
+
+		let alice = Subject::Acurast(Layer::Extrinsic(alice_account_id()));
+		let batch: Vec<MessageFor<Test>> = vec![
+			incoming_message(&incoming_submission(1, alice.clone(), b"a")),
+			incoming_message(&incoming_submission(2, alice.clone(), b"b")),
+		];
+		let hashes: Vec<MessageHash> =
+			batch.iter().map(AcurastHyperdriveIbc::message_hash).collect();
+		let batch_signature = oracle.sign(&AcurastHyperdriveIbc::batch_payload(
+			merkle_root::<BlakeTwo256>(hashes.clone()),
+			None,
+		));
+		// the same (sender, nonce) as the first message of the batch
+		let conflicting = incoming_message(&incoming_submission(1, alice, b"c"));
+		let batch_proof = |leaf_index: u32| MerkleProof {
+			leaf_index,
+			leaf_count: 2,
+			siblings: vec![BlakeTwo256::hash(&[&[0u8][..], hashes[1].as_bytes()].concat())]
+				.try_into()
+				.unwrap(),
+		};
+		let proof = |leaf_index: u32| EquivocationProof::ConflictingMessages {
+			first: SignedMessage {
+				message: batch[0].clone(),
+				relayer: None,
+				signature: batch_signature.clone(),
+				batch_proof: Some(batch_proof(leaf_index)),
+			},
+			second: SignedMessage {
+				message: conflicting.clone(),
+				relayer: None,
+				signature: oracle.sign(&AcurastHyperdriveIbc::signed_payload(&conflicting, None)),
+				batch_proof: None,
+			},
+		};
+
+		// the proof does not lead to the signed root
+		assert_err!(
+			AcurastHyperdriveIbc::report_equivocation(
+				RuntimeOrigin::signed(bob_account_id()),
+				oracle.public(),
+				proof(1),
+			),
+			Error::<Test>::EquivocationSignatureInvalid
+		);
+
+		assert_ok!(AcurastHyperdriveIbc::report_equivocation(
+			RuntimeOrigin::signed(bob_account_id()),
+			oracle.public(),
+			proof(0),
+		));
+		assert!(AcurastHyperdriveIbc::oracle_bonds(oracle.public()).is_none());
+		assert!(events().contains(&RuntimeEvent::AcurastHyperdriveIbc(Event::OracleSlashed {
+			public: oracle.public(),
+			owner: alice_account_id(),
+			amount: UNIT,
+			reporter: bob_account_id(),
+			bounty: UNIT / 10,
+		})));
+	});
+}
+
+fn bond(oracle: &ecdsa::Pair, owner: AccountId, amount: Balance) -> DispatchResult {
+	AcurastHyperdriveIbc::bond_oracle(
+		RuntimeOrigin::signed(owner.clone()),
+		oracle.public(),
+		oracle.sign(&AcurastHyperdriveIbc::oracle_ownership_payload(&owner)),
+		amount,
+	)
+}
+
+fn bonded(owner: &AccountId) -> Balance {
+	Balances::balance_on_hold(&HoldReason::OracleBond.into(), owner)
+}
+
+#[test]
+fn bond_unbond_and_withdraw_oracle() {
+	new_test_ext().execute_with(|| {
+		let oracle = oracle(1);
+		let public = oracle.public();
+
+		assert_err!(bond(&oracle, alice_account_id(), UNIT - 1), Error::<Test>::OracleBondTooLow);
+		// the proof is signed for another owner
+		assert_err!(
+			AcurastHyperdriveIbc::bond_oracle(
+				RuntimeOrigin::signed(bob_account_id()),
+				public,
+				oracle.sign(&AcurastHyperdriveIbc::oracle_ownership_payload(&alice_account_id())),
+				UNIT,
+			),
+			Error::<Test>::InvalidOracleOwnershipProof
+		);
+
+		assert_ok!(bond(&oracle, alice_account_id(), UNIT));
+		assert_eq!(bonded(&alice_account_id()), UNIT);
+		assert_eq!(AcurastHyperdriveIbc::active_bonded_oracles(), 1);
+		assert!(AcurastHyperdriveIbc::relayer_oracle_public_keys(public).is_some());
+		assert_err!(
+			bond(&oracle, alice_account_id(), UNIT),
+			Error::<Test>::OracleAlreadyRegistered
+		);
+
+		// the last active oracle cannot leave a minimum oracle set
+		assert_ok!(AcurastHyperdriveIbc::set_min_oracle_set_size(RuntimeOrigin::root(), 1));
+		assert_err!(
+			AcurastHyperdriveIbc::unbond_oracle(RuntimeOrigin::signed(alice_account_id()), public),
+			Error::<Test>::MinOracleSetSizeReached
+		);
+		assert_ok!(AcurastHyperdriveIbc::set_min_oracle_set_size(RuntimeOrigin::root(), 0));
+
+		assert_err!(
+			AcurastHyperdriveIbc::unbond_oracle(RuntimeOrigin::signed(bob_account_id()), public),
+			Error::<Test>::NotOracleOwner
+		);
+		assert_err!(
+			AcurastHyperdriveIbc::withdraw_oracle_bond(
+				RuntimeOrigin::signed(alice_account_id()),
+				public
+			),
+			Error::<Test>::OracleNotUnbonding
+		);
+		assert_ok!(AcurastHyperdriveIbc::unbond_oracle(
+			RuntimeOrigin::signed(alice_account_id()),
+			public
+		));
+		assert_eq!(AcurastHyperdriveIbc::oracle_bonds(public).unwrap().unbonding_until, Some(11));
+		assert_eq!(
+			AcurastHyperdriveIbc::relayer_oracle_public_keys(public).unwrap().end_block,
+			Some(1)
+		);
+		assert_eq!(AcurastHyperdriveIbc::active_bonded_oracles(), 0);
+		assert_err!(
+			AcurastHyperdriveIbc::unbond_oracle(RuntimeOrigin::signed(alice_account_id()), public),
+			Error::<Test>::OracleAlreadyUnbonding
+		);
+		assert_err!(
+			AcurastHyperdriveIbc::withdraw_oracle_bond(
+				RuntimeOrigin::signed(alice_account_id()),
+				public
+			),
+			Error::<Test>::UnbondingPeriodNotPassed
+		);
+
+		System::set_block_number(11);
+		assert_ok!(AcurastHyperdriveIbc::withdraw_oracle_bond(
+			RuntimeOrigin::signed(alice_account_id()),
+			public
+		));
+		assert_eq!(bonded(&alice_account_id()), 0);
+		assert_eq!(Balances::free_balance(alice_account_id()), INITIAL_BALANCE);
+		assert!(AcurastHyperdriveIbc::oracle_bonds(public).is_none());
+		assert!(AcurastHyperdriveIbc::relayer_oracle_public_keys(public).is_none());
+	});
+}
+
+#[test]
+fn bonded_oracles_count_towards_quorums_once_approved() {
+	new_test_ext().execute_with(|| {
+		let oracle = oracle(1);
+		assert_ok!(bond(&oracle, alice_account_id(), UNIT));
+		assert_eq!(AcurastHyperdriveIbc::oracle_weight(&oracle.public()), 0);
+
+		let payload = b"payload";
+		let check = || {
+			AcurastHyperdriveIbc::check_signatures(
+				payload,
+				Signatures::try_from(vec![(oracle.sign(payload), oracle.public())]).unwrap(),
+				1,
+			)
+		};
+		// a bond alone does not reach a quorum
+		assert_err!(check(), Error::<Test>::NotEnoughSignaturesValid);
+
+		assert_ok!(AcurastHyperdriveIbc::update_oracle_weights(
+			RuntimeOrigin::root(),
+			vec![(oracle.public(), None)].try_into().unwrap(),
+		));
+		assert_eq!(check(), Ok(1));
+
+		// the approval does not outlive the bond
+		assert_ok!(AcurastHyperdriveIbc::unbond_oracle(
+			RuntimeOrigin::signed(alice_account_id()),
+			oracle.public()
+		));
+		System::set_block_number(11);
+		assert_ok!(AcurastHyperdriveIbc::withdraw_oracle_bond(
+			RuntimeOrigin::signed(alice_account_id()),
+			oracle.public()
+		));
+		assert_eq!(AcurastHyperdriveIbc::oracle_weights(oracle.public()), None);
+	});
+}
+
+/// Sends a test message from alice and returns it.
+fn send_message() -> MessageFor<Test> {
+	assert_ok!(AcurastHyperdriveIbc::send_test_message(
+		RuntimeOrigin::signed(alice_account_id()),
+		ethereum_sender(),
+		MinTTL::get(),
+		MinFee::get(),
+	));
+	let id = AcurastHyperdriveIbc::message_id(
+		&Subject::Acurast(Layer::Extrinsic(alice_account_id())),
+		BlakeTwo256::hash_of(&(AcurastHyperdriveIbc::message_counter() - 1)),
+	);
+	AcurastHyperdriveIbc::outgoing_messages(id).unwrap().message
+}
+
+#[test]
+fn report_equivocation_for_unsent_message_delivery() {
+	new_test_ext().execute_with(|| {
+		let oracle = oracle(1);
+		assert_ok!(bond(&oracle, bob_account_id(), UNIT));
+		let sent = send_message();
+
+		let delivery = |message: MessageFor<Test>| {
+			EquivocationProof::UnsentMessageDelivery(SignedMessage {
+				signature: oracle.sign(&AcurastHyperdriveIbc::signed_payload(
+					&message,
+					Some(&alice_account_id()),
+				)),
+				message,
+				relayer: Some(alice_account_id()),
+				batch_proof: None,
+			})
+		};
+		let report = |message: MessageFor<Test>| {
+			AcurastHyperdriveIbc::report_equivocation(
+				RuntimeOrigin::signed(alice_account_id()),
+				oracle.public(),
+				delivery(message),
+			)
+		};
+
+		// confirming the delivery of the sent message is no equivocation
+		assert_err!(report(sent.clone()), Error::<Test>::MessageWasSent);
+		// nothing is known about ids that were never sent
+		let mut unknown = sent.clone();
+		unknown.nonce = MessageNonce::repeat_byte(9);
+		unknown.id = AcurastHyperdriveIbc::message_id(&unknown.sender, unknown.nonce);
+		assert_err!(report(unknown), Error::<Test>::MessageNotTracked);
+
+		let mut forged = sent;
+		forged.payload = Payload::try_from(b"forged".to_vec()).unwrap();
+		let issuance = Balances::total_issuance();
+		let free = Balances::free_balance(alice_account_id());
+		assert_ok!(report(forged));
+
+		assert!(AcurastHyperdriveIbc::oracle_bonds(oracle.public()).is_none());
+		assert!(AcurastHyperdriveIbc::relayer_oracle_public_keys(oracle.public()).is_none());
+		assert_eq!(AcurastHyperdriveIbc::active_bonded_oracles(), 0);
+		assert_eq!(bonded(&bob_account_id()), 0);
+		// the reporter receives the bounty, the rest of the bond is burned
+		assert_eq!(Balances::free_balance(alice_account_id()), free + UNIT / 10);
+		assert_eq!(Balances::total_issuance(), issuance - (UNIT - UNIT / 10));
+	});
+}
+
+#[test]
+fn clean_sent_messages_after_retention() {
+	new_test_ext().execute_with(|| {
+		let sent = send_message();
+		let hash = AcurastHyperdriveIbc::message_hash(&sent);
+		// the TTL ends at block 16, the retention 20 blocks later
+		assert_eq!(AcurastHyperdriveIbc::sent_message_ids(sent.id), Some(36));
+		assert!(AcurastHyperdriveIbc::sent_messages(sent.id, hash).is_some());
+
+		let ids = || MessagesCleanup::try_from(vec![sent.id]).unwrap();
+		System::set_block_number(36);
+		assert_ok!(AcurastHyperdriveIbc::clean_sent_messages(
+			RuntimeOrigin::signed(bob_account_id()),
+			ids()
+		));
+		assert!(AcurastHyperdriveIbc::sent_message_ids(sent.id).is_some());
+
+		System::set_block_number(37);
+		assert_ok!(AcurastHyperdriveIbc::clean_sent_messages(
+			RuntimeOrigin::signed(bob_account_id()),
+			ids()
+		));
+		assert!(AcurastHyperdriveIbc::sent_message_ids(sent.id).is_none());
+		assert!(AcurastHyperdriveIbc::sent_messages(sent.id, hash).is_none());
+	});
+}
+
+#[test]
+fn oracle_weights_count_towards_quorums() {
+	new_test_ext().execute_with(|| {
+		let (heavy, light) = (oracle(1), oracle(2));
+		register_oracles(&[heavy.clone(), light.clone()]);
+		let update_weight = |weight: Option<u32>| {
+			AcurastHyperdriveIbc::update_oracle_weights(
+				RuntimeOrigin::root(),
+				vec![(heavy.public(), weight)].try_into().unwrap(),
+			)
+		};
+
+		assert_err!(
+			update_weight(Some(MaxOracleWeight::get() + 1)),
+			Error::<Test>::OracleWeightTooHigh
+		);
+		assert_ok!(update_weight(Some(2)));
+
+		let payload = b"payload";
+		let check = |oracles: &[&ecdsa::Pair], quorum: u32| {
+			let signatures: Vec<_> =
+				oracles.iter().map(|oracle| (oracle.sign(payload), oracle.public())).collect();
+			AcurastHyperdriveIbc::check_signatures(
+				payload,
+				Signatures::try_from(signatures).unwrap(),
+				quorum,
+			)
+		};
+		assert_eq!(check(&[&heavy], 2), Ok(2));
+		assert_err!(check(&[&light], 2), Error::<Test>::NotEnoughSignaturesValid);
+		assert_eq!(check(&[&heavy, &light], 3), Ok(3));
+		// a repeated signature only counts once
+		assert_err!(check(&[&light, &light], 2), Error::<Test>::NotEnoughSignaturesValid);
+
+		// resetting the weight falls back to the default weight
+		assert_ok!(update_weight(None));
+		assert_err!(check(&[&heavy], 2), Error::<Test>::NotEnoughSignaturesValid);
+	});
+}
+
+#[test]
+fn recipient_quorums_take_precedence_over_chain_quorums() {
+	new_test_ext().execute_with(|| {
+		let alice = Subject::Acurast(Layer::Extrinsic(alice_account_id()));
+		let bob = Subject::Acurast(Layer::Extrinsic(bob_account_id()));
+		let to_alice = incoming_message(&incoming_submission(1, alice.clone(), b"a"));
+		let to_bob = incoming_message(&incoming_submission(2, bob, b"b"));
+		let to_ethereum = MessageFor::<Test> {
+			sender: alice.clone(),
+			recipient: ethereum_sender(),
+			..to_alice.clone()
+		};
+		let update_quorums = |updates: Vec<QuorumUpdate<AccountId, AccountId>>| {
+			AcurastHyperdriveIbc::update_quorums(RuntimeOrigin::root(), updates.try_into().unwrap())
+		};
+
+		// without overrides the runtime's minimums apply
+		assert_eq!(AcurastHyperdriveIbc::receipt_quorum(&to_alice), 1);
+		assert_eq!(AcurastHyperdriveIbc::delivery_quorum(&to_ethereum), 1);
+
+		assert_ok!(update_quorums(vec![
+			QuorumUpdate::Chain(ProxyChain::Ethereum, Some(Quorum { receipt: 2, delivery: 3 })),
+			QuorumUpdate::Recipient(alice.clone(), Some(Quorum { receipt: 4, delivery: 5 })),
+		]));
+		assert_eq!(AcurastHyperdriveIbc::receipt_quorum(&to_alice), 4);
+		assert_eq!(AcurastHyperdriveIbc::receipt_quorum(&to_bob), 2);
+		// outgoing messages are routed by their recipient on the proxy chain
+		assert_eq!(AcurastHyperdriveIbc::delivery_quorum(&to_ethereum), 3);
+
+		// removing the recipient's override falls back to the chain's
+		assert_ok!(update_quorums(vec![QuorumUpdate::Recipient(alice, None)]));
+		assert_eq!(AcurastHyperdriveIbc::receipt_quorum(&to_alice), 2);
+
+		// overrides below the runtime's minimums are raised to them
+		assert_ok!(update_quorums(vec![QuorumUpdate::Chain(
+			ProxyChain::Ethereum,
+			Some(Quorum { receipt: 0, delivery: 0 })
+		)]));
+		assert_eq!(AcurastHyperdriveIbc::receipt_quorum(&to_alice), 1);
+		assert_eq!(AcurastHyperdriveIbc::delivery_quorum(&to_ethereum), 1);
+	});
+}
+
+#[test]
+fn verifies_ethereum_storage_proof() {
+	let message = Message::<AccountId32, AccountId32>::decode(&mut &hex!("7ba7902e9de1360ea56072e7715f4a924cd1260a06f11e377efbb37c4ac35ea403017f44ad0fd6c15cfba6f417c33924c8cf0c751d2300e8e77626586f73b955364c7b4bbf0bb7f7685ebd40e852b164633a4acbd3244c00006d6f646c687970746f6b656e0000000000000000000000000000000000000000f000000000000000000000000000000000000003e80000000000000000185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b")[..]).unwrap();
+	assert_eq!(message.nonce, fixtures::NONCE);
+	assert_eq!(message_commitment(&message), fixtures::COMMITMENT);
+
+	let slot = mapping_slot(fixtures::layout().messages_slot, fixtures::NONCE);
+	assert_eq!(
+		slot,
+		H256(hex!("1e0b22b317393cd9509829cf22b8379cd9607c232836ff64ac34f89d0cc6b67c"))
+	);
+	assert_eq!(
+		verify_storage_proof(
+			fixtures::STATE_ROOT,
+			fixtures::CONTRACT,
+			&fixtures::ACCOUNT_PROOF,
+			slot,
+			&fixtures::STORAGE_PROOF,
+		),
+		Ok(fixtures::COMMITMENT)
+	);
+}
+
+#[test]
+fn verifies_full_branch_storage_proofs() {
+	let slot = mapping_slot(fixtures::layout().messages_slot, fixtures::NONCE);
+	for nodes in [2, 3, 2 * PROOF_NODES_MAX_LENGTH] {
+		let (state_root, proof) = fixtures::full_branch_storage_proof(
+			fixtures::CONTRACT,
+			slot,
+			fixtures::COMMITMENT,
+			nodes,
+		);
+		assert_eq!(MessageProof::EthereumStorage(proof.clone()).node_count(), nodes);
+		assert_eq!(
+			verify_storage_proof(
+				state_root,
+				fixtures::CONTRACT,
+				&proof.account_proof[..],
+				slot,
+				&proof.storage_proof[..],
+			),
+			Ok(fixtures::COMMITMENT)
+		);
+	}
+}
+
+#[test]
+fn rejects_invalid_ethereum_storage_proofs() {
+	let slot = mapping_slot(fixtures::layout().messages_slot, fixtures::NONCE);
+
+	// another state root
+	assert_eq!(
+		verify_storage_proof(
+			H256::repeat_byte(1),
+			fixtures::CONTRACT,
+			&fixtures::ACCOUNT_PROOF,
+			slot,
+			&fixtures::STORAGE_PROOF,
+		),
+		Err(ProofError::InvalidNodeHash)
+	);
+
+	// a tampered leaf
+	let mut leaf = fixtures::STORAGE_PROOF[1].to_vec();
+	*leaf.last_mut().unwrap() ^= 1;
+	assert_eq!(
+		verify_storage_proof(
+			fixtures::STATE_ROOT,
+			fixtures::CONTRACT,
+			&fixtures::ACCOUNT_PROOF,
+			slot,
+			&[fixtures::STORAGE_PROOF[0], leaf.as_slice()],
+		),
+		Err(ProofError::InvalidNodeHash)
+	);
+
+	// a truncated proof
+	assert_eq!(
+		verify_storage_proof(
+			fixtures::STATE_ROOT,
+			fixtures::CONTRACT,
+			&fixtures::ACCOUNT_PROOF,
+			slot,
+			&fixtures::STORAGE_PROOF[..1],
+		),
+		Err(ProofError::MissingNode)
+	);
+}
+
+const PERIOD: u64 = 1_000;
+
+fn initialize_light_client() {
+	assert_ok!(AcurastHyperdriveIbc::update_ethereum_light_client(
+		RuntimeOrigin::root(),
+		beacon_fixtures::config(),
+		Some((PERIOD, beacon_fixtures::sync_committee(0))),
+	));
+}
+
+fn submit_finality_update(update: EthereumFinalityUpdate) -> DispatchResult {
+	AcurastHyperdriveIbc::submit_ethereum_finality_update(
+		RuntimeOrigin::signed(alice_account_id()),
+		Box::new(update),
+	)
+}
+
+#[test]
+fn ethereum_light_client_follows_finality() {
+	new_test_ext().execute_with(|| {
+		let slot = PERIOD * SLOTS_PER_SYNC_COMMITTEE_PERIOD + 64;
+		let next_seed = SYNC_COMMITTEE_SIZE as u64;
+		let update = finality_update(slot, 0, 100, H256::repeat_byte(1), Some(next_seed));
+
+		assert_err!(
+			submit_finality_update(update.clone()),
+			Error::<Test>::EthereumLightClientNotInitialized
+		);
+		initialize_light_client();
+
+		assert_ok!(submit_finality_update(update.clone()));
+		assert_eq!(AcurastHyperdriveIbc::ethereum_state_root(100), Some(H256::repeat_byte(1)));
+		assert_eq!(AcurastHyperdriveIbc::ethereum_finalized_slot(), slot);
+		assert!(EthereumSyncCommittees::<Test>::contains_key(PERIOD + 1));
+		assert!(events().contains(&RuntimeEvent::AcurastHyperdriveIbc(
+			Event::EthereumStateRootFinalized {
+				slot,
+				block_number: 100,
+				state_root: H256::repeat_byte(1)
+			}
+		)));
+
+		// a replayed update does not advance the finalized slot
+		assert_err!(submit_finality_update(update), Error::<Test>::InvalidBeaconSlots);
+
+		// the next period is signed by the sync committee rotated in
+		let slot = (PERIOD + 1) * SLOTS_PER_SYNC_COMMITTEE_PERIOD + 64;
+		assert_ok!(submit_finality_update(finality_update(
+			slot,
+			next_seed,
+			101,
+			H256::repeat_byte(2),
+			None
+		)));
+		assert_ok!(submit_finality_update(finality_update(
+			slot + 64,
+			next_seed,
+			102,
+			H256::repeat_byte(3),
+			None
+		)));
+
+		// only the latest `MaxEthereumStateRoots` are kept
+		assert_eq!(AcurastHyperdriveIbc::ethereum_state_root(100), None);
+		assert_eq!(AcurastHyperdriveIbc::ethereum_state_root(101), Some(H256::repeat_byte(2)));
+		assert_eq!(AcurastHyperdriveIbc::ethereum_state_root(102), Some(H256::repeat_byte(3)));
+	});
+}
+
+#[test]
+fn ethereum_light_client_rejects_invalid_updates() {
+	new_test_ext().execute_with(|| {
+		initialize_light_client();
+		let slot = PERIOD * SLOTS_PER_SYNC_COMMITTEE_PERIOD + 64;
+		let state_root = H256::repeat_byte(1);
+		let update = finality_update(slot, 0, 100, state_root, None);
+
+		// signed by another sync committee
+		assert_err!(
+			submit_finality_update(finality_update(slot, 1, 100, state_root, None)),
+			Error::<Test>::SyncCommitteeSignatureInvalid
+		);
+
+		// signed by less than two thirds of the sync committee
+		let mut sparse = update.clone();
+		sparse.sync_aggregate.sync_committee_bits[..22].fill(0);
+		assert_err!(
+			submit_finality_update(sparse),
+			Error::<Test>::InsufficientSyncCommitteeParticipation
+		);
+
+		// a state root not contained in the finalized block
+		let mut forged = update.clone();
+		forged.execution_state_root = H256::repeat_byte(2);
+		assert_err!(submit_finality_update(forged), Error::<Test>::InvalidBeaconBranch);
+
+		// a header not finalized in the attested state
+		let mut forged = update.clone();
+		forged.finalized_header.proposer_index += 1;
+		assert_err!(submit_finality_update(forged), Error::<Test>::InvalidBeaconBranch);
+
+		// signed in a period without known sync committee
+		assert_err!(
+			submit_finality_update(finality_update(
+				slot + 2 * SLOTS_PER_SYNC_COMMITTEE_PERIOD,
+				0,
+				100,
+				state_root,
+				None
+			)),
+			Error::<Test>::UnknownSyncCommittee
+		);
+
+		assert_ok!(submit_finality_update(update));
+	});
+}
+
+#[test]
+fn ethereum_light_client_selects_fork_by_epoch() {
+	let config = beacon_fixtures::config();
+	assert_eq!(beacon::fork_at(&config, 0), None);
+	assert_eq!(beacon::fork_at(&config, 364_031).map(|fork| fork.version), Some([4, 0, 0, 0]));
+	assert_eq!(beacon::fork_at(&config, 364_032).map(|fork| fork.version), Some([5, 0, 0, 0]));
+	assert_eq!(beacon::fork_at(&config, u64::MAX).map(|fork| fork.version), Some([6, 0, 0, 0]));
+
+	new_test_ext().execute_with(|| {
+		let mut unordered = beacon_fixtures::config();
+		let mut forks = unordered.forks.into_inner();
+		forks.swap(0, 1);
+		unordered.forks = forks.try_into().unwrap();
+		assert_err!(
+			AcurastHyperdriveIbc::update_ethereum_light_client(
+				RuntimeOrigin::root(),
+				unordered,
+				None
+			),
+			Error::<Test>::InvalidBeaconForkSchedule
+		);
+
+		initialize_light_client();
+		let slot = PERIOD * SLOTS_PER_SYNC_COMMITTEE_PERIOD + 64;
+		let update = finality_update(slot, 0, 100, H256::repeat_byte(1), None);
+
+		// the update is signed under Capella, not the latest fork
+		let mut config = beacon_fixtures::config();
+		let mut forks = config.forks.into_inner();
+		forks[2].version = [9, 0, 0, 0];
+		config.forks = forks.try_into().unwrap();
+		assert_ok!(AcurastHyperdriveIbc::update_ethereum_light_client(
+			RuntimeOrigin::root(),
+			config,
+			None
+		));
+		assert_err!(
+			submit_finality_update(update.clone()),
+			Error::<Test>::SyncCommitteeSignatureInvalid
+		);
+
+		// no fork scheduled at the update's epoch
+		let mut config = beacon_fixtures::config();
+		config.forks = config.forks[4..].to_vec().try_into().unwrap();
+		assert_ok!(AcurastHyperdriveIbc::update_ethereum_light_client(
+			RuntimeOrigin::root(),
+			config,
+			None
+		));
+		assert_err!(submit_finality_update(update.clone()), Error::<Test>::UnknownBeaconFork);
+
+		assert_ok!(AcurastHyperdriveIbc::update_ethereum_light_client(
+			RuntimeOrigin::root(),
+			beacon_fixtures::config(),
+			None
+		));
+		assert_ok!(submit_finality_update(update));
+	});
+}
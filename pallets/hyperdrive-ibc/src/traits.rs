@@ -1,5 +1,57 @@
 use frame_support::weights::Weight;
 
+use crate::{AggregatePublic, AggregateSignature, Message, MessageProof};
+
+/// Verifies a signature aggregated from several oracles' signatures over the same message.
+pub trait AggregateSignatureVerifier {
+	fn verify(message: &[u8], signers: &[AggregatePublic], signature: &AggregateSignature) -> bool;
+	/// Verifies that `proof` was created with the secret key of `public`, which protects aggregated public keys against rogue key attacks.
+	fn verify_proof_of_possession(public: &AggregatePublic, proof: &AggregateSignature) -> bool;
+	/// Returns a public key derived from `seed` together with its proof of possession.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn generate_key(seed: u32) -> (AggregatePublic, AggregateSignature);
+	/// Returns the signature over `message` aggregated from the keys derived from `seeds`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn sign(seeds: &[u32], message: &[u8]) -> AggregateSignature;
+}
+
+/// Rejects all aggregated signatures and keys, disabling aggregated attestations.
+impl AggregateSignatureVerifier for () {
+	fn verify(
+		_message: &[u8],
+		_signers: &[AggregatePublic],
+		_signature: &AggregateSignature,
+	) -> bool {
+		false
+	}
+
+	fn verify_proof_of_possession(_public: &AggregatePublic, _proof: &AggregateSignature) -> bool {
+		false
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn generate_key(_seed: u32) -> (AggregatePublic, AggregateSignature) {
+		(Default::default(), Default::default())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn sign(_seeds: &[u32], _message: &[u8]) -> AggregateSignature {
+		Default::default()
+	}
+}
+
+/// Verifies proofs that a message is stored in the state of the proxy chain it was sent from.
+pub trait MessageProofVerifier<AccountId> {
+	fn verify(message: &Message<AccountId, AccountId>, proof: &MessageProof) -> bool;
+}
+
+/// Rejects all message proofs.
+impl<AccountId> MessageProofVerifier<AccountId> for () {
+	fn verify(_message: &Message<AccountId, AccountId>, _proof: &MessageProof) -> bool {
+		false
+	}
+}
+
 /// Weight functions needed for pallet_acurast_hyperdrive_ibc.
 pub trait WeightInfo {
 	fn update_oracles(n: u32) -> Weight;
@@ -8,4 +60,23 @@ pub trait WeightInfo {
 	fn remove_message() -> Weight;
 	fn receive_message() -> Weight;
 	fn clean_incoming(x: u32) -> Weight;
+	fn bond_oracle() -> Weight;
+	fn unbond_oracle() -> Weight;
+	fn withdraw_oracle_bond() -> Weight;
+	fn report_equivocation() -> Weight;
+	fn set_min_oracle_set_size() -> Weight;
+	fn update_oracle_weights(n: u32) -> Weight;
+	fn update_quorums(n: u32) -> Weight;
+	fn update_aggregate_oracles(n: u32) -> Weight;
+	fn receive_message_aggregated(s: u32) -> Weight;
+	fn confirm_message_delivery_aggregated(s: u32) -> Weight;
+	fn receive_messages(n: u32) -> Weight;
+	fn confirm_message_deliveries(n: u32) -> Weight;
+	fn update_message_verifier() -> Weight;
+	fn update_ethereum_ibc() -> Weight;
+	fn submit_ethereum_finality_update(c: u32) -> Weight;
+	fn receive_message_with_proof(n: u32) -> Weight;
+	fn check_aggregate_signature(s: u32) -> Weight;
+	fn update_ethereum_light_client(c: u32) -> Weight;
+	fn clean_sent_messages(x: u32) -> Weight;
 }
@@ -3,14 +3,14 @@ use frame_support::{
 	pallet_prelude::*, storage::bounded_vec::BoundedVec, traits::fungible::Inspect,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
-use pallet_acurast::{Layer, MessageFeeProvider, MultiOrigin, Subject};
+use pallet_acurast::{Layer, MessageFeeProvider, MultiOrigin, ProxyChain, Subject};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 pub use sp_core::ecdsa::{
 	Public, Signature, PUBLIC_KEY_SERIALIZED_SIZE, SIGNATURE_SERIALIZED_SIZE,
 };
-use sp_core::{ConstU32, H256};
-use sp_runtime::RuntimeDebug;
+use sp_core::{ConstU32, H160, H256};
+use sp_runtime::{traits::Hash, RuntimeDebug};
 use sp_std::prelude::*;
 
 pub const SIGNATURES_MAX_LENGTH: u32 = 32;
@@ -25,6 +25,46 @@ pub type MessagesCleanup = BoundedVec<MessageId, ConstU32<MESSAGES_CLEANUP_MAX_L
 
 pub type OracleUpdateFor<T> = OracleUpdate<BlockNumberFor<T>>;
 
+/// The maximum number of oracles that can take part in aggregated attestations.
+pub const AGGREGATE_ORACLES_MAX_LENGTH: u32 = 256;
+pub type AggregateOracleSet = BoundedVec<AggregateOracle, ConstU32<AGGREGATE_ORACLES_MAX_LENGTH>>;
+
+/// Large enough for compressed BLS12-381 G2 public keys, see [`crate::bls`].
+pub const AGGREGATE_PUBLIC_MAX_LENGTH: u32 = 96;
+pub type AggregatePublic = BoundedVec<u8, ConstU32<AGGREGATE_PUBLIC_MAX_LENGTH>>;
+
+/// Large enough for compressed BLS12-381 G1 and G2 signatures, see [`crate::bls`].
+pub const AGGREGATE_SIGNATURE_MAX_LENGTH: u32 = 96;
+pub type AggregateSignature = BoundedVec<u8, ConstU32<AGGREGATE_SIGNATURE_MAX_LENGTH>>;
+
+/// One bit per entry of [`crate::AggregateOracles`], least significant bit first.
+pub type SignerBitmap = BoundedVec<u8, ConstU32<{ AGGREGATE_ORACLES_MAX_LENGTH / 8 }>>;
+
+pub const MESSAGE_BATCH_MAX_LENGTH: u32 = 20;
+pub type IncomingMessageBatch<T> =
+	BoundedVec<IncomingMessageSubmissionFor<T>, ConstU32<MESSAGE_BATCH_MAX_LENGTH>>;
+pub type DeliveryConfirmationBatch =
+	BoundedVec<DeliveryConfirmation, ConstU32<MESSAGE_BATCH_MAX_LENGTH>>;
+
+pub type IncomingMessageSubmissionFor<T> = IncomingMessageSubmission<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::AccountId,
+>;
+
+/// The weight of oracles without an entry in [`crate::OracleWeights`].
+pub const DEFAULT_ORACLE_WEIGHT: u32 = 1;
+
+pub const ORACLE_WEIGHT_UPDATES_MAX_LENGTH: u32 = 50;
+/// Sets (`Some`) or resets to [`DEFAULT_ORACLE_WEIGHT`] (`None`) the weight of oracles.
+pub type OracleWeightUpdates =
+	BoundedVec<(Public, Option<u32>), ConstU32<ORACLE_WEIGHT_UPDATES_MAX_LENGTH>>;
+
+pub const QUORUM_UPDATES_MAX_LENGTH: u32 = 20;
+pub type QuorumUpdates<T> = BoundedVec<QuorumUpdateFor<T>, ConstU32<QUORUM_UPDATES_MAX_LENGTH>>;
+
+pub type QuorumUpdateFor<T> =
+	QuorumUpdate<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId>;
+
 pub type BalanceOf<T, I> =
 	<<T as Config<I>>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
@@ -65,6 +105,14 @@ pub struct ActivityWindow<BlockNumber> {
 	pub end_block: Option<BlockNumber>,
 }
 
+impl<BlockNumber: PartialOrd> ActivityWindow<BlockNumber> {
+	/// Whether the window contains `block`, with inclusive `start_block` and exclusive `end_block`.
+	pub fn is_active(&self, block: &BlockNumber) -> bool {
+		&self.start_block <= block
+			&& self.end_block.as_ref().map_or(true, |end_block| block < end_block)
+	}
+}
+
 impl<BlockNumber: From<u8>> Default for ActivityWindow<BlockNumber> {
 	fn default() -> Self {
 		Self { start_block: BlockNumber::from(0), end_block: None }
@@ -78,6 +126,428 @@ pub enum OracleUpdate<BlockNumber> {
 	Update(Public, ActivityWindow<BlockNumber>),
 }
 
+pub type OracleBondFor<T, I> =
+	OracleBond<<T as frame_system::Config>::AccountId, BalanceOf<T, I>, BlockNumberFor<T>>;
+
+pub type EquivocationProofFor<T> = EquivocationProof<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::AccountId,
+>;
+
+pub type SignedMessageFor<T> =
+	SignedMessage<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId>;
+
+/// An oracle's key used for aggregated signatures, linked to its ECDSA public key.
+///
+/// The ECDSA key determines the oracle's activity window and weight.
+#[derive(
+	RuntimeDebug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq,
+)]
+pub struct AggregateOracle {
+	pub public: Public,
+	pub aggregate_public: AggregatePublic,
+	/// Proves the possession of the secret key of `aggregate_public`, see [`crate::AggregateSignatureVerifier::verify_proof_of_possession`].
+	pub proof_of_possession: AggregateSignature,
+}
+
+/// A single signature aggregated from the signatures of all oracles flagged in `signers`.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct AggregateAttestation {
+	pub signature: AggregateSignature,
+	pub signers: SignerBitmap,
+}
+
+impl AggregateAttestation {
+	/// Whether the oracle at `index` of the aggregate oracle set is flagged as signer.
+	pub fn is_signer(&self, index: usize) -> bool {
+		self.signers.get(index / 8).is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+	}
+
+	/// The number of flagged signers.
+	pub fn signer_count(&self) -> u32 {
+		self.signers.iter().map(|byte| byte.count_ones()).sum()
+	}
+
+	/// Whether any signer is flagged at an index not below `len`.
+	pub fn has_signers_beyond(&self, len: usize) -> bool {
+		(len..self.signers.len() * 8).any(|index| self.is_signer(index))
+	}
+}
+
+/// The oracle attestation for a message.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub enum Attestation {
+	/// Individual ECDSA signatures, each verified separately.
+	Signatures(Signatures),
+	/// An aggregated signature, verified once.
+	Aggregate(AggregateAttestation),
+}
+
+/// Where the attestation of a single message comes from.
+#[derive(RuntimeDebug, Clone, PartialEq)]
+pub enum AttestationSource {
+	/// The message is attested on its own.
+	Message(Attestation),
+	/// The message with `hash` is part of a batch whose Merkle root is attested with `weight`.
+	Batch { hash: MessageHash, weight: u32 },
+	/// The message is proven to be stored in the state of the proxy chain it was sent from.
+	Proof(MessageProof),
+}
+
+/// How incoming messages from a proxy chain are verified.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Default,
+)]
+pub enum MessageVerifier {
+	/// By the signatures of oracles, see [`Attestation`].
+	#[default]
+	Oracles,
+	/// By a proof that the message is stored in the proxy chain's state, see [`MessageProof`].
+	StateProof,
+}
+
+/// The maximum length of a single RLP-encoded trie node.
+pub const PROOF_NODE_MAX_LENGTH: u32 = 1024;
+/// The maximum number of trie nodes along the path of a proven key.
+pub const PROOF_NODES_MAX_LENGTH: u32 = 16;
+
+pub type ProofNode = BoundedVec<u8, ConstU32<PROOF_NODE_MAX_LENGTH>>;
+pub type ProofNodes = BoundedVec<ProofNode, ConstU32<PROOF_NODES_MAX_LENGTH>>;
+
+/// A proof that a message is stored in the state of the proxy chain it was sent from.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub enum MessageProof {
+	EthereumStorage(EthereumStorageProof),
+}
+
+impl MessageProof {
+	/// The number of trie nodes in the proof, which its verification scales with.
+	pub fn node_count(&self) -> u32 {
+		match self {
+			MessageProof::EthereumStorage(proof) => {
+				(proof.account_proof.len() + proof.storage_proof.len()) as u32
+			},
+		}
+	}
+}
+
+/// The Merkle-Patricia proofs of a message's slot in the Ethereum IBC contract, as returned by `eth_getProof`.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct EthereumStorageProof {
+	/// The execution block whose state root the proofs are verified against.
+	pub block_number: u64,
+	/// The proof of the IBC contract's account in the state trie.
+	pub account_proof: ProofNodes,
+	/// The proof of the message's slot in the IBC contract's storage trie.
+	pub storage_proof: ProofNodes,
+}
+
+/// Where the Ethereum IBC contract stores outgoing messages, following the storage of `contracts/HyperdriveIbc.sol`.
+#[derive(
+	RuntimeDebug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq,
+)]
+pub struct EthereumIbcLayout {
+	pub contract: H160,
+	/// The slot of the `outgoingMessages` mapping from nonces to message commitments, 1 in `contracts/HyperdriveIbc.sol`.
+	pub messages_slot: H256,
+}
+
+/// The number of validators in an Ethereum sync committee.
+pub const SYNC_COMMITTEE_SIZE: u32 = 512;
+/// The number of beacon chain slots a sync committee signs for.
+pub const SLOTS_PER_SYNC_COMMITTEE_PERIOD: u64 = 8192;
+/// The number of beacon chain slots per epoch.
+pub const SLOTS_PER_EPOCH: u64 = 32;
+/// The maximum number of forks in the fork schedule of the light client.
+pub const BEACON_FORKS_MAX_LENGTH: u32 = 16;
+/// The maximum depth of a Merkle branch into the beacon state, which depends on the fork.
+pub const BEACON_BRANCH_MAX_LENGTH: u32 = 8;
+/// The depth of the Merkle branches of execution payload fields in a beacon block body.
+pub const EXECUTION_BRANCH_LENGTH: u32 = 9;
+
+/// A compressed BLS12-381 G1 public key of a sync committee member.
+pub type SyncCommitteePublic = [u8; 48];
+/// An uncompressed BLS12-381 G1 public key, stored to save decompressing it on every update.
+pub type SyncCommitteeStoredPublic = [u8; 96];
+pub type SyncCommitteeStoredPublics =
+	BoundedVec<SyncCommitteeStoredPublic, ConstU32<SYNC_COMMITTEE_SIZE>>;
+pub type BeaconBranch = BoundedVec<H256, ConstU32<BEACON_BRANCH_MAX_LENGTH>>;
+pub type ExecutionBranch = BoundedVec<H256, ConstU32<EXECUTION_BRANCH_LENGTH>>;
+pub type BeaconForks = BoundedVec<BeaconFork, ConstU32<BEACON_FORKS_MAX_LENGTH>>;
+
+/// The parameters of a fork of the Ethereum beacon chain, in effect from its activation `epoch` until the next fork.
+#[derive(
+	RuntimeDebug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq,
+)]
+pub struct BeaconFork {
+	pub epoch: u64,
+	/// The fork version sync committees sign with.
+	pub version: [u8; 4],
+	/// The generalized index of `finalized_checkpoint.root` in the beacon state.
+	pub finalized_root_gindex: u64,
+	/// The generalized index of `next_sync_committee` in the beacon state.
+	pub next_sync_committee_gindex: u64,
+}
+
+/// The parameters of the Ethereum beacon chain followed by the light client.
+#[derive(
+	RuntimeDebug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq,
+)]
+pub struct EthereumBeaconConfig {
+	pub genesis_validators_root: H256,
+	/// The fork schedule by ascending activation epoch, to be extended before every fork.
+	pub forks: BeaconForks,
+}
+
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Default,
+)]
+pub struct BeaconBlockHeader {
+	pub slot: u64,
+	pub proposer_index: u64,
+	pub parent_root: H256,
+	pub state_root: H256,
+	pub body_root: H256,
+}
+
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct SyncCommittee {
+	pub pubkeys: BoundedVec<SyncCommitteePublic, ConstU32<SYNC_COMMITTEE_SIZE>>,
+	pub aggregate_pubkey: SyncCommitteePublic,
+}
+
+/// The signature of the participating sync committee members over an attested header.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct SyncAggregate {
+	/// A bit per member, set if the member participated, in SSZ bit order.
+	pub sync_committee_bits: [u8; (SYNC_COMMITTEE_SIZE / 8) as usize],
+	pub sync_committee_signature: [u8; 96],
+}
+
+/// The sync committee of the next period, proven in the attested beacon state.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct NextSyncCommitteeUpdate {
+	pub committee: SyncCommittee,
+	pub branch: BeaconBranch,
+}
+
+/// A finalized execution state root proven by a header signed by the current sync committee.
+///
+/// Follows the light client update of the Ethereum consensus specs, with the execution payload fields proven
+/// directly instead of the whole execution payload header.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct EthereumFinalityUpdate {
+	/// The header signed by the sync committee.
+	pub attested_header: BeaconBlockHeader,
+	/// The header finalized in the state of the attested header.
+	pub finalized_header: BeaconBlockHeader,
+	/// The proof of the finalized header in the attested state.
+	pub finality_branch: BeaconBranch,
+	pub execution_block_number: u64,
+	pub execution_state_root: H256,
+	/// The proof of the execution block number in the finalized block body.
+	pub execution_block_number_branch: ExecutionBranch,
+	/// The proof of the execution state root in the finalized block body.
+	pub execution_state_root_branch: ExecutionBranch,
+	pub next_sync_committee: Option<NextSyncCommitteeUpdate>,
+	pub sync_aggregate: SyncAggregate,
+	/// The slot of the block containing the sync aggregate.
+	pub signature_slot: u64,
+}
+
+/// An incoming message submitted as part of a batch.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct IncomingMessageSubmission<AccountId, Contract> {
+	pub sender: Subject<AccountId, Contract>,
+	pub nonce: MessageNonce,
+	pub recipient: Subject<AccountId, Contract>,
+	pub payload: Payload,
+	pub relayer: MultiOrigin<AccountId>,
+	/// Required unless the batch is attested as a whole.
+	pub attestation: Option<Attestation>,
+}
+
+/// A delivery confirmation submitted as part of a batch.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct DeliveryConfirmation {
+	pub id: MessageId,
+	/// The hash of the delivered message, used as leaf for the batch's Merkle root.
+	pub hash: MessageHash,
+	/// Required unless the batch is attested as a whole.
+	pub attestation: Option<Attestation>,
+}
+
+/// Prefixes the hash of a leaf, so no leaf can be passed off as an inner node of a Merkle tree.
+const MERKLE_LEAF_PREFIX: u8 = 0;
+/// Prefixes the hash of an inner node of a Merkle tree.
+const MERKLE_NODE_PREFIX: u8 = 1;
+
+fn merkle_leaf<H: Hash<Output = H256>>(leaf: &H256) -> H256 {
+	H::hash(&[&[MERKLE_LEAF_PREFIX][..], leaf.as_bytes()].concat())
+}
+
+fn merkle_node<H: Hash<Output = H256>>(left: &H256, right: &H256) -> H256 {
+	H::hash(&[&[MERKLE_NODE_PREFIX][..], left.as_bytes(), right.as_bytes()].concat())
+}
+
+/// Computes the root of a binary Merkle tree over `leaves`.
+///
+/// Leaves are hashed prefixed with `0x00`, each pair of nodes is hashed as `0x01` followed by the concatenation of left and right.
+/// An unpaired last node is promoted to the next layer as is.
+pub fn merkle_root<H: Hash<Output = H256>>(leaves: Vec<H256>) -> H256 {
+	let mut layer: Vec<H256> = leaves.iter().map(merkle_leaf::<H>).collect();
+	while layer.len() > 1 {
+		layer = layer
+			.chunks(2)
+			.map(|pair| match pair {
+				[left, right] => merkle_node::<H>(left, right),
+				_ => pair[0],
+			})
+			.collect();
+	}
+	layer.first().copied().unwrap_or_default()
+}
+
+/// Computes the root of the tree built by [`merkle_root`] that `proof` shows `leaf` to be part of.
+///
+/// Returns `None` if the proof does not match the shape of the tree.
+pub fn merkle_root_from_proof<H: Hash<Output = H256>>(
+	leaf: H256,
+	proof: &MerkleProof,
+) -> Option<H256> {
+	if proof.leaf_index >= proof.leaf_count {
+		return None;
+	}
+	let mut siblings = proof.siblings.iter();
+	let mut node = merkle_leaf::<H>(&leaf);
+	let (mut index, mut len) = (proof.leaf_index, proof.leaf_count);
+	while len > 1 {
+		if index % 2 == 1 {
+			node = merkle_node::<H>(siblings.next()?, &node);
+		} else if index + 1 < len {
+			node = merkle_node::<H>(&node, siblings.next()?);
+		}
+		// otherwise the node is unpaired and promoted as is
+		index /= 2;
+		len = len.div_ceil(2);
+	}
+	siblings.next().is_none().then_some(node)
+}
+
+/// The maximum number of siblings in a [`MerkleProof`], enough for batches of [`MESSAGE_BATCH_MAX_LENGTH`].
+pub const MERKLE_PROOF_MAX_LENGTH: u32 = 8;
+
+/// Proves that a leaf is part of a tree built by [`merkle_root`].
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct MerkleProof {
+	/// The position of the leaf.
+	pub leaf_index: u32,
+	/// The number of leaves of the tree.
+	pub leaf_count: u32,
+	/// The siblings from the leaf up to the root, leaving out the layers in which the node is unpaired.
+	pub siblings: BoundedVec<H256, ConstU32<MERKLE_PROOF_MAX_LENGTH>>,
+}
+
+/// The total oracle weight required to accept a message on a route.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+)]
+pub struct Quorum {
+	/// The weight required to receive an incoming message, at least the runtime's minimum receipt confirmations.
+	pub receipt: u32,
+	/// The weight required to confirm the delivery of an outgoing message, at least the runtime's minimum delivery confirmations.
+	pub delivery: u32,
+}
+
+/// Sets (`Some`) or removes (`None`) a quorum override.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub enum QuorumUpdate<AccountId, Contract> {
+	/// Applies to all messages from or to the given proxy chain.
+	Chain(ProxyChain, Option<Quorum>),
+	/// Applies to all messages to the given recipient, taking precedence over the chain's quorum.
+	Recipient(Subject<AccountId, Contract>, Option<Quorum>),
+}
+
+/// The stake bonded by an oracle that registered itself permissionlessly.
+#[derive(
+	RuntimeDebug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq,
+)]
+pub struct OracleBond<AccountId, Balance, BlockNumber> {
+	/// The account that bonded the stake and receives it back after unbonding.
+	pub owner: AccountId,
+	/// The amount held from `owner`.
+	pub amount: Balance,
+	/// The block from which on the bond can be withdrawn, set when unbonding is requested.
+	///
+	/// The oracle stays slashable until the bond is withdrawn.
+	pub unbonding_until: Option<BlockNumber>,
+}
+
+/// A message together with an oracle's signature over it.
+///
+/// A `relayer` is only present for delivery confirmations, since those signatures also cover the relayer's address.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub struct SignedMessage<AccountId, Contract> {
+	pub message: Message<AccountId, Contract>,
+	pub relayer: Option<AccountId>,
+	pub signature: Signature,
+	/// Present if `signature` is over the root of a batch, proving the message's hash to be one of its leaves.
+	pub batch_proof: Option<MerkleProof>,
+}
+
+/// Evidence that an oracle signed something it must never sign.
+#[derive(RuntimeDebug, Encode, Decode, DecodeWithMemTracking, TypeInfo, Clone, PartialEq)]
+pub enum EquivocationProof<AccountId, Contract> {
+	/// Two signatures over different messages with the same `(sender, nonce)`.
+	ConflictingMessages {
+		first: SignedMessage<AccountId, Contract>,
+		second: SignedMessage<AccountId, Contract>,
+	},
+	/// A signature confirming the delivery of a message that was never sent from this chain under its tracked id.
+	UnsentMessageDelivery(SignedMessage<AccountId, Contract>),
+}
+
+impl<AccountId: PartialEq, Contract: PartialEq> EquivocationProof<AccountId, Contract> {
+	/// Checks the structure of the evidence, not including the signatures.
+	pub fn is_well_formed(&self) -> bool {
+		match self {
+			Self::ConflictingMessages { first, second } => {
+				first.message.sender == second.message.sender
+					&& first.message.nonce == second.message.nonce
+					&& first.message != second.message
+			},
+			Self::UnsentMessageDelivery(signed) => signed.relayer.is_some(),
+		}
+	}
+}
+
 /// The message (without metadata) that gets signed by oracle and verified by recipient.
 #[derive(
 	RuntimeDebug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq,
@@ -157,6 +627,7 @@ impl<AccountId, Contract> From<Message<AccountId, Contract>> for MessageBody<Acc
 pub type MessageIndex = u64;
 pub type MessageId = H256;
 pub type MessageNonce = H256;
+pub type MessageHash = H256;
 
 /// Tracks the progress during `submit_message`, intended to be included in events.
 #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
@@ -106,4 +106,147 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 7114))
 			.saturating_add(T::DbWeight::get().reads(2))
 	}
+	fn bond_oracle() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3668))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	fn unbond_oracle() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3600))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	fn withdraw_oracle_bond() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3668))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	fn report_equivocation() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(120_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6271))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	fn set_min_oracle_set_size() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn update_oracle_weights(n: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(1_300_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn update_quorums(n: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(1_500_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn update_aggregate_oracles(n: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(3_500_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn receive_message_aggregated(s: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(3_009_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(400_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(s.into()))
+	}
+	fn confirm_message_delivery_aggregated(s: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(3_020_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7114))
+			.saturating_add(Weight::from_parts(400_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(s.into()))
+	}
+	fn receive_messages(n: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(9_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn confirm_message_deliveries(n: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(21_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7114).saturating_mul(n.into()))
+	}
+	fn update_message_verifier() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn update_ethereum_ibc() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn submit_ethereum_finality_update(c: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(4_000_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 56102))
+			.saturating_add(Weight::from_parts(52_000_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+	}
+	fn receive_message_with_proof(n: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(80_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4707))
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	fn check_aggregate_signature(s: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(3_000_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 58866))
+			.saturating_add(Weight::from_parts(400_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(s.into()))
+	}
+	fn update_ethereum_light_client(c: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(52_000_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	fn clean_sent_messages(x: u32) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			.saturating_add(Weight::from_parts(10_000_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 5050).saturating_mul(x.into()))
+	}
 }
@@ -1,5 +1,5 @@
 use frame_benchmarking::benchmarks_instance_pallet;
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::fungibles};
 
 pub use crate::stub::*;
 use crate::Pallet as AcurastHyperdriveToken;
@@ -32,6 +32,7 @@ benchmarks_instance_pallet! {
 		T::AccountId: From<AccountId32>,
 		<T as pallet_balances::Config>::Balance: From<u128>,
 		<<T as frame_system::Config>::Lookup as StaticLookup>::Source: From<AccountId32> + From<T::AccountId>,
+		T::Assets: fungibles::Create<T::AccountId>,
 	}
 
 	transfer_native {
@@ -64,7 +65,7 @@ benchmarks_instance_pallet! {
 		assert_ok!(AcurastHyperdriveToken::<T, I>::transfer_native(RawOrigin::Signed(caller).into(), ethereum_dest().into(), amount_to_transfer.into(), fee_amount.into()));
 	}
 
-	retry_transfer_native {
+	retry_transfer {
 		let initial_balance = 1000 * UNIT;
 		let amount_to_transfer = UNIT;
 		let fee_amount = UNIT / 10;
@@ -97,7 +98,7 @@ benchmarks_instance_pallet! {
 
 		run_to_block::<T, I>(200u32.into());
 	}: {
-		assert_ok!(AcurastHyperdriveToken::<T, I>::retry_transfer_native(RawOrigin::Signed(caller).into(),
+		assert_ok!(AcurastHyperdriveToken::<T, I>::retry_transfer(RawOrigin::Signed(caller).into(),
 		ProxyChain::Ethereum,
 		0,
 		retry_fee_amount.into()));
@@ -151,5 +152,153 @@ benchmarks_instance_pallet! {
 		run_to_block::<T, I>(100u32.into());
 	}: _(RawOrigin::Root, ProxyChain::Ethereum, Some(ttl.into()))
 
+	transfer_asset {
+		let initial_balance = 1000 * UNIT;
+		let asset_id: BridgedAssetId = 1;
+
+		let caller: T::AccountId = alice_account_id().into();
+		whitelist_account!(caller);
+		let vault: T::AccountId = bob_account_id().into();
+
+		assert_ok!(AcurastHyperdriveToken::<T, I>::set_enabled(RawOrigin::Root.into(), true));
+
+		// Arrange: initial balances and configuration
+		assert_ok!(Balances::<T>::force_set_balance(RawOrigin::Root.into(), caller.clone().into(), initial_balance.into()));
+		assert_ok!(Balances::<T>::force_set_balance(RawOrigin::Root.into(), ethereum_fee_vault().into(), initial_balance.into()));
+		assert_ok!(<T::Assets as fungibles::Create<T::AccountId>>::create(asset_id, vault.clone(), true, 1u128.into()));
+		assert_ok!(<T::Assets as fungibles::Mutate<T::AccountId>>::mint_into(asset_id, &caller, initial_balance.into()));
+		assert_ok!(AcurastHyperdriveToken::<T, I>::update_ethereum_contract(RawOrigin::Root.into(), ethereum_token_contract()));
+		assert_ok!(AcurastHyperdriveToken::<T, I>::update_bridged_asset(
+			RawOrigin::Root.into(),
+			ProxyChain::Ethereum,
+			ethereum_bridged_token(),
+			Some(BridgedAsset { asset_id, min_transfer_amount: UNIT.into(), vault }),
+		));
+
+		run_to_block::<T, I>(100u32.into());
+	}: _(RawOrigin::Signed(caller), ethereum_dest().into(), asset_id, UNIT.into(), (UNIT / 10).into())
+
+	update_bridged_asset {
+		run_to_block::<T, I>(100u32.into());
+
+		let asset = BridgedAsset { asset_id: 1, min_transfer_amount: UNIT.into(), vault: bob_account_id().into() };
+	}: _(RawOrigin::Root, ProxyChain::Ethereum, ethereum_bridged_token(), Some(asset.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::BridgedAssetUpdated { proxy_chain: ProxyChain::Ethereum, token: ethereum_bridged_token(), asset: Some(asset) }.into());
+	}
+
+	update_transfer_limits {
+		run_to_block::<T, I>(100u32.into());
+
+		let limits = TransferLimits {
+			window: 100u32.into(),
+			max_outgoing: (1000 * UNIT).into(),
+			max_incoming: (1000 * UNIT).into(),
+			max_per_transfer: (100 * UNIT).into(),
+			delay_threshold: (10 * UNIT).into(),
+			challenge_period: 50u32.into(),
+		};
+	}: _(RawOrigin::Root, ProxyChain::Ethereum, None, Some(limits.clone()))
+	verify {
+		assert_last_event::<T, I>(Event::TransferLimitsUpdated { proxy_chain: ProxyChain::Ethereum, asset_id: None, limits: Some(limits) }.into());
+	}
+
+	set_circuit_breaker {
+		run_to_block::<T, I>(100u32.into());
+	}: _(RawOrigin::Root, ProxyChain::Ethereum, true)
+	verify {
+		assert_last_event::<T, I>(Event::CircuitBreakerTripped { proxy_chain: ProxyChain::Ethereum }.into());
+	}
+
+	veto_transfer {
+		run_to_block::<T, I>(100u32.into());
+
+		<PendingTransfers<T, I>>::insert(ProxyChain::Ethereum, 0, PendingTransfer {
+			dest: bob_account_id().into(),
+			asset_id: None,
+			amount: UNIT.into(),
+			executable_at: Some(150u32.into()),
+		});
+	}: _(RawOrigin::Root, ProxyChain::Ethereum, 0)
+	verify {
+		assert_last_event::<T, I>(Event::TransferVetoed { source: ProxyChain::Ethereum, transfer_nonce: 0 }.into());
+	}
+
+	execute_pending_transfer {
+		let initial_balance = 1000 * UNIT;
+
+		let caller: T::AccountId = alice_account_id().into();
+		whitelist_account!(caller);
+
+		assert_ok!(Balances::<T>::force_set_balance(RawOrigin::Root.into(), ethereum_vault().into(), initial_balance.into()));
+		assert_ok!(AcurastHyperdriveToken::<T, I>::update_ethereum_contract(RawOrigin::Root.into(), ethereum_token_contract()));
+		<PendingTransfers<T, I>>::insert(ProxyChain::Ethereum, 0, PendingTransfer {
+			dest: bob_account_id().into(),
+			asset_id: None,
+			amount: UNIT.into(),
+			executable_at: Some(150u32.into()),
+		});
+
+		run_to_block::<T, I>(200u32.into());
+	}: _(RawOrigin::Signed(caller), ProxyChain::Ethereum, 0)
+	verify {
+		assert_last_event::<T, I>(Event::PendingTransferExecuted { source: ProxyChain::Ethereum, transfer_nonce: 0 }.into());
+	}
+
+	release_transfer {
+		let initial_balance = 1000 * UNIT;
+
+		assert_ok!(Balances::<T>::force_set_balance(RawOrigin::Root.into(), ethereum_vault().into(), initial_balance.into()));
+		assert_ok!(AcurastHyperdriveToken::<T, I>::update_ethereum_contract(RawOrigin::Root.into(), ethereum_token_contract()));
+		<PendingTransfers<T, I>>::insert(ProxyChain::Ethereum, 0, PendingTransfer {
+			dest: bob_account_id().into(),
+			asset_id: None,
+			amount: UNIT.into(),
+			executable_at: None,
+		});
+
+		run_to_block::<T, I>(100u32.into());
+	}: _(RawOrigin::Root, ProxyChain::Ethereum, 0)
+	verify {
+		assert_last_event::<T, I>(Event::PendingTransferExecuted { source: ProxyChain::Ethereum, transfer_nonce: 0 }.into());
+	}
+
+	reclaim_vetoed_transfer {
+		let initial_balance = 1000 * UNIT;
+
+		assert_ok!(Balances::<T>::force_set_balance(RawOrigin::Root.into(), ethereum_vault().into(), initial_balance.into()));
+		assert_ok!(AcurastHyperdriveToken::<T, I>::update_ethereum_contract(RawOrigin::Root.into(), ethereum_token_contract()));
+		<VetoedTransfers<T, I>>::insert(ProxyChain::Ethereum, 0, PendingTransfer {
+			dest: bob_account_id().into(),
+			asset_id: None,
+			amount: UNIT.into(),
+			executable_at: Some(150u32.into()),
+		});
+
+		run_to_block::<T, I>(100u32.into());
+	}: _(RawOrigin::Root, ProxyChain::Ethereum, 0, alice_account_id().into())
+	verify {
+		assert_last_event::<T, I>(Event::VetoedTransferReclaimed { source: ProxyChain::Ethereum, transfer_nonce: 0, beneficiary: alice_account_id().into() }.into());
+	}
+
+	refund_transfer {
+		let initial_balance = 1000 * UNIT;
+		let asset_id: BridgedAssetId = 1;
+
+		let caller: T::AccountId = alice_account_id().into();
+		let vault: T::AccountId = bob_account_id().into();
+		let asset = BridgedAsset { asset_id, min_transfer_amount: UNIT.into(), vault: vault.clone() };
+
+		assert_ok!(<T::Assets as fungibles::Create<T::AccountId>>::create(asset_id, vault.clone(), true, 1u128.into()));
+		assert_ok!(<T::Assets as fungibles::Mutate<T::AccountId>>::mint_into(asset_id, &vault, initial_balance.into()));
+		<OutgoingTransfers<T, I>>::insert(ProxyChain::Ethereum, 0, (caller.clone(), ethereum_dest().into(), UNIT.into()));
+		<OutgoingTransferAssets<T, I>>::insert(ProxyChain::Ethereum, 0, asset);
+
+		run_to_block::<T, I>(100u32.into());
+	}: _(RawOrigin::Root, ProxyChain::Ethereum, 0)
+	verify {
+		assert_last_event::<T, I>(Event::TransferRefunded { proxy_chain: ProxyChain::Ethereum, transfer_nonce: 0, source: caller, asset_id, amount: UNIT.into() }.into());
+	}
+
 	//impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
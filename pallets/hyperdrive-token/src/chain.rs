@@ -4,6 +4,7 @@ use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
 pub mod ethereum;
+pub mod solana;
 
 /// Errors returned by decoders.
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq, Display)]
@@ -17,4 +18,5 @@ pub enum ActionDecoderError {
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq, Display)]
 pub enum ActionEncoderError {
 	UnsupportedProxy,
+	InvalidToken,
 }
@@ -8,7 +8,12 @@ use sp_std::prelude::*;
 use pallet_acurast::MultiOrigin;
 
 use super::*;
-use crate::{Action, ActionDecoder, ActionEncoder, RawAction};
+use crate::{Action, ActionDecoder, ActionEncoder, RawAction, RemoteToken};
+
+/// The token kind of a transfer of the native token.
+const NATIVE_TOKEN: u32 = 0;
+/// The token kind of a transfer of an ERC-20 token, whose address follows the destination.
+const ERC20_TOKEN: u32 = 1;
 
 const fn action_id_range() -> ops::Range<usize> {
 	0..4
@@ -22,7 +27,7 @@ const fn enabled_index() -> usize {
 	4
 }
 
-const fn asset_id_range() -> ops::Range<usize> {
+const fn token_kind_range() -> ops::Range<usize> {
 	20..24
 }
 
@@ -38,6 +43,14 @@ const fn acurast_dest_range() -> ops::Range<usize> {
 	32..64
 }
 
+const fn outgoing_token_range() -> ops::Range<usize> {
+	52..72
+}
+
+const fn incoming_token_range() -> ops::Range<usize> {
+	64..84
+}
+
 #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, Eq, PartialEq)]
 #[scale_info(skip_type_params(AccountConverter))]
 pub struct EthereumActionDecoder<I, AccountConverter, AccountId> {
@@ -78,7 +91,7 @@ where
 
 		match raw_action {
 			RawAction::TransferToken => {
-				if encoded.len() != 64 {
+				if encoded.len() < 64 {
 					Err(Self::Error::InvalidActionPayload)?;
 				}
 
@@ -87,11 +100,21 @@ where
 						.try_into()
 						.map_err(|_| ActionDecoderError::InvalidActionPayload)?,
 				);
-				let asset_id: u32 = u32::from_be_bytes(
-					encoded[asset_id_range()]
+				let token_kind: u32 = u32::from_be_bytes(
+					encoded[token_kind_range()]
 						.try_into()
 						.map_err(|_| ActionDecoderError::InvalidActionPayload)?,
 				);
+				let token: Option<RemoteToken> = match (token_kind, encoded.len()) {
+					(NATIVE_TOKEN, 64) => None,
+					(ERC20_TOKEN, 84) => Some(
+						encoded[incoming_token_range()]
+							.to_vec()
+							.try_into()
+							.map_err(|_| ActionDecoderError::InvalidActionPayload)?,
+					),
+					_ => Err(ActionDecoderError::InvalidActionPayload)?,
+				};
 				let transfer_nonce = u64::from_be_bytes(
 					encoded[transfer_nonce_range()]
 						.try_into()
@@ -102,12 +125,7 @@ where
 						.try_into()
 						.map_err(|_| ActionDecoderError::InvalidActionPayload)?,
 				)?;
-				Ok(Action::TransferToken(
-					amount,
-					(asset_id != 0u32).then_some(asset_id),
-					transfer_nonce,
-					MultiOrigin::Acurast(dest),
-				))
+				Ok(Action::TransferToken(amount, token, transfer_nonce, MultiOrigin::Acurast(dest)))
 			},
 			RawAction::Noop => Ok(Action::Noop),
 			RawAction::SetEnabled => {
@@ -128,20 +146,31 @@ impl<AccountId> ActionEncoder<AccountId> for EthereumActionEncoder {
 
 	fn encode(action: &Action<AccountId>) -> Result<Vec<u8>, Self::Error> {
 		Ok(match action {
-			Action::TransferToken(amount, asset_id, transfer_nonce, dest) => match dest {
+			Action::TransferToken(amount, token, transfer_nonce, dest) => match dest {
 				MultiOrigin::Ethereum20(account_id) => {
-					let mut buffer = [0u8; 52];
+					let mut buffer = [0u8; 72];
 
 					let raw_action: RawAction = action.into();
 					let raw_action_encoded: u32 = raw_action.into();
 					buffer[action_id_range()].copy_from_slice(&raw_action_encoded.to_be_bytes());
 					buffer[amount_range()].copy_from_slice(&(*amount).to_be_bytes());
-					buffer[asset_id_range()]
-						.copy_from_slice(&(asset_id.unwrap_or_default()).to_be_bytes());
 					buffer[transfer_nonce_range()].copy_from_slice(&transfer_nonce.to_be_bytes());
 					buffer[ethereum_dest_range()].copy_from_slice(&account_id.0);
 
-					buffer.to_vec()
+					match token {
+						Some(token) => {
+							if token.len() != outgoing_token_range().len() {
+								Err(ActionEncoderError::InvalidToken)?;
+							}
+							buffer[token_kind_range()].copy_from_slice(&ERC20_TOKEN.to_be_bytes());
+							buffer[outgoing_token_range()].copy_from_slice(token);
+							buffer.to_vec()
+						},
+						None => {
+							buffer[token_kind_range()].copy_from_slice(&NATIVE_TOKEN.to_be_bytes());
+							buffer[..outgoing_token_range().start].to_vec()
+						},
+					}
 				},
 				_ => Err(ActionEncoderError::UnsupportedProxy)?,
 			},
@@ -167,6 +196,7 @@ mod tests {
 	use derive_more::{From, Into};
 	use frame_support::assert_ok;
 	use hex_literal::hex;
+	use pallet_acurast::AccountId20;
 	use sp_runtime::AccountId32;
 
 	/// Wrapper around [`AccountId32`] to allow the implementation of [`TryFrom<Vec<u8>>`].
@@ -186,18 +216,66 @@ mod tests {
 	///
 	/// uint32(0), // action_id (0 for transfer)
 	/// uint128 // amount
-	/// uint32(0), // assetId (0 for native token)
+	/// uint32(0), // tokenKind (0 for native token, 1 for an ERC-20 token)
 	/// uint32 // transferNonce
 	/// dest // bytes32
+	/// token // bytes20, only for ERC-20 tokens
 	///
 	/// Example: This payload is split like
 	///
 	/// ```
 	/// 0x00000000000000000000000000000000000003e8000000000000000000000000185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b
 	///   |       |                               |       |               |
-	///   action  amount                          assetId transferNonce   dest
+	///   action  amount                          kind    transferNonce   dest
 	/// ```
 	fn decode() {
 		assert_ok!(<EthereumActionDecoder::<(), MockAccountId, AccountId32> as ActionDecoder<AccountId32>>::decode(&hex!("00000000000000000000000000000000000003e8000000000000000000000000185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b")));
 	}
+
+	#[test]
+	fn decode_token_transfer() {
+		let token: RemoteToken =
+			hex!("dAC17F958D2ee523a2206206994597C13D831ec7").to_vec().try_into().unwrap();
+		assert_eq!(
+			<EthereumActionDecoder::<(), MockAccountId, AccountId32> as ActionDecoder<AccountId32>>::decode(&hex!("00000000000000000000000000000000000003e8000000010000000000000005185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280bdAC17F958D2ee523a2206206994597C13D831ec7")),
+			Ok(Action::TransferToken(
+				1_000,
+				Some(token),
+				5,
+				MultiOrigin::Acurast(AccountId32::new(hex!(
+					"185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b"
+				)))
+			))
+		);
+		// a token kind without the token address
+		assert_eq!(
+			<EthereumActionDecoder::<(), MockAccountId, AccountId32> as ActionDecoder<AccountId32>>::decode(&hex!("00000000000000000000000000000000000003e8000000010000000000000005185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b")),
+			Err(ActionDecoderError::InvalidActionPayload)
+		);
+	}
+
+	#[test]
+	fn encode_token_transfer() {
+		let token: RemoteToken =
+			hex!("dAC17F958D2ee523a2206206994597C13D831ec7").to_vec().try_into().unwrap();
+		let dest = AccountId20(hex!("0000000000000000000000000000000000000001"));
+		assert_eq!(
+			<EthereumActionEncoder as ActionEncoder<AccountId32>>::encode(&Action::TransferToken(
+				1_000,
+				Some(token),
+				5,
+				MultiOrigin::Ethereum20(dest),
+			)),
+			Ok(hex!("00000000000000000000000000000000000003e80000000100000000000000050000000000000000000000000000000000000001dAC17F958D2ee523a2206206994597C13D831ec7").to_vec())
+		);
+		assert_eq!(
+			<EthereumActionEncoder as ActionEncoder<AccountId32>>::encode(&Action::TransferToken(
+				1_000,
+				None,
+				5,
+				MultiOrigin::Ethereum20(dest),
+			)),
+			Ok(hex!("00000000000000000000000000000000000003e80000000000000000000000050000000000000000000000000000000000000001").to_vec())
+		);
+	}
 }
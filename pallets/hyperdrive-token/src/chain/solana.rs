@@ -0,0 +1,254 @@
+use core::marker::PhantomData;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+use sp_core::RuntimeDebug;
+use sp_std::prelude::*;
+
+use pallet_acurast::MultiOrigin;
+
+use super::*;
+use crate::{Action, ActionDecoder, ActionEncoder, RawAction, RemoteToken};
+
+/// Borsh tag of `Option::None`.
+const NONE_TAG: u8 = 0;
+/// Borsh tag of `Option::Some`.
+const SOME_TAG: u8 = 1;
+
+/// Minimal reader over a Borsh-encoded action, as emitted by the _Hyperdrive Token_ program on Solana.
+///
+/// Borsh encodes enum variants as a `u8` tag followed by the variant's fields, integers as little-endian,
+/// `bool` as a single byte and `Option<T>` as a `u8` tag followed by `T` if present.
+struct BorshReader<'a> {
+	input: &'a [u8],
+}
+
+impl<'a> BorshReader<'a> {
+	fn take<const N: usize>(&mut self) -> Result<[u8; N], ActionDecoderError> {
+		if self.input.len() < N {
+			Err(ActionDecoderError::InvalidActionPayload)?;
+		}
+		let (head, tail) = self.input.split_at(N);
+		self.input = tail;
+		head.try_into().map_err(|_| ActionDecoderError::InvalidActionPayload)
+	}
+
+	fn read_u8(&mut self) -> Result<u8, ActionDecoderError> {
+		Ok(self.take::<1>()?[0])
+	}
+
+	fn read_bool(&mut self) -> Result<bool, ActionDecoderError> {
+		match self.read_u8()? {
+			0 => Ok(false),
+			1 => Ok(true),
+			_ => Err(ActionDecoderError::InvalidActionPayload),
+		}
+	}
+
+	/// Reads an optional mint address, `None` standing for the native token.
+	fn read_option_token(&mut self) -> Result<Option<RemoteToken>, ActionDecoderError> {
+		match self.read_u8()? {
+			NONE_TAG => Ok(None),
+			SOME_TAG => Ok(Some(
+				self.take::<32>()?
+					.to_vec()
+					.try_into()
+					.map_err(|_| ActionDecoderError::InvalidActionPayload)?,
+			)),
+			_ => Err(ActionDecoderError::InvalidActionPayload),
+		}
+	}
+
+	fn finish(self) -> Result<(), ActionDecoderError> {
+		if !self.input.is_empty() {
+			Err(ActionDecoderError::InvalidActionPayload)?;
+		}
+		Ok(())
+	}
+}
+
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, Eq, PartialEq)]
+#[scale_info(skip_type_params(AccountConverter))]
+pub struct SolanaActionDecoder<I, AccountConverter, AccountId> {
+	#[cfg(any(test, feature = "runtime-benchmarks"))]
+	pub marker: PhantomData<(I, AccountConverter, AccountId)>,
+	#[cfg(not(any(test, feature = "runtime-benchmarks")))]
+	marker: PhantomData<(I, AccountConverter, AccountId)>,
+}
+
+impl<I: 'static, AccountConverter, AccountId> ActionDecoder<AccountId>
+	for SolanaActionDecoder<I, AccountConverter, AccountId>
+where
+	AccountConverter: TryFrom<Vec<u8>> + Into<AccountId>,
+{
+	type Error = ActionDecoderError;
+
+	fn decode(encoded: &[u8]) -> Result<Action<AccountId>, Self::Error> {
+		let mut reader = BorshReader { input: encoded };
+		let raw_action: RawAction = (reader.read_u8().map_err(|_| Self::Error::InvalidAction)?
+			as u32)
+			.try_into()
+			.map_err(|_err| Self::Error::UnsupportedAction)?;
+
+		let action = match raw_action {
+			RawAction::TransferToken => {
+				let amount = u128::from_le_bytes(reader.take()?);
+				let token = reader.read_option_token()?;
+				let transfer_nonce = u64::from_le_bytes(reader.take()?);
+				let dest: AccountConverter = reader
+					.take::<32>()?
+					.to_vec()
+					.try_into()
+					.map_err(|_| ActionDecoderError::CouldNotConvertAccountId)?;
+				Action::TransferToken(
+					amount,
+					token,
+					transfer_nonce,
+					MultiOrigin::Acurast(dest.into()),
+				)
+			},
+			RawAction::SetEnabled => Action::SetEnabled(reader.read_bool()?),
+			RawAction::Noop => Action::Noop,
+		};
+		reader.finish()?;
+
+		Ok(action)
+	}
+}
+
+pub struct SolanaActionEncoder;
+
+impl<AccountId> ActionEncoder<AccountId> for SolanaActionEncoder {
+	type Error = ActionEncoderError;
+
+	fn encode(action: &Action<AccountId>) -> Result<Vec<u8>, Self::Error> {
+		let raw_action: RawAction = action.into();
+		let raw_action_encoded: u32 = raw_action.into();
+		let mut buffer = vec![raw_action_encoded as u8];
+
+		match action {
+			Action::TransferToken(amount, token, transfer_nonce, dest) => match dest {
+				MultiOrigin::Solana(account_id) => {
+					buffer.extend_from_slice(&amount.to_le_bytes());
+					match token {
+						Some(token) => {
+							// mint addresses are always 32 bytes
+							if token.len() != 32 {
+								Err(ActionEncoderError::InvalidToken)?;
+							}
+							buffer.push(SOME_TAG);
+							buffer.extend_from_slice(token);
+						},
+						None => buffer.push(NONE_TAG),
+					}
+					buffer.extend_from_slice(&transfer_nonce.to_le_bytes());
+					buffer.extend_from_slice(account_id.as_ref());
+				},
+				_ => Err(ActionEncoderError::UnsupportedProxy)?,
+			},
+			Action::Noop => return Ok(vec![]),
+			Action::SetEnabled(enabled) => buffer.push(if *enabled { 1u8 } else { 0u8 }),
+		}
+
+		Ok(buffer)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ActionDecoder;
+	use super::*;
+	use derive_more::{From, Into};
+	use frame_support::{assert_err, assert_ok};
+	use hex_literal::hex;
+	use sp_runtime::AccountId32;
+
+	/// Wrapper around [`AccountId32`] to allow the implementation of [`TryFrom<Vec<u8>>`].
+	#[derive(Debug, From, Into, Clone, Eq, PartialEq)]
+	pub struct MockAccountId(AccountId32);
+	impl TryFrom<Vec<u8>> for MockAccountId {
+		type Error = ();
+
+		fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+			let a: [u8; 32] = value.try_into().map_err(|_| ())?;
+			Ok(MockAccountId(AccountId32::new(a)))
+		}
+	}
+
+	type Decoder = SolanaActionDecoder<(), MockAccountId, AccountId32>;
+
+	#[test]
+	/// Tests decoding a Borsh-encoded transfer
+	///
+	/// u8(0), // action tag (0 for transfer)
+	/// u128 // amount, little-endian
+	/// Option<[u8; 32]>(None), // mint (None for native token)
+	/// u64 // transferNonce, little-endian
+	/// dest // [u8; 32]
+	///
+	/// Example: This payload is split like
+	///
+	/// ```
+	/// 0x00e80300000000000000000000000000000007000000000000001...0b
+	///   | |                               | |               |
+	///   | amount                          | transferNonce   dest
+	///   action                            mint
+	/// ```
+	fn decode_transfer() {
+		assert_eq!(
+			<Decoder as ActionDecoder<AccountId32>>::decode(&hex!("00e8030000000000000000000000000000000700000000000000185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b")),
+			Ok(Action::TransferToken(
+				1_000,
+				None,
+				7,
+				MultiOrigin::Acurast(AccountId32::new(hex!(
+					"185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b"
+				)))
+			))
+		);
+	}
+
+	#[test]
+	fn decode_set_enabled() {
+		assert_eq!(
+			<Decoder as ActionDecoder<AccountId32>>::decode(&hex!("0101")),
+			Ok(Action::SetEnabled(true))
+		);
+		assert_err!(
+			<Decoder as ActionDecoder<AccountId32>>::decode(&hex!("0102")),
+			ActionDecoderError::InvalidActionPayload
+		);
+	}
+
+	#[test]
+	fn decode_rejects_trailing_bytes() {
+		assert_err!(
+			<Decoder as ActionDecoder<AccountId32>>::decode(&hex!("010100")),
+			ActionDecoderError::InvalidActionPayload
+		);
+	}
+
+	#[test]
+	fn encode_transfer() {
+		let dest = AccountId32::new(hex!(
+			"185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b"
+		));
+		let mint: RemoteToken = [2u8; 32].to_vec().try_into().unwrap();
+		let encoded = <SolanaActionEncoder as ActionEncoder<AccountId32>>::encode(
+			&Action::TransferToken(1_000, Some(mint), 7, MultiOrigin::Solana(dest.clone())),
+		);
+		assert_ok!(&encoded);
+		assert_eq!(encoded.unwrap(), hex!("00e80300000000000000000000000000000102020202020202020202020202020202020202020202020202020202020202020700000000000000185a8b5f92ecd348ed9b12a047ca2b28488b1398065a8dff8dcf886245f9280b").to_vec());
+
+		let short_mint: RemoteToken = [2u8; 20].to_vec().try_into().unwrap();
+		assert_err!(
+			<SolanaActionEncoder as ActionEncoder<AccountId32>>::encode(&Action::TransferToken(
+				1_000,
+				Some(short_mint),
+				7,
+				MultiOrigin::Solana(dest),
+			)),
+			ActionEncoderError::InvalidToken
+		);
+	}
+}
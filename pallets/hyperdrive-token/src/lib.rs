@@ -31,7 +31,7 @@ pub mod pallet {
 		traits::{
 			tokens::{
 				fungible::{Inspect, Mutate},
-				Preservation,
+				fungibles, Preservation,
 			},
 			EnsureOrigin, Get,
 		},
@@ -50,6 +50,7 @@ pub mod pallet {
 	use super::*;
 	use chain::{
 		ethereum::{EthereumActionDecoder, EthereumActionEncoder},
+		solana::{SolanaActionDecoder, SolanaActionEncoder},
 		ActionDecoderError, ActionEncoderError,
 	};
 
@@ -79,6 +80,9 @@ pub mod pallet {
 
 		type Currency: Inspect<Self::AccountId, Balance = Self::Balance>
 			+ Mutate<Self::AccountId, Balance = Self::Balance>;
+		/// The non-native assets that can be bridged once registered in [`BridgedAssets`].
+		type Assets: fungibles::Inspect<Self::AccountId, AssetId = BridgedAssetId, Balance = Self::Balance>
+			+ fungibles::Mutate<Self::AccountId>;
 
 		#[pallet::constant]
 		type EthereumVault: Get<Self::AccountId>;
@@ -130,6 +134,18 @@ pub mod pallet {
 			dest: T::AccountId,
 			amount: T::Balance,
 		},
+		AssetTransferToProxy {
+			source: T::AccountId,
+			dest: MultiOrigin<T::AccountId>,
+			asset_id: BridgedAssetId,
+			amount: T::Balance,
+		},
+		AssetTransferFromProxy {
+			source: ProxyChain,
+			dest: T::AccountId,
+			asset_id: BridgedAssetId,
+			amount: T::Balance,
+		},
 		EthereumContractUpdated {
 			contract: AccountId20,
 		},
@@ -143,6 +159,50 @@ pub mod pallet {
 			proxy_chain: ProxyChain,
 			ttl: Option<BlockNumberFor<T>>,
 		},
+		BridgedAssetUpdated {
+			proxy_chain: ProxyChain,
+			token: RemoteToken,
+			asset: Option<BridgedAssetFor<T, I>>,
+		},
+		TransferLimitsUpdated {
+			proxy_chain: ProxyChain,
+			asset_id: Option<BridgedAssetId>,
+			limits: Option<TransferLimitsFor<T, I>>,
+		},
+		CircuitBreakerTripped {
+			proxy_chain: ProxyChain,
+		},
+		CircuitBreakerReset {
+			proxy_chain: ProxyChain,
+		},
+		/// An incoming transfer was delayed until `executable_at`, or held until released if `executable_at` is `None`.
+		TransferDelayed {
+			source: ProxyChain,
+			transfer_nonce: TransferNonce,
+			executable_at: Option<BlockNumberFor<T>>,
+		},
+		TransferVetoed {
+			source: ProxyChain,
+			transfer_nonce: TransferNonce,
+		},
+		PendingTransferExecuted {
+			source: ProxyChain,
+			transfer_nonce: TransferNonce,
+		},
+		/// An outgoing transfer of a deregistered asset was refunded to its `source`.
+		TransferRefunded {
+			proxy_chain: ProxyChain,
+			transfer_nonce: TransferNonce,
+			source: T::AccountId,
+			asset_id: BridgedAssetId,
+			amount: T::Balance,
+		},
+		/// The amount of a vetoed transfer was released from the vault to `beneficiary`.
+		VetoedTransferReclaimed {
+			source: ProxyChain,
+			transfer_nonce: TransferNonce,
+			beneficiary: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -163,6 +223,19 @@ pub mod pallet {
 		UnsupportedAction,
 		TransferAmountTooLow,
 		FeeRefundFailed,
+		SolanaActionDecoderError(u8),
+		SolanaMessageEncoderError(u8),
+		AssetAlreadyBridged,
+		BridgeHalted,
+		TransferAmountTooHigh,
+		OutgoingLimitExceeded,
+		UnknownPendingTransfer,
+		PendingTransferHeld,
+		ChallengePeriodNotPassed,
+		UnknownVetoedTransfer,
+		UnknownTransferRefund,
+		AssetStillBridged,
+		TransferRefundFailed,
 	}
 
 	impl<T: Config<I>, I: 'static> From<ActionDecoderError> for Error<T, I> {
@@ -219,7 +292,7 @@ pub mod pallet {
 
 	/// Transfer nonces seen in processed incoming messages from proxies, uniquely identifying every transfer made _to_ Acurast.
 	///
-	/// The nonce orders all transfers from **just** ethereum proxy to this pallet. This fact they are ordered and sequential if all transfers are relayed is currently not used,
+	/// The nonce orders all transfers from a proxy to this pallet, separate for each proxy. This fact they are ordered and sequential if all transfers are relayed is currently not used,
 	/// but it could be used to optimize the storage required for detecting duplicate transfers.
 	#[pallet::storage]
 	#[pallet::getter(fn incoming_transfer_nonces)]
@@ -239,6 +312,112 @@ pub mod pallet {
 	pub type OutgoingTransferTTL<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Identity, ProxyChain, BlockNumberFor<T>, OptionQuery>;
 
+	/// The registry of non-native assets bridged per proxy chain, keyed by the token's address on the proxy chain.
+	#[pallet::storage]
+	#[pallet::getter(fn bridged_asset)]
+	pub type BridgedAssets<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		ProxyChain,
+		Blake2_128Concat,
+		RemoteToken,
+		BridgedAssetFor<T, I>,
+		OptionQuery,
+	>;
+
+	/// Reverse index of [`BridgedAssets`], resolving the asset id found in incoming and outgoing actions to the token on the proxy chain.
+	#[pallet::storage]
+	#[pallet::getter(fn bridged_asset_token)]
+	pub type BridgedAssetTokens<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		ProxyChain,
+		Identity,
+		BridgedAssetId,
+		RemoteToken,
+		OptionQuery,
+	>;
+
+	/// The asset of outgoing transfers not made in the native token, as registered at the time of the transfer.
+	///
+	/// Needed to retry them, or to refund them from the asset's vault once the asset got deregistered.
+	#[pallet::storage]
+	#[pallet::getter(fn outgoing_transfer_asset)]
+	pub type OutgoingTransferAssets<T: Config<I>, I: 'static = ()> =
+		StorageDoubleMap<_, Identity, ProxyChain, Identity, TransferNonce, BridgedAssetFor<T, I>>;
+
+	/// Limits on the volume bridged per proxy chain and token, keyed by the bridged asset id or `None` for the native token.
+	/// No limits apply to tokens without an entry.
+	#[pallet::storage]
+	#[pallet::getter(fn transfer_limits)]
+	pub type ProxyTransferLimits<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		ProxyChain,
+		Identity,
+		Option<BridgedAssetId>,
+		TransferLimitsFor<T, I>,
+		OptionQuery,
+	>;
+
+	/// The volume transferred _to_ each proxy chain per token, see [`ProxyTransferLimits`].
+	#[pallet::storage]
+	#[pallet::getter(fn outgoing_volume)]
+	pub type OutgoingVolume<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		ProxyChain,
+		Identity,
+		Option<BridgedAssetId>,
+		VolumeWindowFor<T, I>,
+		ValueQuery,
+	>;
+
+	/// The volume received _from_ each proxy chain per token, see [`ProxyTransferLimits`].
+	#[pallet::storage]
+	#[pallet::getter(fn incoming_volume)]
+	pub type IncomingVolume<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		ProxyChain,
+		Identity,
+		Option<BridgedAssetId>,
+		VolumeWindowFor<T, I>,
+		ValueQuery,
+	>;
+
+	/// Proxy chains for which the circuit breaker tripped, halting all transfers from and to them until reset.
+	#[pallet::storage]
+	#[pallet::getter(fn circuit_breaker_tripped)]
+	pub type CircuitBreakers<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, ProxyChain, bool, ValueQuery>;
+
+	/// Incoming transfers that were delayed or held instead of being executed immediately.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_transfer)]
+	pub type PendingTransfers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		ProxyChain,
+		Identity,
+		TransferNonce,
+		PendingTransferFor<T, I>,
+		OptionQuery,
+	>;
+
+	/// Incoming transfers that were vetoed, with their amount still locked in the vault until reclaimed.
+	#[pallet::storage]
+	#[pallet::getter(fn vetoed_transfer)]
+	pub type VetoedTransfers<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		ProxyChain,
+		Identity,
+		TransferNonce,
+		PendingTransferFor<T, I>,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
 		pub initial_eth_token_allocation: Option<T::Balance>,
@@ -285,11 +464,13 @@ pub mod pallet {
 
 		/// Retransfers tokens over Hyperdrive (IBC) to the proxy on recipient chain.
 		///
+		/// Retries transfers made with [`Pallet::transfer_native`] and [`Pallet::transfer_asset`].
 		/// * Cannot change the amount nor recipient since the transfer might be processed already.
 		/// * May specify a different `fee` than the original transfer and always restarts the `ttl` of the Hyperdrive (IBC) message.
+		/// * Transfers of an asset deregistered in the meantime cannot be retried, see [`Pallet::refund_transfer`].
 		#[pallet::call_index(1)]
-		#[pallet::weight(< T as Config<I>>::WeightInfo::retry_transfer_native())]
-		pub fn retry_transfer_native(
+		#[pallet::weight(< T as Config<I>>::WeightInfo::retry_transfer())]
+		pub fn retry_transfer(
 			origin: OriginFor<T>,
 			proxy: ProxyChain,
 			transfer_nonce: TransferNonce,
@@ -303,9 +484,10 @@ pub mod pallet {
 			if prev_source != source {
 				Err(Error::<T, I>::InvalidTransferRetry)?;
 			}
-			let _ = Self::do_transfer_native(
+			let _ = Self::do_transfer(
 				source,
 				prev_recipient,
+				Self::outgoing_transfer_asset(proxy, transfer_nonce).map(|asset| asset.asset_id),
 				prev_amount,
 				fee,
 				Some(transfer_nonce),
@@ -380,6 +562,232 @@ pub mod pallet {
 			Self::deposit_event(Event::DefaultOutgoingTransferTTLUpdated { proxy_chain, ttl });
 			Ok(())
 		}
+
+		/// Transfers a registered non-native asset over Hyperdrive (IBC) to the proxy on recipient chain.
+		///
+		/// The `fee` is paid in the native token, as for [`Pallet::transfer_native`].
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::transfer_asset())]
+		pub fn transfer_asset(
+			origin: OriginFor<T>,
+			dest: MultiOrigin<T::AccountId>,
+			asset_id: BridgedAssetId,
+			amount: T::Balance,
+			fee: T::Balance,
+		) -> DispatchResult {
+			Self::ensure_enabled()?;
+			let source = ensure_signed(origin)?;
+			let proxy: ProxyChain = (&dest).into();
+			let transfer_nonce =
+				Self::do_transfer(source.clone(), dest.clone(), Some(asset_id), amount, fee, None)?;
+			OutgoingTransfers::<T, I>::insert(proxy, transfer_nonce, (source, dest, amount));
+			OutgoingTransferAssets::<T, I>::insert(
+				proxy,
+				transfer_nonce,
+				Self::bridged_asset_for(proxy, asset_id)?,
+			);
+			Ok(())
+		}
+
+		/// Registers, updates or (if `asset` is `None`) removes the bridged asset for `token` on `proxy_chain`.
+		/// Can only be called by a privileged/root account.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::update_bridged_asset())]
+		pub fn update_bridged_asset(
+			origin: OriginFor<T>,
+			proxy_chain: ProxyChain,
+			token: RemoteToken,
+			asset: Option<BridgedAssetFor<T, I>>,
+		) -> DispatchResult {
+			<T as Config<I>>::UpdateOrigin::ensure_origin(origin)?;
+			if let Some(new_asset) = &asset {
+				if let Some(other_token) =
+					Self::bridged_asset_token(proxy_chain, new_asset.asset_id)
+				{
+					ensure!(other_token == token, Error::<T, I>::AssetAlreadyBridged);
+				}
+			}
+			if let Some(prev_asset) = <BridgedAssets<T, I>>::take(proxy_chain, &token) {
+				<BridgedAssetTokens<T, I>>::remove(proxy_chain, prev_asset.asset_id);
+			}
+			if let Some(new_asset) = &asset {
+				<BridgedAssets<T, I>>::insert(proxy_chain, &token, new_asset);
+				<BridgedAssetTokens<T, I>>::insert(proxy_chain, new_asset.asset_id, &token);
+			}
+			Self::deposit_event(Event::BridgedAssetUpdated { proxy_chain, token, asset });
+			Ok(())
+		}
+
+		/// Updates or (if `limits` is `None`) removes the transfer limits for `proxy_chain` and the bridged asset `asset_id`,
+		/// or the native token if `asset_id` is `None`.
+		/// Can only be called by a privileged/root account.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::update_transfer_limits())]
+		pub fn update_transfer_limits(
+			origin: OriginFor<T>,
+			proxy_chain: ProxyChain,
+			asset_id: Option<BridgedAssetId>,
+			limits: Option<TransferLimitsFor<T, I>>,
+		) -> DispatchResult {
+			<T as Config<I>>::UpdateOrigin::ensure_origin(origin)?;
+			match &limits {
+				Some(value) => <ProxyTransferLimits<T, I>>::insert(proxy_chain, asset_id, value),
+				None => <ProxyTransferLimits<T, I>>::remove(proxy_chain, asset_id),
+			}
+			Self::deposit_event(Event::TransferLimitsUpdated { proxy_chain, asset_id, limits });
+			Ok(())
+		}
+
+		/// Trips or resets the circuit breaker for `proxy_chain`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_circuit_breaker())]
+		pub fn set_circuit_breaker(
+			origin: OriginFor<T>,
+			proxy_chain: ProxyChain,
+			tripped: bool,
+		) -> DispatchResult {
+			T::OperatorOrigin::ensure_origin(origin)?;
+			if tripped {
+				Self::trip_circuit_breaker(proxy_chain);
+			} else {
+				<CircuitBreakers<T, I>>::remove(proxy_chain);
+				Self::deposit_event(Event::CircuitBreakerReset { proxy_chain });
+			}
+			Ok(())
+		}
+
+		/// Vetoes a pending incoming transfer. The amount stays locked in the vault until reclaimed with [`Pallet::reclaim_vetoed_transfer`].
+		///
+		/// Can be called by the update or the operator origin.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::veto_transfer())]
+		pub fn veto_transfer(
+			origin: OriginFor<T>,
+			proxy_chain: ProxyChain,
+			transfer_nonce: TransferNonce,
+		) -> DispatchResult {
+			if <T as Config<I>>::UpdateOrigin::ensure_origin(origin.clone()).is_err() {
+				T::OperatorOrigin::ensure_origin(origin)?;
+			}
+			let pending = <PendingTransfers<T, I>>::take(proxy_chain, transfer_nonce)
+				.ok_or(Error::<T, I>::UnknownPendingTransfer)?;
+			<VetoedTransfers<T, I>>::insert(proxy_chain, transfer_nonce, pending);
+			Self::deposit_event(Event::TransferVetoed { source: proxy_chain, transfer_nonce });
+			Ok(())
+		}
+
+		/// Executes a delayed incoming transfer after its challenge period passed. Can be called by anyone.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::execute_pending_transfer())]
+		pub fn execute_pending_transfer(
+			origin: OriginFor<T>,
+			proxy_chain: ProxyChain,
+			transfer_nonce: TransferNonce,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(!Self::circuit_breaker_tripped(proxy_chain), Error::<T, I>::BridgeHalted);
+			let pending = <PendingTransfers<T, I>>::take(proxy_chain, transfer_nonce)
+				.ok_or(Error::<T, I>::UnknownPendingTransfer)?;
+			let executable_at = pending.executable_at.ok_or(Error::<T, I>::PendingTransferHeld)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= executable_at,
+				Error::<T, I>::ChallengePeriodNotPassed
+			);
+			Self::release_pending(proxy_chain, transfer_nonce, pending)
+		}
+
+		/// Executes a pending incoming transfer immediately, including transfers held by the circuit breaker.
+		/// Can only be called by a privileged/root account.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::release_transfer())]
+		pub fn release_transfer(
+			origin: OriginFor<T>,
+			proxy_chain: ProxyChain,
+			transfer_nonce: TransferNonce,
+		) -> DispatchResult {
+			<T as Config<I>>::UpdateOrigin::ensure_origin(origin)?;
+			let pending = <PendingTransfers<T, I>>::take(proxy_chain, transfer_nonce)
+				.ok_or(Error::<T, I>::UnknownPendingTransfer)?;
+			Self::release_pending(proxy_chain, transfer_nonce, pending)
+		}
+
+		/// Releases the amount of a vetoed transfer from the vault to `beneficiary`, e.g. the rightful owner or a treasury.
+		/// Can only be called by a privileged/root account.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::reclaim_vetoed_transfer())]
+		pub fn reclaim_vetoed_transfer(
+			origin: OriginFor<T>,
+			proxy_chain: ProxyChain,
+			transfer_nonce: TransferNonce,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			<T as Config<I>>::UpdateOrigin::ensure_origin(origin)?;
+			let vetoed = <VetoedTransfers<T, I>>::take(proxy_chain, transfer_nonce)
+				.ok_or(Error::<T, I>::UnknownVetoedTransfer)?;
+			let asset = vetoed
+				.asset_id
+				.map(|asset_id| Self::bridged_asset_for(proxy_chain, asset_id))
+				.transpose()?;
+			Self::transfer_from_vault(proxy_chain, &beneficiary, asset.as_ref(), vetoed.amount)?;
+			Self::deposit_event(Event::VetoedTransferReclaimed {
+				source: proxy_chain,
+				transfer_nonce,
+				beneficiary,
+			});
+			Ok(())
+		}
+
+		/// Refunds an outgoing transfer of a bridged asset that got deregistered since, and can therefore not be retried anymore.
+		///
+		/// The amount is released from the vault the asset had at the time of the transfer.
+		/// Can only be called by a privileged/root account, after making sure the transfer was not executed on the proxy chain.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::refund_transfer())]
+		pub fn refund_transfer(
+			origin: OriginFor<T>,
+			proxy_chain: ProxyChain,
+			transfer_nonce: TransferNonce,
+		) -> DispatchResult {
+			<T as Config<I>>::UpdateOrigin::ensure_origin(origin)?;
+			let asset = Self::outgoing_transfer_asset(proxy_chain, transfer_nonce)
+				.ok_or(Error::<T, I>::UnknownTransferRefund)?;
+			ensure!(
+				Self::bridged_asset_token(proxy_chain, asset.asset_id).is_none(),
+				Error::<T, I>::AssetStillBridged
+			);
+			let (source, _dest, amount) =
+				OutgoingTransfers::<T, I>::take(proxy_chain, transfer_nonce)
+					.ok_or(Error::<T, I>::UnknownTransferRefund)?;
+			OutgoingTransferAssets::<T, I>::remove(proxy_chain, transfer_nonce);
+
+			if !amount.is_zero() {
+				<T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+					asset.asset_id,
+					&asset.vault,
+					&source,
+					amount,
+					Preservation::Protect,
+				)
+				.map_err(|e| {
+					log::error!(
+						target: "runtime::acurast_hyperdrive_token",
+						"error in refund_transfer; transfer {:?} to source: {:?}",
+						amount,
+						e,
+					);
+					Error::<T, I>::TransferRefundFailed
+				})?;
+			}
+
+			Self::deposit_event(Event::TransferRefunded {
+				proxy_chain,
+				transfer_nonce,
+				source,
+				asset_id: asset.asset_id,
+				amount,
+			});
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -396,6 +804,33 @@ pub mod pallet {
 					T::EthereumVault::get(),
 					T::EthereumFeeVault::get(),
 				)),
+				ProxyChain::Solana => Ok((
+					Subject::Solana(Layer::Contract(ContractCall {
+						contract: Self::solana_contract()
+							.ok_or(Error::<T, I>::MissingContractConfiguration)?,
+						selector: None,
+					})),
+					T::SolanaVault::get(),
+					T::SolanaFeeVault::get(),
+				)),
+				_ => Err(Error::InvalidRecipient),
+			}
+		}
+
+		/// Encodes the given action for the proxy chain's _Hyperdrive Token_ contract.
+		fn encode_action(
+			proxy: &ProxyChain,
+			action: &Action<T::AccountId>,
+		) -> Result<Vec<u8>, Error<T, I>> {
+			match proxy {
+				ProxyChain::Ethereum => {
+					<EthereumActionEncoder as ActionEncoder<T::AccountId>>::encode(action)
+						.map_err(|e| -> Error<T, I> { e.into() })
+				},
+				ProxyChain::Solana => {
+					<SolanaActionEncoder as ActionEncoder<T::AccountId>>::encode(action)
+						.map_err(|e| Error::<T, I>::SolanaMessageEncoderError(e as u8))
+				},
 				_ => Err(Error::InvalidRecipient),
 			}
 		}
@@ -414,7 +849,72 @@ pub mod pallet {
 			Self::outgoing_transfer_ttl(proxy).unwrap_or_else(T::DefaultOutgoingTransferTTL::get)
 		}
 
-		/// Sends a message with a [`Action::TransferToken`] over Hyperdrive.
+		/// Returns the registered bridged asset for `asset_id` on the given proxy chain.
+		pub fn bridged_asset_for(
+			proxy: ProxyChain,
+			asset_id: BridgedAssetId,
+		) -> Result<BridgedAssetFor<T, I>, Error<T, I>> {
+			Self::bridged_asset_token(proxy, asset_id)
+				.and_then(|token| Self::bridged_asset(proxy, token))
+				.ok_or(Error::<T, I>::UnsupportedAssetId)
+		}
+
+		fn trip_circuit_breaker(proxy: ProxyChain) {
+			<CircuitBreakers<T, I>>::insert(proxy, true);
+			Self::deposit_event(Event::CircuitBreakerTripped { proxy_chain: proxy });
+		}
+
+		/// Accounts an outgoing transfer of the native token (if `asset_id` is `None`) or a bridged asset against the limits of `proxy`,
+		/// failing if they would be exceeded.
+		fn ensure_outgoing_within_limits(
+			proxy: ProxyChain,
+			asset_id: Option<BridgedAssetId>,
+			amount: T::Balance,
+		) -> Result<(), Error<T, I>> {
+			let Some(limits) = Self::transfer_limits(proxy, asset_id) else {
+				return Ok(());
+			};
+			ensure!(amount <= limits.max_per_transfer, Error::<T, I>::TransferAmountTooHigh);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut volume = Self::outgoing_volume(proxy, asset_id);
+			volume.roll(now, limits.window);
+			ensure!(
+				volume.volume(now, limits.window).saturating_add(amount) <= limits.max_outgoing,
+				Error::<T, I>::OutgoingLimitExceeded
+			);
+			volume.current = volume.current.saturating_add(amount);
+			<OutgoingVolume<T, I>>::insert(proxy, asset_id, volume);
+			Ok(())
+		}
+
+		/// Accounts an incoming transfer of the native token (if `asset_id` is `None`) or a bridged asset against the limits of `proxy`.
+		///
+		/// Returns if and until when the transfer has to be delayed: `Some(None)` if it has to be held until released, which is the case when it exceeds the limits and trips the circuit breaker.
+		fn incoming_delay(
+			proxy: ProxyChain,
+			asset_id: Option<BridgedAssetId>,
+			amount: T::Balance,
+		) -> Option<Option<BlockNumberFor<T>>> {
+			let limits = Self::transfer_limits(proxy, asset_id)?;
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut volume = Self::incoming_volume(proxy, asset_id);
+			volume.roll(now, limits.window);
+			if amount > limits.max_per_transfer
+				|| volume.volume(now, limits.window).saturating_add(amount) > limits.max_incoming
+			{
+				Self::trip_circuit_breaker(proxy);
+				return Some(None);
+			}
+			volume.current = volume.current.saturating_add(amount);
+			<IncomingVolume<T, I>>::insert(proxy, asset_id, volume);
+
+			(amount >= limits.delay_threshold)
+				.then(|| Some(now.saturating_add(limits.challenge_period)))
+		}
+
+		/// Sends a message with a [`Action::TransferToken`] of the native token over Hyperdrive.
 		///
 		/// NOTE: the account triggering this message is the payer account, which is getting charged for the amount and the fee; however the sender of the message is a constant pallet account [`T::PalletAccount`]).
 		pub fn do_transfer_native(
@@ -425,21 +925,52 @@ pub mod pallet {
 			// if provided, this is a transfer retry
 			transfer_nonce: Option<TransferNonce>,
 		) -> Result<TransferNonce, DispatchError> {
-			ensure!(amount >= T::MinTransferAmount::get(), Error::<T, I>::TransferAmountTooLow);
+			Self::do_transfer(source, dest, None, amount, fee, transfer_nonce)
+		}
+
+		/// Sends a message with a [`Action::TransferToken`] of the native token (if `asset_id` is `None`) or a bridged asset over Hyperdrive.
+		///
+		/// NOTE: the account triggering this message is the payer account, which is getting charged for the amount and the fee; however the sender of the message is a constant pallet account [`T::PalletAccount`]).
+		pub fn do_transfer(
+			source: T::AccountId,
+			dest: MultiOrigin<T::AccountId>,
+			asset_id: Option<BridgedAssetId>,
+			amount: T::Balance,
+			fee: T::Balance,
+			// if provided, this is a transfer retry
+			transfer_nonce: Option<TransferNonce>,
+		) -> Result<TransferNonce, DispatchError> {
 			let proxy: ProxyChain = (&dest).into();
+			ensure!(!Self::circuit_breaker_tripped(proxy), Error::<T, I>::BridgeHalted);
 			// recipient is the message recipient, not the recipient of amount which is `dest`
-			let (recipient, vault, fee_vault) = Self::proxy_params(&proxy)?;
+			let (recipient, native_vault, fee_vault) = Self::proxy_params(&proxy)?;
+			let asset =
+				asset_id.map(|asset_id| Self::bridged_asset_for(proxy, asset_id)).transpose()?;
+			let min_transfer_amount = asset
+				.as_ref()
+				.map_or_else(T::MinTransferAmount::get, |asset| asset.min_transfer_amount);
+			ensure!(amount >= min_transfer_amount, Error::<T, I>::TransferAmountTooLow);
 
 			if transfer_nonce.is_none() {
+				Self::ensure_outgoing_within_limits(proxy, asset_id, amount)?;
 				// since this is a new transfer, we lock the amount
 				// but not if this is a retry (however we lock the fee for both new transfers and retries below)
 				if !amount.is_zero() {
-					T::Currency::transfer(
-						&source,
-						&vault,
-						amount.saturated_into::<BalanceFor<T, I>>(),
-						Preservation::Preserve,
-					)
+					match &asset {
+						Some(asset) => <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+							asset.asset_id,
+							&source,
+							&asset.vault,
+							amount,
+							Preservation::Preserve,
+						),
+						None => T::Currency::transfer(
+							&source,
+							&native_vault,
+							amount.saturated_into::<BalanceFor<T, I>>(),
+							Preservation::Preserve,
+						),
+					}
 					.map_err(|e| {
 						log::error!(
 							target: "runtime::acurast_hyperdrive_token",
@@ -479,9 +1010,10 @@ pub mod pallet {
 				})?;
 			}
 
-			let action = Action::TransferToken(amount.into(), None, transfer_nonce, dest.clone());
-			let encoded = <EthereumActionEncoder as ActionEncoder<T::AccountId>>::encode(&action)
-				.map_err(|e| -> Error<T, I> { e.into() })?;
+			// the proxy chain identifies bridged assets by their token
+			let token = asset_id.and_then(|asset_id| Self::bridged_asset_token(proxy, asset_id));
+			let action = Action::TransferToken(amount.into(), token, transfer_nonce, dest.clone());
+			let encoded = Self::encode_action(&proxy, &action)?;
 
 			let complete_nonce =
 				&[proxy.encode().as_slice(), transfer_nonce.to_be_bytes().as_slice()].concat();
@@ -503,7 +1035,15 @@ pub mod pallet {
 				}
 			}
 
-			Self::deposit_event(Event::TransferToProxy { source, dest, amount });
+			match asset_id {
+				Some(asset_id) => Self::deposit_event(Event::AssetTransferToProxy {
+					source,
+					dest,
+					asset_id,
+					amount,
+				}),
+				None => Self::deposit_event(Event::TransferToProxy { source, dest, amount }),
+			}
 
 			Ok(transfer_nonce)
 		}
@@ -537,8 +1077,7 @@ pub mod pallet {
 			}
 
 			let action = Action::SetEnabled(enabled);
-			let encoded = <EthereumActionEncoder as ActionEncoder<T::AccountId>>::encode(&action)
-				.map_err(|e| -> Error<T, I> { e.into() })?;
+			let encoded = Self::encode_action(&proxy, &action)?;
 
 			let nonce_prefix = b"enable";
 			let nonce = Self::next_enable_nonce().unwrap_or(0);
@@ -576,10 +1115,13 @@ pub mod pallet {
 		/// So we know the messages originates from the proxy contract counterpart to this pallet.
 		fn execute(proxy: ProxyChain, action: Action<T::AccountId>) -> DispatchResultWithPostInfo {
 			match action {
-				Action::TransferToken(amount, asset_id, transfer_nonce, dest) => {
-					if asset_id.is_some() {
-						Err(Error::<T, I>::UnsupportedAssetId)?;
-					}
+				Action::TransferToken(amount, token, transfer_nonce, dest) => {
+					let asset = token
+						.map(|token| {
+							Self::bridged_asset(proxy, token)
+								.ok_or(Error::<T, I>::UnsupportedAssetId)
+						})
+						.transpose()?;
 
 					if Self::incoming_transfer_nonces(proxy, transfer_nonce).is_some() {
 						Err(Error::<T, I>::TransferAlreadyReceived)?;
@@ -588,27 +1130,38 @@ pub mod pallet {
 
 					match dest {
 						MultiOrigin::Acurast(dest_account_id) => {
-							if !amount.is_zero() {
-								T::Currency::transfer(
-									&T::EthereumVault::get(),
-									&dest_account_id,
-									amount.saturated_into::<BalanceFor<T, I>>(),
-									Preservation::Protect,
+							let amount: T::Balance = amount.into();
+							let delay = if Self::circuit_breaker_tripped(proxy) {
+								Some(None)
+							} else {
+								Self::incoming_delay(
+									proxy,
+									asset.as_ref().map(|asset| asset.asset_id),
+									amount,
 								)
-								.map_err(|e| {
-									log::error!(
-										target: "runtime::acurast_hyperdrive_token",
-										"error in execute action; transfer to dest: {:?}",
-										e,
+							};
+							match delay {
+								Some(executable_at) => {
+									<PendingTransfers<T, I>>::insert(
+										proxy,
+										transfer_nonce,
+										PendingTransfer {
+											dest: dest_account_id,
+											asset_id: asset.map(|asset| asset.asset_id),
+											amount,
+											executable_at,
+										},
 									);
-									Error::<T, I>::TransferToVaultFailed
-								})?;
+									Self::deposit_event(Event::TransferDelayed {
+										source: proxy,
+										transfer_nonce,
+										executable_at,
+									});
+								},
+								None => {
+									Self::release_incoming(proxy, dest_account_id, asset, amount)?
+								},
 							}
-							Self::deposit_event(Event::TransferFromProxy {
-								source: proxy,
-								dest: dest_account_id,
-								amount: amount.into(),
-							});
 							Ok(().into())
 						},
 						_ => Err(Error::<T, I>::InvalidRecipient)?,
@@ -618,6 +1171,80 @@ pub mod pallet {
 				_ => Err(Error::<T, I>::UnsupportedAction)?,
 			}
 		}
+
+		/// Releases an incoming transfer from the proxy's (or the asset's) vault to `dest`.
+		fn release_incoming(
+			proxy: ProxyChain,
+			dest: T::AccountId,
+			asset: Option<BridgedAssetFor<T, I>>,
+			amount: T::Balance,
+		) -> DispatchResult {
+			Self::transfer_from_vault(proxy, &dest, asset.as_ref(), amount)?;
+			match asset {
+				Some(asset) => Self::deposit_event(Event::AssetTransferFromProxy {
+					source: proxy,
+					dest,
+					asset_id: asset.asset_id,
+					amount,
+				}),
+				None => {
+					Self::deposit_event(Event::TransferFromProxy { source: proxy, dest, amount })
+				},
+			}
+			Ok(())
+		}
+
+		/// Transfers `amount` from the proxy's (or the asset's) vault to `dest`.
+		fn transfer_from_vault(
+			proxy: ProxyChain,
+			dest: &T::AccountId,
+			asset: Option<&BridgedAssetFor<T, I>>,
+			amount: T::Balance,
+		) -> DispatchResult {
+			if !amount.is_zero() {
+				match asset {
+					Some(asset) => <T::Assets as fungibles::Mutate<T::AccountId>>::transfer(
+						asset.asset_id,
+						&asset.vault,
+						dest,
+						amount,
+						Preservation::Protect,
+					),
+					None => {
+						let (_, native_vault, _) = Self::proxy_params(&proxy)?;
+						T::Currency::transfer(
+							&native_vault,
+							dest,
+							amount.saturated_into::<BalanceFor<T, I>>(),
+							Preservation::Protect,
+						)
+					},
+				}
+				.map_err(|e| {
+					log::error!(
+						target: "runtime::acurast_hyperdrive_token",
+						"error in execute action; transfer to dest: {:?}",
+						e,
+					);
+					Error::<T, I>::TransferToVaultFailed
+				})?;
+			}
+			Ok(())
+		}
+
+		fn release_pending(
+			proxy: ProxyChain,
+			transfer_nonce: TransferNonce,
+			pending: PendingTransferFor<T, I>,
+		) -> DispatchResult {
+			let asset = pending
+				.asset_id
+				.map(|asset_id| Self::bridged_asset_for(proxy, asset_id))
+				.transpose()?;
+			Self::release_incoming(proxy, pending.dest, asset, pending.amount)?;
+			Self::deposit_event(Event::PendingTransferExecuted { source: proxy, transfer_nonce });
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> MessageProcessor<T::AccountId, T::AccountId> for Pallet<T, I> {
@@ -636,7 +1263,6 @@ pub mod pallet {
 						.map_err(|e| Error::<T, I>::EthereumActionDecoderError(e as u8))?;
 					Ok((ProxyChain::Ethereum, action))
 				},
-				// TODO implement solana
 				SubjectFor::<T>::Solana(Layer::Contract(contract_call)) => {
 					if contract_call.contract
 						!= Self::solana_contract()
@@ -644,9 +1270,9 @@ pub mod pallet {
 					{
 						Err(Error::<T, I>::InvalidSender)?
 					}
-					// TODO complete implementation for Solana
-					// Ok((ProxyChain::Solana, action))
-					Err(Error::<T, I>::InvalidSender)?
+					let action = <SolanaActionDecoder<I, T::ParsableAccountId, T::AccountId> as types::ActionDecoder<T::AccountId>>::decode(&message.payload())
+						.map_err(|e| Error::<T, I>::SolanaActionDecoderError(e as u8))?;
+					Ok((ProxyChain::Solana, action))
 				},
 				_ => Err(Error::<T, I>::InvalidSender),
 			}?;
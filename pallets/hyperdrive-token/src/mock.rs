@@ -8,7 +8,7 @@ use frame_support::{
 	derive_impl,
 	pallet_prelude::*,
 	parameter_types,
-	traits::{ConstU16, ConstU64},
+	traits::{AsEnsureOriginWithArg, ConstU16, ConstU64},
 	Deserialize, PalletId, Serialize,
 };
 use frame_system::{self as system, EnsureRoot};
@@ -19,7 +19,7 @@ use sp_core::*;
 use sp_runtime::traits::AccountIdConversion;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup, Keccak256},
-	AccountId32, BuildStorage, MultiSignature,
+	AccountId32, BuildStorage, MultiSignature, Perbill,
 };
 use sp_std::prelude::*;
 
@@ -30,6 +30,7 @@ frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system,
 		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
+		Assets: pallet_assets::{Pallet, Call, Storage, Event<T>},
 		AcurastHyperdriveIbc: pallet_acurast_hyperdrive_ibc::{Pallet, Call, Storage, Event<T>},
 		AcurastHyperdriveToken: crate::{Pallet, Call, Storage, Event<T>},
 	}
@@ -61,6 +62,12 @@ parameter_types! {
 	pub const MinFee: Balance = UNIT / 10;
 	pub const ParachainId: ParaId = ParaId::new(2000);
 	pub const SelfChain: ProxyAcurastChain = ProxyAcurastChain::Acurast;
+	pub const MinOracleBond: Balance = UNIT;
+	pub const OracleUnbondingPeriod: BlockNumber = 10;
+	pub const EquivocationBounty: Perbill = Perbill::from_percent(10);
+	pub const MaxEthereumStateRoots: u32 = 16;
+	pub const SentMessageRetention: BlockNumber = 20;
+	pub const MaxOracleWeight: u32 = 2;
 }
 
 impl pallet_acurast_hyperdrive_ibc::Config for Test {
@@ -77,6 +84,14 @@ impl pallet_acurast_hyperdrive_ibc::Config for Test {
 	type UpdateOrigin = EnsureRoot<Self::AccountId>;
 	type ParachainId = ParachainId;
 	type SelfChain = SelfChain;
+	type MinOracleBond = MinOracleBond;
+	type OracleUnbondingPeriod = OracleUnbondingPeriod;
+	type EquivocationBounty = EquivocationBounty;
+	type AggregateSignatureVerifier = ();
+	type MessageProofVerifier = ();
+	type MaxEthereumStateRoots = MaxEthereumStateRoots;
+	type SentMessageRetention = SentMessageRetention;
+	type MaxOracleWeight = MaxOracleWeight;
 	type WeightInfo = pallet_acurast_hyperdrive_ibc::weights::WeightInfo<Test>;
 }
 
@@ -98,6 +113,7 @@ impl crate::Config for Test {
 	type ParsableAccountId = AcurastAccountId;
 	type Balance = Balance;
 	type Currency = Balances;
+	type Assets = Assets;
 	type MessageSender = AcurastHyperdriveIbc;
 	type MessageIdHasher = BlakeTwo256;
 
@@ -158,6 +174,15 @@ impl pallet_balances::Config for Test {
 	type DoneSlashHandler = ();
 }
 
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+	type Balance = Balance;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Freezer = ();
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let storage = system::GenesisConfig::<Test>::default().build_storage().unwrap();
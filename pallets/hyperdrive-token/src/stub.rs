@@ -7,6 +7,8 @@ use sp_core::crypto::Ss58Codec;
 use sp_runtime::AccountId32;
 use sp_std::prelude::*;
 
+use crate::RemoteToken;
+
 pub type AccountId = AccountId32;
 pub type Balance = u128;
 pub type BlockNumber = u32;
@@ -57,3 +59,11 @@ pub fn ethereum_dest() -> MultiOrigin<AccountId> {
 pub fn ethereum_token_contract() -> AccountId20 {
 	AccountId20(hex!("7F44aD0fD6c15CfBA6f417C33924c8cF0C751d23"))
 }
+
+pub fn solana_token_contract() -> AccountId32 {
+	AccountId32::new([7u8; 32])
+}
+
+pub fn ethereum_bridged_token() -> RemoteToken {
+	hex!("dAC17F958D2ee523a2206206994597C13D831ec7").to_vec().try_into().unwrap()
+}
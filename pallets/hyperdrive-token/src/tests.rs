@@ -1,12 +1,18 @@
-use frame_support::{assert_err, assert_ok, error::BadOrigin};
+use frame_support::{
+	assert_err, assert_ok,
+	error::BadOrigin,
+	traits::fungibles::{Create, Mutate},
+};
 use hex_literal::hex;
-use pallet_acurast::{AccountId20, ProxyChain};
+use pallet_acurast::{AccountId20, ContractCall, Layer, MessageProcessor, ProxyChain, Subject};
+use pallet_acurast_hyperdrive_ibc::MessageBody;
 use sp_runtime::AccountId32;
 use sp_tracing::try_init_simple;
 
 use crate::{
-	mock::*, stub::*, Enabled, EthereumContract, Event, NextTransferNonce, OutgoingTransfers,
-	SolanaContract,
+	mock::*, stub::*, BridgedAsset, Enabled, Error, EthereumContract, Event, NextTransferNonce,
+	OutgoingTransferAssets, OutgoingTransfers, PendingTransfer, SolanaContract, TransferLimits,
+	VolumeWindow,
 };
 
 #[test]
@@ -86,7 +92,7 @@ fn test_transfer_native_success() {
 		// Act (retry)
 		System::set_block_number(initial_block + 15);
 		assert_err!(
-			AcurastHyperdriveToken::retry_transfer_native(
+			AcurastHyperdriveToken::retry_transfer(
 				RuntimeOrigin::signed(alice_account_id()), // Must be called by the original sender
 				ProxyChain::Ethereum,
 				initial_nonce,
@@ -97,7 +103,7 @@ fn test_transfer_native_success() {
 
 		System::set_block_number(initial_block + 16);
 		System::reset_events(); // Clear events before action
-		assert_ok!(AcurastHyperdriveToken::retry_transfer_native(
+		assert_ok!(AcurastHyperdriveToken::retry_transfer(
 			RuntimeOrigin::signed(alice_account_id()), // Must be called by the original sender
 			ProxyChain::Ethereum,
 			initial_nonce,
@@ -232,3 +238,711 @@ fn test_update_solana_contract_fail_bad_origin() {
 		assert!(AcurastHyperdriveToken::solana_contract().is_none());
 	});
 }
+
+#[test]
+fn test_process_solana_transfer_success() {
+	let initial_balance = 1000 * UNIT;
+	let amount = UNIT;
+
+	new_test_ext().execute_with(|| {
+		let solana_vault = HyperdriveTokenSolanaVault::get();
+		let _ = Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			solana_vault.clone(),
+			initial_balance,
+		);
+		SolanaContract::<Test>::set(Some(solana_token_contract()));
+
+		// Borsh-encoded `TransferToken { amount, asset_id: None, transfer_nonce: 3, dest: bob }`
+		let payload = [
+			[0u8].as_slice(),
+			amount.to_le_bytes().as_slice(),
+			[0u8].as_slice(),
+			3u64.to_le_bytes().as_slice(),
+			bob_account_id().as_ref(),
+		]
+		.concat();
+		let message = || MessageBody::<AccountId, AccountId> {
+			sender: Subject::Solana(Layer::Contract(ContractCall {
+				contract: solana_token_contract(),
+				selector: None,
+			})),
+			recipient: Subject::Acurast(Layer::Extrinsic(HyperdriveTokenPalletAccount::get())),
+			payload: payload.clone().try_into().unwrap(),
+		};
+
+		System::reset_events();
+		assert_ok!(AcurastHyperdriveToken::process(message()));
+
+		assert_eq!(Balances::free_balance(solana_vault.clone()), initial_balance - amount);
+		assert_eq!(Balances::free_balance(bob_account_id()), amount);
+		assert!(AcurastHyperdriveToken::incoming_transfer_nonces(ProxyChain::Solana, 3).is_some());
+		System::assert_has_event(
+			Event::TransferFromProxy { source: ProxyChain::Solana, dest: bob_account_id(), amount }
+				.into(),
+		);
+
+		// the same transfer nonce cannot be processed twice
+		assert_err!(
+			AcurastHyperdriveToken::process(message()),
+			Error::<Test>::TransferAlreadyReceived
+		);
+		assert_eq!(Balances::free_balance(solana_vault), initial_balance - amount);
+	});
+}
+
+#[test]
+fn test_process_solana_transfer_fail_unknown_contract() {
+	new_test_ext().execute_with(|| {
+		SolanaContract::<Test>::set(Some(solana_token_contract()));
+
+		let message = MessageBody::<AccountId, AccountId> {
+			sender: Subject::Solana(Layer::Contract(ContractCall {
+				contract: AccountId32::new([8u8; 32]),
+				selector: None,
+			})),
+			recipient: Subject::Acurast(Layer::Extrinsic(HyperdriveTokenPalletAccount::get())),
+			payload: Default::default(),
+		};
+		assert_err!(AcurastHyperdriveToken::process(message), Error::<Test>::InvalidSender);
+	});
+}
+
+fn register_bridged_asset(asset_id: u32) -> AccountId32 {
+	let vault = bob_account_id();
+	assert_ok!(<Assets as Create<AccountId32>>::create(asset_id, vault.clone(), true, 1));
+	assert_ok!(AcurastHyperdriveToken::update_bridged_asset(
+		RuntimeOrigin::root(),
+		ProxyChain::Ethereum,
+		ethereum_bridged_token(),
+		Some(BridgedAsset { asset_id, min_transfer_amount: UNIT, vault: vault.clone() }),
+	));
+	vault
+}
+
+#[test]
+fn test_update_bridged_asset_success() {
+	new_test_ext().execute_with(|| {
+		let asset =
+			BridgedAsset { asset_id: 1, min_transfer_amount: UNIT, vault: bob_account_id() };
+		assert_ok!(AcurastHyperdriveToken::update_bridged_asset(
+			RuntimeOrigin::root(),
+			ProxyChain::Ethereum,
+			ethereum_bridged_token(),
+			Some(asset.clone()),
+		));
+		assert_eq!(
+			AcurastHyperdriveToken::bridged_asset(ProxyChain::Ethereum, ethereum_bridged_token()),
+			Some(asset.clone())
+		);
+		assert_eq!(
+			AcurastHyperdriveToken::bridged_asset_token(ProxyChain::Ethereum, 1),
+			Some(ethereum_bridged_token())
+		);
+
+		// the same asset cannot be bridged from two tokens of the same proxy
+		let other_token =
+			hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48").to_vec().try_into().unwrap();
+		assert_err!(
+			AcurastHyperdriveToken::update_bridged_asset(
+				RuntimeOrigin::root(),
+				ProxyChain::Ethereum,
+				other_token,
+				Some(asset),
+			),
+			Error::<Test>::AssetAlreadyBridged
+		);
+
+		assert_ok!(AcurastHyperdriveToken::update_bridged_asset(
+			RuntimeOrigin::root(),
+			ProxyChain::Ethereum,
+			ethereum_bridged_token(),
+			None,
+		));
+		assert!(AcurastHyperdriveToken::bridged_asset(
+			ProxyChain::Ethereum,
+			ethereum_bridged_token()
+		)
+		.is_none());
+		assert!(AcurastHyperdriveToken::bridged_asset_token(ProxyChain::Ethereum, 1).is_none());
+	});
+}
+
+#[test]
+fn test_transfer_asset_success() {
+	let initial_balance = 1000 * UNIT;
+	let amount = 10 * UNIT;
+	let fee = UNIT / 10;
+	let asset_id = 1;
+
+	new_test_ext().execute_with(|| {
+		let _ =
+			Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), initial_balance);
+		let _ = Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ethereum_fee_vault(),
+			initial_balance,
+		);
+		EthereumContract::<Test>::set(Some(ethereum_token_contract()));
+		Enabled::<Test>::set(Some(true));
+		let vault = register_bridged_asset(asset_id);
+		assert_ok!(Assets::mint_into(asset_id, &alice_account_id(), initial_balance));
+
+		assert_err!(
+			AcurastHyperdriveToken::transfer_asset(
+				RuntimeOrigin::signed(alice_account_id()),
+				ethereum_dest(),
+				asset_id,
+				UNIT - 1,
+				fee,
+			),
+			Error::<Test>::TransferAmountTooLow
+		);
+		assert_err!(
+			AcurastHyperdriveToken::transfer_asset(
+				RuntimeOrigin::signed(alice_account_id()),
+				ethereum_dest(),
+				asset_id + 1,
+				amount,
+				fee,
+			),
+			Error::<Test>::UnsupportedAssetId
+		);
+
+		assert_ok!(AcurastHyperdriveToken::transfer_asset(
+			RuntimeOrigin::signed(alice_account_id()),
+			ethereum_dest(),
+			asset_id,
+			amount,
+			fee,
+		));
+
+		assert_eq!(Assets::balance(asset_id, alice_account_id()), initial_balance - amount);
+		assert_eq!(Assets::balance(asset_id, vault), amount);
+		// the fee is paid in the native token
+		assert_eq!(Balances::free_balance(alice_account_id()), initial_balance - fee);
+		assert_eq!(
+			OutgoingTransferAssets::<Test>::get(ProxyChain::Ethereum, 0),
+			Some(BridgedAsset { asset_id, min_transfer_amount: UNIT, vault: vault.clone() })
+		);
+		System::assert_has_event(
+			Event::AssetTransferToProxy {
+				source: alice_account_id(),
+				dest: ethereum_dest(),
+				asset_id,
+				amount,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn test_process_asset_transfer_success() {
+	let amount = 10 * UNIT;
+	let asset_id = 1;
+
+	new_test_ext().execute_with(|| {
+		EthereumContract::<Test>::set(Some(ethereum_token_contract()));
+		let vault = register_bridged_asset(asset_id);
+		assert_ok!(Assets::mint_into(asset_id, &vault, 1000 * UNIT));
+
+		let dest = AccountId32::new([9u8; 32]);
+		// `TransferToken(amount, Some(token), 5, dest)` as encoded by the Ethereum proxy
+		let payload = [
+			0u32.to_be_bytes().as_slice(),
+			amount.to_be_bytes().as_slice(),
+			1u32.to_be_bytes().as_slice(),
+			5u64.to_be_bytes().as_slice(),
+			dest.as_ref(),
+			ethereum_bridged_token().as_slice(),
+		]
+		.concat();
+		let message = MessageBody::<AccountId, AccountId> {
+			sender: Subject::Ethereum(Layer::Contract(ContractCall {
+				contract: ethereum_token_contract(),
+				selector: None,
+			})),
+			recipient: Subject::Acurast(Layer::Extrinsic(HyperdriveTokenPalletAccount::get())),
+			payload: payload.try_into().unwrap(),
+		};
+
+		assert_ok!(AcurastHyperdriveToken::process(message));
+
+		assert_eq!(Assets::balance(asset_id, dest.clone()), amount);
+		assert_eq!(Assets::balance(asset_id, vault), 1000 * UNIT - amount);
+		System::assert_has_event(
+			Event::AssetTransferFromProxy { source: ProxyChain::Ethereum, dest, asset_id, amount }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn test_refund_transfer_of_deregistered_asset() {
+	let initial_balance = 1000 * UNIT;
+	let amount = 10 * UNIT;
+	let fee = UNIT / 10;
+	let asset_id = 1;
+
+	new_test_ext().execute_with(|| {
+		let _ =
+			Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), initial_balance);
+		let _ = Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ethereum_fee_vault(),
+			initial_balance,
+		);
+		EthereumContract::<Test>::set(Some(ethereum_token_contract()));
+		Enabled::<Test>::set(Some(true));
+		let vault = register_bridged_asset(asset_id);
+		assert_ok!(Assets::mint_into(asset_id, &alice_account_id(), initial_balance));
+		assert_ok!(Assets::mint_into(asset_id, &vault, UNIT));
+		assert_ok!(AcurastHyperdriveToken::transfer_asset(
+			RuntimeOrigin::signed(alice_account_id()),
+			ethereum_dest(),
+			asset_id,
+			amount,
+			fee,
+		));
+
+		// cannot be refunded while the asset is still bridged
+		assert_err!(
+			AcurastHyperdriveToken::refund_transfer(RuntimeOrigin::root(), ProxyChain::Ethereum, 0),
+			Error::<Test>::AssetStillBridged
+		);
+
+		assert_ok!(AcurastHyperdriveToken::update_bridged_asset(
+			RuntimeOrigin::root(),
+			ProxyChain::Ethereum,
+			ethereum_bridged_token(),
+			None,
+		));
+		assert_err!(
+			AcurastHyperdriveToken::retry_transfer(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProxyChain::Ethereum,
+				0,
+				fee,
+			),
+			Error::<Test>::UnsupportedAssetId
+		);
+
+		assert_err!(
+			AcurastHyperdriveToken::refund_transfer(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProxyChain::Ethereum,
+				0
+			),
+			BadOrigin
+		);
+		assert_ok!(AcurastHyperdriveToken::refund_transfer(
+			RuntimeOrigin::root(),
+			ProxyChain::Ethereum,
+			0
+		));
+
+		assert_eq!(Assets::balance(asset_id, alice_account_id()), initial_balance);
+		assert_eq!(Assets::balance(asset_id, vault), UNIT);
+		assert!(OutgoingTransfers::<Test>::get(ProxyChain::Ethereum, 0).is_none());
+		assert!(OutgoingTransferAssets::<Test>::get(ProxyChain::Ethereum, 0).is_none());
+		System::assert_has_event(
+			Event::TransferRefunded {
+				proxy_chain: ProxyChain::Ethereum,
+				transfer_nonce: 0,
+				source: alice_account_id(),
+				asset_id,
+				amount,
+			}
+			.into(),
+		);
+
+		assert_err!(
+			AcurastHyperdriveToken::refund_transfer(RuntimeOrigin::root(), ProxyChain::Ethereum, 0),
+			Error::<Test>::UnknownTransferRefund
+		);
+	});
+}
+
+fn transfer_limits() -> TransferLimits<Balance, u64> {
+	TransferLimits {
+		window: 100,
+		max_outgoing: 20 * UNIT,
+		max_incoming: 20 * UNIT,
+		max_per_transfer: 15 * UNIT,
+		delay_threshold: 10 * UNIT,
+		challenge_period: 50,
+	}
+}
+
+/// A Borsh-encoded native `TransferToken` from the Solana proxy to bob.
+fn solana_transfer_message(
+	amount: Balance,
+	transfer_nonce: u64,
+) -> MessageBody<AccountId, AccountId> {
+	let payload = [
+		[0u8].as_slice(),
+		amount.to_le_bytes().as_slice(),
+		[0u8].as_slice(),
+		transfer_nonce.to_le_bytes().as_slice(),
+		bob_account_id().as_ref(),
+	]
+	.concat();
+	MessageBody {
+		sender: Subject::Solana(Layer::Contract(ContractCall {
+			contract: solana_token_contract(),
+			selector: None,
+		})),
+		recipient: Subject::Acurast(Layer::Extrinsic(HyperdriveTokenPalletAccount::get())),
+		payload: payload.try_into().unwrap(),
+	}
+}
+
+fn setup_solana_limits() {
+	let _ = Balances::force_set_balance(
+		RuntimeOrigin::root(),
+		HyperdriveTokenSolanaVault::get(),
+		1000 * UNIT,
+	);
+	SolanaContract::<Test>::set(Some(solana_token_contract()));
+	assert_ok!(AcurastHyperdriveToken::update_transfer_limits(
+		RuntimeOrigin::root(),
+		ProxyChain::Solana,
+		None,
+		Some(transfer_limits()),
+	));
+}
+
+#[test]
+fn test_volume_window() {
+	let mut window = VolumeWindow::<Balance, u64>::default();
+	window.current = 10;
+
+	// halfway into the next window, half of the previous window's volume is still counted
+	window.roll(150, 100);
+	assert_eq!(window, VolumeWindow { start: 100, current: 0, previous: 10 });
+	assert_eq!(window.volume(150, 100), 5);
+
+	// after two windows without transfers, nothing is counted anymore
+	window.roll(320, 100);
+	assert_eq!(window, VolumeWindow { start: 300, current: 0, previous: 0 });
+	assert_eq!(window.volume(320, 100), 0);
+}
+
+#[test]
+fn test_process_transfer_delayed() {
+	let amount = 10 * UNIT;
+
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		setup_solana_limits();
+
+		assert_ok!(AcurastHyperdriveToken::process(solana_transfer_message(amount, 1)));
+
+		assert_eq!(Balances::free_balance(bob_account_id()), 0);
+		assert_eq!(
+			AcurastHyperdriveToken::pending_transfer(ProxyChain::Solana, 1),
+			Some(PendingTransfer {
+				dest: bob_account_id(),
+				asset_id: None,
+				amount,
+				executable_at: Some(60),
+			})
+		);
+		System::assert_has_event(
+			Event::TransferDelayed {
+				source: ProxyChain::Solana,
+				transfer_nonce: 1,
+				executable_at: Some(60),
+			}
+			.into(),
+		);
+
+		assert_err!(
+			AcurastHyperdriveToken::execute_pending_transfer(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProxyChain::Solana,
+				1,
+			),
+			Error::<Test>::ChallengePeriodNotPassed
+		);
+
+		System::set_block_number(60);
+		assert_ok!(AcurastHyperdriveToken::execute_pending_transfer(
+			RuntimeOrigin::signed(alice_account_id()),
+			ProxyChain::Solana,
+			1,
+		));
+		assert_eq!(Balances::free_balance(bob_account_id()), amount);
+		assert_eq!(AcurastHyperdriveToken::pending_transfer(ProxyChain::Solana, 1), None);
+		System::assert_has_event(
+			Event::PendingTransferExecuted { source: ProxyChain::Solana, transfer_nonce: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn test_veto_transfer() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		setup_solana_limits();
+
+		assert_ok!(AcurastHyperdriveToken::process(solana_transfer_message(10 * UNIT, 1)));
+		assert_err!(
+			AcurastHyperdriveToken::veto_transfer(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProxyChain::Solana,
+				1,
+			),
+			BadOrigin
+		);
+		assert_ok!(AcurastHyperdriveToken::veto_transfer(
+			RuntimeOrigin::root(),
+			ProxyChain::Solana,
+			1,
+		));
+
+		System::set_block_number(60);
+		assert_err!(
+			AcurastHyperdriveToken::execute_pending_transfer(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProxyChain::Solana,
+				1,
+			),
+			Error::<Test>::UnknownPendingTransfer
+		);
+		assert_eq!(Balances::free_balance(bob_account_id()), 0);
+
+		// the vetoed amount can only be reclaimed by the update origin
+		let beneficiary = AccountId32::new([9u8; 32]);
+		assert_err!(
+			AcurastHyperdriveToken::reclaim_vetoed_transfer(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProxyChain::Solana,
+				1,
+				beneficiary.clone(),
+			),
+			BadOrigin
+		);
+		assert_ok!(AcurastHyperdriveToken::reclaim_vetoed_transfer(
+			RuntimeOrigin::root(),
+			ProxyChain::Solana,
+			1,
+			beneficiary.clone(),
+		));
+		assert_eq!(Balances::free_balance(&beneficiary), 10 * UNIT);
+		assert_eq!(Balances::free_balance(HyperdriveTokenSolanaVault::get()), 990 * UNIT);
+		assert_eq!(AcurastHyperdriveToken::vetoed_transfer(ProxyChain::Solana, 1), None);
+		System::assert_has_event(
+			Event::VetoedTransferReclaimed {
+				source: ProxyChain::Solana,
+				transfer_nonce: 1,
+				beneficiary: beneficiary.clone(),
+			}
+			.into(),
+		);
+
+		assert_err!(
+			AcurastHyperdriveToken::reclaim_vetoed_transfer(
+				RuntimeOrigin::root(),
+				ProxyChain::Solana,
+				1,
+				beneficiary,
+			),
+			Error::<Test>::UnknownVetoedTransfer
+		);
+	});
+}
+
+#[test]
+fn test_process_transfer_trips_circuit_breaker() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		setup_solana_limits();
+
+		// below the delay threshold, executed immediately
+		assert_ok!(AcurastHyperdriveToken::process(solana_transfer_message(5 * UNIT, 1)));
+		assert_eq!(Balances::free_balance(bob_account_id()), 5 * UNIT);
+		assert_ok!(AcurastHyperdriveToken::process(solana_transfer_message(9 * UNIT, 2)));
+		assert_eq!(Balances::free_balance(bob_account_id()), 14 * UNIT);
+
+		// exceeds the incoming volume of the window
+		assert_ok!(AcurastHyperdriveToken::process(solana_transfer_message(9 * UNIT, 3)));
+		assert!(AcurastHyperdriveToken::circuit_breaker_tripped(ProxyChain::Solana));
+		assert_eq!(Balances::free_balance(bob_account_id()), 14 * UNIT);
+		System::assert_has_event(
+			Event::CircuitBreakerTripped { proxy_chain: ProxyChain::Solana }.into(),
+		);
+
+		// any further transfer is held, outgoing transfers are rejected
+		assert_ok!(AcurastHyperdriveToken::process(solana_transfer_message(UNIT, 4)));
+		assert_eq!(
+			AcurastHyperdriveToken::pending_transfer(ProxyChain::Solana, 4)
+				.unwrap()
+				.executable_at,
+			None
+		);
+		assert_err!(
+			AcurastHyperdriveToken::execute_pending_transfer(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProxyChain::Solana,
+				4,
+			),
+			Error::<Test>::BridgeHalted
+		);
+
+		assert_ok!(AcurastHyperdriveToken::set_circuit_breaker(
+			RuntimeOrigin::root(),
+			ProxyChain::Solana,
+			false,
+		));
+		assert_err!(
+			AcurastHyperdriveToken::execute_pending_transfer(
+				RuntimeOrigin::signed(alice_account_id()),
+				ProxyChain::Solana,
+				4,
+			),
+			Error::<Test>::PendingTransferHeld
+		);
+		assert_ok!(AcurastHyperdriveToken::release_transfer(
+			RuntimeOrigin::root(),
+			ProxyChain::Solana,
+			4,
+		));
+		assert_eq!(Balances::free_balance(bob_account_id()), 15 * UNIT);
+	});
+}
+
+#[test]
+fn test_transfer_native_fail_limits() {
+	let initial_balance = 1000 * UNIT;
+	let fee_amount = UNIT / 10;
+
+	new_test_ext().execute_with(|| {
+		let _ =
+			Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), initial_balance);
+		let _ = Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ethereum_fee_vault(),
+			initial_balance,
+		);
+		EthereumContract::<Test>::set(Some(ethereum_token_contract()));
+		Enabled::<Test>::set(Some(true));
+		System::set_block_number(10);
+		assert_ok!(AcurastHyperdriveToken::update_transfer_limits(
+			RuntimeOrigin::root(),
+			ProxyChain::Ethereum,
+			None,
+			Some(transfer_limits()),
+		));
+
+		assert_err!(
+			AcurastHyperdriveToken::transfer_native(
+				RuntimeOrigin::signed(alice_account_id()),
+				ethereum_dest(),
+				16 * UNIT,
+				fee_amount,
+			),
+			Error::<Test>::TransferAmountTooHigh
+		);
+		assert_ok!(AcurastHyperdriveToken::transfer_native(
+			RuntimeOrigin::signed(alice_account_id()),
+			ethereum_dest(),
+			15 * UNIT,
+			fee_amount,
+		));
+		assert_err!(
+			AcurastHyperdriveToken::transfer_native(
+				RuntimeOrigin::signed(alice_account_id()),
+				ethereum_dest(),
+				6 * UNIT,
+				fee_amount,
+			),
+			Error::<Test>::OutgoingLimitExceeded
+		);
+
+		assert_ok!(AcurastHyperdriveToken::set_circuit_breaker(
+			RuntimeOrigin::root(),
+			ProxyChain::Ethereum,
+			true,
+		));
+		assert_err!(
+			AcurastHyperdriveToken::transfer_native(
+				RuntimeOrigin::signed(alice_account_id()),
+				ethereum_dest(),
+				UNIT,
+				fee_amount,
+			),
+			Error::<Test>::BridgeHalted
+		);
+	});
+}
+
+#[test]
+fn test_transfer_asset_fail_limits() {
+	let initial_balance = 1000 * UNIT;
+	let fee_amount = UNIT / 10;
+	let asset_id = 1;
+
+	new_test_ext().execute_with(|| {
+		let _ =
+			Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), initial_balance);
+		let _ = Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			ethereum_fee_vault(),
+			initial_balance,
+		);
+		EthereumContract::<Test>::set(Some(ethereum_token_contract()));
+		Enabled::<Test>::set(Some(true));
+		System::set_block_number(10);
+		register_bridged_asset(asset_id);
+		assert_ok!(Assets::mint_into(asset_id, &alice_account_id(), initial_balance));
+		assert_ok!(AcurastHyperdriveToken::update_transfer_limits(
+			RuntimeOrigin::root(),
+			ProxyChain::Ethereum,
+			Some(asset_id),
+			Some(transfer_limits()),
+		));
+
+		assert_err!(
+			AcurastHyperdriveToken::transfer_asset(
+				RuntimeOrigin::signed(alice_account_id()),
+				ethereum_dest(),
+				asset_id,
+				16 * UNIT,
+				fee_amount,
+			),
+			Error::<Test>::TransferAmountTooHigh
+		);
+		assert_ok!(AcurastHyperdriveToken::transfer_asset(
+			RuntimeOrigin::signed(alice_account_id()),
+			ethereum_dest(),
+			asset_id,
+			15 * UNIT,
+			fee_amount,
+		));
+		assert_err!(
+			AcurastHyperdriveToken::transfer_asset(
+				RuntimeOrigin::signed(alice_account_id()),
+				ethereum_dest(),
+				asset_id,
+				6 * UNIT,
+				fee_amount,
+			),
+			Error::<Test>::OutgoingLimitExceeded
+		);
+
+		// the volume of the asset does not count against the native token without limits
+		assert_ok!(AcurastHyperdriveToken::transfer_native(
+			RuntimeOrigin::signed(alice_account_id()),
+			ethereum_dest(),
+			20 * UNIT,
+			fee_amount,
+		));
+		assert_eq!(
+			AcurastHyperdriveToken::outgoing_volume(ProxyChain::Ethereum, Some(asset_id)).current,
+			15 * UNIT
+		);
+		assert_eq!(AcurastHyperdriveToken::outgoing_volume(ProxyChain::Ethereum, None).current, 0);
+	});
+}
@@ -3,10 +3,19 @@ use frame_support::weights::Weight;
 /// Weight functions needed for pallet_acurast_hyperdrive.
 pub trait WeightInfo {
 	fn transfer_native() -> Weight;
-	fn retry_transfer_native() -> Weight;
+	fn retry_transfer() -> Weight;
 	fn update_ethereum_contract() -> Weight;
 	fn update_solana_contract() -> Weight;
 	fn set_enabled() -> Weight;
 	fn enable_proxy_chain() -> Weight;
 	fn update_outgoing_transfer_ttl() -> Weight;
+	fn transfer_asset() -> Weight;
+	fn update_bridged_asset() -> Weight;
+	fn update_transfer_limits() -> Weight;
+	fn set_circuit_breaker() -> Weight;
+	fn veto_transfer() -> Weight;
+	fn execute_pending_transfer() -> Weight;
+	fn release_transfer() -> Weight;
+	fn reclaim_vetoed_transfer() -> Weight;
+	fn refund_transfer() -> Weight;
 }
@@ -5,29 +5,61 @@ use pallet_acurast::{MultiOrigin, Subject};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_core::ConstU32;
-use sp_runtime::RuntimeDebug;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Saturating, Zero},
+	Perbill, RuntimeDebug, SaturatedConversion, TokenError,
+};
 use sp_std::prelude::*;
 use strum_macros::{EnumString, IntoStaticStr};
 
+use frame_system::pallet_prelude::BlockNumberFor;
+
 use crate::Config;
 
 pub type TransferNonce = u64;
 pub type EnableNonce = u32;
+/// The local id of a bridged asset.
+pub type BridgedAssetId = u32;
 
 pub const TRANSFER_RECIPIENT_MAX_LENGTH: u32 = 64;
 /// The recipient of a transfer, on Acurast or proxy chain. The length depends on the chain the transfer is received on.
 pub type TransferRecipient = BoundedVec<u8, ConstU32<TRANSFER_RECIPIENT_MAX_LENGTH>>;
 
+pub const REMOTE_TOKEN_MAX_LENGTH: u32 = 32;
+/// The address of a token on a proxy chain, e.g. an ERC-20 contract address on Ethereum or a mint address on Solana.
+pub type RemoteToken = BoundedVec<u8, ConstU32<REMOTE_TOKEN_MAX_LENGTH>>;
+
+/// A registered asset that can be bridged from and to a proxy chain.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+)]
+pub struct BridgedAsset<AccountId, Balance> {
+	/// The local asset the remote token is bridged to.
+	pub asset_id: BridgedAssetId,
+	/// The minimum amount of a single transfer to the proxy chain.
+	pub min_transfer_amount: Balance,
+	/// The account locking the bridged amounts of this asset on Acurast.
+	pub vault: AccountId,
+}
+
 /// The action is triggered in target chain (the _Hyperdrive Token_ contract on proxy chain) upon a [`hyperdrive_ibc::Message`].
 #[derive(RuntimeDebug, Encode, Decode, TypeInfo, Eq, PartialEq, Clone, MaxEncodedLen)]
 pub enum Action<AccountId> {
 	/// Transfers (bridges) a token to the target chain.
 	///
-	/// Message consists of `amount, asset_id, transfer_nonce, dest`.
-	/// * Using None for asset_id stands for the native token.
+	/// Message consists of `amount, token, transfer_nonce, dest`.
+	/// * Using None for token stands for the native token, otherwise it is the address of the token on the proxy chain the asset is bridged from, see [`crate::BridgedAssets`].
 	/// * The `transfer_nonce` is used to identify and order transfers **per proxy**. Note that the [`pallet_acurast_hyperdrive_ibc::Message.id`] does change on each send retry, therefore this nonce is required for deduplication of transfers.
 	///   Even though hyperdrive_ibc allows to resend messages with same nonce after ttl expired, _Exactly-once delivery_ is and cannot be guaranteed.
-	TransferToken(u128, Option<u32>, TransferNonce, MultiOrigin<AccountId>),
+	TransferToken(u128, Option<RemoteToken>, TransferNonce, MultiOrigin<AccountId>),
 	/// A noop action that solely suits the purpose of testing that messages get sent.
 	Noop,
 	SetEnabled(bool),
@@ -63,6 +95,8 @@ impl TryFrom<u32> for RawAction {
 	fn try_from(value: u32) -> Result<Self, Self::Error> {
 		match value {
 			o if o == RawAction::TransferToken as u32 => Ok(RawAction::TransferToken),
+			o if o == RawAction::SetEnabled as u32 => Ok(RawAction::SetEnabled),
+			o if o == RawAction::Noop as u32 => Ok(RawAction::Noop),
 
 			_ => Err(()),
 		}
@@ -107,5 +141,192 @@ impl From<DispatchError> for ProcessMessageResult {
 pub type BalanceFor<T, I> =
 	<<T as Config<I>>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Limits on the volume of a token bridged from and to a proxy chain.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+)]
+pub struct TransferLimits<Balance, BlockNumber> {
+	/// The length of the rolling window in blocks.
+	pub window: BlockNumber,
+	/// The maximum volume transferred to the proxy chain within a rolling window.
+	pub max_outgoing: Balance,
+	/// The maximum volume received from the proxy chain within a rolling window.
+	pub max_incoming: Balance,
+	/// The maximum amount of a single transfer, in either direction.
+	pub max_per_transfer: Balance,
+	/// Incoming transfers of at least this amount are delayed by the `challenge_period`.
+	pub delay_threshold: Balance,
+	/// The number of blocks a delayed transfer can be vetoed before it can be executed.
+	pub challenge_period: BlockNumber,
+}
+
+/// The volume transferred in the current and the previous (fixed) window, approximating a rolling window.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Default)]
+pub struct VolumeWindow<Balance, BlockNumber> {
+	/// The first block of the current window.
+	pub start: BlockNumber,
+	pub current: Balance,
+	pub previous: Balance,
+}
+
+impl<Balance: AtLeast32BitUnsigned + Copy, BlockNumber: AtLeast32BitUnsigned + Copy>
+	VolumeWindow<Balance, BlockNumber>
+{
+	/// Moves the window forward so that it contains `now`.
+	pub fn roll(&mut self, now: BlockNumber, length: BlockNumber) {
+		if length.is_zero() {
+			return;
+		}
+		let elapsed = now.saturating_sub(self.start);
+		if elapsed >= length.saturating_mul(2u32.into()) {
+			self.previous = Zero::zero();
+			self.current = Zero::zero();
+			self.start = now.saturating_sub(elapsed % length);
+		} else if elapsed >= length {
+			self.previous = self.current;
+			self.current = Zero::zero();
+			self.start = self.start.saturating_add(length);
+		}
+	}
+
+	/// The volume within the rolling window of `length` blocks ending at `now`.
+	///
+	/// The previous window's volume is weighted by the share it still overlaps with the rolling window. Expects the window to be [`Self::roll`]ed to `now`.
+	pub fn volume(&self, now: BlockNumber, length: BlockNumber) -> Balance {
+		if length.is_zero() {
+			return self.current;
+		}
+		let elapsed = now.saturating_sub(self.start).min(length);
+		let overlap = Perbill::from_rational(
+			length.saturating_sub(elapsed).saturated_into::<u64>(),
+			length.saturated_into::<u64>(),
+		);
+		self.current.saturating_add(overlap.mul_floor(self.previous))
+	}
+}
+
+/// An incoming transfer that was not executed immediately.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq)]
+pub struct PendingTransfer<AccountId, Balance, BlockNumber> {
+	pub dest: AccountId,
+	pub asset_id: Option<BridgedAssetId>,
+	pub amount: Balance,
+	/// The block from which on the transfer can be executed by anyone, or `None` if it was held by the circuit breaker and requires a release.
+	pub executable_at: Option<BlockNumber>,
+}
+
+/// A [`fungibles`](frame_support::traits::fungibles) implementation without any assets.
+///
+/// For runtimes that only bridge the native token: every asset is unknown, so registered bridged assets can neither be sent nor received.
+pub struct NoAssets<Balance>(PhantomData<Balance>);
+
+impl<AccountId, Balance: frame_support::traits::tokens::Balance>
+	frame_support::traits::fungibles::Inspect<AccountId> for NoAssets<Balance>
+{
+	type AssetId = BridgedAssetId;
+	type Balance = Balance;
+
+	fn total_issuance(_asset: Self::AssetId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn minimum_balance(_asset: Self::AssetId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn total_balance(_asset: Self::AssetId, _who: &AccountId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn balance(_asset: Self::AssetId, _who: &AccountId) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn reducible_balance(
+		_asset: Self::AssetId,
+		_who: &AccountId,
+		_preservation: frame_support::traits::tokens::Preservation,
+		_force: frame_support::traits::tokens::Fortitude,
+	) -> Self::Balance {
+		Zero::zero()
+	}
+
+	fn can_deposit(
+		_asset: Self::AssetId,
+		_who: &AccountId,
+		_amount: Self::Balance,
+		_provenance: frame_support::traits::tokens::Provenance,
+	) -> frame_support::traits::tokens::DepositConsequence {
+		frame_support::traits::tokens::DepositConsequence::UnknownAsset
+	}
+
+	fn can_withdraw(
+		_asset: Self::AssetId,
+		_who: &AccountId,
+		_amount: Self::Balance,
+	) -> frame_support::traits::tokens::WithdrawConsequence<Self::Balance> {
+		frame_support::traits::tokens::WithdrawConsequence::UnknownAsset
+	}
+
+	fn asset_exists(_asset: Self::AssetId) -> bool {
+		false
+	}
+}
+
+impl<AccountId, Balance: frame_support::traits::tokens::Balance>
+	frame_support::traits::fungibles::Unbalanced<AccountId> for NoAssets<Balance>
+{
+	fn handle_dust(_dust: frame_support::traits::fungibles::Dust<AccountId, Self>) {}
+
+	fn write_balance(
+		_asset: Self::AssetId,
+		_who: &AccountId,
+		_amount: Self::Balance,
+	) -> Result<Option<Self::Balance>, DispatchError> {
+		Err(TokenError::UnknownAsset.into())
+	}
+
+	fn set_total_issuance(_asset: Self::AssetId, _amount: Self::Balance) {}
+}
+
+impl<AccountId: Eq, Balance: frame_support::traits::tokens::Balance>
+	frame_support::traits::fungibles::Mutate<AccountId> for NoAssets<Balance>
+{
+}
+
+impl<AccountId, Balance: frame_support::traits::tokens::Balance>
+	frame_support::traits::fungibles::Create<AccountId> for NoAssets<Balance>
+{
+	fn create(
+		_id: Self::AssetId,
+		_admin: AccountId,
+		_is_sufficient: bool,
+		_min_balance: Self::Balance,
+	) -> DispatchResult {
+		Err(TokenError::UnknownAsset.into())
+	}
+}
+
+pub type BridgedAssetFor<T, I> =
+	BridgedAsset<<T as frame_system::Config>::AccountId, <T as Config<I>>::Balance>;
+
+pub type TransferLimitsFor<T, I> = TransferLimits<<T as Config<I>>::Balance, BlockNumberFor<T>>;
+
+pub type VolumeWindowFor<T, I> = VolumeWindow<<T as Config<I>>::Balance, BlockNumberFor<T>>;
+
+pub type PendingTransferFor<T, I> = PendingTransfer<
+	<T as frame_system::Config>::AccountId,
+	<T as Config<I>>::Balance,
+	BlockNumberFor<T>,
+>;
+
 pub type SubjectFor<T> =
 	Subject<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::AccountId>;
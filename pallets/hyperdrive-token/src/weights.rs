@@ -46,7 +46,7 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 
     /// Storage: AcurastHyperdriveTezos CurrentVaraContract (r:0 w:1)
 	/// Proof: AcurastHyperdriveTezos CurrentAlephZeroContract (max_values: Some(1), max_size: Some(66), added: 561, mode: MaxEncodedLen)
-	fn retry_transfer_native() -> Weight {
+	fn retry_transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
@@ -109,4 +109,66 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+
+	fn transfer_asset() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	fn update_bridged_asset() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	fn update_transfer_limits() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	fn set_circuit_breaker() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	fn veto_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	fn execute_pending_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	fn release_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	fn reclaim_vetoed_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		// not benchmarked yet, same storage accesses as `release_transfer`
+		<Self as crate::WeightInfo>::release_transfer()
+	}
+
+	fn refund_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		// not benchmarked yet, same storage accesses as `release_transfer` plus the outgoing transfer and the asset registry
+		<Self as crate::WeightInfo>::release_transfer()
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
 }
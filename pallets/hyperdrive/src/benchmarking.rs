@@ -125,5 +125,9 @@ benchmarks_instance_pallet! {
 		let owner: ProxyAddress = proxy_address();
 	}: _(RawOrigin::Root, owner)
 
+	update_ethereum_contract {
+		let contract = pallet_acurast::AccountId20([0u8; 20]);
+	}: _(RawOrigin::Root, contract)
+
 	impl_benchmark_test_suite!(AcurastHyperdrive, crate::mock::new_test_ext(), mock::Test);
 }
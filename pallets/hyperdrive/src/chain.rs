@@ -1 +1,2 @@
+pub mod ethereum;
 pub mod substrate;
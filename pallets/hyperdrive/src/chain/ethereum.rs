@@ -0,0 +1,989 @@
+use core::marker::PhantomData;
+use derive_more::Display;
+use frame_support::pallet_prelude::*;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_io::hashing::keccak_256;
+
+use sp_core::{bounded::BoundedVec, RuntimeDebug};
+use sp_std::prelude::*;
+
+use pallet_acurast::{
+	AccountId20, AllowedSources, Environment, JobModule, JobModules, JobRegistration, MultiOrigin,
+	Schedule, Script,
+};
+use pallet_acurast_marketplace::{
+	AssignmentStrategy, ExecutionResult, JobRequirements, PlannedExecution, PlannedExecutions,
+	PubKey, PubKeyBytes, RegistrationExtra, Runtime,
+};
+
+use crate::{
+	IncomingAction, Message, MessageDecoder, MessageEncoder, ParsedAction, ProxyChain,
+	RawIncomingAction, RawOutgoingAction,
+};
+
+/// The size of a word in the Solidity ABI encoding.
+const WORD_SIZE: usize = 32;
+
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, Eq, PartialEq)]
+#[scale_info(skip_type_params(AccountConverter))]
+pub struct EthereumMessageDecoder<I, AccountConverter, AccountId> {
+	#[cfg(any(test, feature = "runtime-benchmarks"))]
+	pub marker: PhantomData<(I, AccountConverter, AccountId)>,
+	#[cfg(not(any(test, feature = "runtime-benchmarks")))]
+	marker: PhantomData<(I, AccountConverter, AccountId)>,
+}
+
+/// Errors returned by this crate.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq, Display)]
+pub enum EthereumMessageDecoderError {
+	CouldNotDecodeAction,
+	UnsupportedAction,
+	InvalidActionPayload,
+	InvalidAssignmentStrategy,
+	TooManyPlannedExecutions,
+	TooManyAllowedSources,
+	InvalidJobModule,
+	TooManyJobModules,
+	CouldNotConvertAccountId,
+}
+
+impl From<AbiDecodeError> for EthereumMessageDecoderError {
+	fn from(_: AbiDecodeError) -> Self {
+		EthereumMessageDecoderError::InvalidActionPayload
+	}
+}
+
+/// Decodes messages sent by the Acurast proxy contract on Ethereum.
+///
+/// The message is the ABI encoded tuple `(uint16 actionId, address origin, bytes payload)`,
+/// where `payload` is the ABI encoded tuple of the action:
+///
+/// * `RegisterJob`: `(uint128 jobId, bytes script, bytes32[] allowedSources, bool allowOnlyVerifiedSources,
+///   uint64 duration, uint64 startTime, uint64 endTime, uint64 interval, uint64 maxStartDelay,
+///   uint32 memory, uint32 networkRequests, uint32 storage, uint16[] requiredModules,
+///   uint8 assignmentStrategy, (bytes32 source, uint64 startDelay)[] plannedExecutions,
+///   uint8 slots, uint128 reward, uint128 minReputation, bool deliverResultsToOrigin)`
+/// * `DeregisterJob`: `(uint128 jobId)`
+/// * `FinalizeJob`: `(uint128[] jobIds)`
+/// * `SetJobEnvironment`: `(uint128 jobId, bytes publicKey, (bytes32 processor, (bytes key, bytes value)[] variables)[] processors)`
+/// * `Noop`: empty payload
+///
+/// An empty `allowedSources` or `plannedExecutions` array and a zero `minReputation` are interpreted as not set.
+/// The `assignmentStrategy` is `0` for single and `1` for competing assignment.
+impl<T, I: 'static, AccountConverter> MessageDecoder<T>
+	for EthereumMessageDecoder<I, AccountConverter, T::AccountId>
+where
+	T: crate::pallet::Config<I>,
+	T::RegistrationExtra: From<
+		RegistrationExtra<
+			T::Balance,
+			T::AccountId,
+			T::MaxSlots,
+			T::ProcessorVersion,
+			T::MaxVersions,
+		>,
+	>,
+	AccountConverter: TryFrom<Vec<u8>> + Into<T::AccountId>,
+{
+	type Error = EthereumMessageDecoderError;
+
+	fn decode(encoded: &[u8], _chain: ProxyChain) -> Result<ParsedAction<T>, Self::Error> {
+		let message = AbiReader::new(encoded);
+		let (action_id, origin, payload) = (|| -> Result<_, AbiDecodeError> {
+			Ok((message.uint::<u16>(0)?, message.address(1)?, message.bytes(2)?))
+		})()
+		.map_err(|_| Self::Error::CouldNotDecodeAction)?;
+
+		let raw_action: RawIncomingAction =
+			action_id.try_into().map_err(|_| Self::Error::UnsupportedAction)?;
+		let origin = MultiOrigin::Ethereum20(AccountId20(origin));
+		let payload = AbiReader::new(&payload);
+
+		fn convert_account_id<Account, AccountConverter: TryFrom<Vec<u8>> + Into<Account>>(
+			bytes: &[u8; 32],
+		) -> Result<Account, EthereumMessageDecoderError> {
+			let parsed: AccountConverter = bytes
+				.to_vec()
+				.try_into()
+				.map_err(|_| EthereumMessageDecoderError::CouldNotConvertAccountId)?;
+			Ok(parsed.into())
+		}
+
+		let parsed_action: ParsedAction<T> = match raw_action {
+			RawIncomingAction::RegisterJob => {
+				let job_id = (origin, payload.uint::<u128>(0)?);
+
+				let (allowed_sources_len, allowed_sources) = payload.array(2)?;
+				let allowed_sources = if allowed_sources_len > 0 {
+					Some(
+						AllowedSources::try_from(
+							(0..allowed_sources_len)
+								.map(|i| {
+									convert_account_id::<T::AccountId, AccountConverter>(
+										&allowed_sources.bytes32(i)?,
+									)
+								})
+								.collect::<Result<Vec<T::AccountId>, Self::Error>>()?,
+						)
+						.map_err(|_| Self::Error::TooManyAllowedSources)?,
+					)
+				} else {
+					None
+				};
+
+				let (required_modules_len, required_modules) = payload.array(12)?;
+				let required_modules = JobModules::try_from(
+					(0..required_modules_len)
+						.map(|i| {
+							JobModule::try_from(required_modules.uint::<u32>(i)?)
+								.map_err(|_| Self::Error::InvalidJobModule)
+						})
+						.collect::<Result<Vec<_>, Self::Error>>()?,
+				)
+				.map_err(|_| Self::Error::TooManyJobModules)?;
+
+				let assignment_strategy = match payload.uint::<u8>(13)? {
+					0 => {
+						let (planned_executions_len, planned_executions) = payload.array(14)?;
+						AssignmentStrategy::Single(if planned_executions_len > 0 {
+							Some(
+								PlannedExecutions::try_from(
+									(0..planned_executions_len)
+										.map(|i| {
+											Ok(PlannedExecution {
+												source: convert_account_id::<
+													T::AccountId,
+													AccountConverter,
+												>(
+													&planned_executions.bytes32(2 * i)?
+												)?,
+												start_delay: planned_executions
+													.uint::<u64>(2 * i + 1)?,
+											})
+										})
+										.collect::<Result<Vec<PlannedExecution<T::AccountId>>, Self::Error>>(
+										)?,
+								)
+								.map_err(|_| Self::Error::TooManyPlannedExecutions)?,
+							)
+						} else {
+							None
+						})
+					},
+					1 => AssignmentStrategy::Competing,
+					_ => Err(Self::Error::InvalidAssignmentStrategy)?,
+				};
+
+				let min_reputation = payload.uint::<u128>(17)?;
+				let extra: T::RegistrationExtra = RegistrationExtra {
+					requirements: JobRequirements {
+						assignment_strategy,
+						slots: payload.uint::<u8>(15)?,
+						reward: T::Balance::from(payload.uint::<u128>(16)?),
+						min_reputation: (min_reputation > 0).then_some(min_reputation),
+						processor_version: None,
+						runtime: Runtime::NodeJS,
+						consensus: false,
+						payment_asset: None,
+						deliver_results_to_origin: payload.bool(18)?,
+					},
+				}
+				.into();
+
+				let registration = JobRegistration {
+					script: Script::truncate_from(payload.bytes(1)?),
+					allowed_sources,
+					allow_only_verified_sources: payload.bool(3)?,
+					schedule: Schedule {
+						duration: payload.uint::<u64>(4)?,
+						start_time: payload.uint::<u64>(5)?,
+						end_time: payload.uint::<u64>(6)?,
+						interval: payload.uint::<u64>(7)?,
+						max_start_delay: payload.uint::<u64>(8)?,
+					},
+					memory: payload.uint::<u32>(9)?,
+					network_requests: payload.uint::<u32>(10)?,
+					storage: payload.uint::<u32>(11)?,
+					required_modules,
+					extra,
+				};
+
+				ParsedAction::RegisterJob(job_id, registration)
+			},
+			RawIncomingAction::DeregisterJob => {
+				ParsedAction::DeregisterJob((origin, payload.uint::<u128>(0)?))
+			},
+			RawIncomingAction::FinalizeJob => {
+				let (job_ids_len, job_ids) = payload.array(0)?;
+				ParsedAction::FinalizeJob(
+					(0..job_ids_len)
+						.map(|i| Ok((origin.clone(), job_ids.uint::<u128>(i)?)))
+						.collect::<Result<Vec<_>, Self::Error>>()?,
+				)
+			},
+			RawIncomingAction::SetJobEnvironment => {
+				let job_id = (origin, payload.uint::<u128>(0)?);
+				let public_key = payload.bytes(1)?;
+
+				let (processors_len, processors) = payload.array(2)?;
+				let variables = (0..processors_len)
+					.map(|i| {
+						let processor = processors.tail(i)?;
+						let processor_address = convert_account_id::<T::AccountId, AccountConverter>(
+							&processor.bytes32(0)?,
+						)?;
+
+						let (variables_len, variables) = processor.array(1)?;
+						let variables = (0..variables_len)
+							.map(|j| {
+								let variable = variables.tail(j)?;
+								Ok((
+									BoundedVec::truncate_from(variable.bytes(0)?),
+									BoundedVec::truncate_from(variable.bytes(1)?),
+								))
+							})
+							.collect::<Result<Vec<_>, Self::Error>>()?;
+
+						let env = Environment {
+							public_key: BoundedVec::truncate_from(public_key.clone()),
+							variables: BoundedVec::truncate_from(variables),
+						};
+
+						Ok((processor_address, env))
+					})
+					.collect::<Result<Vec<_>, Self::Error>>()?;
+
+				ParsedAction::SetJobEnvironment(job_id, BoundedVec::truncate_from(variables))
+			},
+			RawIncomingAction::Noop => ParsedAction::Noop,
+		};
+
+		Ok(parsed_action)
+	}
+}
+
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Eq, PartialEq, Display)]
+pub enum EthereumMessageEncoderError {
+	UnexpectedPublicKey,
+	InvalidPublicKey,
+}
+
+pub struct EthereumMessageEncoder;
+
+impl MessageEncoder for EthereumMessageEncoder {
+	type Error = EthereumMessageEncoderError;
+
+	/// Encodes the given message for Ethereum.
+	///
+	/// The message is the ABI encoded tuple `(uint64 id, uint16 actionId, bytes payload)`,
+	/// where `payload` is the ABI encoded tuple of the action:
+	///
+	/// * `AssignJob`: `(uint128 jobId, address processor)`
+	/// * `FinalizeJob`: `(uint128 jobId, uint128 unusedReward)`
+	/// * `ExecutionResult`: `(uint128 jobId, bool success, bytes result)`
+	/// * `Noop`: empty payload
+	fn encode(message: &Message) -> Result<Vec<u8>, Self::Error> {
+		let payload = match &message.action {
+			IncomingAction::AssignJob(job_id, processor_public_key) => {
+				let address_bytes = match processor_public_key {
+					PubKey::SECP256k1(pk) => public_key_to_address_bytes(pk)?,
+					_ => Err(Self::Error::UnexpectedPublicKey)?,
+				};
+
+				[abi_uint(*job_id), abi_address(&address_bytes)].concat()
+			},
+			IncomingAction::FinalizeJob(job_id, refund_amount) => {
+				[abi_uint(*job_id), abi_uint(*refund_amount)].concat()
+			},
+			IncomingAction::ExecutionResult(job_id, execution_result) => {
+				let (success, result) = match execution_result {
+					ExecutionResult::Success(operation_hash) => (true, operation_hash.to_vec()),
+					ExecutionResult::Failure(message) => (false, message.to_vec()),
+				};
+
+				abi_encode_with_bytes(&[abi_uint(*job_id), abi_bool(success)], &result)
+			},
+			IncomingAction::Noop => vec![],
+		};
+
+		let raw_action: RawOutgoingAction = (&message.action).into();
+		let raw_action_encoded: u16 = raw_action.into();
+
+		Ok(abi_encode_with_bytes(
+			&[abi_uint(message.id as u128), abi_uint(raw_action_encoded as u128)],
+			&payload,
+		))
+	}
+}
+
+/// Helper function to covert a SECP256k1 public key (compressed or uncompressed) to an Ethereum address.
+pub fn public_key_to_address_bytes(
+	pub_key: &PubKeyBytes,
+) -> Result<[u8; 20], EthereumMessageEncoderError> {
+	let public_key = k256::PublicKey::from_sec1_bytes(pub_key)
+		.map_err(|_| EthereumMessageEncoderError::InvalidPublicKey)?;
+	let hash = keccak_256(&public_key.to_encoded_point(false).as_bytes()[1..]);
+
+	let mut address = [0u8; 20];
+	address.copy_from_slice(&hash[12..]);
+	Ok(address)
+}
+
+fn abi_uint(value: u128) -> [u8; WORD_SIZE] {
+	let mut word = [0u8; WORD_SIZE];
+	word[16..].copy_from_slice(&value.to_be_bytes());
+	word
+}
+
+fn abi_bool(value: bool) -> [u8; WORD_SIZE] {
+	abi_uint(value as u128)
+}
+
+fn abi_address(address: &[u8; 20]) -> [u8; WORD_SIZE] {
+	let mut word = [0u8; WORD_SIZE];
+	word[12..].copy_from_slice(address);
+	word
+}
+
+/// ABI encodes a tuple of the given static `head` values followed by a single `bytes` value.
+fn abi_encode_with_bytes(head: &[[u8; WORD_SIZE]], bytes: &[u8]) -> Vec<u8> {
+	let mut encoded = head.concat();
+	encoded.extend_from_slice(&abi_uint(((head.len() + 1) * WORD_SIZE) as u128));
+	encoded.extend_from_slice(&abi_uint(bytes.len() as u128));
+	encoded.extend_from_slice(bytes);
+	encoded.resize(encoded.len() + (WORD_SIZE - bytes.len() % WORD_SIZE) % WORD_SIZE, 0u8);
+	encoded
+}
+
+#[derive(RuntimeDebug, Clone, Eq, PartialEq)]
+pub struct AbiDecodeError;
+
+/// Reads values from an ABI encoded tuple starting at the beginning of `data`.
+///
+/// Values are addressed by the index of their head word, dynamic values are resolved relative to the start of the tuple.
+struct AbiReader<'a> {
+	data: &'a [u8],
+}
+
+impl<'a> AbiReader<'a> {
+	fn new(data: &'a [u8]) -> Self {
+		Self { data }
+	}
+
+	fn word(&self, index: usize) -> Result<&'a [u8; WORD_SIZE], AbiDecodeError> {
+		let start = index.checked_mul(WORD_SIZE).ok_or(AbiDecodeError)?;
+		let end = start.checked_add(WORD_SIZE).ok_or(AbiDecodeError)?;
+		self.data
+			.get(start..end)
+			.ok_or(AbiDecodeError)?
+			.try_into()
+			.map_err(|_| AbiDecodeError)
+	}
+
+	fn uint<U: TryFrom<u128>>(&self, index: usize) -> Result<U, AbiDecodeError> {
+		let word = self.word(index)?;
+		if word[..16].iter().any(|b| *b != 0) {
+			Err(AbiDecodeError)?;
+		}
+		let value = u128::from_be_bytes(word[16..].try_into().map_err(|_| AbiDecodeError)?);
+		U::try_from(value).map_err(|_| AbiDecodeError)
+	}
+
+	fn bool(&self, index: usize) -> Result<bool, AbiDecodeError> {
+		match self.uint::<u8>(index)? {
+			0 => Ok(false),
+			1 => Ok(true),
+			_ => Err(AbiDecodeError),
+		}
+	}
+
+	fn address(&self, index: usize) -> Result<[u8; 20], AbiDecodeError> {
+		let word = self.word(index)?;
+		if word[..12].iter().any(|b| *b != 0) {
+			Err(AbiDecodeError)?;
+		}
+		word[12..].try_into().map_err(|_| AbiDecodeError)
+	}
+
+	fn bytes32(&self, index: usize) -> Result<[u8; WORD_SIZE], AbiDecodeError> {
+		Ok(*self.word(index)?)
+	}
+
+	/// Returns a reader for the dynamic value whose offset is stored at `index`.
+	fn tail(&self, index: usize) -> Result<AbiReader<'a>, AbiDecodeError> {
+		let offset = self.uint::<usize>(index)?;
+		Ok(AbiReader::new(self.data.get(offset..).ok_or(AbiDecodeError)?))
+	}
+
+	fn bytes(&self, index: usize) -> Result<Vec<u8>, AbiDecodeError> {
+		let tail = self.tail(index)?;
+		let len = tail.uint::<usize>(0)?;
+		let end = WORD_SIZE.checked_add(len).ok_or(AbiDecodeError)?;
+		Ok(tail.data.get(WORD_SIZE..end).ok_or(AbiDecodeError)?.to_vec())
+	}
+
+	/// Returns the length of the dynamic array at `index` and a reader for its elements.
+	fn array(&self, index: usize) -> Result<(usize, AbiReader<'a>), AbiDecodeError> {
+		let tail = self.tail(index)?;
+		let len = tail.uint::<usize>(0)?;
+		Ok((len, AbiReader::new(tail.data.get(WORD_SIZE..).ok_or(AbiDecodeError)?)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::{assert_err, assert_ok};
+	use hex_literal::hex;
+	use pallet_acurast::{ContractCall, JobId, Layer, MessageProcessor, Subject};
+	use pallet_acurast_hyperdrive_ibc::{MessageBody, Payload};
+
+	use crate::{mock::*, stub::AcurastAccountId, Error};
+
+	const ASSIGN_JOB_ENCODED: &[u8] = &hex!("000000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000050000000000000000000000007e5f4552091a69125d5dfcb7b8c2659029395bdf");
+
+	const FINALIZE_JOB_ENCODED: &[u8] = &hex!("0000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000100000000000000000000000000000000000000000000000000000000000000600000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000000500000000000000000000000000000000000000000000000000000000000003e8");
+
+	const EXECUTION_RESULT_ENCODED: &[u8] = &hex!("00000000000000000000000000000000000000000000000000000000000000090000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000066661696c65640000000000000000000000000000000000000000000000000000");
+
+	const FINALIZE_JOB_MESSAGE: &[u8] = &hex!("00000000000000000000000000000000000000000000000000000000000000020000000000000000000000007e5f4552091a69125d5dfcb7b8c2659029395bdf000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002");
+
+	const SET_JOB_ENVIRONMENT_PAYLOAD: &[u8] = &hex!("0000000000000000000000000000000000000000000000000000000000000005000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000003aabbcc000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000020010101010101010101010101010101010101010101010101010101010101010100000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000001000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000044b45593100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000656414c55453100000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000044b45593200000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000656414c5545320000000000000000000000000000000000000000000000000000");
+
+	#[test]
+	fn test_public_key_to_address_bytes() {
+		// public key of the private key `1`
+		let compressed = PubKeyBytes::truncate_from(
+			hex!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").to_vec(),
+		);
+		let uncompressed = PubKeyBytes::truncate_from(hex!("0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8").to_vec());
+
+		let expected = hex!("7e5f4552091a69125d5dfcb7b8c2659029395bdf");
+		assert_eq!(public_key_to_address_bytes(&compressed), Ok(expected));
+		assert_eq!(public_key_to_address_bytes(&uncompressed), Ok(expected));
+		assert_eq!(
+			public_key_to_address_bytes(&PubKeyBytes::truncate_from(vec![1u8; 33])),
+			Err(EthereumMessageEncoderError::InvalidPublicKey)
+		);
+	}
+
+	#[test]
+	fn test_encode_assign_job() {
+		let message = Message {
+			id: 7,
+			action: IncomingAction::AssignJob(
+				5,
+				PubKey::SECP256k1(PubKeyBytes::truncate_from(
+					hex!("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+						.to_vec(),
+				)),
+			),
+		};
+
+		let encoded = EthereumMessageEncoder::encode(&message).unwrap();
+		assert_eq!(encoded, ASSIGN_JOB_ENCODED.to_vec());
+	}
+
+	#[test]
+	fn test_encode_finalize_job() {
+		let message = Message { id: 8, action: IncomingAction::FinalizeJob(5, 1_000) };
+
+		let encoded = EthereumMessageEncoder::encode(&message).unwrap();
+		assert_eq!(encoded, FINALIZE_JOB_ENCODED.to_vec());
+	}
+
+	#[test]
+	fn test_encode_execution_result() {
+		let message = Message {
+			id: 9,
+			action: IncomingAction::ExecutionResult(
+				5,
+				ExecutionResult::Failure(BoundedVec::truncate_from(b"failed".to_vec())),
+			),
+		};
+
+		let encoded = EthereumMessageEncoder::encode(&message).unwrap();
+		assert_eq!(encoded, EXECUTION_RESULT_ENCODED.to_vec());
+	}
+
+	#[test]
+	fn test_encode_unexpected_public_key() {
+		let message = Message {
+			id: 7,
+			action: IncomingAction::AssignJob(
+				5,
+				PubKey::SECP256r1(PubKeyBytes::truncate_from(vec![0u8; 33])),
+			),
+		};
+
+		assert_eq!(
+			EthereumMessageEncoder::encode(&message),
+			Err(EthereumMessageEncoderError::UnexpectedPublicKey)
+		);
+	}
+
+	#[test]
+	fn test_read_message() {
+		let message = AbiReader::new(FINALIZE_JOB_MESSAGE);
+		assert_eq!(message.uint::<u16>(0), Ok(RawIncomingAction::FinalizeJob as u16));
+		assert_eq!(message.address(1), Ok(hex!("7e5f4552091a69125d5dfcb7b8c2659029395bdf")));
+
+		let payload = message.bytes(2).unwrap();
+		let (len, job_ids) = AbiReader::new(&payload).array(0).unwrap();
+		assert_eq!(len, 2);
+		assert_eq!(job_ids.uint::<u128>(0), Ok(1));
+		assert_eq!(job_ids.uint::<u128>(1), Ok(2));
+		assert_eq!(job_ids.uint::<u128>(2), Err(AbiDecodeError));
+	}
+
+	#[test]
+	fn test_read_set_job_environment_payload() {
+		let payload = AbiReader::new(SET_JOB_ENVIRONMENT_PAYLOAD);
+		assert_eq!(payload.uint::<u128>(0), Ok(5));
+		assert_eq!(payload.bytes(1), Ok(hex!("aabbcc").to_vec()));
+
+		let (processors_len, processors) = payload.array(2).unwrap();
+		assert_eq!(processors_len, 1);
+		let processor = processors.tail(0).unwrap();
+		assert_eq!(processor.bytes32(0), Ok([1u8; 32]));
+
+		let (variables_len, variables) = processor.array(1).unwrap();
+		assert_eq!(variables_len, 2);
+		let variable = variables.tail(1).unwrap();
+		assert_eq!(variable.bytes(0), Ok(b"KEY2".to_vec()));
+		assert_eq!(variable.bytes(1), Ok(b"VALUE2".to_vec()));
+	}
+
+	#[test]
+	fn test_read_invalid_values() {
+		let word = abi_uint(256);
+		let reader = AbiReader::new(&word);
+		assert_eq!(reader.uint::<u8>(0), Err(AbiDecodeError));
+		assert_eq!(reader.bool(0), Err(AbiDecodeError));
+		assert_eq!(reader.word(1), Err(AbiDecodeError));
+		assert_eq!(reader.bytes(0), Err(AbiDecodeError));
+	}
+
+	const ORIGIN: [u8; 20] = hex!("7e5f4552091a69125d5dfcb7b8c2659029395bdf");
+	const SCRIPT: &[u8] = b"ipfs://QmcBDBUFsGFqjVNt4w9MNDVWXdrhzYsTmyTsVwRVAVUn3u";
+
+	/// A value to ABI encode in tests, mirroring the Solidity types used by the proxy contract.
+	enum Token {
+		Word([u8; WORD_SIZE]),
+		Bytes(Vec<u8>),
+		Array(Vec<Token>),
+		Tuple(Vec<Token>),
+	}
+
+	impl Token {
+		fn uint(value: u128) -> Self {
+			Token::Word(abi_uint(value))
+		}
+
+		fn is_dynamic(&self) -> bool {
+			match self {
+				Token::Word(_) => false,
+				Token::Bytes(_) | Token::Array(_) => true,
+				Token::Tuple(tokens) => tokens.iter().any(Token::is_dynamic),
+			}
+		}
+
+		fn encode(&self) -> Vec<u8> {
+			match self {
+				Token::Word(word) => word.to_vec(),
+				Token::Bytes(bytes) => {
+					let mut encoded = abi_uint(bytes.len() as u128).to_vec();
+					encoded.extend_from_slice(bytes);
+					encoded.resize(
+						encoded.len() + (WORD_SIZE - bytes.len() % WORD_SIZE) % WORD_SIZE,
+						0u8,
+					);
+					encoded
+				},
+				Token::Array(tokens) => {
+					[abi_uint(tokens.len() as u128).to_vec(), encode_tuple(tokens)].concat()
+				},
+				Token::Tuple(tokens) => encode_tuple(tokens),
+			}
+		}
+	}
+
+	/// ABI encodes `tokens` as a tuple, with the offsets of dynamic values relative to the start of the tuple.
+	fn encode_tuple(tokens: &[Token]) -> Vec<u8> {
+		let head_len: usize = tokens
+			.iter()
+			.map(|token| if token.is_dynamic() { WORD_SIZE } else { token.encode().len() })
+			.sum();
+		let (mut head, mut tail) = (vec![], vec![]);
+		for token in tokens {
+			if token.is_dynamic() {
+				head.extend_from_slice(&abi_uint((head_len + tail.len()) as u128));
+				tail.extend(token.encode());
+			} else {
+				head.extend(token.encode());
+			}
+		}
+		[head, tail].concat()
+	}
+
+	fn encode_message(action_id: u16, payload: Vec<u8>) -> Vec<u8> {
+		encode_tuple(&[
+			Token::uint(action_id as u128),
+			Token::Word(abi_address(&ORIGIN)),
+			Token::Bytes(payload),
+		])
+	}
+
+	/// The fields of a `RegisterJob` payload using every optional feature.
+	fn register_job_fields() -> Vec<Token> {
+		vec![
+			// jobId
+			Token::uint(9),
+			// script
+			Token::Bytes(SCRIPT.to_vec()),
+			// allowedSources
+			Token::Array(vec![Token::Word([1u8; 32]), Token::Word([2u8; 32])]),
+			// allowOnlyVerifiedSources
+			Token::Word(abi_bool(true)),
+			// duration, startTime, endTime, interval, maxStartDelay
+			Token::uint(5_000),
+			Token::uint(1_700_000_000_000),
+			Token::uint(1_700_000_100_000),
+			Token::uint(10_000),
+			Token::uint(500),
+			// memory, networkRequests, storage
+			Token::uint(128),
+			Token::uint(5),
+			Token::uint(20_000),
+			// requiredModules
+			Token::Array(vec![Token::uint(0), Token::uint(2)]),
+			// assignmentStrategy
+			Token::uint(0),
+			// plannedExecutions
+			Token::Array(vec![
+				Token::Tuple(vec![Token::Word([1u8; 32]), Token::uint(0)]),
+				Token::Tuple(vec![Token::Word([2u8; 32]), Token::uint(10)]),
+			]),
+			// slots, reward, minReputation, deliverResultsToOrigin
+			Token::uint(2),
+			Token::uint(1_000_000),
+			Token::uint(500_000),
+			Token::Word(abi_bool(true)),
+		]
+	}
+
+	fn register_job_message(fields: Vec<Token>) -> Vec<u8> {
+		encode_message(RawIncomingAction::RegisterJob as u16, encode_tuple(&fields))
+	}
+
+	fn decode(encoded: &[u8]) -> Result<ParsedAction<Test>, EthereumMessageDecoderError> {
+		<EthereumMessageDecoder<(), AcurastAccountId, AccountId> as MessageDecoder<Test>>::decode(
+			encoded,
+			ProxyChain::Ethereum,
+		)
+	}
+
+	fn job_id(id: u128) -> JobId<AccountId> {
+		(MultiOrigin::Ethereum20(AccountId20(ORIGIN)), id)
+	}
+
+	#[test]
+	fn test_encode_fixtures() {
+		assert_eq!(
+			encode_message(
+				RawIncomingAction::FinalizeJob as u16,
+				encode_tuple(&[Token::Array(vec![Token::uint(1), Token::uint(2)])])
+			),
+			FINALIZE_JOB_MESSAGE.to_vec()
+		);
+		assert_eq!(
+			encode_tuple(&[
+				Token::uint(5),
+				Token::Bytes(hex!("aabbcc").to_vec()),
+				Token::Array(vec![Token::Tuple(vec![
+					Token::Word([1u8; 32]),
+					Token::Array(vec![
+						Token::Tuple(vec![
+							Token::Bytes(b"KEY1".to_vec()),
+							Token::Bytes(b"VALUE1".to_vec())
+						]),
+						Token::Tuple(vec![
+							Token::Bytes(b"KEY2".to_vec()),
+							Token::Bytes(b"VALUE2".to_vec())
+						]),
+					]),
+				])]),
+			]),
+			SET_JOB_ENVIRONMENT_PAYLOAD.to_vec()
+		);
+	}
+
+	#[test]
+	fn test_decode_register_job() {
+		let expected = ParsedAction::RegisterJob(
+			job_id(9),
+			JobRegistration {
+				script: Script::truncate_from(SCRIPT.to_vec()),
+				allowed_sources: Some(
+					AllowedSources::try_from(vec![
+						AccountId::new([1u8; 32]),
+						AccountId::new([2u8; 32]),
+					])
+					.unwrap(),
+				),
+				allow_only_verified_sources: true,
+				schedule: Schedule {
+					duration: 5_000,
+					start_time: 1_700_000_000_000,
+					end_time: 1_700_000_100_000,
+					interval: 10_000,
+					max_start_delay: 500,
+				},
+				memory: 128,
+				network_requests: 5,
+				storage: 20_000,
+				required_modules: JobModules::try_from(vec![
+					JobModule::DataEncryption,
+					JobModule::Shell,
+				])
+				.unwrap(),
+				extra: RegistrationExtra {
+					requirements: JobRequirements {
+						assignment_strategy: AssignmentStrategy::Single(Some(
+							PlannedExecutions::try_from(vec![
+								PlannedExecution {
+									source: AccountId::new([1u8; 32]),
+									start_delay: 0,
+								},
+								PlannedExecution {
+									source: AccountId::new([2u8; 32]),
+									start_delay: 10,
+								},
+							])
+							.unwrap(),
+						)),
+						slots: 2,
+						reward: 1_000_000,
+						min_reputation: Some(500_000),
+						processor_version: None,
+						runtime: Runtime::NodeJS,
+						consensus: false,
+						payment_asset: None,
+						deliver_results_to_origin: true,
+					},
+				},
+			},
+		);
+
+		assert_eq!(decode(&register_job_message(register_job_fields())), Ok(expected));
+	}
+
+	#[test]
+	fn test_decode_register_job_without_optional_fields() {
+		let mut fields = register_job_fields();
+		fields[2] = Token::Array(vec![]);
+		fields[12] = Token::Array(vec![]);
+		fields[13] = Token::uint(1);
+		fields[14] = Token::Array(vec![]);
+		fields[17] = Token::uint(0);
+		fields[18] = Token::Word(abi_bool(false));
+
+		let Ok(ParsedAction::RegisterJob(id, registration)) = decode(&register_job_message(fields))
+		else {
+			panic!("expected a job registration");
+		};
+		assert_eq!(id, job_id(9));
+		assert_eq!(registration.allowed_sources, None);
+		assert!(registration.required_modules.is_empty());
+		let requirements = registration.extra.requirements;
+		assert_eq!(requirements.assignment_strategy, AssignmentStrategy::Competing);
+		assert_eq!(requirements.min_reputation, None);
+		assert!(!requirements.deliver_results_to_origin);
+	}
+
+	#[test]
+	fn test_decode_register_job_invalid_fields() {
+		let mut fields = register_job_fields();
+		fields[13] = Token::uint(2);
+		assert_eq!(
+			decode(&register_job_message(fields)),
+			Err(EthereumMessageDecoderError::InvalidAssignmentStrategy)
+		);
+
+		let mut fields = register_job_fields();
+		fields[12] = Token::Array(vec![Token::uint(999)]);
+		assert_eq!(
+			decode(&register_job_message(fields)),
+			Err(EthereumMessageDecoderError::InvalidJobModule)
+		);
+
+		let mut fields = register_job_fields();
+		fields[2] = Token::Array((1..=5u8).map(|i| Token::Word([i; 32])).collect());
+		assert_eq!(
+			decode(&register_job_message(fields)),
+			Err(EthereumMessageDecoderError::TooManyAllowedSources)
+		);
+
+		let mut fields = register_job_fields();
+		fields[3] = Token::uint(2);
+		assert_eq!(
+			decode(&register_job_message(fields)),
+			Err(EthereumMessageDecoderError::InvalidActionPayload)
+		);
+
+		let mut fields = register_job_fields();
+		fields[15] = Token::uint(256);
+		assert_eq!(
+			decode(&register_job_message(fields)),
+			Err(EthereumMessageDecoderError::InvalidActionPayload)
+		);
+	}
+
+	#[test]
+	fn test_decode_other_actions() {
+		assert_eq!(
+			decode(&encode_message(
+				RawIncomingAction::DeregisterJob as u16,
+				encode_tuple(&[Token::uint(9)])
+			)),
+			Ok(ParsedAction::DeregisterJob(job_id(9)))
+		);
+		assert_eq!(
+			decode(FINALIZE_JOB_MESSAGE),
+			Ok(ParsedAction::FinalizeJob(vec![job_id(1), job_id(2)]))
+		);
+		assert_eq!(
+			decode(&encode_message(
+				RawIncomingAction::SetJobEnvironment as u16,
+				SET_JOB_ENVIRONMENT_PAYLOAD.to_vec()
+			)),
+			Ok(ParsedAction::SetJobEnvironment(
+				job_id(5),
+				BoundedVec::truncate_from(vec![(
+					AccountId::new([1u8; 32]),
+					Environment {
+						public_key: BoundedVec::truncate_from(hex!("aabbcc").to_vec()),
+						variables: BoundedVec::truncate_from(vec![
+							(
+								BoundedVec::truncate_from(b"KEY1".to_vec()),
+								BoundedVec::truncate_from(b"VALUE1".to_vec())
+							),
+							(
+								BoundedVec::truncate_from(b"KEY2".to_vec()),
+								BoundedVec::truncate_from(b"VALUE2".to_vec())
+							),
+						]),
+					}
+				)])
+			))
+		);
+		assert_eq!(
+			decode(&encode_message(RawIncomingAction::Noop as u16, vec![])),
+			Ok(ParsedAction::Noop)
+		);
+	}
+
+	#[test]
+	fn test_decode_malformed_messages() {
+		assert_eq!(decode(&[]), Err(EthereumMessageDecoderError::CouldNotDecodeAction));
+		assert_eq!(
+			decode(&FINALIZE_JOB_MESSAGE[..FINALIZE_JOB_MESSAGE.len() - WORD_SIZE]),
+			Err(EthereumMessageDecoderError::CouldNotDecodeAction)
+		);
+
+		// non-zero padding in front of the origin address
+		let mut dirty_address = FINALIZE_JOB_MESSAGE.to_vec();
+		dirty_address[WORD_SIZE] = 1;
+		assert_eq!(decode(&dirty_address), Err(EthereumMessageDecoderError::CouldNotDecodeAction));
+
+		assert_eq!(
+			decode(&encode_message(9, vec![])),
+			Err(EthereumMessageDecoderError::UnsupportedAction)
+		);
+	}
+
+	#[test]
+	fn test_decode_malformed_payloads() {
+		let payload = encode_tuple(&register_job_fields());
+		assert_eq!(
+			decode(&encode_message(
+				RawIncomingAction::RegisterJob as u16,
+				payload[..payload.len() / 2].to_vec()
+			)),
+			Err(EthereumMessageDecoderError::InvalidActionPayload)
+		);
+		assert_eq!(
+			decode(&encode_message(RawIncomingAction::DeregisterJob as u16, vec![])),
+			Err(EthereumMessageDecoderError::InvalidActionPayload)
+		);
+
+		// offset of the job ids pointing past the end of the payload
+		assert_eq!(
+			decode(&encode_message(
+				RawIncomingAction::FinalizeJob as u16,
+				[abi_uint(0x1000), abi_uint(0)].concat()
+			)),
+			Err(EthereumMessageDecoderError::InvalidActionPayload)
+		);
+		// more job ids announced than encoded
+		assert_eq!(
+			decode(&encode_message(
+				RawIncomingAction::FinalizeJob as u16,
+				[abi_uint(WORD_SIZE as u128), abi_uint(3), abi_uint(1)].concat()
+			)),
+			Err(EthereumMessageDecoderError::InvalidActionPayload)
+		);
+	}
+
+	fn ethereum_message(contract: [u8; 20], payload: &[u8]) -> MessageBody<AccountId, AccountId> {
+		MessageBody {
+			sender: Subject::Ethereum(Layer::Contract(ContractCall {
+				contract: AccountId20(contract),
+				selector: None,
+			})),
+			recipient: Subject::Acurast(Layer::Extrinsic(AcurastPalletAccount::get())),
+			payload: Payload::truncate_from(payload.to_vec()),
+		}
+	}
+
+	#[test]
+	fn test_process_ethereum_message() {
+		let contract = hex!("5fbdb2315678afecb367f032d93f642f64180aa3");
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				AcurastHyperdrive::process(ethereum_message(contract, FINALIZE_JOB_MESSAGE)),
+				Error::<Test>::MissingContractConfiguration
+			);
+
+			assert_ok!(AcurastHyperdrive::update_ethereum_contract(
+				RuntimeOrigin::root(),
+				AccountId20(contract)
+			));
+			assert_err!(
+				AcurastHyperdrive::process(ethereum_message([1u8; 20], FINALIZE_JOB_MESSAGE)),
+				Error::<Test>::InvalidSender
+			);
+			assert_err!(
+				AcurastHyperdrive::process(ethereum_message(
+					contract,
+					&FINALIZE_JOB_MESSAGE[..WORD_SIZE]
+				)),
+				Error::<Test>::EthereumMessageDecoderError(
+					EthereumMessageDecoderError::CouldNotDecodeAction as u8
+				)
+			);
+			assert!(executed_actions().is_empty());
+
+			assert_ok!(AcurastHyperdrive::process(ethereum_message(
+				contract,
+				FINALIZE_JOB_MESSAGE
+			)));
+			assert_eq!(
+				executed_actions(),
+				vec![ParsedAction::FinalizeJob(vec![job_id(1), job_id(2)])]
+			);
+		});
+	}
+}
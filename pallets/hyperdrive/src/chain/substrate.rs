@@ -13,15 +13,16 @@ use pallet_acurast::{
 	Script,
 };
 use pallet_acurast_marketplace::{
-	AssignmentStrategy, JobRequirements, PlannedExecution, PlannedExecutions, PubKey, PubKeyBytes,
-	RegistrationExtra, Runtime,
+	AssignmentStrategy, ExecutionResult, JobRequirements, PlannedExecution, PlannedExecutions,
+	PubKey, PubKeyBytes, RegistrationExtra, Runtime,
 };
 
 use crate::{IncomingAction, Message, MessageDecoder, MessageEncoder, ParsedAction, ProxyChain};
 use acurast_hyperdrive_substrate_core::types::{
-	AssignProcessorPayloadV1, AssignmentStrategyV1, FinalizeJobPayloadV1,
-	IncomingAction as IncomingActionOnProxy, IncomingActionPayloadV1, OutgoingAction,
-	OutgoingActionPayloadV1 as ActionPayloadV1, PlannedExecutionV1, VersionedIncomingActionPayload,
+	AssignProcessorPayloadV1, AssignmentStrategyV1, ExecutionResultPayloadV1, ExecutionResultV1,
+	FinalizeJobPayloadV1, IncomingAction as IncomingActionOnProxy, IncomingActionPayloadV1,
+	OutgoingAction, OutgoingActionPayloadV1 as ActionPayloadV1, PlannedExecutionV1,
+	RegisterJobOptionsV1, RegisterJobPayloadV1, VersionedIncomingActionPayload,
 	VersionedOutgoingActionPayload,
 };
 
@@ -43,6 +44,7 @@ pub enum SubstrateMessageDecoderError {
 	InvalidJobModule,
 	TooManyJobModules,
 	CouldNotConvertAccountId,
+	UnsupportedProxyChain,
 }
 
 impl<T, I: 'static, AccountConverter> MessageDecoder<T>
@@ -70,6 +72,7 @@ where
 		let origin = match chain {
 			ProxyChain::AlephZero => MultiOrigin::AlephZero(account),
 			ProxyChain::Vara => MultiOrigin::Vara(account),
+			ProxyChain::Ethereum => Err(Self::Error::UnsupportedProxyChain)?,
 		};
 
 		fn convert_account_id<Account, AccountConverter: TryFrom<Vec<u8>> + Into<Account>>(
@@ -82,94 +85,102 @@ where
 			Ok(parsed.into())
 		}
 
-		let parsed_action: ParsedAction<T> = match action.payload {
-			VersionedOutgoingActionPayload::V1(action) => match action {
-				ActionPayloadV1::RegisterJob(job_payload) => {
-					let j = job_payload.job_registration;
+		let register_job = |job_payload: RegisterJobPayloadV1,
+		                    options: RegisterJobOptionsV1|
+		 -> Result<ParsedAction<T>, Self::Error> {
+			let j = job_payload.job_registration;
 
-					let assignment_strategy = match j.extra.assignment_strategy {
-						AssignmentStrategyV1::Single(executions) => {
-							AssignmentStrategy::Single(if let Some(e) = executions {
-								Some(
-									PlannedExecutions::try_from(
-										e.into_iter()
-											.map(|m: PlannedExecutionV1| {
-												Ok(PlannedExecution {
-													source: convert_account_id::<
-														T::AccountId,
-														AccountConverter,
-													>(&m.source)?,
-													start_delay: m.start_delay,
-												})
-											})
-											.collect::<Result<
-												Vec<PlannedExecution<T::AccountId>>,
-												Self::Error,
-											>>()?,
-									)
-									.map_err(|_| Self::Error::TooManyPlannedExecutions)?,
-								)
-							} else {
-								None
-							})
-						},
-						AssignmentStrategyV1::Competing => AssignmentStrategy::Competing,
-					};
-					let extra: T::RegistrationExtra = RegistrationExtra {
-						requirements: JobRequirements {
-							assignment_strategy,
-							slots: j.extra.slots,
-							reward: T::Balance::from(j.extra.reward),
-							min_reputation: j.extra.min_reputation,
-							processor_version: None,
-							runtime: Runtime::NodeJS,
-						},
-					}
-					.into();
-					let allowed_sources = if let Some(a) = j.allowed_sources {
+			let assignment_strategy = match j.extra.assignment_strategy {
+				AssignmentStrategyV1::Single(executions) => {
+					AssignmentStrategy::Single(if let Some(e) = executions {
 						Some(
-							AllowedSources::try_from(
-								a.iter()
-									.map(|s| {
-										convert_account_id::<T::AccountId, AccountConverter>(s)
+							PlannedExecutions::try_from(
+								e.into_iter()
+									.map(|m: PlannedExecutionV1| {
+										Ok(PlannedExecution {
+											source: convert_account_id::<
+												T::AccountId,
+												AccountConverter,
+											>(&m.source)?,
+											start_delay: m.start_delay,
+										})
 									})
-									.collect::<Result<Vec<T::AccountId>, Self::Error>>()?,
+									.collect::<Result<Vec<PlannedExecution<T::AccountId>>, Self::Error>>(
+									)?,
 							)
-							.map_err(|_| Self::Error::TooManyAllowedSources)?,
+							.map_err(|_| Self::Error::TooManyPlannedExecutions)?,
 						)
 					} else {
 						None
-					};
-					let registration = JobRegistration {
-						script: Script::truncate_from(j.script),
-						allowed_sources,
-						allow_only_verified_sources: j.allow_only_verified_sources,
-						schedule: Schedule {
-							duration: j.schedule.duration,
-							start_time: j.schedule.start_time,
-							end_time: j.schedule.end_time,
-							interval: j.schedule.interval,
-							max_start_delay: j.schedule.max_start_delay,
-						},
-						memory: j.memory,
-						network_requests: j.network_requests,
-						storage: j.storage,
-						required_modules: JobModules::try_from(
-							j.required_modules
-								.iter()
-								.map(|item| {
-									JobModule::try_from(*item as u32)
-										.map_err(|_| Self::Error::InvalidJobModule)
-								})
-								.collect::<Result<Vec<_>, Self::Error>>()?,
-						)
-						.map_err(|_| Self::Error::TooManyJobModules)?,
-						extra,
-					};
+					})
+				},
+				AssignmentStrategyV1::Competing => AssignmentStrategy::Competing,
+			};
+			let extra: T::RegistrationExtra = RegistrationExtra {
+				requirements: JobRequirements {
+					assignment_strategy,
+					slots: j.extra.slots,
+					reward: T::Balance::from(j.extra.reward),
+					min_reputation: j.extra.min_reputation,
+					processor_version: None,
+					runtime: Runtime::NodeJS,
+					consensus: false,
+					payment_asset: None,
+					deliver_results_to_origin: options.deliver_results_to_origin,
+				},
+			}
+			.into();
+			let allowed_sources = if let Some(a) = j.allowed_sources {
+				Some(
+					AllowedSources::try_from(
+						a.iter()
+							.map(|s| convert_account_id::<T::AccountId, AccountConverter>(s))
+							.collect::<Result<Vec<T::AccountId>, Self::Error>>()?,
+					)
+					.map_err(|_| Self::Error::TooManyAllowedSources)?,
+				)
+			} else {
+				None
+			};
+			let registration = JobRegistration {
+				script: Script::truncate_from(j.script),
+				allowed_sources,
+				allow_only_verified_sources: j.allow_only_verified_sources,
+				schedule: Schedule {
+					duration: j.schedule.duration,
+					start_time: j.schedule.start_time,
+					end_time: j.schedule.end_time,
+					interval: j.schedule.interval,
+					max_start_delay: j.schedule.max_start_delay,
+				},
+				memory: j.memory,
+				network_requests: j.network_requests,
+				storage: j.storage,
+				required_modules: JobModules::try_from(
+					j.required_modules
+						.iter()
+						.map(|item| {
+							JobModule::try_from(*item as u32)
+								.map_err(|_| Self::Error::InvalidJobModule)
+						})
+						.collect::<Result<Vec<_>, Self::Error>>()?,
+				)
+				.map_err(|_| Self::Error::TooManyJobModules)?,
+				extra,
+			};
 
-					let job_id = (origin, job_payload.job_id);
+			let job_id = (origin.clone(), job_payload.job_id);
 
-					ParsedAction::RegisterJob(job_id, registration)
+			Ok(ParsedAction::RegisterJob(job_id, registration))
+		};
+
+		let parsed_action: ParsedAction<T> = match action.payload {
+			VersionedOutgoingActionPayload::V1(action) => match action {
+				ActionPayloadV1::RegisterJob(job_payload) => {
+					register_job(job_payload, RegisterJobOptionsV1::default())?
+				},
+				ActionPayloadV1::RegisterJobWithOptions(job_payload, options) => {
+					register_job(job_payload, options)?
 				},
 				ActionPayloadV1::DeregisterJob(job_id) => {
 					ParsedAction::DeregisterJob((origin, job_id))
@@ -247,6 +258,19 @@ impl MessageEncoder for SubstrateMessageEncoder {
 
 				IncomingActionPayloadV1::FinalizeJob(payload)
 			},
+			IncomingAction::ExecutionResult(job_id, execution_result) => {
+				let result = match execution_result {
+					ExecutionResult::Success(operation_hash) => {
+						ExecutionResultV1::Success(operation_hash.to_vec())
+					},
+					ExecutionResult::Failure(message) => {
+						ExecutionResultV1::Failure(message.to_vec())
+					},
+				};
+				let payload = ExecutionResultPayloadV1 { job_id: *job_id, result };
+
+				IncomingActionPayloadV1::ExecutionResult(payload)
+			},
 			IncomingAction::Noop => IncomingActionPayloadV1::Noop,
 		};
 		let message = IncomingActionOnProxy {
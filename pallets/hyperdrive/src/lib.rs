@@ -6,10 +6,10 @@ pub use pallet::*;
 pub use traits::*;
 pub use types::*;
 
-// #[cfg(test)]
-// mod mock;
-// #[cfg(any(test, feature = "runtime-benchmarks"))]
-// mod stub;
+#[cfg(test)]
+mod mock;
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+mod stub;
 // #[cfg(test)]
 // mod substrate_tests;
 // #[cfg(test)]
@@ -36,14 +36,20 @@ pub mod pallet {
 	use sp_std::{prelude::*, vec};
 
 	use pallet_acurast::{
-		ContractCall, Layer, MessageBody, MessageProcessor, MessageSender, Subject,
+		AccountId20, ContractCall, Layer, MessageBody, MessageProcessor, MessageSender, Subject,
 	};
 	use pallet_acurast_hyperdrive_ibc::OutgoingMessageWithMeta;
 	use pallet_acurast_marketplace::RegistrationExtra;
 
-	use chain::substrate::{
-		SubstrateMessageDecoder, SubstrateMessageDecoderError, SubstrateMessageEncoder,
-		SubstrateMessageEncoderError,
+	use chain::{
+		ethereum::{
+			EthereumMessageDecoder, EthereumMessageDecoderError, EthereumMessageEncoder,
+			EthereumMessageEncoderError,
+		},
+		substrate::{
+			SubstrateMessageDecoder, SubstrateMessageDecoderError, SubstrateMessageEncoder,
+			SubstrateMessageEncoderError,
+		},
 	};
 
 	use super::*;
@@ -107,6 +113,9 @@ pub mod pallet {
 		),
 		ReceivedFromProxy(ProcessMessageResult),
 		SentToProxyV2(<T::MessageIdHasher as Hash>::Output),
+		EthereumContractUpdated {
+			contract: AccountId20,
+		},
 	}
 
 	#[pallet::error]
@@ -116,6 +125,9 @@ pub mod pallet {
 		SubstrateMessageDecoderError(u8),
 		SubstrateMessageEncoderError(u8),
 		InvalidSender,
+		EthereumMessageDecoderError(u8),
+		EthereumMessageEncoderError(u8),
+		MissingContractConfiguration,
 	}
 
 	impl<T: Config<I>, I: 'static> From<pallet_acurast_hyperdrive_ibc::Error<T, I>> for Error<T, I> {
@@ -136,6 +148,18 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config<I>, I: 'static> From<EthereumMessageDecoderError> for Error<T, I> {
+		fn from(e: EthereumMessageDecoderError) -> Self {
+			Error::<T, I>::EthereumMessageDecoderError(e as u8)
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> From<EthereumMessageEncoderError> for Error<T, I> {
+		fn from(e: EthereumMessageEncoderError) -> Self {
+			Error::<T, I>::EthereumMessageEncoderError(e as u8)
+		}
+	}
+
 	#[pallet::type_value]
 	pub fn InitialAlephZeroContract<T: Config<I>, I: 'static>() -> ContractCall<T::AccountId> {
 		ContractCall {
@@ -159,6 +183,11 @@ pub mod pallet {
 	pub type VaraContract<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, T::AccountId, ValueQuery, InitialVaraContract<T, I>>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn ethereum_contract)]
+	pub type EthereumContract<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, AccountId20, OptionQuery>;
+
 	/// Next outgoing message number. The latest used number is the stored value - 1.
 	#[pallet::storage]
 	#[pallet::getter(fn next_message_number)]
@@ -191,6 +220,19 @@ pub mod pallet {
 			Self::deposit_event(Event::VaraContractUpdated { contract });
 			Ok(())
 		}
+
+		/// Updates the Ethereum (target chain) contract address in storage. Can only be called by a privileged/root account.
+		#[pallet::call_index(2)]
+		#[pallet::weight(< T as Config<I>>::WeightInfo::update_ethereum_contract())]
+		pub fn update_ethereum_contract(
+			origin: OriginFor<T>,
+			contract: AccountId20,
+		) -> DispatchResult {
+			<T as Config<I>>::UpdateOrigin::ensure_origin(origin)?;
+			<EthereumContract<T, I>>::set(Some(contract));
+			Self::deposit_event(Event::EthereumContractUpdated { contract });
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I>
@@ -209,8 +251,16 @@ pub mod pallet {
 			NextMessageNumber::<T, I>::put(next_message_number + 1);
 
 			let message = Message { id: next_message_number, action };
-			let encoded = <SubstrateMessageEncoder as MessageEncoder>::encode(&message)
-				.map_err(|e| -> Error<T, I> { e.into() })?;
+			let encoded = match chain {
+				ProxyChain::AlephZero | ProxyChain::Vara => {
+					<SubstrateMessageEncoder as MessageEncoder>::encode(&message)
+						.map_err(|e| -> Error<T, I> { e.into() })?
+				},
+				ProxyChain::Ethereum => {
+					<EthereumMessageEncoder as MessageEncoder>::encode(&message)
+						.map_err(|e| -> Error<T, I> { e.into() })?
+				},
+			};
 
 			let recipient = match chain {
 				ProxyChain::AlephZero => {
@@ -220,6 +270,11 @@ pub mod pallet {
 					contract: Self::vara_contract(),
 					selector: None,
 				})),
+				ProxyChain::Ethereum => Subject::Ethereum(Layer::Contract(ContractCall {
+					contract: Self::ethereum_contract()
+						.ok_or(Error::<T, I>::MissingContractConfiguration)?,
+					selector: None,
+				})),
 			};
 			let message_nonce = T::MessageIdHasher::hash_of(&next_message_number);
 			_ = T::MessageSender::send_message(
@@ -281,6 +336,23 @@ pub mod pallet {
 
 					Ok(())
 				},
+				SubjectFor::<T>::Ethereum(Layer::Contract(c)) => {
+					if c.contract
+						!= Self::ethereum_contract()
+							.ok_or(Error::<T, I>::MissingContractConfiguration)?
+					{
+						Err(Error::<T, I>::InvalidSender)?
+					}
+					let action =
+						<EthereumMessageDecoder::<I, T::ParsableAccountId, T::AccountId> as types::MessageDecoder<T>>::decode(
+							&message.payload(),
+                            ProxyChain::Ethereum,
+						)
+						.map_err(|e| Error::<T, I>::EthereumMessageDecoderError(e as u8))?;
+					T::ActionExecutor::execute(action)?;
+
+					Ok(())
+				},
 				_ => Err(Error::<T, I>::InvalidSender),
 			}?;
 
@@ -1,53 +1,55 @@
-#![allow(unused_imports)]
+use std::cell::RefCell;
 
-use crate::{
-	chain::tezos::TezosParser, stub::AcurastAccountId, types::RawIncomingAction, weights,
-	ActionExecutor, ParsedAction, ProxyAddress, StateProof, StateProofNode,
-};
 use cumulus_primitives_core::ParaId;
-use derive_more::{Display, From, Into};
 use frame_support::{
 	derive_impl,
-	instances::Instance1,
 	pallet_prelude::*,
 	parameter_types,
 	traits::{ConstU16, ConstU64},
-	Deserialize, PalletId, Serialize,
+	PalletId,
 };
 use frame_system::{self as system, EnsureRoot};
 use hex_literal::hex;
-use pallet_acurast::{MessageBody, MessageProcessor, ProxyAcurastChain, ProxyChain, CU32};
+use pallet_acurast::{ProxyAcurastChain, CU32};
 use pallet_acurast_marketplace::RegistrationExtra;
-use sp_core::*;
+use sp_core::H256;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentityLookup, Keccak256},
-	AccountId32, BuildStorage, MultiSignature,
+	traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage, Perbill,
 };
-use sp_std::prelude::*;
+
+use crate::{stub::AcurastAccountId, weights, ActionExecutor, ParsedAction};
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
-parameter_types! {
-	pub const TransmissionRate: u64 = 5;
-	pub const TransmissionQuorum: u8 = 2;
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type BlockNumber = u64;
 
+parameter_types! {
 	pub const AcurastPalletId: PalletId = PalletId(*b"acrstpid");
 	pub const MinimumPeriod: u64 = 2000;
 
 	/// The acurast contract on the aleph zero network
-	pub AlephZeroContract: AccountId = hex_literal::hex!("e2ab38a7567ec7e9cb208ffff65ea5b5a610a6f1cc7560a27d61b47223d6baa3").into();
-	pub AlephZeroContractSelector: [u8; 4] = hex_literal::hex!("7cd99c82");
-	pub VaraContract: AccountId = hex_literal::hex!("e2ab38a7567ec7e9cb208ffff65ea5b5a610a6f1cc7560a27d61b47223d6baa3").into(); // TODO(vara)
+	pub AlephZeroContract: AccountId = hex!("e2ab38a7567ec7e9cb208ffff65ea5b5a610a6f1cc7560a27d61b47223d6baa3").into();
+	pub AlephZeroContractSelector: [u8; 4] = hex!("7cd99c82");
+	pub VaraContract: AccountId = hex!("e2ab38a7567ec7e9cb208ffff65ea5b5a610a6f1cc7560a27d61b47223d6baa3").into();
 	pub AcurastPalletAccount: AccountId = AcurastPalletId::get().into_account_truncating();
-	pub HyperdriveIbcFeePalletAccount: AccountId = HyperdriveIbcFeePalletId::get().into_account_truncating();
 
-	pub MinTTL: BlockNumber = 15;
-	pub IncomingTTL: BlockNumber = 50;
-	pub MinDeliveryConfirmationSignatures: u32 = 1;
-	pub MinReceiptConfirmationSignatures: u32 = 1;
+	pub const MinTTL: BlockNumber = 15;
+	pub const IncomingTTL: BlockNumber = 50;
+	pub const MinDeliveryConfirmationSignatures: u32 = 1;
+	pub const MinReceiptConfirmationSignatures: u32 = 1;
 	pub const MinFee: Balance = 1;
+	pub const ExistentialDeposit: Balance = 1;
 	pub const ParachainId: ParaId = ParaId::new(2000);
 	pub const SelfChain: ProxyAcurastChain = ProxyAcurastChain::Acurast;
+	pub const MinOracleBond: Balance = 1;
+	pub const OracleUnbondingPeriod: BlockNumber = 10;
+	pub const EquivocationBounty: Perbill = Perbill::from_percent(10);
+	pub const MaxEthereumStateRoots: u32 = 16;
+	pub const SentMessageRetention: BlockNumber = 20;
+	pub const MaxOracleWeight: u32 = 2;
 }
 
 // Configure a mock runtime to test the pallet.
@@ -55,22 +57,23 @@ frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system,
 		Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
+		Balances: pallet_balances::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Acurast: pallet_acurast::{Pallet, Call, Storage, Event<T>},
 		AcurastHyperdrive: crate::{Pallet, Call, Storage, Event<T>},
-		AcurastHyperdriveIbc: pallet_acurast_hyperdrive_ibc::{Pallet, Call, Storage, Event<T>},
+		AcurastHyperdriveIbc: pallet_acurast_hyperdrive_ibc::{Pallet, Call, Storage, Event<T>, HoldReason},
 	}
 );
 
 #[derive_impl(frame_system::config_preludes::ParaChainDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Test {
-	type AccountId = AccountId32;
+	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Nonce = u64;
 	type Hash = H256;
 	type Block = Block;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type DbWeight = ();
 	type BlockWeights = ();
 	type BlockLength = ();
@@ -86,43 +89,71 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = ();
+	type MaxFreezes = ConstU32<0>;
+	type DoneSlashHandler = ();
+}
+
 pub type MaxAllowedSources = CU32<4>;
 pub type MaxSlots = CU32<64>;
+pub type MaxVersions = CU32<1>;
 
 impl pallet_acurast::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type RegistrationExtra =
-		RegistrationExtra<Balance, <Self as frame_system::Config>::AccountId, Self::MaxSlots>;
+	type RegistrationExtra = RegistrationExtra<Balance, AccountId, MaxSlots, u32, MaxVersions>;
 	type MaxAllowedSources = MaxAllowedSources;
-	type MaxCertificateRevocationListUpdates = frame_support::traits::ConstU32<10>;
+	type MaxCertificateRevocationListUpdates = ConstU32<10>;
+	type MaxAttestationAllowlistUpdates = ConstU32<10>;
+	type MaxAttestationAllowlistEntries = ConstU32<32>;
+	type InitialAttestationAllowlist = ();
+	type ReattestationInterval = ConstU64<{ u64::MAX }>;
+	type ReattestationGracePeriod = ConstU64<0>;
+	type MaxAttestationExpiryChecks = ConstU32<10>;
+	type MaxRevocationPropagations = ConstU32<10>;
 	type MaxSlots = MaxSlots;
 	type PalletId = AcurastPalletId;
 	type MaxEnvVars = CU32<10>;
 	type EnvKeyMaxSize = CU32<32>;
 	type EnvValueMaxSize = CU32<1024>;
-	type RevocationListUpdateBarrier = ();
 	type KeyAttestationBarrier = ();
+	type AttestationVerifiers = ();
 	type UnixTime = pallet_timestamp::Pallet<Test>;
 	type JobHooks = ();
+	type ProcessorVersion = u32;
+	type MaxVersions = MaxVersions;
+	type UpdateOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = pallet_acurast::weights::WeightInfo<Test>;
-	#[cfg(feature = "runtime-benchmarks")]
-	type BenchmarkHelper = benchmarking::AcurastBenchmarkHelper;
 }
 
-impl crate::Config<Instance1> for Test {
+impl crate::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
-	type ActionExecutor = ();
+	type ActionExecutor = RecordingActionExecutor;
 	type Sender = AcurastPalletAccount;
 	type ParsableAccountId = AcurastAccountId;
 	type AlephZeroContract = AlephZeroContract;
 	type AlephZeroContractSelector = AlephZeroContractSelector;
 	type VaraContract = VaraContract;
 	type Balance = Balance;
+	type MessageSender = AcurastHyperdriveIbc;
+	type MessageIdHasher = BlakeTwo256;
 	type MessageFee = MinFee;
+	type UpdateOrigin = EnsureRoot<Self::AccountId>;
 	type WeightInfo = weights::WeightInfo<Test>;
 }
 
-impl pallet_acurast_hyperdrive_ibc::Config<Instance1> for Test {
+impl pallet_acurast_hyperdrive_ibc::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MinTTL = MinTTL;
 	type IncomingTTL = IncomingTTL;
@@ -132,16 +163,42 @@ impl pallet_acurast_hyperdrive_ibc::Config<Instance1> for Test {
 	type Currency = Balances;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type MessageIdHashing = BlakeTwo256;
-	type MessageProcessor = HyperdriveMessageProcessor;
+	type MessageProcessor = AcurastHyperdrive;
 	type UpdateOrigin = EnsureRoot<Self::AccountId>;
 	type ParachainId = ParachainId;
 	type SelfChain = SelfChain;
-	type WeightInfo = weights::WeightInfo<Test>;
+	type MinOracleBond = MinOracleBond;
+	type OracleUnbondingPeriod = OracleUnbondingPeriod;
+	type EquivocationBounty = EquivocationBounty;
+	type AggregateSignatureVerifier = ();
+	type MessageProofVerifier = ();
+	type MaxEthereumStateRoots = MaxEthereumStateRoots;
+	type SentMessageRetention = SentMessageRetention;
+	type MaxOracleWeight = MaxOracleWeight;
+	type WeightInfo = pallet_acurast_hyperdrive_ibc::weights::WeightInfo<Test>;
+}
+
+thread_local! {
+	static EXECUTED_ACTIONS: RefCell<Vec<ParsedAction<Test>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records the actions instead of executing them, see [`executed_actions`].
+pub struct RecordingActionExecutor;
+impl ActionExecutor<Test> for RecordingActionExecutor {
+	fn execute(action: ParsedAction<Test>) -> DispatchResultWithPostInfo {
+		EXECUTED_ACTIONS.with(|actions| actions.borrow_mut().push(action));
+		Ok(().into())
+	}
+}
+
+/// Returns and clears the actions passed to [`RecordingActionExecutor`].
+pub fn executed_actions() -> Vec<ParsedAction<Test>> {
+	EXECUTED_ACTIONS.with(|actions| actions.take())
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	let storage = system::GenesisConfig::<Test>::default().build_storage().unwrap().into();
+	let storage = system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
 	let mut ext = sp_io::TestExternalities::new(storage);
 	ext.execute_with(|| System::set_block_number(1));
@@ -156,11 +213,3 @@ pub fn events() -> Vec<RuntimeEvent> {
 
 	evt
 }
-
-pub type Balance = u128;
-
-impl<T: pallet_acurast::Config> ActionExecutor<T> for () {
-	fn execute(_: ParsedAction<T>) -> DispatchResultWithPostInfo {
-		Ok(().into())
-	}
-}
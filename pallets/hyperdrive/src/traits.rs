@@ -4,4 +4,5 @@ use frame_support::weights::Weight;
 pub trait WeightInfo {
 	fn update_aleph_zero_contract() -> Weight;
 	fn update_vara_contract() -> Weight;
+	fn update_ethereum_contract() -> Weight;
 }
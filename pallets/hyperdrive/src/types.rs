@@ -1,5 +1,5 @@
 use frame_support::{pallet_prelude::*, storage::bounded_vec::BoundedVec};
-use pallet_acurast_marketplace::PubKey;
+use pallet_acurast_marketplace::{ExecutionResult, PubKey};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_core::ConstU32;
@@ -16,8 +16,8 @@ pub type ProxyAddress = BoundedVec<u8, ConstU32<PROXY_ADDRESS_MAX_LENGTH>>;
 pub enum ProxyChain {
 	AlephZero,
 	Vara,
+	Ethereum,
 	// Tezos,
-	// Ethereum,
 }
 
 #[derive(
@@ -146,6 +146,8 @@ pub enum RawOutgoingAction {
 	AssignJob,
 	#[strum(serialize = "FINALIZE_JOB")]
 	FinalizeJob,
+	#[strum(serialize = "EXECUTION_RESULT")]
+	ExecutionResult,
 	#[strum(serialize = "NOOP")]
 	Noop = 255,
 }
@@ -155,6 +157,7 @@ impl From<&IncomingAction> for RawOutgoingAction {
 		match action {
 			IncomingAction::AssignJob(_, _) => RawOutgoingAction::AssignJob,
 			IncomingAction::FinalizeJob(_, _) => RawOutgoingAction::FinalizeJob,
+			IncomingAction::ExecutionResult(_, _) => RawOutgoingAction::ExecutionResult,
 			IncomingAction::Noop => RawOutgoingAction::Noop,
 		}
 	}
@@ -180,6 +183,11 @@ pub enum IncomingAction {
 	/// Consists of `(Job ID, refund amount)`,
 	/// where `Job ID` is the subset of [`pallet_acurast::JobId`] for jobs created externally.
 	FinalizeJob(JobIdSequence, u128), // (u128, u128)
+	/// Delivers a result reported for a job to the target chain.
+	///
+	/// Consists of `(Job ID, execution result)`,
+	/// where `Job ID` is the subset of [`pallet_acurast::JobId`] for jobs created externally.
+	ExecutionResult(JobIdSequence, ExecutionResult),
 	/// A noop action that solely suits the purpose of testing that messages get sent.
 	Noop,
 }
@@ -82,6 +82,9 @@ pub fn job_registration_with_reward<T: Config>(
 		),
 		processor_version: None,
 		runtime: Runtime::NodeJS,
+		consensus: false,
+		payment_asset: None,
+		deliver_results_to_origin: false,
 	};
 	let r: <T as Config>::RegistrationExtra = <T as Config>::BenchmarkHelper::registration_extra(r);
 	let r: <T as pallet_acurast::Config>::RegistrationExtra = r.into();
@@ -110,15 +113,34 @@ pub fn competing_job_registration_with_reward<T: Config>(
 	slots: u8,
 	duration: u64,
 	reward_value: u128,
+) -> JobRegistrationFor<T> {
+	competing_job_registration_with_strategy::<T>(
+		script,
+		slots,
+		duration,
+		reward_value,
+		AssignmentStrategy::Competing,
+	)
+}
+
+pub fn competing_job_registration_with_strategy<T: Config>(
+	script: Script,
+	slots: u8,
+	duration: u64,
+	reward_value: u128,
+	assignment_strategy: AssignmentStrategy<T::AccountId, T::MaxSlots>,
 ) -> JobRegistrationFor<T> {
 	let reward: <T as Config>::Balance = reward_value.into();
 	let r = JobRequirements {
 		slots,
 		reward,
 		min_reputation: Some(0),
-		assignment_strategy: AssignmentStrategy::Competing,
+		assignment_strategy,
 		processor_version: None,
 		runtime: Runtime::NodeJS,
+		consensus: false,
+		payment_asset: None,
+		deliver_results_to_origin: false,
 	};
 	let r: <T as Config>::RegistrationExtra = <T as Config>::BenchmarkHelper::registration_extra(r);
 	let r: <T as pallet_acurast::Config>::RegistrationExtra = r.into();
@@ -493,6 +515,172 @@ where
 	(job, job_id, processor_counter)
 }
 
+/// Registers a job with [`AssignmentStrategy::CompetingCommitReveal`] and matches the maximum number of candidates for its first execution.
+///
+/// If `commit` is true, all candidates commit a blinded acknowledgment.
+#[allow(clippy::type_complexity)]
+fn commit_reveal_matching_helper<T>(
+	commit: bool,
+) -> (Vec<(T::AccountId, H256)>, JobRegistrationFor<T>, JobId<T::AccountId>)
+where
+	T: Config + pallet_balances::Config + pallet_timestamp::Config + pallet_acurast_compute::Config,
+	<T as pallet_timestamp::Config>::Moment: From<u64>,
+	BalanceFor<T>: IsType<u128>,
+{
+	let max_slots = <T as pallet_acurast::Config>::MaxSlots::get() as u8;
+	let consumer: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+	let job = competing_job_registration_with_strategy::<T>(
+		script(),
+		max_slots,
+		100,
+		2_000_000_000,
+		AssignmentStrategy::CompetingCommitReveal,
+	);
+
+	pallet_timestamp::Pallet::<T>::set_timestamp((job.schedule.start_time - 310_000).into());
+
+	assert_ok!(Acurast::<T>::register(RawOrigin::Signed(consumer.clone()).into(), job.clone()));
+
+	let job_id: JobId<T::AccountId> =
+		(MultiOrigin::Acurast(consumer.clone()), Acurast::<T>::job_id_sequence());
+
+	let match_time = (job.schedule.start_time - 2 * T::MatchingCommitRevealPeriod::get() - 1)
+		.max(job.schedule.start_time - T::MatchingCompetingDueDelta::get());
+	pallet_timestamp::Pallet::<T>::set_timestamp(match_time.into());
+
+	let mut candidates: Vec<(T::AccountId, H256)> = vec![];
+	for i in 0..max_slots {
+		let (processor, _) = advertise_helper::<T>(i as u32 + 1, true);
+		candidates.push((processor, H256::repeat_byte(i)));
+	}
+
+	assert_ok!(AcurastMarketplace::<T>::propose_execution_matching(
+		RawOrigin::Signed(consumer.clone()).into(),
+		vec![ExecutionMatch {
+			job_id: job_id.clone(),
+			execution_index: 0,
+			sources: candidates
+				.iter()
+				.map(|(source, _)| PlannedExecution { source: source.clone(), start_delay: 0 })
+				.collect::<Vec<_>>()
+				.try_into()
+				.unwrap()
+		}]
+		.try_into()
+		.unwrap()
+	));
+
+	if commit {
+		for (source, salt) in candidates.iter() {
+			assert_ok!(AcurastMarketplace::<T>::commit_execution_acknowledgment(
+				RawOrigin::Signed(source.clone()).into(),
+				job_id.clone(),
+				0,
+				ExecutionCandidateFor::<T>::blinded_acknowledgment(
+					source,
+					salt,
+					&benchmark_pub_keys()
+				),
+			));
+		}
+	}
+
+	(candidates, job, job_id)
+}
+
+/// Reveals the acknowledgments of all but the last candidate.
+fn reveal_helper<T>(
+	candidates: &[(T::AccountId, H256)],
+	job: &JobRegistrationFor<T>,
+	job_id: &JobId<T::AccountId>,
+) where
+	T: Config + pallet_timestamp::Config,
+	<T as pallet_timestamp::Config>::Moment: From<u64>,
+{
+	pallet_timestamp::Pallet::<T>::set_timestamp(
+		(job.schedule.start_time - 2 * T::MatchingCommitRevealPeriod::get()).into(),
+	);
+	for (source, salt) in candidates.iter().take(candidates.len() - 1) {
+		assert_ok!(AcurastMarketplace::<T>::reveal_execution_acknowledgment(
+			RawOrigin::Signed(source.clone()).into(),
+			job_id.clone(),
+			0,
+			*salt,
+			benchmark_pub_keys(),
+		));
+	}
+}
+
+/// Registers a job requiring consensus with the maximum number of slots, all of which committed a result for the first execution.
+///
+/// The results of all but the last processor are revealed and the timestamp is set to the execution's reveal phase.
+#[allow(clippy::type_complexity)]
+fn result_commitments_helper<T>(
+) -> (Vec<(T::AccountId, H256)>, ExecutionOperationHash, JobRegistrationFor<T>, JobId<T::AccountId>)
+where
+	T: Config + pallet_timestamp::Config,
+	<T as pallet_timestamp::Config>::Moment: From<u64>,
+{
+	let max_slots = <T as pallet_acurast::Config>::MaxSlots::get() as u8;
+	let consumer: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+	let r = JobRequirements {
+		slots: max_slots,
+		reward: 2_000_000_000u128.into(),
+		min_reputation: Some(0),
+		assignment_strategy: AssignmentStrategy::Single(None),
+		processor_version: None,
+		runtime: Runtime::NodeJS,
+		consensus: true,
+		payment_asset: None,
+		deliver_results_to_origin: false,
+	};
+	let r: <T as Config>::RegistrationExtra = <T as Config>::BenchmarkHelper::registration_extra(r);
+	let job = JobRegistrationFor::<T> {
+		extra: r.into(),
+		..competing_job_registration_with_reward::<T>(script(), max_slots, 100, 2_000_000_000)
+	};
+
+	pallet_timestamp::Pallet::<T>::set_timestamp((job.schedule.start_time - 310_000).into());
+	assert_ok!(Acurast::<T>::register(RawOrigin::Signed(consumer.clone()).into(), job.clone()));
+	let job_id: JobId<T::AccountId> =
+		(MultiOrigin::Acurast(consumer), Acurast::<T>::job_id_sequence());
+
+	let result = operation_hash();
+	let mut processors: Vec<(T::AccountId, H256)> = vec![];
+	let mut commitments: Vec<ResultCommitmentFor<T>> = vec![];
+	for i in 0..max_slots {
+		let processor =
+			<T as Config>::BenchmarkHelper::funded_account(i as u32 + 1, u64::MAX.into());
+		let salt = H256::repeat_byte(i);
+		commitments.push(ResultCommitment {
+			processor: processor.clone(),
+			commitment: Some(ResultCommitmentFor::<T>::blinded_result(&processor, &salt, &result)),
+			result: (i + 1 < max_slots).then(|| result.clone()),
+			fee: 1_000_000u128.into(),
+		});
+		processors.push((processor, salt));
+	}
+	<ResultCommitments<T>>::insert(
+		&job_id,
+		0,
+		ResultCommitmentsFor::<T>::truncate_from(commitments),
+	);
+
+	let (reveal_start, _) = AcurastMarketplace::<T>::result_reveal_window(&job, 0).unwrap();
+	pallet_timestamp::Pallet::<T>::set_timestamp(reveal_start.into());
+
+	(processors, result, job, job_id)
+}
+
+fn benchmark_pub_keys() -> PubKeys {
+	vec![
+		PubKey::SECP256r1([0u8; 33].to_vec().try_into().unwrap()),
+		PubKey::SECP256k1([0u8; 33].to_vec().try_into().unwrap()),
+	]
+	.try_into()
+	.unwrap()
+}
+
 fn set_timestamp<T: pallet_timestamp::Config<Moment = u64>>(timestamp: u64) {
 	pallet_timestamp::Pallet::<T>::set_timestamp(timestamp);
 }
@@ -783,5 +971,163 @@ benchmarks! {
 		<JobMatcher<T>>::insert(&job_id, consumer.clone());
 	}: _(RawOrigin::Signed(consumer), job_id)
 
+	cancel_job {
+		set_timestamp::<T>(1000);
+		let consumer = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+		let (_, _, job_id) = acknowledge_match_submit_helper::<T>(Some(consumer.clone()), None)?;
+	}: _(RawOrigin::Signed(consumer), job_id.1)
+
+	extend_job {
+		set_timestamp::<T>(1000);
+		let consumer = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+		let (_, job, job_id) = acknowledge_match_submit_helper::<T>(Some(consumer.clone()), None)?;
+		let end_time = job.schedule.end_time + job.schedule.interval * 10;
+	}: _(RawOrigin::Signed(consumer), job_id.1, end_time, None)
+	verify {
+		assert_last_event::<T>(Event::JobExtended(job_id, end_time).into());
+	}
+
+	accept_job_extension {
+		set_timestamp::<T>(1000);
+		let consumer = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+		let (processor, job, job_id) = acknowledge_match_submit_helper::<T>(Some(consumer.clone()), None)?;
+		assert_ok!(AcurastMarketplace::<T>::extend_job(
+			RawOrigin::Signed(consumer).into(),
+			job_id.1,
+			job.schedule.end_time + job.schedule.interval * 10,
+			None,
+		));
+	}: _(RawOrigin::Signed(processor.clone()), job_id.clone())
+	verify {
+		assert_last_event::<T>(Event::JobExtensionAccepted(job_id, processor).into());
+	}
+
+	decline_job_extension {
+		set_timestamp::<T>(1000);
+		let consumer = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+		let (processor, job, job_id) = acknowledge_match_submit_helper::<T>(Some(consumer.clone()), None)?;
+		assert_ok!(AcurastMarketplace::<T>::extend_job(
+			RawOrigin::Signed(consumer).into(),
+			job_id.1,
+			job.schedule.end_time + job.schedule.interval * 10,
+			None,
+		));
+	}: _(RawOrigin::Signed(processor.clone()), job_id.clone())
+	verify {
+		assert_last_event::<T>(Event::JobExtensionDeclined(job_id, processor).into());
+	}
+
+	propose_extension_matching {
+		let x in 1 .. T::MaxProposedMatches::get();
+		set_timestamp::<T>(1000);
+		let caller: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+		whitelist_account!(caller);
+		let mut matches: Vec<MatchFor<T>> = vec![];
+		for i in 0..x {
+			let consumer = <T as Config>::BenchmarkHelper::funded_account(100 + i, u64::MAX.into());
+			let processor = <T as Config>::BenchmarkHelper::funded_account(200 + i, u64::MAX.into());
+			let (processor, job, job_id) = acknowledge_match_submit_helper::<T>(Some(consumer.clone()), Some(processor))?;
+			assert_ok!(AcurastMarketplace::<T>::extend_job(
+				RawOrigin::Signed(consumer).into(),
+				job_id.1,
+				job.schedule.end_time + job.schedule.interval * 10,
+				None,
+			));
+			assert_ok!(AcurastMarketplace::<T>::decline_job_extension(
+				RawOrigin::Signed(processor).into(),
+				job_id.clone(),
+			));
+			let new_processor = <T as Config>::BenchmarkHelper::funded_account(300 + i, u64::MAX.into());
+			assert_ok!(AcurastMarketplace::<T>::advertise(
+				RawOrigin::Signed(new_processor.clone()).into(),
+				advertisement::<T>(1, 1_000_000),
+			));
+			matches.push(Match {
+				job_id,
+				sources: vec![PlannedExecution { source: new_processor, start_delay: 0 }].try_into().unwrap(),
+			});
+		}
+	}: _(RawOrigin::Signed(caller), matches.try_into().unwrap())
+
+	mark_slot_rematchable {
+		set_timestamp::<T>(1000);
+		let caller: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+		let (processor, job, job_id) = acknowledge_match_submit_helper::<T>(None, None)?;
+		// the processor misses to report the first execution
+		set_timestamp::<T>(job.schedule.start_time + job.schedule.interval + 1);
+	}: _(RawOrigin::Signed(caller), job_id.clone(), processor.clone())
+	verify {
+		assert_last_event::<T>(Event::SlotMarkedRematchable(job_id, processor, 0, 2).into());
+	}
+
+	commit_execution_acknowledgment {
+		set_timestamp::<T>(1000);
+		setup_pools::<T>();
+		let (candidates, _, job_id) = commit_reveal_matching_helper::<T>(false);
+		let (processor, salt) = candidates[0].clone();
+		let commitment = ExecutionCandidateFor::<T>::blinded_acknowledgment(&processor, &salt, &benchmark_pub_keys());
+	}: _(RawOrigin::Signed(processor.clone()), job_id.clone(), 0, commitment)
+	verify {
+		assert_last_event::<T>(Event::ExecutionAcknowledgmentCommitted(job_id, 0, processor).into());
+	}
+
+	reveal_execution_acknowledgment {
+		set_timestamp::<T>(1000);
+		setup_pools::<T>();
+		let (candidates, job, job_id) = commit_reveal_matching_helper::<T>(true);
+		reveal_helper::<T>(&candidates, &job, &job_id);
+		// the last reveal picks the candidates
+		let (processor, salt) = candidates[candidates.len() - 1].clone();
+	}: _(RawOrigin::Signed(processor), job_id.clone(), 0, salt, benchmark_pub_keys())
+	verify {
+		assert_eq!(None, AcurastMarketplace::<T>::execution_candidates(&job_id, 0));
+	}
+
+	pick_execution_candidates {
+		set_timestamp::<T>(1000);
+		setup_pools::<T>();
+		let caller: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+		let (candidates, job, job_id) = commit_reveal_matching_helper::<T>(true);
+		reveal_helper::<T>(&candidates, &job, &job_id);
+		set_timestamp::<T>(job.schedule.start_time - T::MatchingCommitRevealPeriod::get());
+	}: _(RawOrigin::Signed(caller), job_id.clone(), 0)
+	verify {
+		assert_eq!(None, AcurastMarketplace::<T>::execution_candidates(&job_id, 0));
+	}
+
+	reveal_result {
+		set_timestamp::<T>(1000);
+		let (processors, result, _, job_id) = result_commitments_helper::<T>();
+		// the last reveal settles the results
+		let (processor, salt) = processors[processors.len() - 1].clone();
+	}: _(RawOrigin::Signed(processor), job_id.clone(), 0, salt, result.clone())
+	verify {
+		assert_eq!(Some(result), AcurastMarketplace::<T>::consensus_results(&job_id, 0));
+	}
+
+	settle_execution_results {
+		set_timestamp::<T>(1000);
+		let caller: T::AccountId = <T as Config>::BenchmarkHelper::funded_account(0, u64::MAX.into());
+		let (_, result, job, job_id) = result_commitments_helper::<T>();
+		let (_, reveal_end) = AcurastMarketplace::<T>::result_reveal_window(&job, 0).unwrap();
+		set_timestamp::<T>(reveal_end);
+	}: _(RawOrigin::Signed(caller), job_id.clone(), 0)
+	verify {
+		assert_eq!(Some(result), AcurastMarketplace::<T>::consensus_results(&job_id, 0));
+	}
+
+	set_advertisement_unavailability {
+		set_timestamp::<T>(1000);
+		let (caller, _) = advertise_helper::<T>(0, true);
+		let unavailability: UnavailabilityWindows = (0..MAX_UNAVAILABILITY_WINDOWS as u64)
+			.map(|i| UnavailabilityWindow { start: i * DAY, duration: 3_600_000, period: 7 * DAY })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), unavailability.clone())
+	verify {
+		assert_eq!(Some(unavailability), AcurastMarketplace::<T>::stored_advertisement_unavailability(&caller));
+	}
+
 	//impl_benchmark_test_suite!(AcurastMarketplace, mock::ExtBuilder::default().build(), mock::Test);
 }
@@ -0,0 +1,325 @@
+use frame_support::{
+	ensure,
+	pallet_prelude::*,
+	sp_runtime::{
+		traits::{CheckedAdd, CheckedDiv, Zero},
+		PerThing,
+	},
+	traits::Randomness,
+};
+use pallet_acurast::{JobId, JobRegistrationFor, RequiredMinMetrics, StoredJobRegistration};
+use reputation::{BetaReputation, ReputationEngine};
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_std::prelude::*;
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Matches the sources of an [`ExecutionMatch`] as candidates for an execution of a job with [`AssignmentStrategy::CompetingCommitReveal`].
+	///
+	/// At least as many candidates as the job has slots have to be proposed. The slots get assigned by [`Self::do_pick_execution_candidates`].
+	pub(crate) fn process_execution_candidates(
+		m: &ExecutionMatchFor<T>,
+		registration: &JobRegistrationFor<T>,
+		requirements: &JobRequirementsFor<T>,
+		execution_start_time: u64,
+		matcher_account: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let (commit_deadline, _) = Self::commit_reveal_deadlines(execution_start_time);
+		// CHECK that candidates are matched before the commit phase ends
+		ensure!(Self::now()? < commit_deadline, Error::<T>::OverdueMatch);
+		ensure!(
+			m.sources.len() >= requirements.slots as usize,
+			Error::<T>::IncorrectSourceCountInMatch
+		);
+
+		let min_metrics = <RequiredMinMetrics<T>>::get(&m.job_id);
+
+		// cleanup storage items of previous matches that are not needed anymore
+		Self::cleanup_previous_execution_matches(m);
+
+		let mut candidates: Vec<ExecutionCandidateFor<T>> = Vec::new();
+		for planned_execution in m.sources.iter() {
+			ensure!(
+				!candidates.iter().any(|c| c.source == planned_execution.source),
+				Error::<T>::DuplicateSourceInMatch
+			);
+			let fee_per_execution = Self::check_source_matches(
+				&planned_execution.source,
+				&m.job_id.0,
+				registration,
+				requirements,
+				min_metrics.as_ref(),
+				ExecutionSpecifier::Index(m.execution_index),
+				planned_execution.start_delay,
+			)?;
+			candidates.push(ExecutionCandidate {
+				source: planned_execution.source.clone(),
+				start_delay: planned_execution.start_delay,
+				fee_per_execution,
+				commitment: None,
+				revealed: None,
+				salt: None,
+			});
+		}
+		<ExecutionCandidates<T>>::insert(
+			&m.job_id,
+			m.execution_index,
+			ExecutionCandidatesFor::<T>::try_from(candidates)
+				.map_err(|_| Error::<T>::TooManySlots)?,
+		);
+
+		<StoredJobStatus<T>>::insert(&m.job_id.0, m.job_id.1, JobStatus::Matched);
+		<StoredJobExecutionStatus<T>>::insert(&m.job_id, m.execution_index, JobStatus::Matched);
+		if let Some(matcher_account) = matcher_account {
+			<JobMatcher<T>>::insert(&m.job_id, matcher_account);
+		}
+
+		Self::deposit_event(Event::JobExecutionMatchedV2(m.job_id.clone()));
+		Ok(())
+	}
+
+	/// Commits the blinded acknowledgment of a candidate. A previous commitment can be replaced until the commit phase ends.
+	pub(crate) fn do_commit_execution_acknowledgment(
+		who: &T::AccountId,
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+		commitment: H256,
+	) -> DispatchResult {
+		let (commit_deadline, _) =
+			Self::commit_reveal_deadlines(Self::execution_start_time(job_id, execution_index)?);
+		ensure!(Self::now()? < commit_deadline, Error::<T>::ExecutionCommitPhaseOver);
+
+		<ExecutionCandidates<T>>::try_mutate(job_id, execution_index, |candidates| {
+			let candidate = candidates
+				.as_mut()
+				.and_then(|candidates| candidates.iter_mut().find(|c| &c.source == who))
+				.ok_or(Error::<T>::NotExecutionCandidate)?;
+			candidate.commitment = Some(commitment);
+			Ok(())
+		})
+	}
+
+	/// Reveals the acknowledgment of a candidate and picks the candidates once all candidates that committed revealed.
+	pub(crate) fn do_reveal_execution_acknowledgment(
+		who: &T::AccountId,
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+		salt: H256,
+		pub_keys: PubKeys,
+	) -> DispatchResult {
+		let (commit_deadline, reveal_deadline) =
+			Self::commit_reveal_deadlines(Self::execution_start_time(job_id, execution_index)?);
+		let now = Self::now()?;
+		ensure!(
+			commit_deadline <= now && now < reveal_deadline,
+			Error::<T>::OutsideExecutionRevealPhase
+		);
+
+		let all_revealed = <ExecutionCandidates<T>>::try_mutate(
+			job_id,
+			execution_index,
+			|candidates| -> Result<bool, DispatchError> {
+				let candidates = candidates.as_mut().ok_or(Error::<T>::NotExecutionCandidate)?;
+				let candidate = candidates
+					.iter_mut()
+					.find(|c| &c.source == who)
+					.ok_or(Error::<T>::NotExecutionCandidate)?;
+				let commitment = match (candidate.commitment, &candidate.revealed) {
+					(Some(commitment), None) => commitment,
+					_ => Err(Error::<T>::ExecutionAcknowledgmentNotCommitted)?,
+				};
+				ensure!(
+					ExecutionCandidateFor::<T>::blinded_acknowledgment(who, &salt, &pub_keys)
+						== commitment,
+					Error::<T>::ExecutionAcknowledgmentMismatch
+				);
+				candidate.revealed = Some(pub_keys);
+				candidate.salt = Some(salt);
+
+				Ok(Self::all_committed_revealed(candidates))
+			},
+		)?;
+
+		Self::deposit_event(Event::ExecutionAcknowledgmentRevealed(
+			job_id.clone(),
+			execution_index,
+			who.clone(),
+		));
+
+		if all_revealed {
+			Self::do_pick_execution_candidates(job_id, execution_index)?;
+		}
+		Ok(())
+	}
+
+	/// Picks the candidates for the slots of an execution and assigns them with their revealed acknowledgment.
+	///
+	/// Candidates are picked from the revealed ones without replacement, with a probability proportional to their reputation.
+	/// The randomness is taken from [`Config::Randomness`], which is not known before the reveal phase ends, with the revealed salts as
+	/// extra entropy. Since the last candidate to reveal knows all salts, the salts alone would let it predict the pick and withhold its reveal.
+	/// Withholding a reveal still changes the pick, so candidates that committed without revealing lose reputation and get slashed the fee
+	/// of the execution, see [`Self::do_slash`].
+	///
+	/// If no candidate revealed, the job is reset to [`JobStatus::Open`] so that the next execution can be matched.
+	pub(crate) fn do_pick_execution_candidates(
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+	) -> DispatchResult {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		let execution_start_time = registration
+			.schedule
+			.nth_start_time(0, execution_index)
+			.ok_or(Error::<T>::IncorrectExecutionIndex)?;
+		let (commit_deadline, reveal_deadline) =
+			Self::commit_reveal_deadlines(execution_start_time);
+		let candidates = <ExecutionCandidates<T>>::get(job_id, execution_index)
+			.ok_or(Error::<T>::NotExecutionCandidate)?;
+
+		let now = Self::now()?;
+		ensure!(
+			reveal_deadline <= now
+				|| (commit_deadline <= now && Self::all_committed_revealed(&candidates)),
+			Error::<T>::ExecutionRevealPhaseNotOver
+		);
+		<ExecutionCandidates<T>>::remove(job_id, execution_index);
+
+		let e: <T as Config>::RegistrationExtra = registration.extra.into();
+		let requirements: JobRequirementsFor<T> = e.into();
+
+		let (revealed, unrevealed): (Vec<ExecutionCandidateFor<T>>, Vec<_>) =
+			candidates.into_iter().partition(|c| c.revealed.is_some());
+		for candidate in unrevealed.into_iter().filter(|c| c.commitment.is_some()) {
+			Self::do_penalize_unrevealed(job_id, execution_index, &candidate);
+		}
+
+		let (randomness, _) = T::Randomness::random(&(job_id, execution_index).encode());
+		let salts: Vec<H256> = revealed.iter().filter_map(|c| c.salt).collect();
+		let mut seed = blake2_256(&(job_id, execution_index, randomness, salts).encode());
+
+		let mut pool: Vec<(u64, ExecutionCandidateFor<T>)> =
+			revealed.into_iter().map(|c| (Self::candidate_weight(&c.source), c)).collect();
+		let mut picked: Vec<ExecutionCandidateFor<T>> = Vec::new();
+		while picked.len() < requirements.slots as usize && !pool.is_empty() {
+			let total_weight: u64 = pool.iter().map(|(weight, _)| *weight).sum();
+			let mut random = u64::from_le_bytes(seed[..8].try_into().unwrap_or_default())
+				.checked_rem(total_weight)
+				.unwrap_or(0);
+			let index = pool
+				.iter()
+				.position(|(weight, _)| {
+					if random < *weight {
+						return true;
+					}
+					random -= *weight;
+					false
+				})
+				.unwrap_or(0);
+			picked.push(pool.remove(index).1);
+			seed = blake2_256(&seed);
+		}
+
+		if picked.is_empty() {
+			// no candidate revealed, the execution is left unassigned
+			<StoredJobExecutionStatus<T>>::remove(job_id, execution_index);
+			<StoredJobStatus<T>>::insert(&job_id.0, job_id.1, JobStatus::Open);
+		}
+
+		let mut total_fee: <T as Config>::Balance = 0u8.into();
+		for (slot, candidate) in picked.iter().enumerate() {
+			Self::assign_execution(
+				job_id,
+				execution_index,
+				slot as u8,
+				&PlannedExecution {
+					source: candidate.source.clone(),
+					start_delay: candidate.start_delay,
+				},
+				candidate.fee_per_execution,
+			)?;
+			Self::process_acknowledge_match(
+				candidate.source.clone(),
+				job_id.clone(),
+				ExecutionSpecifier::Index(execution_index),
+				candidate.revealed.clone().unwrap_or_default(),
+			)
+			.map_err(|e| e.error)?;
+			total_fee = total_fee
+				.checked_add(&candidate.fee_per_execution)
+				.ok_or(Error::<T>::CalculationOverflow)?;
+		}
+
+		// only update average on first execution's match, see `process_execution_matching`
+		if execution_index == 0 && !total_fee.is_zero() && requirements.payment_asset.is_none() {
+			let average_fee_per_execution = total_fee
+				.checked_div(&(picked.len() as u128).into())
+				.ok_or(Error::<T>::UnexpectedCheckedCalculation)?;
+			Self::update_average_reward(average_fee_per_execution.into())?;
+		}
+
+		Self::deposit_event(Event::ExecutionCandidatesPicked(
+			job_id.clone(),
+			execution_index,
+			picked.into_iter().map(|c| c.source).collect(),
+		));
+		Ok(())
+	}
+
+	/// Penalizes a candidate that committed an acknowledgment without revealing it until the candidates got picked.
+	fn do_penalize_unrevealed(
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+		candidate: &ExecutionCandidateFor<T>,
+	) {
+		// a missing reputation must not prevent the other candidates from getting picked
+		_ = Self::do_update_reputation_with(&candidate.source, 0, 1, candidate.fee_per_execution);
+		let (slashed, paid_to_consumer) =
+			Self::do_slash(job_id, &candidate.source, candidate.fee_per_execution.into())
+				.unwrap_or_default();
+
+		Self::deposit_event(Event::ExecutionAcknowledgmentNotRevealed(
+			job_id.clone(),
+			execution_index,
+			candidate.source.clone(),
+			slashed.into(),
+			paid_to_consumer.into(),
+		));
+	}
+
+	/// Returns the end of the commit phase and the end of the reveal phase for an execution starting at `execution_start_time`.
+	fn commit_reveal_deadlines(execution_start_time: u64) -> (u64, u64) {
+		let period = T::MatchingCommitRevealPeriod::get();
+		(
+			execution_start_time.saturating_sub(period.saturating_mul(2)),
+			execution_start_time.saturating_sub(period),
+		)
+	}
+
+	fn execution_start_time(
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+	) -> Result<u64, DispatchError> {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		Ok(registration
+			.schedule
+			.nth_start_time(0, execution_index)
+			.ok_or(Error::<T>::IncorrectExecutionIndex)?)
+	}
+
+	fn all_committed_revealed(candidates: &[ExecutionCandidateFor<T>]) -> bool {
+		candidates.iter().all(|c| c.commitment.is_none() || c.revealed.is_some())
+	}
+
+	/// The weight of a candidate when picking candidates: its normalized reputation in parts per million, plus one so that
+	/// candidates without reputation can be picked as well.
+	fn candidate_weight(source: &T::AccountId) -> u64 {
+		<StoredReputation<T>>::get(source)
+			.and_then(BetaReputation::<u128>::normalize)
+			.map(|reputation| reputation.deconstruct() as u64)
+			.unwrap_or(0)
+			.saturating_add(1)
+	}
+}
@@ -0,0 +1,247 @@
+use frame_support::pallet_prelude::*;
+use pallet_acurast::{JobId, JobRegistrationFor, StoredJobRegistration};
+use sp_core::H256;
+use sp_std::prelude::*;
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Commits the result reported by a processor for an execution of a job requiring [`JobRequirements::consensus`].
+	///
+	/// A successful report carries the blinded result, see [`ResultCommitment::blinded_result`], which is revealed with
+	/// [`Self::do_reveal_result`] once the report windows of all slots closed. A failure report commits no result.
+	/// Commitments arriving after the report windows closed are ignored, since the first results may already be revealed.
+	pub(crate) fn do_commit_result(
+		job_id: &JobId<T::AccountId>,
+		processor: &T::AccountId,
+		assignment: &AssignmentFor<T>,
+		registration: &JobRegistrationFor<T>,
+		execution_index: u64,
+		execution_result: &ExecutionResult,
+	) -> DispatchResult {
+		let commitment = match execution_result {
+			ExecutionResult::Success(blinded_result) => Some(
+				<[u8; 32]>::try_from(blinded_result.as_slice())
+					.map(H256)
+					.map_err(|_| Error::<T>::InvalidResultCommitment)?,
+			),
+			ExecutionResult::Failure(_) => None,
+		};
+
+		let (reveal_start, _) = Self::result_reveal_window(registration, execution_index)?;
+		if Self::now()? >= reveal_start {
+			return Ok(());
+		}
+
+		<ResultCommitments<T>>::try_mutate(job_id, execution_index, |commitments| {
+			commitments
+				.try_push(ResultCommitment {
+					processor: processor.clone(),
+					commitment,
+					result: None,
+					fee: assignment.fee_per_execution,
+				})
+				.map_err(|_| Error::<T>::TooManySlots)
+		})?;
+
+		Ok(())
+	}
+
+	/// Reveals the result committed by a processor for an execution, settling the execution once all slots revealed.
+	pub(crate) fn do_reveal_result(
+		who: &T::AccountId,
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+		salt: H256,
+		result: ExecutionOperationHash,
+	) -> DispatchResult {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		let (reveal_start, reveal_end) =
+			Self::result_reveal_window(&registration, execution_index)?;
+		let now = Self::now()?;
+		ensure!(reveal_start <= now && now < reveal_end, Error::<T>::OutsideResultRevealPhase);
+
+		let commitments =
+			<ResultCommitments<T>>::try_mutate(job_id, execution_index, |commitments| {
+				let commitment = commitments
+					.iter_mut()
+					.find(|c| &c.processor == who && c.commitment.is_some() && c.result.is_none())
+					.ok_or(Error::<T>::ResultNotCommitted)?;
+				ensure!(
+					commitment.commitment
+						== Some(ResultCommitmentFor::<T>::blinded_result(who, &salt, &result)),
+					Error::<T>::ResultCommitmentMismatch
+				);
+				commitment.result = Some(result.clone());
+				Ok::<_, DispatchError>(commitments.clone())
+			})?;
+
+		Self::deposit_event(Event::ExecutionResultRevealed(
+			job_id.clone(),
+			execution_index,
+			who.clone(),
+		));
+
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		if requirements.deliver_results_to_origin {
+			Self::do_deliver_result(job_id, who, &ExecutionResult::Success(result));
+		}
+
+		let all_revealed = commitments.len() >= requirements.slots as usize
+			&& commitments.iter().all(|c| c.commitment.is_none() || c.result.is_some());
+		if all_revealed {
+			Self::settle_result_commitments(job_id, execution_index, requirements.slots)?;
+		}
+
+		Ok(())
+	}
+
+	/// Settles the results committed for an execution once its reveal phase is over.
+	pub(crate) fn do_settle_results(
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+	) -> DispatchResult {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		let (_, reveal_end) = Self::result_reveal_window(&registration, execution_index)?;
+		ensure!(Self::now()? >= reveal_end, Error::<T>::ResultRevealPhaseNotOver);
+		ensure!(
+			<ResultCommitments<T>>::contains_key(job_id, execution_index),
+			Error::<T>::ResultNotCommitted
+		);
+
+		let e: <T as Config>::RegistrationExtra = registration.extra.into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		Self::settle_result_commitments(job_id, execution_index, requirements.slots)
+	}
+
+	/// The window `[start, end)` in which the results committed for an execution get revealed.
+	///
+	/// It starts once the report windows of all slots closed, independent of the start delay a slot got matched with.
+	pub(crate) fn result_reveal_window(
+		registration: &JobRegistrationFor<T>,
+		execution_index: u64,
+	) -> Result<(u64, u64), DispatchError> {
+		let reveal_start = registration
+			.schedule
+			.nth_start_time(registration.schedule.max_start_delay, execution_index)
+			.ok_or(Error::<T>::ReportOutsideSchedule)?
+			.saturating_add(registration.schedule.duration)
+			.saturating_add(T::ReportTolerance::get());
+		Ok((reveal_start, reveal_start.saturating_add(T::ResultRevealPeriod::get())))
+	}
+
+	/// Settles the results committed for an execution.
+	///
+	/// A result revealed by more than half of the job's `slots` becomes the execution's consensus result. If some slots did not
+	/// report, a result revealed by all processors that reported is accepted as well. Processors that revealed the consensus
+	/// result get paid; all others are not paid, get a reputation hit and are recorded in [`ResultDisagreements`].
+	///
+	/// Without a consensus result, all results are recorded as disagreements. If all slots reported, nobody gets paid. Otherwise
+	/// the slots that did not report prevent a majority, so the processors that revealed a result get paid nonetheless.
+	/// Processors that did not reveal their committed result are never paid and get a reputation hit.
+	pub(crate) fn settle_result_commitments(
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+		slots: u8,
+	) -> DispatchResult {
+		let commitments = <ResultCommitments<T>>::take(job_id, execution_index);
+		let all_reported = commitments.len() >= slots as usize;
+
+		let revealed = || commitments.iter().filter_map(|c| c.result.as_ref());
+		let majority = revealed()
+			.find(|result| revealed().filter(|r| r == result).count() > (slots / 2) as usize)
+			.cloned();
+		let consensus_result = majority.or_else(|| {
+			let first = revealed().next()?;
+			(!all_reported && revealed().all(|r| r == first)).then(|| first.clone())
+		});
+
+		match consensus_result {
+			Some(consensus_result) => {
+				<ConsensusResults<T>>::insert(job_id, execution_index, &consensus_result);
+				Self::deposit_event(Event::ExecutionConsensusReached(
+					job_id.clone(),
+					execution_index,
+					consensus_result.clone(),
+				));
+				for commitment in commitments {
+					Self::settle_result_commitment(
+						job_id,
+						execution_index,
+						&consensus_result,
+						commitment,
+					)?;
+				}
+			},
+			None if !commitments.is_empty() => {
+				for commitment in commitments.iter() {
+					if commitment.result.is_some() {
+						if !all_reported {
+							T::RewardManager::handle_reward(job_id, commitment.fee)?;
+						}
+					} else if commitment.commitment.is_some() {
+						Self::do_update_reputation_with(
+							&commitment.processor,
+							0,
+							1,
+							commitment.fee,
+						)?;
+					}
+				}
+				<ResultDisagreements<T>>::insert(job_id, execution_index, commitments);
+				Self::deposit_event(Event::ExecutionConsensusFailed(
+					job_id.clone(),
+					execution_index,
+				));
+			},
+			None => {},
+		}
+
+		Ok(())
+	}
+
+	/// Pays a committed result agreeing with the consensus result, otherwise records it as a disagreement.
+	fn settle_result_commitment(
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+		consensus_result: &ExecutionOperationHash,
+		commitment: ResultCommitmentFor<T>,
+	) -> DispatchResult {
+		if commitment.result.as_ref() == Some(consensus_result) {
+			return T::RewardManager::handle_reward(job_id, commitment.fee);
+		}
+
+		Self::do_update_reputation_with(&commitment.processor, 0, 1, commitment.fee)?;
+		Self::deposit_event(Event::ExecutionResultDisagreed(
+			job_id.clone(),
+			execution_index,
+			commitment.processor.clone(),
+		));
+		<ResultDisagreements<T>>::try_mutate(job_id, execution_index, |disagreements| {
+			disagreements.try_push(commitment).map_err(|_| Error::<T>::TooManySlots)
+		})?;
+
+		Ok(())
+	}
+
+	/// Settles the pending result commitments of a job, then removes all its result commitments, consensus results and disagreements.
+	///
+	/// Called before the job's remaining budget is refunded, so processors that reported are paid even if nobody settled their results.
+	pub(crate) fn clear_result_commitments(
+		job_id: &JobId<T::AccountId>,
+		slots: u8,
+		execution_count: u32,
+	) -> DispatchResult {
+		let pending: Vec<u64> = <ResultCommitments<T>>::iter_key_prefix(job_id).collect();
+		for execution_index in pending {
+			Self::settle_result_commitments(job_id, execution_index, slots)?;
+		}
+		let _ = <ResultCommitments<T>>::clear_prefix(job_id, execution_count, None);
+		let _ = <ConsensusResults<T>>::clear_prefix(job_id, execution_count, None);
+		let _ = <ResultDisagreements<T>>::clear_prefix(job_id, execution_count, None);
+		Ok(())
+	}
+}
@@ -1,4 +1,4 @@
-use crate::{Config, Error};
+use crate::{Config, Error, MatchingCheckFailure};
 
 impl<T> From<pallet_acurast::Error<T>> for Error<T> {
 	fn from(e: pallet_acurast::Error<T>) -> Self {
@@ -11,3 +11,27 @@ impl<T: Config> From<Error<T>> for pallet_acurast::Error<T> {
 		Self::JobHookFailed
 	}
 }
+
+impl<T> From<Error<T>> for MatchingCheckFailure {
+	fn from(e: Error<T>) -> Self {
+		match e {
+			Error::<T>::UnverifiedSourceInMatch => Self::UnverifiedSource,
+			Error::<T>::AdvertisementNotFound => Self::AdvertisementNotFound,
+			Error::<T>::ModuleNotAvailableInMatch => Self::ModuleNotAvailable,
+			Error::<T>::NetworkRequestQuotaExceededInMatch => Self::NetworkRequestQuotaExceeded,
+			Error::<T>::SourceNotAllowedInMatch => Self::SourceNotAllowed,
+			Error::<T>::ConsumerNotAllowedInMatch => Self::ConsumerNotAllowed,
+			Error::<T>::InsufficientReputationInMatch | Error::<T>::ReputationNotFound => {
+				Self::InsufficientReputation
+			},
+			Error::<T>::ProcessorVersionMismatch => Self::ProcessorVersionMismatch,
+			Error::<T>::ProcessorMinMetricsNotMet(pool_id) => {
+				Self::ProcessorMinMetricsNotMet(pool_id)
+			},
+			Error::<T>::ScheduleOverlapInMatch => Self::ScheduleOverlap,
+			Error::<T>::InsufficientRewardInMatch => Self::InsufficientReward,
+			Error::<T>::UnsupportedPaymentAsset => Self::UnsupportedPaymentAsset,
+			_ => Self::Other,
+		}
+	}
+}
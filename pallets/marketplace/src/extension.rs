@@ -0,0 +1,301 @@
+use frame_support::{ensure, pallet_prelude::*};
+use pallet_acurast::{JobId, JobRegistrationFor, RequiredMinMetrics, StoredJobRegistration};
+use sp_std::prelude::*;
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Extends the schedule of a job up to `end_time`, optionally raising the reward per execution, and locks the additional budget.
+	///
+	/// The raised reward only applies to the executions added by the extension, so only their budget is locked.
+	///
+	/// For an already matched job with [`AssignmentStrategy::Single`], a [`JobExtension`] is created so that each processor
+	/// assigned until the current end of the job accepts or declines the extended range within [`Config::ExtensionAcceptPeriod`].
+	/// Slots without such a processor are directly open for re-matching.
+	pub(crate) fn do_extend_job(
+		job_id: &JobId<T::AccountId>,
+		end_time: u64,
+		reward: Option<T::Balance>,
+	) -> DispatchResult {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		let job_status =
+			<StoredJobStatus<T>>::get(&job_id.0, job_id.1).ok_or(Error::<T>::JobStatusNotFound)?;
+		ensure!(!<StoredJobExtension<T>>::contains_key(job_id), Error::<T>::JobExtensionInProgress);
+
+		let now = Self::now()?;
+		ensure!(now < registration.schedule.end_time, Error::<T>::JobExtensionAfterEnd);
+		ensure!(end_time > registration.schedule.end_time, Error::<T>::JobExtensionEndTimeNotLater);
+
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let mut requirements: JobRequirementsFor<T> = e.into();
+		if let Some(reward) = reward {
+			ensure!(reward >= requirements.reward, Error::<T>::JobExtensionRewardDecreased);
+			requirements.reward = reward;
+		}
+		let slots = requirements.slots;
+		let is_single = matches!(requirements.assignment_strategy, AssignmentStrategy::Single(_));
+
+		let mut extended = registration.clone();
+		extended.schedule.end_time = end_time;
+		let e: <T as Config>::RegistrationExtra = requirements.into();
+		extended.extra = e.into();
+
+		let first_execution_index = registration.schedule.execution_count();
+		let additional_budget = Self::total_budget_amount(
+			job_id,
+			&Self::registration_from(&extended, first_execution_index)?,
+		)?;
+		T::RewardManager::lock_reward(job_id, additional_budget)?;
+
+		if is_single && job_status != JobStatus::Open {
+			let mut pending = Vec::new();
+			let mut assigned_slots = Vec::new();
+			for (processor, _) in <AssignedProcessors<T>>::iter_prefix(job_id) {
+				let Some(assignment) = <StoredMatches<T>>::get(&processor, job_id) else {
+					continue;
+				};
+				// processors that declined a previous extension are not assigned until the current end
+				let (_, end) = Self::assignment_execution_range(job_id, &processor, &assignment);
+				if end < first_execution_index {
+					continue;
+				}
+				assigned_slots.push(assignment.slot);
+				pending.push(processor);
+			}
+			let declined_slots: Vec<u8> =
+				(0..slots).filter(|slot| !assigned_slots.contains(slot)).collect();
+
+			// leave time to re-match the slots of processors that did not respond
+			let accept_deadline = now.saturating_add(T::ExtensionAcceptPeriod::get());
+			ensure!(
+				accept_deadline
+					< Self::registration_from(&extended, first_execution_index)?
+						.schedule
+						.start_time,
+				Error::<T>::JobExtensionTooLate
+			);
+
+			<StoredJobExtension<T>>::insert(
+				job_id,
+				JobExtension {
+					first_execution_index,
+					accept_deadline,
+					pending: pending.try_into().map_err(|_| Error::<T>::TooManySlots)?,
+					declined_slots: declined_slots
+						.try_into()
+						.map_err(|_| Error::<T>::TooManySlots)?,
+				},
+			);
+		}
+
+		<StoredJobRegistration<T>>::insert(&job_id.0, job_id.1, extended);
+
+		Ok(())
+	}
+
+	/// Accepts a pending job extension for an assigned processor after checking the extended range still fits its advertisement and schedule.
+	pub(crate) fn do_accept_job_extension(
+		processor: &T::AccountId,
+		job_id: &JobId<T::AccountId>,
+	) -> DispatchResult {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+
+		<StoredJobExtension<T>>::try_mutate_exists(job_id, |maybe_extension| {
+			let extension = maybe_extension.as_mut().ok_or(Error::<T>::JobExtensionNotFound)?;
+			ensure!(
+				Self::now()? < extension.accept_deadline,
+				Error::<T>::JobExtensionAcceptDeadlinePassed
+			);
+			let position = extension
+				.pending
+				.iter()
+				.position(|p| p == processor)
+				.ok_or(Error::<T>::JobExtensionNotPending)?;
+			let assignment = <StoredMatches<T>>::get(processor, job_id)
+				.ok_or(Error::<T>::JobExtensionNotPending)?;
+
+			let extension_registration =
+				Self::registration_from(&registration, extension.first_execution_index)?;
+			ensure!(
+				Self::now()? < extension_registration.schedule.actual_start(assignment.start_delay),
+				Error::<T>::CannotAcknowledgeAfterStartTime
+			);
+			Self::check_extension_fits(
+				processor,
+				job_id,
+				&extension_registration,
+				assignment.start_delay,
+			)?;
+
+			let (first, _) = Self::assignment_execution_range(job_id, processor, &assignment);
+			<StoredMatches<T>>::mutate(processor, job_id, |a| {
+				if let Some(a) = a.as_mut() {
+					a.sla.total = registration.schedule.execution_count().saturating_sub(first);
+				}
+			});
+
+			extension.pending.remove(position);
+			if extension.is_completed() {
+				*maybe_extension = None;
+			}
+
+			Ok(())
+		})
+	}
+
+	/// Declines a pending job extension for an assigned processor, leaving its slot open for re-matching of the extended range.
+	///
+	/// The processor stays assigned for the executions before the extension, but its slot is no longer counted in
+	/// [`JobStatus::Assigned`] until the processor matched for the extended range acknowledges.
+	pub(crate) fn do_decline_job_extension(
+		processor: &T::AccountId,
+		job_id: &JobId<T::AccountId>,
+	) -> DispatchResult {
+		<StoredJobExtension<T>>::try_mutate(job_id, |maybe_extension| {
+			let extension = maybe_extension.as_mut().ok_or(Error::<T>::JobExtensionNotFound)?;
+			ensure!(
+				Self::now()? < extension.accept_deadline,
+				Error::<T>::JobExtensionAcceptDeadlinePassed
+			);
+			let position = extension
+				.pending
+				.iter()
+				.position(|p| p == processor)
+				.ok_or(Error::<T>::JobExtensionNotPending)?;
+			let assignment = <StoredMatches<T>>::get(processor, job_id)
+				.ok_or(Error::<T>::JobExtensionNotPending)?;
+
+			extension.pending.remove(position);
+			extension
+				.declined_slots
+				.try_push(assignment.slot)
+				.map_err(|_| Error::<T>::TooManySlots)?;
+			if assignment.acknowledged {
+				Self::release_assigned_slot(job_id);
+			}
+
+			Ok(())
+		})
+	}
+
+	/// Matches the declined slots of pending job extensions with new processors, assigned for the extended range only.
+	///
+	/// The sources of a [`Match`] are assigned to the declined slots in the order the slots got declined. Once the accept deadline
+	/// passed, the slots of processors that did not respond are appended as declined, in the order the processors got assigned.
+	pub(crate) fn process_extension_matching<'a>(
+		matching: impl IntoIterator<Item = &'a MatchFor<T>>,
+		matcher_account: &T::AccountId,
+	) -> DispatchResult {
+		for m in matching {
+			let registration = <StoredJobRegistration<T>>::get(&m.job_id.0, m.job_id.1)
+				.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+			let mut extension =
+				<StoredJobExtension<T>>::get(&m.job_id).ok_or(Error::<T>::JobExtensionNotFound)?;
+			Self::decline_unresponsive(&m.job_id, &mut extension)?;
+
+			ensure!(
+				m.sources.len() == extension.declined_slots.len(),
+				Error::<T>::IncorrectSourceCountInMatch
+			);
+
+			let extension_registration =
+				Self::registration_from(&registration, extension.first_execution_index)?;
+			// CHECK that matching happens before the extended range starts
+			ensure!(
+				Self::now()? < extension_registration.schedule.start_time,
+				Error::<T>::OverdueMatch
+			);
+
+			let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+			let requirements: JobRequirementsFor<T> = e.into();
+			let min_metrics = <RequiredMinMetrics<T>>::get(&m.job_id);
+			let execution_count = extension_registration.schedule.execution_count();
+
+			for (slot, planned_execution) in extension.declined_slots.iter().zip(m.sources.iter()) {
+				let fee_per_execution = Self::check_source_matches(
+					&planned_execution.source,
+					&m.job_id.0,
+					&extension_registration,
+					&requirements,
+					min_metrics.as_ref(),
+					ExecutionSpecifier::All,
+					planned_execution.start_delay,
+				)?;
+
+				<StoredMatches<T>>::try_mutate(
+					&planned_execution.source,
+					&m.job_id,
+					|s| -> Result<(), Error<T>> {
+						ensure!(s.is_none(), Error::<T>::DuplicateSourceInMatch);
+						*s = Some(Assignment {
+							slot: *slot,
+							execution: ExecutionSpecifier::All,
+							start_delay: planned_execution.start_delay,
+							fee_per_execution,
+							acknowledged: false,
+							sla: SLA { total: execution_count, met: 0 },
+							pub_keys: PubKeys::default(),
+						});
+						Ok(())
+					},
+				)?;
+				<ExtendedAssignmentStart<T>>::insert(
+					&m.job_id,
+					&planned_execution.source,
+					extension.first_execution_index,
+				);
+				<AssignedProcessors<T>>::insert(&m.job_id, &planned_execution.source, ());
+			}
+			<JobMatcher<T>>::insert(&m.job_id, matcher_account);
+
+			extension.declined_slots = Default::default();
+			if extension.is_completed() {
+				<StoredJobExtension<T>>::remove(&m.job_id);
+			} else {
+				<StoredJobExtension<T>>::insert(&m.job_id, extension);
+			}
+
+			Self::deposit_event(Event::JobExtensionMatched(m.job_id.clone()));
+		}
+		Ok(())
+	}
+
+	/// Counts the processors still pending to respond to `extension` as declined once its accept deadline passed.
+	fn decline_unresponsive(
+		job_id: &JobId<T::AccountId>,
+		extension: &mut JobExtensionFor<T>,
+	) -> DispatchResult {
+		if Self::now()? < extension.accept_deadline {
+			return Ok(());
+		}
+		for processor in core::mem::take(&mut extension.pending) {
+			let Some(assignment) = <StoredMatches<T>>::get(&processor, job_id) else {
+				continue;
+			};
+			extension
+				.declined_slots
+				.try_push(assignment.slot)
+				.map_err(|_| Error::<T>::TooManySlots)?;
+			if assignment.acknowledged {
+				Self::release_assigned_slot(job_id);
+			}
+			Self::deposit_event(Event::JobExtensionDeclined(job_id.clone(), processor));
+		}
+		Ok(())
+	}
+
+	/// Returns the registration restricted to the range of executions starting at `first_execution_index`.
+	pub(crate) fn registration_from(
+		registration: &JobRegistrationFor<T>,
+		first_execution_index: u64,
+	) -> Result<JobRegistrationFor<T>, Error<T>> {
+		let mut extension_registration = registration.clone();
+		extension_registration.schedule.start_time = registration
+			.schedule
+			.nth_start_time(0, first_execution_index)
+			.ok_or(Error::<T>::CalculationOverflow)?;
+		Ok(extension_registration)
+	}
+}
@@ -1,8 +1,16 @@
+use acurast_common::Slashable;
 use frame_support::{
-	ensure, pallet_prelude::DispatchResult, sp_runtime::DispatchError, traits::IsSubType,
+	ensure,
+	pallet_prelude::DispatchResult,
+	sp_runtime::{traits::Saturating, DispatchError, Percent, SaturatedConversion},
+	storage::with_storage_layer,
+	traits::{fungible::Balanced, tokens::imbalance::Imbalance, IsSubType},
+	weights::Weight,
 };
 use pallet_acurast::{
-	utils::ensure_source_verified, IsFundableCall, JobId, JobRegistrationFor, StoredJobRegistration,
+	utils::{ensure_source_verified, ensure_source_verified_weight},
+	IsFundableCall, JobId, JobIdSequence, JobRegistrationFor, MultiOrigin, Schedule,
+	StoredJobRegistration,
 };
 use reputation::{BetaParameters, BetaReputation, ReputationEngine};
 use sp_core::Get;
@@ -10,11 +18,32 @@ use sp_std::prelude::*;
 
 use crate::{
 	AdvertisementFor, AdvertisementRestriction, AssignedProcessors, AssignmentFor, Call, Config,
-	Error, ExecutionSpecifier, NextReportIndex, Pallet, RewardManager, StoredAdvertisementPricing,
-	StoredAdvertisementRestriction, StoredAverageRewardV3, StoredMatches, StoredReputation,
+	Error, Event, ExecutionCandidates, ExecutionResult, ExecutionSpecifier,
+	ExtendedAssignmentStart, JobBudgets, JobKeyIds, JobMatcher, JobRequirementsFor, JobStatus,
+	JobViewAssignment, JobViewBudget, JobViewFor, LapsedProcessors, MarketplaceHooks,
+	NextReportIndex, Pallet, PaymentAssetId, RematchableSlots, ResultDeliveryFees, RewardManager,
+	StoredAdvertisementPricing, StoredAdvertisementRestriction, StoredAdvertisementUnavailability,
+	StoredAverageRewardV3, StoredJobExecutionStatus, StoredJobExtension, StoredJobStatus,
+	StoredMatches, StoredReputation, UnavailabilityWindows, MAX_JOB_VIEWS_PER_PAGE,
 };
 
 impl<T: Config> Pallet<T> {
+	/// The weight of the attestation checks for up to [pallet_acurast::Config::MaxSlots] sources of each of `matches`, not covered by the benchmarks.
+	pub(crate) fn attestation_checks_weight(matches: u32) -> Weight {
+		let sources =
+			(matches as u64).saturating_mul(<T as pallet_acurast::Config>::MaxSlots::get() as u64);
+		ensure_source_verified_weight::<T>().saturating_mul(sources)
+	}
+
+	/// The weight of delivering a reported result to the job's origin, not covered by the benchmarks.
+	///
+	/// Accounts for reading the reserved fee, paying it to the delivery payer and sending the result.
+	pub(crate) fn result_delivery_weight() -> Weight {
+		T::DbWeight::get()
+			.reads_writes(3, 2)
+			.saturating_add(T::MarketplaceHooks::deliver_result_weight())
+	}
+
 	pub fn do_advertise(
 		processor: &T::AccountId,
 		advertisement: &AdvertisementFor<T>,
@@ -49,16 +78,42 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Stores the recurring windows in which `processor` is not available to execute jobs, replacing the previous ones.
+	///
+	/// An empty `unavailability` removes them.
+	pub fn do_set_advertisement_unavailability(
+		processor: &T::AccountId,
+		unavailability: UnavailabilityWindows,
+	) -> DispatchResult {
+		ensure!(
+			<StoredAdvertisementRestriction<T>>::contains_key(processor),
+			Error::<T>::AdvertisementNotFound
+		);
+		ensure!(
+			unavailability.iter().all(|window| window.is_valid()),
+			Error::<T>::InvalidUnavailabilityWindow
+		);
+
+		if unavailability.is_empty() {
+			<StoredAdvertisementUnavailability<T>>::remove(processor);
+		} else {
+			<StoredAdvertisementUnavailability<T>>::insert(processor, unavailability);
+		}
+
+		Ok(())
+	}
+
 	pub fn do_report(
 		job_id: &JobId<T::AccountId>,
 		processor: &T::AccountId,
+		execution_result: &ExecutionResult,
 	) -> Result<AssignmentFor<T>, DispatchError> {
 		let assignment = Self::update_assignment(processor, job_id)?;
 
 		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
 			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
 
-		let (missing_reports, next_expected_report_index) =
+		let (missing_reports, reported_index, next_expected_report_index) =
 			Self::update_next_report_index_on_report(
 				job_id,
 				processor,
@@ -66,30 +121,83 @@ impl<T: Config> Pallet<T> {
 				&assignment,
 			)?;
 
-		T::RewardManager::handle_reward(job_id, assignment.fee_per_execution)?;
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		if requirements.consensus {
+			Self::do_commit_result(
+				job_id,
+				processor,
+				&assignment,
+				&registration,
+				reported_index,
+				execution_result,
+			)?;
+		} else {
+			T::RewardManager::handle_reward(job_id, assignment.fee_per_execution)?;
+		}
+
+		// results of jobs requiring consensus are delivered once revealed
+		if requirements.deliver_results_to_origin && !requirements.consensus {
+			Self::do_deliver_result(job_id, processor, execution_result);
+		}
 
 		Self::do_update_reputation(processor, &assignment, missing_reports)?;
 
 		// if this is the last report, do cleanup
 		if next_expected_report_index.is_none() {
+			Self::do_slash_for_sla(job_id, processor, &assignment);
 			<StoredMatches<T>>::remove(processor, job_id);
 			<AssignedProcessors<T>>::remove(job_id, processor);
+			<ExtendedAssignmentStart<T>>::remove(job_id, processor);
 		}
 
 		Ok(assignment)
 	}
 
+	/// Sends a reported result back to the origin of a job and pays the delivery fee reserved at registration from the job's budget.
+	///
+	/// A failing delivery does not fail the report, it gets reverted and signaled with [`Event::ExecutionResultDeliveryFailed`].
+	pub(crate) fn do_deliver_result(
+		job_id: &JobId<T::AccountId>,
+		processor: &T::AccountId,
+		execution_result: &ExecutionResult,
+	) {
+		let delivery = with_storage_layer(|| -> Result<T::Balance, DispatchError> {
+			let fee = <ResultDeliveryFees<T>>::get(job_id)
+				.ok_or(Error::<T>::ResultDeliveryUnsupported)?;
+			let (payer, _) = T::MarketplaceHooks::result_delivery_fee(job_id)
+				.ok_or(Error::<T>::ResultDeliveryUnsupported)?;
+			T::RewardManager::pay_result_delivery_fee(job_id, fee, &payer)?;
+			T::MarketplaceHooks::deliver_result(job_id, execution_result).map_err(|e| e.error)?;
+			Ok(fee)
+		});
+
+		match delivery {
+			Ok(fee) => Self::deposit_event(Event::ExecutionResultDelivered(
+				job_id.clone(),
+				processor.clone(),
+				fee,
+			)),
+			Err(error) => Self::deposit_event(Event::ExecutionResultDeliveryFailed(
+				job_id.clone(),
+				processor.clone(),
+				error,
+			)),
+		}
+	}
+
 	fn update_next_report_index_on_report(
 		job_id: &JobId<T::AccountId>,
 		processor: &T::AccountId,
 		registration: &JobRegistrationFor<T>,
 		assignment: &AssignmentFor<T>,
-	) -> Result<(u64, Option<u64>), DispatchError> {
+	) -> Result<(u64, u64, Option<u64>), DispatchError> {
 		let now = Self::now()?;
 		let execution_index = registration
 			.schedule
 			.current_execution_index(assignment.start_delay, now)
 			.unwrap_or(0);
+		let (_, end) = Self::assignment_execution_range(job_id, processor, assignment);
 		<NextReportIndex<T>>::try_mutate_exists(job_id, processor, |value| {
 			let mut missing_reports = 0;
 			let mut expected_report_index = (*value).unwrap_or(execution_index);
@@ -109,7 +217,7 @@ impl<T: Config> Pallet<T> {
 				ExecutionSpecifier::All => {
 					let next_expected_report_index = expected_report_index + 1;
 
-					*value = if next_expected_report_index < assignment.sla.total {
+					*value = if next_expected_report_index < end {
 						Some(next_expected_report_index)
 					} else {
 						None
@@ -120,7 +228,7 @@ impl<T: Config> Pallet<T> {
 				},
 			}
 
-			Ok::<_, DispatchError>((missing_reports, *value))
+			Ok::<_, DispatchError>((missing_reports, expected_report_index, *value))
 		})
 	}
 
@@ -177,10 +285,24 @@ impl<T: Config> Pallet<T> {
 		processor: &T::AccountId,
 		assignment: &AssignmentFor<T>,
 		missing_reports: u64,
+	) -> Result<(), DispatchError> {
+		Self::do_update_reputation_with(
+			processor,
+			assignment.sla.met,
+			missing_reports,
+			assignment.fee_per_execution,
+		)
+	}
+
+	pub(crate) fn do_update_reputation_with(
+		processor: &T::AccountId,
+		successes: u64,
+		failures: u64,
+		fee_per_execution: T::Balance,
 	) -> Result<(), DispatchError> {
 		if ensure_source_verified::<T>(processor).is_ok() {
 			// skip reputation update if reward is 0
-			if assignment.fee_per_execution > 0u8.into() {
+			if fee_per_execution > 0u8.into() {
 				let average_reward = <StoredAverageRewardV3<T>>::get().unwrap_or_default();
 
 				let mut beta_params =
@@ -188,9 +310,9 @@ impl<T: Config> Pallet<T> {
 
 				beta_params = BetaReputation::update(
 					beta_params,
-					assignment.sla.met,
-					missing_reports,
-					assignment.fee_per_execution,
+					successes,
+					failures,
+					fee_per_execution,
 					average_reward.into(),
 				)
 				.ok_or(Error::<T>::CalculationOverflow)?;
@@ -204,6 +326,78 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Slashes a processor whose concluded assignment met less than [`Config::SlaSlashThreshold`] of its SLA.
+	///
+	/// Only acknowledged [`ExecutionSpecifier::All`] assignments are considered. The amount slashed is the fee of all missed executions,
+	/// of which a [`Config::SlaSlashConsumerShare`] is paid to the job's consumer if the consumer has an Acurast account. The rest is burned.
+	pub(crate) fn do_slash_for_sla(
+		job_id: &JobId<T::AccountId>,
+		processor: &T::AccountId,
+		assignment: &AssignmentFor<T>,
+	) {
+		if !assignment.acknowledged
+			|| !matches!(assignment.execution, ExecutionSpecifier::All)
+			|| assignment.sla.total == 0
+		{
+			return;
+		}
+		if Percent::from_rational(assignment.sla.met, assignment.sla.total)
+			>= T::SlaSlashThreshold::get()
+		{
+			return;
+		}
+
+		let missed_reports = assignment.sla.total.saturating_sub(assignment.sla.met);
+		let amount: u128 =
+			assignment.fee_per_execution.saturating_mul(missed_reports.into()).into();
+		let Some((slashed, paid_to_consumer)) = Self::do_slash(job_id, processor, amount) else {
+			return;
+		};
+
+		Self::deposit_event(Event::ProcessorSlashedForSla(
+			job_id.clone(),
+			processor.clone(),
+			assignment.sla,
+			slashed.into(),
+			paid_to_consumer.into(),
+		));
+	}
+
+	/// Slashes up to `amount` from a processor, of which a [`Config::SlaSlashConsumerShare`] is paid to the job's consumer
+	/// if the consumer has an Acurast account. The rest is burned.
+	///
+	/// Returns the amount slashed and the amount paid to the consumer, or `None` if nothing got slashed.
+	pub(crate) fn do_slash(
+		job_id: &JobId<T::AccountId>,
+		processor: &T::AccountId,
+		amount: u128,
+	) -> Option<(u128, u128)> {
+		if amount == 0 {
+			return None;
+		}
+		let mut credit = T::Slashable::slash(processor, amount.saturated_into())?;
+		let slashed: u128 = credit.peek().saturated_into();
+
+		let mut paid_to_consumer: u128 = 0;
+		if let MultiOrigin::Acurast(consumer) = &job_id.0 {
+			let consumer_share = T::SlaSlashConsumerShare::get().mul_floor(slashed);
+			let consumer_credit = credit.extract(consumer_share.saturated_into());
+			let consumer_amount: u128 = consumer_credit.peek().saturated_into();
+			// if resolving fails, merge back so the consumer's share gets burned together with the rest
+			match <<T::Slashable as Slashable<T::AccountId>>::Currency as Balanced<
+				T::AccountId,
+			>>::resolve(consumer, consumer_credit)
+			{
+				Ok(()) => paid_to_consumer = consumer_amount,
+				Err(remaining) => credit = credit.merge(remaining),
+			}
+		}
+		// burn the remaining slashed amount
+		drop(credit);
+
+		Some((slashed, paid_to_consumer))
+	}
+
 	pub(crate) fn do_cleanup_assignments(
 		processor: &T::AccountId,
 		job_ids: &[JobId<T::AccountId>],
@@ -226,14 +420,259 @@ impl<T: Config> Pallet<T> {
 		let Some(job) = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1) else {
 			<StoredMatches<T>>::remove(processor, job_id);
 			<AssignedProcessors<T>>::remove(job_id, processor);
+			<ExtendedAssignmentStart<T>>::remove(job_id, processor);
 			return Ok(());
 		};
-		if assignment.is_invalid(&job.schedule, now, T::ReportTolerance::get()) {
+		let schedule = Self::assignment_schedule(job_id, processor, &assignment, &job.schedule);
+		if assignment.is_invalid(&schedule, now, T::ReportTolerance::get()) {
+			Self::do_slash_for_sla(job_id, processor, &assignment);
 			<StoredMatches<T>>::remove(processor, job_id);
 			<AssignedProcessors<T>>::remove(job_id, processor);
+			<ExtendedAssignmentStart<T>>::remove(job_id, processor);
 		}
 		Ok(())
 	}
+
+	/// Settles the budget of a job that gets cancelled and removes all its marketplace related data.
+	///
+	/// The final act of removing the job from [`StoredJobRegistration`] is the responsibility of the caller,
+	/// since this storage point is owned by pallet_acurast.
+	///
+	/// Returns the compensation paid per acknowledged processor, the cancellation fee and the refunded amount.
+	#[allow(clippy::type_complexity)]
+	pub(crate) fn do_cancel_job(
+		job_id: &JobId<T::AccountId>,
+	) -> Result<(Vec<(T::AccountId, T::Balance)>, T::Balance, T::Balance), DispatchError> {
+		let job_status =
+			<StoredJobStatus<T>>::get(&job_id.0, job_id.1).ok_or(Error::<T>::JobStatusNotFound)?;
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		let now = Self::now()?;
+		let notice_deadline = now.saturating_add(T::CancellationNoticePeriod::get());
+
+		// Settle reported results first, so processors get paid for them before the budget is refunded
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		Self::clear_result_commitments(
+			job_id,
+			requirements.slots,
+			registration.schedule.execution_count() as u32,
+		)?;
+
+		// Compensate acknowledged processors and clear matching data
+		let mut compensations = Vec::new();
+		for (processor, _) in <AssignedProcessors<T>>::drain_prefix(job_id) {
+			let Some(assignment) = <StoredMatches<T>>::take(&processor, job_id) else {
+				continue;
+			};
+			let execution_range = Self::assignment_execution_range(job_id, &processor, &assignment);
+			<ExtendedAssignmentStart<T>>::remove(job_id, &processor);
+			if !assignment.acknowledged {
+				continue;
+			}
+			let executions = Self::executions_to_compensate(
+				&registration,
+				&assignment,
+				execution_range,
+				<NextReportIndex<T>>::get(job_id, &processor),
+				now,
+				notice_deadline,
+			);
+			let compensation = assignment
+				.fee_per_execution
+				.saturating_mul(executions.into())
+				.min(<JobBudgets<T>>::get(job_id));
+			if compensation > 0u8.into() {
+				T::RewardManager::handle_reward(job_id, compensation)?;
+				compensations.push((processor, compensation));
+			}
+		}
+
+		let cancellation_fee = match job_status {
+			JobStatus::Assigned(_) => {
+				T::CancellationFee::get().mul_floor(<JobBudgets<T>>::get(job_id))
+			},
+			JobStatus::Open | JobStatus::Matched => 0u8.into(),
+		};
+		T::RewardManager::pay_cancellation_fee(job_id, cancellation_fee)?;
+
+		let refund = T::RewardManager::refund(job_id)?;
+		T::MarketplaceHooks::finalize_job(job_id, refund)?;
+
+		<JobKeyIds<T>>::remove(job_id);
+		<StoredJobStatus<T>>::remove(&job_id.0, job_id.1);
+		let _ = <StoredJobExecutionStatus<T>>::clear_prefix(
+			job_id,
+			registration.schedule.execution_count() as u32,
+			None,
+		);
+		let _ = <NextReportIndex<T>>::clear_prefix(
+			job_id,
+			<T as pallet_acurast::Config>::MaxSlots::get(),
+			None,
+		);
+		<JobMatcher<T>>::remove(job_id);
+		<StoredJobExtension<T>>::remove(job_id);
+		<ResultDeliveryFees<T>>::remove(job_id);
+		let _ = <RematchableSlots<T>>::clear_prefix(
+			job_id,
+			<T as pallet_acurast::Config>::MaxSlots::get(),
+			None,
+		);
+		let _ = <LapsedProcessors<T>>::clear_prefix(job_id, u32::MAX, None);
+		let _ = <ExecutionCandidates<T>>::clear_prefix(
+			job_id,
+			registration.schedule.execution_count() as u32,
+			None,
+		);
+
+		Ok((compensations, cancellation_fee, refund))
+	}
+
+	/// Counts the unreported executions of an acknowledged assignment that can still be reported at `now` and start before `notice_deadline`.
+	fn executions_to_compensate(
+		registration: &JobRegistrationFor<T>,
+		assignment: &AssignmentFor<T>,
+		execution_range: (u64, u64),
+		next_report_index: Option<u64>,
+		now: u64,
+		notice_deadline: u64,
+	) -> u64 {
+		// no further report is expected
+		let Some(next_report_index) = next_report_index else {
+			return 0;
+		};
+		let schedule = &registration.schedule;
+		let (first, end) = match assignment.execution {
+			ExecutionSpecifier::All => (next_report_index, execution_range.1),
+			ExecutionSpecifier::Index(index) => {
+				if next_report_index > index {
+					return 0;
+				}
+				(index, index.saturating_add(1))
+			},
+		};
+		let report_window = schedule.duration.saturating_add(T::ReportTolerance::get());
+		// skip the executions whose report window is already over
+		let first = first.max(
+			schedule
+				.current_execution_index(assignment.start_delay, now.saturating_sub(report_window))
+				.unwrap_or(0),
+		);
+
+		(first..end)
+			.map_while(|index| schedule.nth_start_time(assignment.start_delay, index))
+			.take_while(|start| *start < notice_deadline)
+			.filter(|start| start.saturating_add(report_window) >= now)
+			.count() as u64
+	}
+
+	/// Returns the range `[first, end)` of execution indices covered by an assignment.
+	///
+	/// For [`ExecutionSpecifier::All`], this differs from the job's full range only for jobs extended after being matched.
+	pub(crate) fn assignment_execution_range(
+		job_id: &JobId<T::AccountId>,
+		processor: &T::AccountId,
+		assignment: &AssignmentFor<T>,
+	) -> (u64, u64) {
+		match assignment.execution {
+			ExecutionSpecifier::All => {
+				let first = <ExtendedAssignmentStart<T>>::get(job_id, processor).unwrap_or(0);
+				(first, first.saturating_add(assignment.sla.total))
+			},
+			ExecutionSpecifier::Index(index) => (index, index.saturating_add(1)),
+		}
+	}
+
+	/// Returns the part of a job's `schedule` covered by an assignment, see [`Self::assignment_execution_range`].
+	pub(crate) fn assignment_schedule(
+		job_id: &JobId<T::AccountId>,
+		processor: &T::AccountId,
+		assignment: &AssignmentFor<T>,
+		schedule: &Schedule,
+	) -> Schedule {
+		let mut assignment_schedule = schedule.clone();
+		if let ExecutionSpecifier::All = assignment.execution {
+			let (first, end) = Self::assignment_execution_range(job_id, processor, assignment);
+			if let Some(start_time) = schedule.nth_start_time(0, first) {
+				assignment_schedule.start_time = start_time;
+			}
+			// the end time is kept for assignments that cover the job's last execution
+			if let Some(end_time) = schedule.nth_start_time(0, end) {
+				assignment_schedule.end_time = end_time;
+			}
+		}
+		assignment_schedule
+	}
+
+	/// The asset a job is paid in, or [None] if it is paid in the native currency.
+	pub fn payment_asset(
+		job_id: &JobId<T::AccountId>,
+	) -> Result<Option<PaymentAssetId>, DispatchError> {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		let e: <T as Config>::RegistrationExtra = registration.extra.into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		Ok(requirements.payment_asset)
+	}
+
+	/// Returns an aggregated view on a job's lifecycle or [None] if the job is not (or no longer) known to the marketplace.
+	pub fn job_view(job_id: &JobId<T::AccountId>) -> Option<JobViewFor<T>> {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)?;
+		let status = <StoredJobStatus<T>>::get(&job_id.0, job_id.1)?;
+
+		let assignments = <AssignedProcessors<T>>::iter_key_prefix(job_id)
+			.filter_map(|processor| {
+				let assignment = <StoredMatches<T>>::get(&processor, job_id)?;
+				let execution_status = match assignment.execution {
+					ExecutionSpecifier::All => None,
+					ExecutionSpecifier::Index(index) => {
+						Some(<StoredJobExecutionStatus<T>>::get(job_id, index))
+					},
+				};
+				Some(JobViewAssignment {
+					next_report_index: <NextReportIndex<T>>::get(job_id, &processor),
+					processor,
+					assignment,
+					execution_status,
+				})
+			})
+			.collect();
+
+		let reserved = <JobBudgets<T>>::get(job_id);
+		let spent = Self::total_budget_amount(job_id, &registration)
+			.map(|total| total.saturating_sub(reserved))
+			.unwrap_or_default();
+
+		Some(JobViewFor::<T> {
+			job_id: job_id.clone(),
+			registration,
+			status,
+			assignments,
+			budget: JobViewBudget { reserved, spent },
+		})
+	}
+
+	/// Returns a page of at most `limit` aggregated job views for the jobs registered by `owner`.
+	///
+	/// Jobs are returned in storage order. To fetch the next page, pass the [`JobIdSequence`] of the last job returned as `start_after`.
+	pub fn job_views_for(
+		owner: &MultiOrigin<T::AccountId>,
+		start_after: Option<JobIdSequence>,
+		limit: u32,
+	) -> Vec<JobViewFor<T>> {
+		let job_ids = match start_after {
+			Some(sequence) => <StoredJobRegistration<T>>::iter_key_prefix_from(
+				owner,
+				<StoredJobRegistration<T>>::hashed_key_for(owner, sequence),
+			),
+			None => <StoredJobRegistration<T>>::iter_key_prefix(owner),
+		};
+		job_ids
+			.filter_map(|sequence| Self::job_view(&(owner.clone(), sequence)))
+			.take(limit.min(MAX_JOB_VIEWS_PER_PAGE) as usize)
+			.collect()
+	}
 }
 
 impl<T: Config> IsFundableCall<T::RuntimeCall> for Pallet<T>
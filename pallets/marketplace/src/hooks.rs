@@ -34,10 +34,32 @@ impl<T: Config> JobHooks<T> for Pallet<T> {
 			Error::<T>::JobRegistrationEndBeforeStart
 		);
 		ensure!(requirements.slots > 0, Error::<T>::JobRegistrationZeroSlots);
+		ensure!(
+			!requirements.consensus || requirements.slots > 1,
+			Error::<T>::JobRegistrationConsensusRequiresMultipleSlots
+		);
 		ensure!(
 			requirements.slots as u32 <= <T as pallet_acurast::Config>::MaxSlots::get(),
 			Error::<T>::TooManySlots
 		);
+		if let Some(asset) = requirements.payment_asset {
+			// both the reward manager and the oracle have to support the asset, otherwise the job would be paid in the native currency
+			ensure!(
+				T::RewardManager::supports_payment_asset(asset)
+					&& T::PaymentAssetPriceOracle::from_native(asset, T::DefaultMinPrice::get())
+						.is_some(),
+				Error::<T>::UnsupportedPaymentAsset
+			);
+		}
+		let result_delivery_fee = if requirements.deliver_results_to_origin {
+			ensure!(requirements.payment_asset.is_none(), Error::<T>::ResultDeliveryUnsupported);
+			let (_, fee) = T::MarketplaceHooks::result_delivery_fee(job_id)
+				.ok_or(Error::<T>::ResultDeliveryUnsupported)?;
+			Some(fee)
+		} else {
+			None
+		};
+		<ResultDeliveryFees<T>>::set(job_id, result_delivery_fee);
 
 		if let Some(job_status) = <StoredJobStatus<T>>::get(&job_id.0, job_id.1) {
 			ensure!(job_status == JobStatus::Open, Error::<T>::JobRegistrationUnmodifiable);
@@ -54,7 +76,7 @@ impl<T: Config> JobHooks<T> for Pallet<T> {
 					)?;
 				}
 			},
-			AssignmentStrategy::Competing => {
+			AssignmentStrategy::Competing | AssignmentStrategy::CompetingCommitReveal => {
 				// ensure the interval is big enough for matchings and acknowledgments to happen
 
 				ensure!(
@@ -68,13 +90,17 @@ impl<T: Config> JobHooks<T> for Pallet<T> {
 		// - lock only after all other steps succeeded without errors because locking reward is not revertable
 		// - reward is understood per slot and execution, so calculate total_reward_amount first
 		// - lock the complete reward inclusive the matcher share and potential gap to actual fee that will be refunded during job finalization
-		T::RewardManager::lock_reward(job_id, Self::total_reward_amount(registration)?)?;
+		// - lock the result delivery fees for every slot and execution on top of the rewards
+		T::RewardManager::lock_reward(job_id, Self::total_budget_amount(job_id, registration)?)?;
 
 		Ok(().into())
 	}
 
 	/// Deregisters a job.
 	///
+	/// Assigned jobs that are not yet expired are cancelled with the same compensations and fee as in
+	/// [`Pallet::cancel_job`], so deregistering cannot be used to bypass the cancellation policy.
+	///
 	/// The final act of removing the job from [`StoredJobRegistration`] is the responsibility of the caller,
 	/// since this storage point is owned by pallet_acurast.
 	fn deregister_hook(job_id: &JobId<T::AccountId>) -> DispatchResultWithPostInfo {
@@ -82,8 +108,47 @@ impl<T: Config> JobHooks<T> for Pallet<T> {
 			<StoredJobStatus<T>>::get(&job_id.0, job_id.1).ok_or(Error::<T>::JobStatusNotFound)?;
 		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
 			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		let now = Self::now()?;
+
+		if let JobStatus::Assigned(_) = job_status {
+			if !registration.schedule.is_expired(now, T::ReportTolerance::get()) {
+				let (compensations, cancellation_fee, refund) = Self::do_cancel_job(job_id)?;
+				Self::deposit_event(Event::JobCancelled(
+					job_id.clone(),
+					compensations,
+					cancellation_fee,
+					refund,
+				));
+				return Ok(().into());
+			}
+		}
 
 		<JobKeyIds<T>>::remove(job_id);
+		<StoredJobExtension<T>>::remove(job_id);
+		<ResultDeliveryFees<T>>::remove(job_id);
+		let _ = <ExtendedAssignmentStart<T>>::clear_prefix(
+			job_id,
+			<T as pallet_acurast::Config>::MaxSlots::get(),
+			None,
+		);
+		let _ = <RematchableSlots<T>>::clear_prefix(
+			job_id,
+			<T as pallet_acurast::Config>::MaxSlots::get(),
+			None,
+		);
+		let _ = <LapsedProcessors<T>>::clear_prefix(job_id, u32::MAX, None);
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		Self::clear_result_commitments(
+			job_id,
+			requirements.slots,
+			registration.schedule.execution_count() as u32,
+		)?;
+		let _ = <ExecutionCandidates<T>>::clear_prefix(
+			job_id,
+			registration.schedule.execution_count() as u32,
+			None,
+		);
 
 		match job_status {
 			JobStatus::Open => {
@@ -112,12 +177,7 @@ impl<T: Config> JobHooks<T> for Pallet<T> {
 				);
 			},
 			JobStatus::Assigned(_) => {
-				// Get the job requirements
-				let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
-					.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
-				let now = Self::now()?;
-
-				// Pay reward to the processor and clear matching data
+				// Only expired jobs remain here: pay reward to the processor and clear matching data
 				for (processor, _) in <AssignedProcessors<T>>::drain_prefix(job_id) {
 					// find assignment
 					let assignment = <StoredMatches<T>>::take(&processor, job_id);
@@ -132,6 +192,8 @@ impl<T: Config> JobHooks<T> for Pallet<T> {
 							}
 						}
 
+						Self::do_slash_for_sla(job_id, &processor, &assignment);
+
 						if assignment.acknowledged {
 							T::RewardManager::handle_reward(job_id, assignment.fee_per_execution)?;
 						}
@@ -175,4 +237,10 @@ impl<T: Config> JobHooks<T> for Pallet<T> {
 
 		Ok(().into())
 	}
+
+	/// Queues the assignments of a processor whose attestation got revoked for removal from jobs allowing only verified sources.
+	fn attestation_revoked_hook(processor: &T::AccountId) -> Weight {
+		<UnverifiedAssignmentRemovals<T>>::insert(processor, None::<JobId<T::AccountId>>);
+		T::DbWeight::get().writes(1)
+	}
 }
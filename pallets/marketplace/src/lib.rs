@@ -2,6 +2,7 @@
 
 pub use pallet::*;
 pub use payments::*;
+pub use runtime_api::*;
 pub use traits::*;
 pub use types::*;
 
@@ -15,14 +16,22 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+mod commit_reveal;
+mod consensus;
 mod error;
+mod extension;
 mod functions;
 mod hooks;
 mod match_checker;
 mod migration;
 pub mod payments;
+mod rematch;
+#[cfg(feature = "std")]
+pub mod rpc;
+mod runtime_api;
 pub mod traits;
 pub mod types;
+mod unverified;
 mod utils;
 pub mod weights;
 
@@ -34,19 +43,21 @@ pub use benchmarking::BenchmarkHelper;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use acurast_common::Slashable;
 	use frame_support::{
 		dispatch::DispatchResultWithPostInfo,
 		ensure,
 		pallet_prelude::*,
 		sp_runtime::{
 			traits::{CheckedAdd, CheckedSub, Hash},
-			FixedPointOperand, FixedU128,
+			FixedPointOperand, FixedU128, Percent,
 		},
-		traits::{tokens::Balance, EnsureOrigin},
+		traits::{tokens::Balance, EnsureOrigin, Randomness},
 		Blake2_128, Blake2_128Concat, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
 	use reputation::BetaParameters;
+	use sp_core::H256;
 	use sp_std::prelude::*;
 
 	use pallet_acurast::{
@@ -73,6 +84,26 @@ pub mod pallet {
 		/// Maximum time delta in ms that each job execution can be matched before the execution's start. Relevant for [`AssignmentStrategy::Competing`].
 		#[pallet::constant]
 		type MatchingCompetingDueDelta: Get<u64>;
+		/// The length in ms of the reveal phase for executions of jobs with [`AssignmentStrategy::CompetingCommitReveal`].
+		///
+		/// Candidates commit until `2 * MatchingCommitRevealPeriod` and reveal until `MatchingCommitRevealPeriod` before the execution's start,
+		/// so it should be less than half of [`Config::MatchingCompetingDueDelta`].
+		#[pallet::constant]
+		type MatchingCommitRevealPeriod: Get<u64>;
+		/// The randomness mixed into the pick of candidates for executions of jobs with [`AssignmentStrategy::CompetingCommitReveal`].
+		///
+		/// It must not be known before the reveal phase ends, otherwise the candidates could predict the pick when revealing.
+		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+		/// The length in ms of the phase in which processors reveal the results they reported for executions of jobs requiring [`JobRequirements::consensus`].
+		///
+		/// It starts once the report windows of all slots of an execution closed, i.e. `ReportTolerance` after the execution's latest possible end.
+		#[pallet::constant]
+		type ResultRevealPeriod: Get<u64>;
+		/// The time in ms assigned processors have to accept or decline a job extension.
+		///
+		/// Processors that did not respond until then count as declined, so their slots can be re-matched for the extended range.
+		#[pallet::constant]
+		type ExtensionAcceptPeriod: Get<u64>;
 		/// The maximum matches that can be proposed with one extrinsic call.
 		#[pallet::constant]
 		type MaxProposedMatches: Get<u32>;
@@ -98,6 +129,22 @@ pub mod pallet {
 		/// would be considered outside of the agreed schedule despite being within schedule.
 		#[pallet::constant]
 		type ReportTolerance: Get<u64>;
+		/// The notice period in milliseconds for cancelling a job.
+		///
+		/// Acknowledged processors get paid for all their unreported executions that start before `now + CancellationNoticePeriod`.
+		#[pallet::constant]
+		type CancellationNoticePeriod: Get<u64>;
+		/// The share of the remaining budget of a cancelled job that is retained as cancellation fee, only charged if the job was already assigned.
+		#[pallet::constant]
+		type CancellationFee: Get<Percent>;
+		/// The ratio of reported to assigned executions below which a processor gets slashed when its [`ExecutionSpecifier::All`] assignment concludes.
+		#[pallet::constant]
+		type SlaSlashThreshold: Get<Percent>;
+		/// The share of the amount slashed for a missed SLA that is paid to the job's consumer. The rest is burned.
+		#[pallet::constant]
+		type SlaSlashConsumerShare: Get<Percent>;
+		/// Slashes processors that missed their SLA.
+		type Slashable: Slashable<Self::AccountId>;
 		type Balance: Parameter + From<u64> + IsType<u128> + Balance + FixedPointOperand;
 		type ProcessorInfoProvider: ProcessorInfoProvider<Self>;
 		/// Logic for locking and paying tokens for job execution
@@ -106,6 +153,9 @@ pub mod pallet {
 		type MarketplaceHooks: MarketplaceHooks<Self>;
 		#[pallet::constant]
 		type MaxJobCleanups: Get<u32>;
+		/// The max number of assignments checked per block when removing the assignments of processors whose attestation got revoked.
+		#[pallet::constant]
+		type MaxUnverifiedAssignmentRemovals: Get<u32>;
 		/// The hashing system (algorithm) being used to hash deployments (owner + script) (e.g. Blake2).
 		type DeploymentHashing: Hash<Output = DeploymentHash> + TypeInfo;
 		/// The hashing system (algorithm) being used to generate key ids for deployments (e.g. Blake2).
@@ -117,13 +167,15 @@ pub mod pallet {
 		type ProcessorPriceProvider: PriceProvider<Self::AccountId, Self::Balance>;
 		type DefaultMinPrice: Get<Self::Balance>;
 		type DefaultPriceMultiplier: Get<FixedU128>;
+		/// Converts processor prices into the assets jobs can be paid in, see [`JobRequirements::payment_asset`].
+		type PaymentAssetPriceOracle: PaymentAssetPriceOracle<Self::Balance>;
 		/// WeightInfo
 		type WeightInfo: WeightInfo;
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: crate::benchmarking::BenchmarkHelper<Self>;
 	}
 
-	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(7);
+	pub(crate) const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -172,6 +224,13 @@ pub mod pallet {
 	pub type StoredAdvertisementPricing<T: Config> =
 		StorageMap<_, Blake2_128, T::AccountId, PricingFor<T>>;
 
+	/// The storage for advertisements' unavailability windows. They are stored as a map [`AccountId`] `(source)` -> [`UnavailabilityWindows`]
+	/// and only for sources advertising at least one window.
+	#[pallet::storage]
+	#[pallet::getter(fn stored_advertisement_unavailability)]
+	pub type StoredAdvertisementUnavailability<T: Config> =
+		StorageMap<_, Blake2_128, T::AccountId, UnavailabilityWindows>;
+
 	/// The storage for remaining capacity for each source. Can be negative if capacity is reduced beyond the number of jobs currently assigned.
 	#[pallet::storage]
 	#[pallet::getter(fn stored_storage_capacity)]
@@ -294,6 +353,118 @@ pub mod pallet {
 	pub type JobMatcher<T: Config> =
 		StorageMap<_, Blake2_128Concat, JobId<T::AccountId>, T::AccountId>;
 
+	/// Pending extensions of assigned jobs as a map [`JobId`] -> [`JobExtensionFor<T>`].
+	#[pallet::storage]
+	#[pallet::getter(fn stored_job_extension)]
+	pub type StoredJobExtension<T: Config> =
+		StorageMap<_, Blake2_128Concat, JobId<T::AccountId>, JobExtensionFor<T>>;
+
+	/// The index of the first execution assigned to processors that got matched for the extended range of a job or as replacement
+	/// for a lapsed assignment, as a map [`JobId`] -> [`AccountId`] `(source)` -> `execution_index`.
+	///
+	/// Assignments without an entry cover all executions from the job's first execution on.
+	#[pallet::storage]
+	#[pallet::getter(fn extended_assignment_start)]
+	pub type ExtendedAssignmentStart<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		JobId<T::AccountId>,
+		Blake2_128Concat,
+		T::AccountId,
+		u64,
+	>;
+
+	/// Slots of jobs whose assignment lapsed, as a map [`JobId`] -> `slot` -> `execution_index`, where `execution_index` is the first execution
+	/// a replacement processor gets matched for.
+	#[pallet::storage]
+	#[pallet::getter(fn rematchable_slots)]
+	pub type RematchableSlots<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, JobId<T::AccountId>, Blake2_128Concat, u8, u64>;
+
+	/// Processors whose assignment to a job lapsed, as a map [`JobId`] -> [`T::AccountId`] -> `()`. They cannot replace a re-matchable slot of the job.
+	#[pallet::storage]
+	#[pallet::getter(fn lapsed_processors)]
+	pub type LapsedProcessors<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		JobId<T::AccountId>,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+	>;
+
+	/// The blinded results reported for executions of jobs requiring consensus that are not settled yet, as a map [`JobId`] -> `execution_index` -> [`ResultCommitmentsFor`].
+	#[pallet::storage]
+	#[pallet::getter(fn result_commitments)]
+	pub type ResultCommitments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		JobId<T::AccountId>,
+		Blake2_128Concat,
+		u64,
+		ResultCommitmentsFor<T>,
+		ValueQuery,
+	>;
+
+	/// The majority results of executions of jobs requiring consensus, as a map [`JobId`] -> `execution_index` -> [`ExecutionOperationHash`].
+	#[pallet::storage]
+	#[pallet::getter(fn consensus_results)]
+	pub type ConsensusResults<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		JobId<T::AccountId>,
+		Blake2_128Concat,
+		u64,
+		ExecutionOperationHash,
+	>;
+
+	/// The results disagreeing with the majority, or all results of an execution that could not reach a majority, as a map [`JobId`] -> `execution_index` -> [`ResultCommitmentsFor`].
+	///
+	/// Kept for the consumer to inspect until the job gets deregistered.
+	#[pallet::storage]
+	#[pallet::getter(fn result_disagreements)]
+	pub type ResultDisagreements<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		JobId<T::AccountId>,
+		Blake2_128Concat,
+		u64,
+		ResultCommitmentsFor<T>,
+		ValueQuery,
+	>;
+
+	/// The candidates matched for executions of jobs with [`AssignmentStrategy::CompetingCommitReveal`] until the slots get assigned,
+	/// as a map [`JobId`] -> `execution_index` -> [`ExecutionCandidatesFor`].
+	#[pallet::storage]
+	#[pallet::getter(fn execution_candidates)]
+	pub type ExecutionCandidates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		JobId<T::AccountId>,
+		Blake2_128Concat,
+		u64,
+		ExecutionCandidatesFor<T>,
+	>;
+
+	/// The fee paid per delivered result of jobs delivering their results to their origin, as a map [`JobId`] -> `fee`.
+	///
+	/// Fixed at registration, when the fees for every slot and execution get reserved in the job's budget.
+	#[pallet::storage]
+	#[pallet::getter(fn result_delivery_fee)]
+	pub type ResultDeliveryFees<T: Config> =
+		StorageMap<_, Blake2_128Concat, JobId<T::AccountId>, T::Balance>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn v8_migration_state)]
+	pub type V8MigrationState<T: Config> =
+		StorageValue<_, BoundedVec<u8, ConstU32<256>>, OptionQuery>;
+
+	/// Processors whose attestation got revoked and whose assignments are still to be checked, as a map [`AccountId`] -> last checked [`JobId`].
+	#[pallet::storage]
+	#[pallet::getter(fn unverified_assignment_removal)]
+	pub type UnverifiedAssignmentRemovals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Option<JobId<T::AccountId>>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -348,6 +519,67 @@ pub mod pallet {
 		PriceSettingsUpdated,
 		/// Job Matcher entry cleaned up
 		JobMatcherEntryCleanedUp(JobId<T::AccountId>),
+		/// A job was cancelled by its creator. [JobId, [(SourceId, compensation)], cancellation_fee, refund]
+		JobCancelled(
+			JobId<T::AccountId>,
+			Vec<(T::AccountId, <T as Config>::Balance)>,
+			<T as Config>::Balance,
+			<T as Config>::Balance,
+		),
+		/// A job's schedule was extended. [JobId, end_time]
+		JobExtended(JobId<T::AccountId>, u64),
+		/// An assigned processor accepted a job's extension. [JobId, SourceId]
+		JobExtensionAccepted(JobId<T::AccountId>, T::AccountId),
+		/// An assigned processor declined a job's extension. [JobId, SourceId]
+		JobExtensionDeclined(JobId<T::AccountId>, T::AccountId),
+		/// The declined slots of a job's extension were matched. [JobId]
+		JobExtensionMatched(JobId<T::AccountId>),
+		/// A processor got slashed for missing its SLA. [JobId, SourceId, SLA, slashed, paid_to_consumer]
+		ProcessorSlashedForSla(
+			JobId<T::AccountId>,
+			T::AccountId,
+			SLA,
+			<T as Config>::Balance,
+			<T as Config>::Balance,
+		),
+		/// The lapsed assignment of a processor was removed and its slot can be re-matched. [JobId, SourceId, slot, execution_index]
+		SlotMarkedRematchable(JobId<T::AccountId>, T::AccountId, u8, u64),
+		/// Replacement processors were matched for the re-matchable slots of a job. [JobId]
+		JobSlotsRematched(JobId<T::AccountId>),
+		/// The results reported for an execution of a job requiring consensus reached a majority. [JobId, execution_index, result]
+		ExecutionConsensusReached(JobId<T::AccountId>, u64, ExecutionOperationHash),
+		/// The results reported for an execution of a job requiring consensus got settled without reaching a majority. [JobId, execution_index]
+		ExecutionConsensusFailed(JobId<T::AccountId>, u64),
+		/// A processor reported a result disagreeing with the majority. [JobId, execution_index, SourceId]
+		ExecutionResultDisagreed(JobId<T::AccountId>, u64, T::AccountId),
+		/// Migration started.
+		V8MigrationStarted,
+		/// Migration completed.
+		V8MigrationCompleted,
+		/// A candidate committed a blinded acknowledgment for an execution. [JobId, execution_index, SourceId]
+		ExecutionAcknowledgmentCommitted(JobId<T::AccountId>, u64, T::AccountId),
+		/// A candidate revealed its acknowledgment for an execution. [JobId, execution_index, SourceId]
+		ExecutionAcknowledgmentRevealed(JobId<T::AccountId>, u64, T::AccountId),
+		/// The slots of an execution were assigned to the picked candidates. [JobId, execution_index, [SourceId]]
+		ExecutionCandidatesPicked(JobId<T::AccountId>, u64, Vec<T::AccountId>),
+		/// A reported result was sent back to the origin of the job. [JobId, SourceId, fee]
+		ExecutionResultDelivered(JobId<T::AccountId>, T::AccountId, <T as Config>::Balance),
+		/// A reported result could not be sent back to the origin of the job. [JobId, SourceId, error]
+		ExecutionResultDeliveryFailed(JobId<T::AccountId>, T::AccountId, DispatchError),
+		/// The assignment to a job allowing only verified sources was removed since the processor's attestation got revoked. [JobId, SourceId]
+		UnverifiedAssignmentRemoved(JobId<T::AccountId>, T::AccountId),
+		/// A candidate committed an acknowledgment for an execution without revealing it in time and got penalized. [JobId, execution_index, SourceId, slashed, paid_to_consumer]
+		ExecutionAcknowledgmentNotRevealed(
+			JobId<T::AccountId>,
+			u64,
+			T::AccountId,
+			<T as Config>::Balance,
+			<T as Config>::Balance,
+		),
+		/// A processor revealed the result it reported for an execution of a job requiring consensus. [JobId, execution_index, SourceId]
+		ExecutionResultRevealed(JobId<T::AccountId>, u64, T::AccountId),
+		/// The unavailability windows of an advertisement were stored. [who]
+		AdvertisementUnavailabilityStored(T::AccountId),
 	}
 
 	#[pallet::error]
@@ -480,6 +712,62 @@ pub mod pallet {
 		CannotAcknowledgeAfterStartTime,
 		/// Cannot get a price for a processor
 		CannotGetProcessorPrice,
+		/// The end time of a job extension must be later than the job's current end time.
+		JobExtensionEndTimeNotLater,
+		/// The reward of a job extension must not be lower than the job's current reward.
+		JobExtensionRewardDecreased,
+		/// A job can only be extended before its current end time.
+		JobExtensionAfterEnd,
+		/// The job's previous extension is not yet accepted or matched by all slots.
+		JobExtensionInProgress,
+		/// The job has no pending extension.
+		JobExtensionNotFound,
+		/// The processor is not pending to respond to the job's extension.
+		JobExtensionNotPending,
+		/// Only slots of jobs with [`AssignmentStrategy::Single`] can be re-matched.
+		RematchOnlyForSingleAssignment,
+		/// The assignment did not lapse.
+		AssignmentNotLapsed,
+		/// There are no executions left to re-match a slot for.
+		NoRemainingExecutions,
+		/// Consensus on execution results requires a job with more than one slot.
+		JobRegistrationConsensusRequiresMultipleSlots,
+		/// The source is not a candidate for the execution.
+		NotExecutionCandidate,
+		/// Blinded acknowledgments can only be committed before the reveal phase.
+		ExecutionCommitPhaseOver,
+		/// Acknowledgments can only be revealed during the reveal phase.
+		OutsideExecutionRevealPhase,
+		/// The candidate did not commit a blinded acknowledgment or already revealed it.
+		ExecutionAcknowledgmentNotCommitted,
+		/// The revealed acknowledgment does not match the committed one.
+		ExecutionAcknowledgmentMismatch,
+		/// Candidates can only be picked after the reveal phase or once all committed candidates revealed.
+		ExecutionRevealPhaseNotOver,
+		/// An unavailability window of an advertisement has a zero duration or a duration not shorter than its period.
+		InvalidUnavailabilityWindow,
+		/// Match is invalid since the source is not available during the job's schedule.
+		SourceUnavailableInMatch,
+		/// The job's payment asset is not supported by [`Config::PaymentAssetPriceOracle`] or [`Config::RewardManager`].
+		UnsupportedPaymentAsset,
+		/// Results can only be delivered to origins supported by [`Config::MarketplaceHooks`] for jobs paid in the native currency.
+		ResultDeliveryUnsupported,
+		/// The accept deadline of a job extension would not pass before the extended range starts.
+		JobExtensionTooLate,
+		/// The accept deadline of the job's extension passed.
+		JobExtensionAcceptDeadlinePassed,
+		/// A result reported for a job requiring consensus is not a 32 byte blinded result.
+		InvalidResultCommitment,
+		/// No unrevealed result of the caller is committed for the execution.
+		ResultNotCommitted,
+		/// The revealed result does not match the committed blinded result.
+		ResultCommitmentMismatch,
+		/// Results of an execution can only be revealed within its reveal phase.
+		OutsideResultRevealPhase,
+		/// Results of an execution can only be settled once its reveal phase is over.
+		ResultRevealPhaseNotOver,
+		/// Match is invalid since the source's assignment to the job lapsed before.
+		LapsedSourceInMatch,
 	}
 
 	#[pallet::hooks]
@@ -496,7 +784,7 @@ pub mod pallet {
 		>,
 	{
 		fn on_initialize(_block_number: BlockNumberFor<T>) -> frame_support::weights::Weight {
-			crate::migration::migrate::<T>()
+			crate::migration::migrate::<T>().saturating_add(Self::remove_unverified_assignments())
 		}
 	}
 
@@ -531,6 +819,7 @@ pub mod pallet {
 
 			<StoredAdvertisementPricing<T>>::remove(&who);
 			<StoredAdvertisementRestriction<T>>::remove(&who);
+			<StoredAdvertisementUnavailability<T>>::remove(&who);
 
 			Self::deposit_event(Event::AdvertisementRemoved(who));
 			Ok(().into())
@@ -538,7 +827,7 @@ pub mod pallet {
 
 		/// Proposes processors to match with a job. The match fails if it conflicts with the processor's schedule.
 		#[pallet::call_index(2)]
-		#[pallet::weight(< T as Config >::WeightInfo::propose_matching(matches.len() as u32))]
+		#[pallet::weight(< T as Config >::WeightInfo::propose_matching(matches.len() as u32).saturating_add(Pallet::<T>::attestation_checks_weight(matches.len() as u32)))]
 		pub fn propose_matching(
 			origin: OriginFor<T>,
 			matches: BoundedVec<MatchFor<T>, <T as Config>::MaxProposedMatches>,
@@ -585,8 +874,15 @@ pub mod pallet {
 		/// Reward is paid out to source if timing of this call is within expected interval. More precisely,
 		/// the report is accepted if `[now, now + tolerance]` overlaps with an execution of the schedule agreed on.
 		/// `tolerance` is a pallet config value.
+		///
+		/// For jobs requiring [`JobRequirements::consensus`], a successful report carries the blinded result, see [`ResultCommitment::blinded_result`],
+		/// which is committed for the execution instead and revealed with [`Pallet::reveal_result`]. The reward is only paid out once
+		/// the revealed result agrees with the majority of the job's slots.
+		///
+		/// For jobs requiring [`JobRequirements::deliver_results_to_origin`], the reported result is also sent back to the job's origin,
+		/// or the revealed result for jobs requiring consensus.
 		#[pallet::call_index(4)]
-		#[pallet::weight(< T as Config >::WeightInfo::report())]
+		#[pallet::weight(< T as Config >::WeightInfo::report().saturating_add(Pallet::<T>::attestation_checks_weight(1)).saturating_add(Pallet::<T>::result_delivery_weight()))]
 		pub fn report(
 			origin: OriginFor<T>,
 			job_id: JobId<T::AccountId>,
@@ -594,7 +890,7 @@ pub mod pallet {
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			_ = Self::do_report(&job_id, &who)?;
+			_ = Self::do_report(&job_id, &who, &execution_result)?;
 
 			match execution_result {
 				ExecutionResult::Success(operation_hash) => {
@@ -647,7 +943,7 @@ pub mod pallet {
 
 		/// Proposes processors to match with a job's execution.
 		#[pallet::call_index(7)]
-		#[pallet::weight(< T as Config >::WeightInfo::propose_execution_matching(matches.len() as u32))]
+		#[pallet::weight(< T as Config >::WeightInfo::propose_execution_matching(matches.len() as u32).saturating_add(Pallet::<T>::attestation_checks_weight(matches.len() as u32)))]
 		pub fn propose_execution_matching(
 			origin: OriginFor<T>,
 			matches: BoundedVec<ExecutionMatchFor<T>, <T as Config>::MaxProposedExecutionMatches>,
@@ -672,6 +968,7 @@ pub mod pallet {
 				let mut remaining_iterations = max_iterations;
 				for (processor, _) in <AssignedProcessors<T>>::drain_prefix(&job_id) {
 					<StoredMatches<T>>::remove(&processor, &job_id);
+					<ExtendedAssignmentStart<T>>::remove(&job_id, &processor);
 					remaining_iterations -= 1;
 					if remaining_iterations == 0 {
 						break;
@@ -911,8 +1208,17 @@ pub mod pallet {
 				.unwrap_or(true);
 			if is_expired {
 				for (p, _) in <AssignedProcessors<T>>::drain_prefix(&job_id) {
-					<StoredMatches<T>>::remove(&p, &job_id);
+					if let Some(assignment) = <StoredMatches<T>>::take(&p, &job_id) {
+						Self::do_slash_for_sla(&job_id, &p, &assignment);
+					}
+					<ExtendedAssignmentStart<T>>::remove(&job_id, &p);
 				}
+				let _ = <RematchableSlots<T>>::clear_prefix(
+					&job_id,
+					<T as pallet_acurast::Config>::MaxSlots::get(),
+					None,
+				);
+				let _ = <LapsedProcessors<T>>::clear_prefix(&job_id, u32::MAX, None);
 				Self::deposit_event(Event::JobAssignmentsCleanedUp(job_id));
 			}
 			Ok(().into())
@@ -949,6 +1255,243 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Cancels a job registered by the caller.
+		///
+		/// In contrast to [`pallet_acurast::Pallet::deregister`], acknowledged processors get compensated for their unreported
+		/// executions starting within [`Config::CancellationNoticePeriod`] and a [`Config::CancellationFee`] is charged on
+		/// the remaining budget if the job was already assigned. The rest of the budget is refunded.
+		#[pallet::call_index(19)]
+		#[pallet::weight(< T as Config >::WeightInfo::cancel_job())]
+		pub fn cancel_job(
+			origin: OriginFor<T>,
+			local_job_id: JobIdSequence,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let job_id = (MultiOrigin::Acurast(who), local_job_id);
+
+			let (compensations, cancellation_fee, refund) = Self::do_cancel_job(&job_id)?;
+			pallet_acurast::Pallet::<T>::remove_job_registration(job_id.clone());
+
+			Self::deposit_event(Event::JobCancelled(
+				job_id,
+				compensations,
+				cancellation_fee,
+				refund,
+			));
+			Ok(().into())
+		}
+
+		/// Extends a job's schedule up to `end_time` and optionally raises the reward per execution, locking the additional budget.
+		///
+		/// If the job is already matched with [`AssignmentStrategy::Single`], the assigned processors have to accept or decline
+		/// the extension within [`Config::ExtensionAcceptPeriod`]. Slots of processors that declined or did not respond get re-matched
+		/// for the extended range only.
+		#[pallet::call_index(20)]
+		#[pallet::weight(< T as Config >::WeightInfo::extend_job())]
+		pub fn extend_job(
+			origin: OriginFor<T>,
+			local_job_id: JobIdSequence,
+			end_time: u64,
+			reward: Option<<T as Config>::Balance>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let job_id = (MultiOrigin::Acurast(who), local_job_id);
+
+			Self::do_extend_job(&job_id, end_time, reward)?;
+
+			Self::deposit_event(Event::JobExtended(job_id, end_time));
+			Ok(().into())
+		}
+
+		/// Accepts the pending extension of a job the origin is assigned to.
+		#[pallet::call_index(21)]
+		#[pallet::weight(< T as Config >::WeightInfo::accept_job_extension())]
+		pub fn accept_job_extension(
+			origin: OriginFor<T>,
+			job_id: JobId<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_accept_job_extension(&who, &job_id)?;
+
+			Self::deposit_event(Event::JobExtensionAccepted(job_id, who));
+			Ok(().into())
+		}
+
+		/// Declines the pending extension of a job the origin is assigned to. The origin stays assigned for the job's executions before the extension.
+		#[pallet::call_index(22)]
+		#[pallet::weight(< T as Config >::WeightInfo::decline_job_extension())]
+		pub fn decline_job_extension(
+			origin: OriginFor<T>,
+			job_id: JobId<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_decline_job_extension(&who, &job_id)?;
+
+			Self::deposit_event(Event::JobExtensionDeclined(job_id, who));
+			Ok(().into())
+		}
+
+		/// Proposes processors for the declined slots of job extensions. The match fails if it conflicts with the processor's schedule.
+		///
+		/// Once the accept deadline of an extension passed, the slots of processors that did not respond count as declined.
+		#[pallet::call_index(23)]
+		#[pallet::weight(< T as Config >::WeightInfo::propose_extension_matching(matches.len() as u32).saturating_add(Pallet::<T>::attestation_checks_weight(matches.len() as u32)))]
+		pub fn propose_extension_matching(
+			origin: OriginFor<T>,
+			matches: BoundedVec<MatchFor<T>, <T as Config>::MaxProposedMatches>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::process_extension_matching(&matches, &who)?;
+
+			Ok(().into())
+		}
+
+		/// Removes the lapsed assignment of `processor` to a job with [`AssignmentStrategy::Single`] and marks its slot as re-matchable.
+		///
+		/// A replacement for the slot can be proposed with `propose_matching`, assigned for the remaining executions only.
+		#[pallet::call_index(24)]
+		#[pallet::weight(< T as Config >::WeightInfo::mark_slot_rematchable())]
+		pub fn mark_slot_rematchable(
+			origin: OriginFor<T>,
+			job_id: JobId<T::AccountId>,
+			processor: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			_ = ensure_signed(origin)?;
+
+			let (slot, execution_index) = Self::do_mark_slot_rematchable(&job_id, &processor)?;
+
+			Self::deposit_event(Event::SlotMarkedRematchable(
+				job_id,
+				processor,
+				slot,
+				execution_index,
+			));
+			Ok(().into())
+		}
+
+		/// Commits a blinded acknowledgment of a candidate for an execution of a job with [`AssignmentStrategy::CompetingCommitReveal`].
+		///
+		/// The blinded acknowledgment is computed as `blake2_256(SCALE(source, salt, pub_keys))`.
+		#[pallet::call_index(25)]
+		#[pallet::weight(< T as Config >::WeightInfo::commit_execution_acknowledgment())]
+		pub fn commit_execution_acknowledgment(
+			origin: OriginFor<T>,
+			job_id: JobId<T::AccountId>,
+			execution_index: u64,
+			commitment: H256,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_commit_execution_acknowledgment(&who, &job_id, execution_index, commitment)?;
+
+			Self::deposit_event(Event::ExecutionAcknowledgmentCommitted(
+				job_id,
+				execution_index,
+				who,
+			));
+			Ok(().into())
+		}
+
+		/// Reveals the acknowledgment previously committed by a candidate with `commit_execution_acknowledgment`.
+		///
+		/// Once all candidates that committed revealed, the slots of the execution get assigned.
+		#[pallet::call_index(26)]
+		#[pallet::weight(< T as Config >::WeightInfo::reveal_execution_acknowledgment())]
+		pub fn reveal_execution_acknowledgment(
+			origin: OriginFor<T>,
+			job_id: JobId<T::AccountId>,
+			execution_index: u64,
+			salt: H256,
+			pub_keys: PubKeys,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_reveal_execution_acknowledgment(
+				&who,
+				&job_id,
+				execution_index,
+				salt,
+				pub_keys,
+			)?;
+
+			Ok(().into())
+		}
+
+		/// Assigns the slots of an execution to the picked candidates after the reveal phase is over.
+		///
+		/// Can be called by anyone. Candidates that committed without revealing are not considered, lose reputation and get slashed the fee of the execution.
+		/// If no candidate revealed, the job is reopened for the next execution.
+		#[pallet::call_index(27)]
+		#[pallet::weight(< T as Config >::WeightInfo::pick_execution_candidates())]
+		pub fn pick_execution_candidates(
+			origin: OriginFor<T>,
+			job_id: JobId<T::AccountId>,
+			execution_index: u64,
+		) -> DispatchResultWithPostInfo {
+			_ = ensure_signed(origin)?;
+
+			Self::do_pick_execution_candidates(&job_id, execution_index)?;
+
+			Ok(().into())
+		}
+
+		/// Reveals the result the caller reported blinded for an execution of a job requiring [`JobRequirements::consensus`].
+		///
+		/// Only allowed within the execution's reveal phase, see [`Config::ResultRevealPeriod`].
+		/// Once all processors that reported revealed, the results of the execution get settled.
+		#[pallet::call_index(28)]
+		#[pallet::weight(< T as Config >::WeightInfo::reveal_result().saturating_add(Pallet::<T>::result_delivery_weight()))]
+		pub fn reveal_result(
+			origin: OriginFor<T>,
+			job_id: JobId<T::AccountId>,
+			execution_index: u64,
+			salt: H256,
+			result: ExecutionOperationHash,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_reveal_result(&who, &job_id, execution_index, salt, result)?;
+
+			Ok(().into())
+		}
+
+		/// Settles the results reported for an execution of a job requiring [`JobRequirements::consensus`] after its reveal phase is over.
+		///
+		/// Can be called by anyone. Processors that revealed their result get paid even if not all slots reported.
+		#[pallet::call_index(29)]
+		#[pallet::weight(< T as Config >::WeightInfo::settle_execution_results())]
+		pub fn settle_execution_results(
+			origin: OriginFor<T>,
+			job_id: JobId<T::AccountId>,
+			execution_index: u64,
+		) -> DispatchResultWithPostInfo {
+			_ = ensure_signed(origin)?;
+
+			Self::do_settle_results(&job_id, execution_index)?;
+
+			Ok(().into())
+		}
+
+		/// Sets the recurring windows in which the caller is not available to execute jobs, replacing the previous ones.
+		///
+		/// Requires an advertisement of the caller. New matches must not intersect any of the windows, existing assignments are not affected.
+		#[pallet::call_index(30)]
+		#[pallet::weight(< T as Config >::WeightInfo::set_advertisement_unavailability())]
+		pub fn set_advertisement_unavailability(
+			origin: OriginFor<T>,
+			unavailability: UnavailabilityWindows,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			Self::do_set_advertisement_unavailability(&who, unavailability)?;
+
+			Self::deposit_event(Event::AdvertisementUnavailabilityStored(who));
+			Ok(().into())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -10,7 +10,8 @@ use frame_support::{
 use itertools::Itertools;
 use pallet_acurast::{
 	utils::{ensure_source_verified, ensure_source_verified_and_of_type},
-	JobId, JobRegistrationFor, ProcessorType, RequiredMinMetrics, Schedule, StoredJobRegistration,
+	JobId, JobRegistrationFor, Metrics, MinMetric, MinMetrics, MultiOrigin, ProcessorType,
+	RequiredMinMetrics, Schedule, StoredJobRegistration,
 };
 use reputation::{BetaReputation, ReputationEngine};
 
@@ -22,7 +23,8 @@ use crate::{
 impl<T: Config> Pallet<T> {
 	/// Checks if a Processor - Job match is possible and returns the remaining job rewards by `job_id`.
 	///
-	/// If the job is no longer in status [`JobStatus::Open`], the matching is skipped without returning an error.
+	/// If the job is no longer in status [`JobStatus::Open`], the matching is skipped without returning an error,
+	/// unless the job has slots marked as re-matchable. In that case the sources are matched as replacements for these slots.
 	/// **The returned vector does not include an entry for skipped matches.**
 	///
 	/// Every other invalidity in a provided [`Match`] fails the entire call.
@@ -34,13 +36,16 @@ impl<T: Config> Pallet<T> {
 			let job_status = <StoredJobStatus<T>>::get(&m.job_id.0, m.job_id.1)
 				.ok_or(Error::<T>::JobStatusNotFound)?;
 
+			let registration = <StoredJobRegistration<T>>::get(&m.job_id.0, m.job_id.1)
+				.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+
 			if job_status != JobStatus::Open {
+				if <RematchableSlots<T>>::iter_prefix(&m.job_id).next().is_some() {
+					Self::process_replacement_matching(m, &registration, matcher_account)?;
+				}
 				// skip but don't fail this match (another matcher was quicker)
 				continue;
 			}
-
-			let registration = <StoredJobRegistration<T>>::get(&m.job_id.0, m.job_id.1)
-				.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
 			let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
 			let requirements: JobRequirementsFor<T> = e.into();
 
@@ -62,7 +67,7 @@ impl<T: Config> Pallet<T> {
 				Error::<T>::IncorrectSourceCountInMatch
 			);
 
-			let reward_amount: <T as Config>::Balance = requirements.reward;
+			let min_metrics = <RequiredMinMetrics<T>>::get(&m.job_id);
 
 			// keep track of total fee in assignments to check later if it exceeds reward
 			let mut total_fee: <T as Config>::Balance = 0u8.into();
@@ -71,74 +76,16 @@ impl<T: Config> Pallet<T> {
 			// `slot` is used for detecting duplicate source proposed for distinct slots
 			// TODO: add global (configurable) maximum of jobs assigned. This would limit the weight of `propose_matching` to a constant, since it depends on the number of active matches.
 			for (slot, planned_execution) in m.sources.iter().enumerate() {
-				// CHECK attestation
-				ensure!(
-					!registration.allow_only_verified_sources
-						|| ensure_source_verified_and_of_type::<T>(
-							&planned_execution.source,
-							ProcessorType::Core
-						)
-						.is_ok(),
-					Error::<T>::UnverifiedSourceInMatch
-				);
-
-				let ad = <StoredAdvertisementRestriction<T>>::get(&planned_execution.source)
-					.ok_or(Error::<T>::AdvertisementNotFound)?;
-
-				for required_module in &registration.required_modules {
-					ensure!(
-						ad.available_modules.contains(required_module),
-						Error::<T>::ModuleNotAvailableInMatch
-					);
-				}
-
-				// CHECK network request quota sufficient
-				Self::check_network_request_quota_sufficient(
-					&ad,
-					&registration.schedule,
-					registration.network_requests,
-				)?;
-
-				// CHECK source is whitelisted
-				ensure!(
-					is_processor_allowed::<T>(
-						&planned_execution.source,
-						&registration.allowed_sources
-					),
-					Error::<T>::SourceNotAllowedInMatch
-				);
-
-				// CHECK consumer is whitelisted
-				ensure!(
-					is_consumer_allowed::<T>(&m.job_id.0, &ad.allowed_consumers),
-					Error::<T>::ConsumerNotAllowedInMatch
-				);
-
-				// CHECK reputation sufficient
-				Self::check_min_reputation(requirements.min_reputation, &planned_execution.source)?;
-
-				Self::check_processor_version(
-					&requirements.processor_version,
-					&planned_execution.source,
-				)?;
-
-				Self::check_min_metrics(&m.job_id, &planned_execution.source)?;
-
-				// CHECK schedule
-				Self::fits_schedule(
+				let fee_per_execution = Self::check_source_matches(
 					&planned_execution.source,
+					&m.job_id.0,
+					&registration,
+					&requirements,
+					min_metrics.as_ref(),
 					ExecutionSpecifier::All,
-					&registration.schedule,
 					planned_execution.start_delay,
 				)?;
 
-				// calculate fee
-				let fee_per_execution =
-					Self::price_for(&planned_execution.source, &registration.schedule);
-
-				// CHECK price not exceeding reward
-				ensure!(fee_per_execution <= reward_amount, Error::<T>::InsufficientRewardInMatch);
-
 				let execution_count = registration.schedule.execution_count();
 
 				total_fee = total_fee
@@ -184,7 +131,8 @@ impl<T: Config> Pallet<T> {
 			}
 
 			let total_reward_per_execution: u128 = total_reward_per_execution.into();
-			if total_reward_per_execution > 0 {
+			// the average reward is denominated in the native currency
+			if total_reward_per_execution > 0 && requirements.payment_asset.is_none() {
 				let avarage_fee_per_execution =
 					total_reward_per_execution / (m.sources.len() as u128);
 				Self::update_average_reward(avarage_fee_per_execution)?;
@@ -196,12 +144,13 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn cleanup_previous_execution_matches(current_match: &ExecutionMatchFor<T>) {
+	pub(crate) fn cleanup_previous_execution_matches(current_match: &ExecutionMatchFor<T>) {
 		if current_match.execution_index < 2 {
 			return;
 		}
 		let index_to_cleanup = current_match.execution_index - 2;
 		<StoredJobExecutionStatus<T>>::remove(&current_match.job_id, index_to_cleanup);
+		<ExecutionCandidates<T>>::remove(&current_match.job_id, index_to_cleanup);
 		let mut to_remove: Vec<T::AccountId> = vec![];
 		for (processor, _) in <AssignedProcessors<T>>::iter_prefix(&current_match.job_id) {
 			let maybe_assignment = <StoredMatches<T>>::get(&processor, &current_match.job_id);
@@ -262,6 +211,17 @@ impl<T: Config> Pallet<T> {
 				Error::<T>::UnderdueMatch
 			);
 
+			if let AssignmentStrategy::CompetingCommitReveal = requirements.assignment_strategy {
+				Self::process_execution_candidates(
+					m,
+					&registration,
+					&requirements,
+					nth_start_time_lower_bound,
+					matcher_account,
+				)?;
+				continue;
+			}
+
 			let l: u8 = m.sources.len().try_into().unwrap_or(0);
 			ensure!(
 				// NOTE: we are checking for duplicates while inserting/mutating StoredMatches below
@@ -269,7 +229,7 @@ impl<T: Config> Pallet<T> {
 				Error::<T>::IncorrectSourceCountInMatch
 			);
 
-			let reward_amount: <T as Config>::Balance = requirements.reward;
+			let min_metrics = <RequiredMinMetrics<T>>::get(&m.job_id);
 
 			// keep track of total fee in assignments to check later if it exceeds reward
 			let mut total_fee: <T as Config>::Balance = 0u8.into();
@@ -280,124 +240,34 @@ impl<T: Config> Pallet<T> {
 			// `slot` is used for detecting duplicate source proposed for distinct slots
 			// TODO: add global (configurable) maximum of jobs assigned. This would limit the weight of `propose_execution_matching` to a constant, since it depends on the number of active matches.
 			for (slot, planned_execution) in m.sources.iter().enumerate() {
-				// CHECK attestation
-				ensure!(
-					!registration.allow_only_verified_sources
-						|| ensure_source_verified::<T>(&planned_execution.source).is_ok(),
-					Error::<T>::UnverifiedSourceInMatch
-				);
-
-				let ad = <StoredAdvertisementRestriction<T>>::get(&planned_execution.source)
-					.ok_or(Error::<T>::AdvertisementNotFound)?;
-
-				for required_module in &registration.required_modules {
-					ensure!(
-						ad.available_modules.contains(required_module),
-						Error::<T>::ModuleNotAvailableInMatch
-					);
-				}
-
-				// CHECK network request quota sufficient
-				Self::check_network_request_quota_sufficient(
-					&ad,
-					&registration.schedule,
-					registration.network_requests,
-				)?;
-
-				// CHECK source is whitelisted
-				ensure!(
-					is_processor_allowed::<T>(
-						&planned_execution.source,
-						&registration.allowed_sources
-					),
-					Error::<T>::SourceNotAllowedInMatch
-				);
-
-				// CHECK consumer is whitelisted
-				ensure!(
-					is_consumer_allowed::<T>(&m.job_id.0, &ad.allowed_consumers),
-					Error::<T>::ConsumerNotAllowedInMatch
-				);
-
-				// CHECK reputation sufficient
-				Self::check_min_reputation(requirements.min_reputation, &planned_execution.source)?;
-
-				Self::check_processor_version(
-					&requirements.processor_version,
-					&planned_execution.source,
-				)?;
-
-				Self::check_min_metrics(&m.job_id, &planned_execution.source)?;
-
-				// CHECK schedule
-				Self::fits_schedule(
+				let fee_per_execution = Self::check_source_matches(
 					&planned_execution.source,
+					&m.job_id.0,
+					&registration,
+					&requirements,
+					min_metrics.as_ref(),
 					ExecutionSpecifier::Index(m.execution_index),
-					&registration.schedule,
 					planned_execution.start_delay,
 				)?;
 
-				// calculate fee
-				let fee_per_execution =
-					Self::price_for(&planned_execution.source, &registration.schedule);
-
-				// CHECK price not exceeding reward
-				ensure!(fee_per_execution <= reward_amount, Error::<T>::InsufficientRewardInMatch);
-
 				total_fee = total_fee
 					.checked_add(&fee_per_execution)
 					.ok_or(Error::<T>::CalculationOverflow)?;
 
-				// ASSIGN if not yet assigned (equals to CHECK that no duplicate source in a single mutate operation)
-				<StoredMatches<T>>::try_mutate(
-					&planned_execution.source,
+				Self::assign_execution(
 					&m.job_id,
-					|s| -> Result<(), Error<T>> {
-						match s {
-							Some(prev_assignment) => {
-								if let ExecutionSpecifier::Index(e) = prev_assignment.execution {
-									if e == m.execution_index {
-										return Err(Error::<T>::DuplicateSourceInMatch);
-									}
-								}
-								*s = Some(Assignment {
-									slot: slot as u8,
-									execution: ExecutionSpecifier::Index(m.execution_index),
-									start_delay: planned_execution.start_delay,
-									fee_per_execution,
-									acknowledged: false,
-									// increment total executions expected
-									sla: SLA {
-										total: prev_assignment.sla.total.saturating_add(1),
-										met: prev_assignment.sla.met,
-									},
-									pub_keys: PubKeys::default(),
-								});
-								Ok::<(), Error<T>>(())
-							},
-							// NOTE: the None case is the "good case", used when there is *no entry yet and thus no duplicate assignment so far*.
-							None => {
-								*s = Some(Assignment {
-									slot: slot as u8,
-									execution: ExecutionSpecifier::Index(m.execution_index),
-									start_delay: planned_execution.start_delay,
-									fee_per_execution,
-									acknowledged: false,
-									// we start with one total executions expected and increment with every future match
-									sla: SLA { total: 1, met: 0 },
-									pub_keys: PubKeys::default(),
-								});
-								Ok(())
-							},
-						}?;
-						Ok(())
-					},
+					m.execution_index,
+					slot as u8,
+					planned_execution,
+					fee_per_execution,
 				)?;
-				<AssignedProcessors<T>>::insert(&m.job_id, &planned_execution.source, ());
 			}
 
 			// only update average on first execution's match to not have the average proportionally influenced by single executions that get matched
-			if m.execution_index == 0 && !total_fee.is_zero() {
+			if m.execution_index == 0
+				&& !total_fee.is_zero()
+				&& requirements.payment_asset.is_none()
+			{
 				let avarage_fee_per_execution = total_fee
 					.checked_div(&(m.sources.len() as u128).into())
 					.ok_or(Error::<T>::UnexpectedCheckedCalculation)?;
@@ -415,23 +285,91 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
-	fn price_for(processor: &T::AccountId, schedule: &Schedule) -> T::Balance {
+	/// Assigns a source to a slot of an execution of a job with [`AssignmentStrategy::Competing`] or [`AssignmentStrategy::CompetingCommitReveal`].
+	pub(crate) fn assign_execution(
+		job_id: &JobId<T::AccountId>,
+		execution_index: u64,
+		slot: u8,
+		planned_execution: &PlannedExecution<T::AccountId>,
+		fee_per_execution: T::Balance,
+	) -> Result<(), Error<T>> {
+		// ASSIGN if not yet assigned (equals to CHECK that no duplicate source in a single mutate operation)
+		<StoredMatches<T>>::try_mutate(
+			&planned_execution.source,
+			job_id,
+			|s| -> Result<(), Error<T>> {
+				match s {
+					Some(prev_assignment) => {
+						if let ExecutionSpecifier::Index(e) = prev_assignment.execution {
+							if e == execution_index {
+								return Err(Error::<T>::DuplicateSourceInMatch);
+							}
+						}
+						*s = Some(Assignment {
+							slot,
+							execution: ExecutionSpecifier::Index(execution_index),
+							start_delay: planned_execution.start_delay,
+							fee_per_execution,
+							acknowledged: false,
+							// increment total executions expected
+							sla: SLA {
+								total: prev_assignment.sla.total.saturating_add(1),
+								met: prev_assignment.sla.met,
+							},
+							pub_keys: PubKeys::default(),
+						});
+						Ok::<(), Error<T>>(())
+					},
+					// NOTE: the None case is the "good case", used when there is *no entry yet and thus no duplicate assignment so far*.
+					None => {
+						*s = Some(Assignment {
+							slot,
+							execution: ExecutionSpecifier::Index(execution_index),
+							start_delay: planned_execution.start_delay,
+							fee_per_execution,
+							acknowledged: false,
+							// we start with one total executions expected and increment with every future match
+							sla: SLA { total: 1, met: 0 },
+							pub_keys: PubKeys::default(),
+						});
+						Ok(())
+					},
+				}?;
+				Ok(())
+			},
+		)?;
+		<AssignedProcessors<T>>::insert(job_id, &planned_execution.source, ());
+		Ok(())
+	}
+
+	/// Calculates the price of `processor` per execution of `schedule`, converted into `payment_asset` if the job is not paid in the native currency.
+	fn price_for(
+		processor: &T::AccountId,
+		schedule: &Schedule,
+		payment_asset: Option<PaymentAssetId>,
+	) -> Result<T::Balance, Error<T>> {
 		let price_settings = Self::price_settings().unwrap_or(PriceSettingsFor::<T> {
 			min_price: T::DefaultMinPrice::get(),
 			multiplier: T::DefaultPriceMultiplier::get(),
 		});
 
-		price_settings
+		let price = price_settings
 			.multiplier
 			.saturating_mul_int(
 				T::ProcessorPriceProvider::price_per_millisecond_for(processor)
 					.map(|price| price.saturating_mul(schedule.duration.into()))
 					.unwrap_or_default(),
 			)
-			.max(price_settings.min_price)
+			.max(price_settings.min_price);
+
+		match payment_asset {
+			None => Ok(price),
+			Some(asset) => T::PaymentAssetPriceOracle::from_native(asset, price)
+				.ok_or(Error::<T>::UnsupportedPaymentAsset),
+		}
 	}
 
-	fn update_average_reward(fee_per_execution: u128) -> Result<(), DispatchError> {
+	pub(crate) fn update_average_reward(fee_per_execution: u128) -> Result<(), DispatchError> {
 		let total_assigned = <StoredTotalAssignedV3<T>>::mutate(|t| {
 			let new_value = t.unwrap_or(0u128).saturating_add(1);
 			*t = Some(new_value);
@@ -524,10 +462,10 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn check_min_metrics(
-		job_id: &JobId<T::AccountId>,
+		min_metrics: Option<&MinMetrics>,
 		processor: &T::AccountId,
 	) -> Result<(), Error<T>> {
-		let Some(min_metrics) = <RequiredMinMetrics<T>>::get(job_id) else {
+		let Some(min_metrics) = min_metrics else {
 			return Ok(());
 		};
 
@@ -546,6 +484,206 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Runs all checks required to match `source` with a job of `consumer`, in the order they are applied during matching.
+	///
+	/// The attestation is checked to be of [`ProcessorType::Core`] for [`ExecutionSpecifier::All`] and only to be valid for [`ExecutionSpecifier::Index`].
+	///
+	/// Returns the fee per execution the source would be assigned with.
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) fn check_source_matches(
+		source: &T::AccountId,
+		consumer: &MultiOrigin<T::AccountId>,
+		registration: &JobRegistrationFor<T>,
+		requirements: &JobRequirementsFor<T>,
+		min_metrics: Option<&MinMetrics>,
+		execution: ExecutionSpecifier,
+		start_delay: u64,
+	) -> Result<T::Balance, Error<T>> {
+		// CHECK attestation
+		if registration.allow_only_verified_sources {
+			let verified = match execution {
+				ExecutionSpecifier::All => {
+					ensure_source_verified_and_of_type::<T>(source, ProcessorType::Core).is_ok()
+				},
+				ExecutionSpecifier::Index(_) => ensure_source_verified::<T>(source).is_ok(),
+			};
+			ensure!(verified, Error::<T>::UnverifiedSourceInMatch);
+		}
+
+		let ad = <StoredAdvertisementRestriction<T>>::get(source)
+			.ok_or(Error::<T>::AdvertisementNotFound)?;
+
+		for required_module in &registration.required_modules {
+			ensure!(
+				ad.available_modules.contains(required_module),
+				Error::<T>::ModuleNotAvailableInMatch
+			);
+		}
+
+		// CHECK network request quota sufficient
+		Self::check_network_request_quota_sufficient(
+			&ad,
+			&registration.schedule,
+			registration.network_requests,
+		)?;
+
+		// CHECK source is whitelisted
+		ensure!(
+			is_processor_allowed::<T>(source, &registration.allowed_sources),
+			Error::<T>::SourceNotAllowedInMatch
+		);
+
+		// CHECK consumer is whitelisted
+		ensure!(
+			is_consumer_allowed::<T>(consumer, &ad.allowed_consumers),
+			Error::<T>::ConsumerNotAllowedInMatch
+		);
+
+		// CHECK reputation sufficient
+		Self::check_min_reputation(requirements.min_reputation, source)?;
+
+		Self::check_processor_version(&requirements.processor_version, source)?;
+
+		Self::check_min_metrics(min_metrics, source)?;
+
+		// CHECK schedule
+		Self::fits_schedule(source, execution, &registration.schedule, start_delay, None)?;
+
+		// calculate fee
+		let fee_per_execution =
+			Self::price_for(source, &registration.schedule, requirements.payment_asset)?;
+
+		// CHECK price not exceeding reward
+		ensure!(fee_per_execution <= requirements.reward, Error::<T>::InsufficientRewardInMatch);
+
+		Ok(fee_per_execution)
+	}
+
+	/// Runs the matching checks for a partially specified job registration against all advertised processors.
+	///
+	/// Checks depending on unspecified fields of `registration` are skipped, as is the consumer whitelist check if no `consumer` is provided.
+	/// Since the schedule is checked with a start delay of `0`, a processor might still be matched with a different start delay.
+	pub fn filter_matching_sources(
+		registration: PartialJobRegistrationForMarketplace<T>,
+		consumer: Option<MultiOrigin<T::AccountId>>,
+	) -> MatchingDryRunFor<T> {
+		let mut result = MatchingDryRunFor::<T>::default();
+		for (source, ad) in <StoredAdvertisementRestriction<T>>::iter() {
+			match Self::check_source_matches_partially(&source, &ad, &registration, &consumer) {
+				Ok(fee_per_execution) => result.matching.push((source, fee_per_execution)),
+				Err(error) => result.rejected.push((source, error.into())),
+			}
+		}
+		result
+	}
+
+	fn check_source_matches_partially(
+		source: &T::AccountId,
+		ad: &AdvertisementRestriction<T::AccountId, T::MaxAllowedConsumers>,
+		registration: &PartialJobRegistrationForMarketplace<T>,
+		consumer: &Option<MultiOrigin<T::AccountId>>,
+	) -> Result<Option<T::Balance>, Error<T>> {
+		// CHECK attestation
+		ensure!(
+			!registration.allow_only_verified_sources
+				|| ensure_source_verified_and_of_type::<T>(source, ProcessorType::Core).is_ok(),
+			Error::<T>::UnverifiedSourceInMatch
+		);
+
+		for required_module in &registration.required_modules {
+			ensure!(
+				ad.available_modules.contains(required_module),
+				Error::<T>::ModuleNotAvailableInMatch
+			);
+		}
+
+		// CHECK network request quota sufficient
+		if let (Some(schedule), Some(network_requests)) =
+			(&registration.schedule, registration.network_requests)
+		{
+			Self::check_network_request_quota_sufficient(ad, schedule, network_requests)?;
+		}
+
+		// CHECK source is whitelisted
+		ensure!(
+			is_processor_allowed::<T>(source, &registration.allowed_sources),
+			Error::<T>::SourceNotAllowedInMatch
+		);
+
+		// CHECK consumer is whitelisted
+		if let Some(consumer) = consumer {
+			ensure!(
+				is_consumer_allowed::<T>(consumer, &ad.allowed_consumers),
+				Error::<T>::ConsumerNotAllowedInMatch
+			);
+		}
+
+		// CHECK reputation sufficient
+		Self::check_min_reputation(registration.min_reputation, source)?;
+
+		let Some(schedule) = &registration.schedule else {
+			return Ok(None);
+		};
+
+		// CHECK schedule
+		Self::fits_schedule(source, ExecutionSpecifier::All, schedule, 0, None)?;
+
+		// calculate fee
+		let fee_per_execution = Self::price_for(source, schedule, registration.payment_asset)?;
+
+		// CHECK price not exceeding reward
+		ensure!(fee_per_execution <= registration.reward, Error::<T>::InsufficientRewardInMatch);
+
+		Ok(Some(fee_per_execution))
+	}
+
+	/// Runs the matching checks for a full job registration of `consumer` against all advertised processors.
+	///
+	/// The schedule is checked with a start delay of `0`, for [`AssignmentStrategy::Competing`] only for the next execution.
+	pub fn check_matching(
+		registration: JobRegistrationFor<T>,
+		consumer: MultiOrigin<T::AccountId>,
+		min_metrics: Option<Metrics>,
+	) -> Result<MatchingDryRunFor<T>, RuntimeApiError> {
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		let min_metrics = min_metrics
+			.map(|metrics| {
+				MinMetrics::try_from(
+					metrics
+						.into_iter()
+						.filter_map(MinMetric::checked_from)
+						.collect::<Vec<MinMetric>>(),
+				)
+			})
+			.transpose()
+			.map_err(|_| RuntimeApiError::CheckMatching)?;
+		let execution = match requirements.assignment_strategy {
+			AssignmentStrategy::Single(_) => ExecutionSpecifier::All,
+			AssignmentStrategy::Competing | AssignmentStrategy::CompetingCommitReveal => {
+				let now = Self::now().map_err(|_| RuntimeApiError::CheckMatching)?;
+				ExecutionSpecifier::Index(registration.schedule.next_execution_index(0, now))
+			},
+		};
+
+		let mut result = MatchingDryRunFor::<T>::default();
+		for source in <StoredAdvertisementRestriction<T>>::iter_keys() {
+			match Self::check_source_matches(
+				&source,
+				&consumer,
+				&registration,
+				&requirements,
+				min_metrics.as_ref(),
+				execution,
+				0,
+			) {
+				Ok(fee_per_execution) => result.matching.push((source, Some(fee_per_execution))),
+				Err(error) => result.rejected.push((source, error.into())),
+			}
+		}
+		Ok(result)
+	}
+
 	/// Returns true if the source has currently at least one match (not necessarily assigned).
 	pub(crate) fn has_matches(source: &T::AccountId) -> bool {
 		// NOTE we use a trick to check if map contains *any* secondary key: we use `any` to short-circuit
@@ -553,19 +691,73 @@ impl<T: Config> Pallet<T> {
 		<StoredMatches<T>>::iter_prefix_values(source).any(|_| true)
 	}
 
+	/// Checks if an assigned source can process the extended range of a job it is assigned to.
+	///
+	/// The checks only cover what the extension changes: the advertisement's capacity and allowed consumers and the source's schedule,
+	/// ignoring the source's current assignment for the same job.
+	pub(crate) fn check_extension_fits(
+		source: &T::AccountId,
+		job_id: &JobId<T::AccountId>,
+		extension_registration: &JobRegistrationFor<T>,
+		start_delay: u64,
+	) -> Result<(), Error<T>> {
+		let ad = <StoredAdvertisementRestriction<T>>::get(source)
+			.ok_or(Error::<T>::AdvertisementNotFound)?;
+
+		for required_module in &extension_registration.required_modules {
+			ensure!(
+				ad.available_modules.contains(required_module),
+				Error::<T>::ModuleNotAvailableInMatch
+			);
+		}
+
+		// CHECK network request quota sufficient
+		Self::check_network_request_quota_sufficient(
+			&ad,
+			&extension_registration.schedule,
+			extension_registration.network_requests,
+		)?;
+
+		// CHECK consumer is (still) whitelisted
+		ensure!(
+			is_consumer_allowed::<T>(&job_id.0, &ad.allowed_consumers),
+			Error::<T>::ConsumerNotAllowedInMatch
+		);
+
+		// CHECK schedule
+		Self::fits_schedule(
+			source,
+			ExecutionSpecifier::All,
+			&extension_registration.schedule,
+			start_delay,
+			Some(job_id),
+		)
+	}
+
 	/// Checks of a new job schedule fits with the existing schedule for a processor.
+	///
+	/// The processor's assignment for `excluded_job_id` is not considered.
 	fn fits_schedule(
 		source: &T::AccountId,
 		execution_specifier: ExecutionSpecifier,
 		schedule: &Schedule,
 		start_delay: u64,
+		excluded_job_id: Option<&JobId<T::AccountId>>,
 	) -> Result<(), Error<T>> {
+		Self::fits_availability(source, execution_specifier, schedule, start_delay)?;
+
 		let now = Self::now()?;
 		let report_tolerance = T::ReportTolerance::get();
 		for (job_id, assignment) in <StoredMatches<T>>::iter_prefix(source) {
+			if excluded_job_id == Some(&job_id) {
+				continue;
+			}
 			// ignore job registrations not found (shouldn't happen if invariant is kept that assignments are cleared whenever a job is removed)
 			// TODO decide tradeoff: we could save this lookup at the cost of storing the schedule along with the match or even completely move it from StoredJobRegistration into StoredMatches
-			if let Some(other) = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1) {
+			if let Some(mut other) = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1) {
+				// only consider the executions actually assigned (differs for extended jobs)
+				other.schedule =
+					Self::assignment_schedule(&job_id, source, &assignment, &other.schedule);
 				if assignment.is_invalid(&other.schedule, now, report_tolerance) {
 					// assignment that have not been acknowledged after the start time is already in the past do not count
 					continue;
@@ -667,6 +859,60 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Checks that the executions of a new job schedule do not intersect any of the processor's advertised unavailability windows.
+	fn fits_availability(
+		source: &T::AccountId,
+		execution_specifier: ExecutionSpecifier,
+		schedule: &Schedule,
+		start_delay: u64,
+	) -> Result<(), Error<T>> {
+		let Some(unavailability) = <StoredAdvertisementUnavailability<T>>::get(source) else {
+			return Ok(());
+		};
+		let (start, end) = schedule.range(start_delay);
+		if !unavailability.iter().any(|window| window.intersects(start, end)) {
+			// no window intersects the whole schedule so no detail checks are necessary
+			return Ok(());
+		}
+
+		let fits = |start: u64| -> Result<(), Error<T>> {
+			let end =
+				start.checked_add(schedule.duration).ok_or(Error::<T>::CalculationOverflow)?;
+			ensure!(
+				!unavailability.iter().any(|window| window.intersects(start, end)),
+				Error::<T>::SourceUnavailableInMatch
+			);
+			Ok(())
+		};
+
+		match execution_specifier {
+			ExecutionSpecifier::All => {
+				let first_start = schedule
+					.start_time
+					.checked_add(start_delay)
+					.ok_or(Error::<T>::CalculationOverflow)?;
+				ensure!(
+					!unavailability.iter().any(|window| window.intersects_any(
+						first_start,
+						schedule.interval,
+						schedule.execution_count(),
+						schedule.duration
+					)),
+					Error::<T>::SourceUnavailableInMatch
+				);
+			},
+			ExecutionSpecifier::Index(execution_index) => {
+				fits(
+					schedule
+						.nth_start_time(start_delay, execution_index)
+						.ok_or(Error::<T>::CalculationOverflow)?,
+				)?;
+			},
+		}
+
+		Ok(())
+	}
+
 	/// Calculates the total reward amount.
 	pub(crate) fn total_reward_amount(
 		registration: &JobRegistrationFor<T>,
@@ -682,6 +928,33 @@ impl<T: Config> Pallet<T> {
 			.ok_or(Error::<T>::CalculationOverflow)
 	}
 
+	/// Returns the fees for delivering the results of every slot and execution of a job to its origin, see [`ResultDeliveryFees`].
+	pub(crate) fn total_result_delivery_fee(
+		job_id: &JobId<T::AccountId>,
+		registration: &JobRegistrationFor<T>,
+	) -> Result<T::Balance, Error<T>> {
+		let Some(fee) = <ResultDeliveryFees<T>>::get(job_id) else {
+			return Ok(Zero::zero());
+		};
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+
+		fee.checked_mul(&((requirements.slots as u128).into()))
+			.ok_or(Error::<T>::CalculationOverflow)?
+			.checked_mul(&registration.schedule.execution_count().into())
+			.ok_or(Error::<T>::CalculationOverflow)
+	}
+
+	/// Returns the budget reserved for a job, the rewards and result delivery fees for every slot and execution.
+	pub(crate) fn total_budget_amount(
+		job_id: &JobId<T::AccountId>,
+		registration: &JobRegistrationFor<T>,
+	) -> Result<T::Balance, Error<T>> {
+		Self::total_reward_amount(registration)?
+			.checked_add(&Self::total_result_delivery_fee(job_id, registration)?)
+			.ok_or(Error::<T>::CalculationOverflow)
+	}
+
 	pub fn process_acknowledge_match(
 		who: T::AccountId,
 		job_id: JobId<T::AccountId>,
@@ -711,7 +984,7 @@ impl<T: Config> Pallet<T> {
 				let price_diff = requirements.reward.saturating_sub(assignment.fee_per_execution);
 				let remaining = match execution {
 					ExecutionSpecifier::All => {
-						price_diff.saturating_mul(job.schedule.execution_count().into())
+						price_diff.saturating_mul(assignment.sla.total.into())
 					},
 					ExecutionSpecifier::Index(_) => price_diff,
 				};
@@ -739,7 +1012,8 @@ impl<T: Config> Pallet<T> {
 				let now = Self::now()?;
 				let actual_start_time = match execution {
 					ExecutionSpecifier::All => {
-						schedule.start_time.saturating_add(assignment.start_delay)
+						Self::assignment_schedule(job_id, processor, assignment, schedule)
+							.actual_start(assignment.start_delay)
 					},
 					ExecutionSpecifier::Index(i) => {
 						schedule.nth_start_time(assignment.start_delay, i).unwrap_or_default()
@@ -805,6 +1079,20 @@ impl<T: Config> Pallet<T> {
 		Ok::<_, DispatchError>(())
 	}
 
+	/// Reverts the count of [`JobStatus::Assigned`] for the slot of an acknowledged processor that gets replaced,
+	/// so that the acknowledgment of its replacement counts the slot again.
+	pub(crate) fn release_assigned_slot(job_id: &JobId<T::AccountId>) {
+		<StoredJobStatus<T>>::mutate(&job_id.0, job_id.1, |status| {
+			if let Some(JobStatus::Assigned(count)) = status {
+				*status = Some(if *count > 1 {
+					JobStatus::Assigned(*count - 1)
+				} else {
+					JobStatus::Matched
+				});
+			}
+		});
+	}
+
 	fn update_next_report_index_on_acknowledge(
 		job_id: &JobId<T::AccountId>,
 		processor: &T::AccountId,
@@ -813,7 +1101,8 @@ impl<T: Config> Pallet<T> {
 	) -> Result<(), DispatchError> {
 		match execution {
 			ExecutionSpecifier::All => {
-				<NextReportIndex<T>>::insert(job_id, processor, 0);
+				let (first, _) = Self::assignment_execution_range(job_id, processor, assignment);
+				<NextReportIndex<T>>::insert(job_id, processor, first);
 				Ok::<_, DispatchError>(())
 			},
 			ExecutionSpecifier::Index(execution_index) => {
@@ -1,14 +1,52 @@
 #![allow(deprecated)]
 
 use frame_support::{
+	storage::unhashed,
 	traits::{GetStorageVersion, IsType, StorageVersion},
 	weights::Weight,
 	BoundedVec,
 };
+use pallet_acurast::{JobRegistration, StoredJobRegistration};
+use parity_scale_codec::DecodeAll;
 use sp_core::{ConstU32, Get};
 
 use super::*;
 
+mod v7 {
+	use frame_support::pallet_prelude::*;
+	use pallet_acurast::{JobRegistration, ParameterBound};
+
+	use crate::{AssignmentStrategy, ProcessorVersionRequirements, Runtime};
+
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq)]
+	pub struct JobRequirements<
+		Reward,
+		AccountId,
+		MaxSlots: ParameterBound,
+		Version,
+		MaxVersions: ParameterBound,
+	> {
+		pub assignment_strategy: AssignmentStrategy<AccountId, MaxSlots>,
+		pub slots: u8,
+		pub reward: Reward,
+		pub min_reputation: Option<u128>,
+		pub processor_version: Option<ProcessorVersionRequirements<Version, MaxVersions>>,
+		pub runtime: Runtime,
+	}
+
+	pub type JobRegistrationFor<T> = JobRegistration<
+		<T as frame_system::Config>::AccountId,
+		<T as pallet_acurast::Config>::MaxAllowedSources,
+		JobRequirements<
+			<T as crate::Config>::Balance,
+			<T as frame_system::Config>::AccountId,
+			<T as pallet_acurast::Config>::MaxSlots,
+			<T as pallet_acurast::Config>::ProcessorVersion,
+			<T as pallet_acurast::Config>::MaxVersions,
+		>,
+	>;
+}
+
 type MigrationFn = dyn Fn() -> (Weight, bool);
 
 pub fn migrate<T: Config>() -> Weight
@@ -23,7 +61,7 @@ where
 		>,
 	>,
 {
-	let migrations: [(u16, &MigrationFn); 1] = [(7, &migrate_to_v7::<T>)];
+	let migrations: [(u16, &MigrationFn); 2] = [(7, &migrate_to_v7::<T>), (8, &migrate_to_v8::<T>)];
 
 	let mut onchain_version = Pallet::<T>::on_chain_storage_version();
 	let mut weight: Weight = Default::default();
@@ -69,3 +107,91 @@ pub fn migrate_to_v7<T: Config>() -> (Weight, bool) {
 
 	(weight, migration_completed)
 }
+
+/// Adds [`JobRequirements::consensus`] and [`JobRequirements::deliver_results_to_origin`], disabled for all existing job
+/// registrations, and [`JobRequirements::payment_asset`], set to the native currency.
+pub fn migrate_to_v8<T: Config>() -> (Weight, bool)
+where
+	<T as pallet_acurast::Config>::RegistrationExtra: IsType<
+		RegistrationExtra<
+			T::Balance,
+			T::AccountId,
+			T::MaxSlots,
+			T::ProcessorVersion,
+			T::MaxVersions,
+		>,
+	>,
+{
+	const MIGRATION_LIMIT: u32 = 50;
+
+	let mut migration_completed = false;
+	let mut weight = T::DbWeight::get().reads(1);
+	let cursor = V8MigrationState::<T>::get().map(|c| c.to_vec());
+	if cursor.is_none() {
+		crate::Pallet::<T>::deposit_event(Event::<T>::V8MigrationStarted);
+	}
+	let mut keys = match cursor {
+		Some(cursor) => StoredJobRegistration::<T>::iter_keys_from(cursor),
+		None => StoredJobRegistration::<T>::iter_keys(),
+	};
+
+	let mut last_key = None;
+	for _ in 0..MIGRATION_LIMIT {
+		let Some((origin, job_id_sequence)) = keys.next() else {
+			migration_completed = true;
+			break;
+		};
+		let key = StoredJobRegistration::<T>::hashed_key_for(&origin, job_id_sequence);
+		weight = weight.saturating_add(T::DbWeight::get().reads(1));
+		// registrations stored after the upgrade already have the new format and fail to decode entirely as the old one
+		let old_registration = unhashed::get_raw(&key)
+			.and_then(|raw| v7::JobRegistrationFor::<T>::decode_all(&mut raw.as_slice()).ok());
+		if let Some(old) = old_registration {
+			let extra: <T as pallet_acurast::Config>::RegistrationExtra = RegistrationExtra {
+				requirements: JobRequirements {
+					assignment_strategy: old.extra.assignment_strategy,
+					slots: old.extra.slots,
+					reward: old.extra.reward,
+					min_reputation: old.extra.min_reputation,
+					processor_version: old.extra.processor_version,
+					runtime: old.extra.runtime,
+					consensus: false,
+					payment_asset: None,
+					deliver_results_to_origin: false,
+				},
+			}
+			.into();
+			unhashed::put(
+				&key,
+				&JobRegistration {
+					script: old.script,
+					allowed_sources: old.allowed_sources,
+					allow_only_verified_sources: old.allow_only_verified_sources,
+					schedule: old.schedule,
+					memory: old.memory,
+					network_requests: old.network_requests,
+					storage: old.storage,
+					required_modules: old.required_modules,
+					extra,
+				},
+			);
+			weight = weight.saturating_add(T::DbWeight::get().writes(1));
+		}
+		last_key = Some(key);
+	}
+
+	if !migration_completed && keys.next().is_none() {
+		migration_completed = true;
+	}
+	if migration_completed {
+		V8MigrationState::<T>::kill();
+		crate::Pallet::<T>::deposit_event(Event::<T>::V8MigrationCompleted);
+	} else {
+		let bounded_cursor: Option<BoundedVec<u8, ConstU32<256>>> =
+			last_key.and_then(|key| key.try_into().ok());
+		V8MigrationState::<T>::set(bounded_cursor);
+	}
+	weight = weight.saturating_add(T::DbWeight::get().writes(1));
+
+	(weight, migration_completed)
+}
@@ -2,24 +2,28 @@ use acurast_common::{ManagerIdProvider, Slashable};
 #[cfg(feature = "runtime-benchmarks")]
 use frame_support::traits::fungible;
 use frame_support::{
-	derive_impl, parameter_types,
+	derive_impl,
+	pallet_prelude::DispatchResultWithPostInfo,
+	parameter_types,
 	sp_runtime::{
 		traits::{AccountIdConversion, BlakeTwo256, IdentityLookup},
-		BuildStorage, FixedU128, Perbill, Percent, Perquintill,
+		BuildStorage, DispatchError, FixedU128, Perbill, Percent, Perquintill,
 	},
 	traits::{
 		nonfungibles::{Create, InspectEnumerable as NFTInspectEnumerable},
 		AsEnsureOriginWithArg, LockIdentifier,
 	},
+	weights::Weight,
 	PalletId,
 };
 use frame_system::{EnsureRoot, EnsureRootWithSuccess};
 use pallet_acurast_compute::BlockAuthorProvider;
+use parity_scale_codec::Encode;
 use sp_core::*;
 use sp_io;
 use sp_std::prelude::*;
 
-use pallet_acurast::{CommitmentIdProvider, JobModules, ManagerLookup, CU32};
+use pallet_acurast::{CommitmentIdProvider, JobId, JobModules, ManagerLookup, CU32};
 
 use crate::{stub::*, *};
 
@@ -87,6 +91,7 @@ frame_support::construct_runtime!(
 		AcurastMarketplace: crate::{Pallet, Call, Storage, Event<T>},
 		AcurastCompute: pallet_acurast_compute::{Pallet, Call, Storage, Event<T>},
 		Uniques: pallet_uniques,
+		Assets: pallet_assets,
 	}
 );
 
@@ -104,6 +109,10 @@ parameter_types! {
 	pub const AcurastPalletId: PalletId = PalletId(*b"acrstpid");
 	pub const HyperdrivePalletId: PalletId = PalletId(*b"hypdrpid");
 	pub const ReportTolerance: u64 = 70_000;
+	pub const CancellationNoticePeriod: u64 = 3_600_000;
+	pub const CancellationFee: Percent = Percent::from_percent(10);
+	pub const SlaSlashThreshold: Percent = Percent::from_percent(75);
+	pub const SlaSlashConsumerShare: Percent = Percent::from_percent(80);
 	pub RootAccountId: AccountId = alice_account_id();
 }
 
@@ -158,12 +167,20 @@ impl pallet_acurast::Config for Test {
 	type RegistrationExtra = ExtraFor<Self>;
 	type MaxAllowedSources = CU32<4>;
 	type MaxCertificateRevocationListUpdates = frame_support::traits::ConstU32<10>;
+	type MaxAttestationAllowlistUpdates = frame_support::traits::ConstU32<10>;
+	type MaxAttestationAllowlistEntries = frame_support::traits::ConstU32<32>;
+	type InitialAttestationAllowlist = ();
+	type ReattestationInterval = frame_support::traits::ConstU64<{ u64::MAX }>;
+	type ReattestationGracePeriod = frame_support::traits::ConstU64<0>;
+	type MaxAttestationExpiryChecks = frame_support::traits::ConstU32<10>;
+	type MaxRevocationPropagations = frame_support::traits::ConstU32<10>;
 	type MaxSlots = CU32<64>;
 	type PalletId = AcurastPalletId;
 	type MaxEnvVars = CU32<10>;
 	type EnvKeyMaxSize = CU32<32>;
 	type EnvValueMaxSize = CU32<1024>;
 	type KeyAttestationBarrier = ();
+	type AttestationVerifiers = ();
 	type UnixTime = pallet_timestamp::Pallet<Test>;
 	type JobHooks = Pallet<Test>;
 	type ProcessorVersion = u32;
@@ -196,6 +213,15 @@ impl pallet_uniques::Config for Test {
 	type Helper = ();
 }
 
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+	type Balance = Balance;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type Freezer = ();
+}
+
 parameter_types! {
 	pub const Epoch: BlockNumber = 900; // 1.5 hours
 	pub const BusyWeightBonus: Perquintill = Perquintill::from_percent(20);
@@ -286,6 +312,26 @@ impl Slashable<AccountId> for MockSlashable {
 	}
 }
 
+/// Slashes from the free balance, so that tests can observe where slashed funds go.
+pub struct MockSlaSlashable;
+impl Slashable<AccountId> for MockSlaSlashable {
+	type Currency = Balances;
+
+	fn slash(
+		account: &AccountId,
+		amount: acurast_common::BalanceFor<Self::Currency, AccountId>,
+	) -> Option<acurast_common::ImbalanceFor<Self::Currency, AccountId>> {
+		<Balances as frame_support::traits::fungible::Balanced<AccountId>>::withdraw(
+			account,
+			amount,
+			frame_support::traits::tokens::Precision::BestEffort,
+			frame_support::traits::tokens::Preservation::Expendable,
+			frame_support::traits::tokens::Fortitude::Force,
+		)
+		.ok()
+	}
+}
+
 pub const MANAGER_COLLECTION_ID: u128 = 0;
 pub const COMMITMENT_COLLECTION_ID: u128 = 1;
 
@@ -392,6 +438,9 @@ impl pallet_acurast::BenchmarkHelper<Test> for TestBenchmarkHelper {
 				min_reputation: None,
 				processor_version: Some(ProcessorVersionRequirements::Min(bounded_vec!(1))),
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		}
 	}
@@ -457,18 +506,34 @@ impl Config for Test {
 	type Competing = pallet_acurast::CU32<2>;
 	type MatchingCompetingMinInterval = frame_support::traits::ConstU64<300_000>;
 	type MatchingCompetingDueDelta = frame_support::traits::ConstU64<120_000>;
+	type MatchingCommitRevealPeriod = frame_support::traits::ConstU64<30_000>;
+	type Randomness = MockRandomness;
+	type ResultRevealPeriod = frame_support::traits::ConstU64<30_000>;
+	type ExtensionAcceptPeriod = frame_support::traits::ConstU64<600_000>;
 	type MaxProposedMatches = frame_support::traits::ConstU32<10>;
 	type MaxProposedExecutionMatches = frame_support::traits::ConstU32<10>;
 	type MaxFinalizeJobs = frame_support::traits::ConstU32<10>;
 	type MaxJobCleanups = frame_support::traits::ConstU32<100>;
+	type MaxUnverifiedAssignmentRemovals = frame_support::traits::ConstU32<10>;
 	type RegistrationExtra = ExtraFor<Test>;
 	type PalletId = AcurastPalletId;
 	type HyperdrivePalletId = HyperdrivePalletId;
 	type ReportTolerance = ReportTolerance;
+	type CancellationNoticePeriod = CancellationNoticePeriod;
+	type CancellationFee = CancellationFee;
+	type SlaSlashThreshold = SlaSlashThreshold;
+	type SlaSlashConsumerShare = SlaSlashConsumerShare;
+	type Slashable = MockSlaSlashable;
 	type Balance = Balance;
-	type RewardManager = AssetRewardManager<FeeManagerImpl, Balances, Pallet<Self>, ()>;
+	type RewardManager = FungiblesRewardManager<
+		FeeManagerImpl,
+		AssetRewardManager<FeeManagerImpl, Balances, Pallet<Self>, ()>,
+		Assets,
+		Pallet<Self>,
+		(),
+	>;
 	type ProcessorInfoProvider = ProcessorLastSeenProvider;
-	type MarketplaceHooks = ();
+	type MarketplaceHooks = MockMarketplaceHooks;
 	type DeploymentHashing = BlakeTwo256;
 	type KeyIdHashing = BlakeTwo256;
 	type WeightInfo = weights::WeightInfo<Test>;
@@ -477,10 +542,20 @@ impl Config for Test {
 	type ProcessorPriceProvider = MockPriceProvider;
 	type DefaultMinPrice = MinPrice;
 	type DefaultPriceMultiplier = PriceMultiplier;
+	type PaymentAssetPriceOracle = MockPaymentAssetPriceOracle;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = TestBenchmarkHelper;
 }
 
+/// Derives the randomness from the subject and the current block number.
+pub struct MockRandomness;
+impl frame_support::traits::Randomness<H256, u64> for MockRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let block_number = System::block_number();
+		(H256(sp_io::hashing::blake2_256(&(subject, block_number).encode())), block_number)
+	}
+}
+
 pub struct MockPriceProvider;
 impl PriceProvider<AccountId, Balance> for MockPriceProvider {
 	fn price_per_millisecond_for(
@@ -490,6 +565,48 @@ impl PriceProvider<AccountId, Balance> for MockPriceProvider {
 	}
 }
 
+/// Supports payments in [`MOCK_PAYMENT_ASSET`], priced at twice the native currency.
+pub struct MockPaymentAssetPriceOracle;
+impl PaymentAssetPriceOracle<Balance> for MockPaymentAssetPriceOracle {
+	fn from_native(asset: PaymentAssetId, amount: Balance) -> Option<Balance> {
+		(asset == MOCK_PAYMENT_ASSET).then(|| amount.saturating_mul(2))
+	}
+}
+
+pub const MOCK_PAYMENT_ASSET: PaymentAssetId = 1;
+
+pub const MOCK_RESULT_DELIVERY_FEE: Balance = 100_000;
+
+/// Delivers results to every origin, except failure results which are rejected to test reverting a failed delivery.
+pub struct MockMarketplaceHooks;
+impl MarketplaceHooks<Test> for MockMarketplaceHooks {
+	fn assign_job(_job_id: &JobId<AccountId>, _pub_keys: &PubKeys) -> DispatchResultWithPostInfo {
+		Ok(().into())
+	}
+
+	fn finalize_job(_job_id: &JobId<AccountId>, _refund: Balance) -> DispatchResultWithPostInfo {
+		Ok(().into())
+	}
+
+	fn result_delivery_fee(_job_id: &JobId<AccountId>) -> Option<(AccountId, Balance)> {
+		Some((result_delivery_fee_payer(), MOCK_RESULT_DELIVERY_FEE))
+	}
+
+	fn deliver_result(
+		_job_id: &JobId<AccountId>,
+		execution_result: &ExecutionResult,
+	) -> DispatchResultWithPostInfo {
+		match execution_result {
+			ExecutionResult::Success(_) => Ok(().into()),
+			ExecutionResult::Failure(_) => Err(DispatchError::Other("delivery failed").into()),
+		}
+	}
+
+	fn deliver_result_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl crate::benchmarking::BenchmarkHelper<Test> for TestBenchmarkHelper {
 	fn registration_extra(r: JobRequirementsFor<Test>) -> <Test as Config>::RegistrationExtra {
@@ -522,6 +639,10 @@ pub fn pallet_acurast_acount() -> <Test as frame_system::Config>::AccountId {
 	PalletId(*b"acrstpid").into_account_truncating()
 }
 
+pub fn result_delivery_fee_payer() -> <Test as frame_system::Config>::AccountId {
+	PalletId(*b"hypdrfee").into_account_truncating()
+}
+
 pub fn advertisement(
 	fee_per_millisecond: u128,
 	fee_per_storage_byte: u128,
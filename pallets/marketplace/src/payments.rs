@@ -7,6 +7,7 @@ use frame_support::{
 	},
 	traits::{
 		fungible::{Balanced, Credit},
+		fungibles,
 		tokens::{fungible, Fortitude, Precision, Preservation},
 		OnUnbalanced,
 	},
@@ -15,7 +16,7 @@ use frame_support::{
 
 use pallet_acurast::{JobId, MultiOrigin};
 
-use crate::Config;
+use crate::{Config, Pallet, PaymentAssetId};
 
 /// Trait used to manage lock up and payments of rewards.
 pub trait RewardManager<T: frame_system::Config + Config> {
@@ -32,7 +33,18 @@ pub trait RewardManager<T: frame_system::Config + Config> {
 		price_difference: <T as Config>::Balance,
 		matcher: &T::AccountId,
 	) -> Result<(), DispatchError>;
+	fn pay_cancellation_fee(
+		job_id: &JobId<T::AccountId>,
+		fee: <T as Config>::Balance,
+	) -> Result<(), DispatchError>;
+	fn pay_result_delivery_fee(
+		job_id: &JobId<T::AccountId>,
+		fee: <T as Config>::Balance,
+		payer: &T::AccountId,
+	) -> Result<(), DispatchError>;
 	fn refund(job_id: &JobId<T::AccountId>) -> Result<T::Balance, DispatchError>;
+	/// Whether jobs can be paid in `asset`, see [`crate::JobRequirements::payment_asset`].
+	fn supports_payment_asset(asset: PaymentAssetId) -> bool;
 }
 
 impl<T: frame_system::Config + Config> RewardManager<T> for () {
@@ -58,9 +70,28 @@ impl<T: frame_system::Config + Config> RewardManager<T> for () {
 		Ok(())
 	}
 
+	fn pay_cancellation_fee(
+		_job_id: &JobId<T::AccountId>,
+		_fee: <T as Config>::Balance,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
+	fn pay_result_delivery_fee(
+		_job_id: &JobId<T::AccountId>,
+		_fee: <T as Config>::Balance,
+		_payer: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		Ok(())
+	}
+
 	fn refund(_job_id: &JobId<T::AccountId>) -> Result<T::Balance, DispatchError> {
 		Ok(0u8.into())
 	}
+
+	fn supports_payment_asset(_asset: PaymentAssetId) -> bool {
+		false
+	}
 }
 
 // This trait provides methods for managing the fees.
@@ -188,6 +219,51 @@ where
 		Ok(())
 	}
 
+	fn pay_cancellation_fee(
+		job_id: &JobId<T::AccountId>,
+		fee: T::Balance,
+	) -> Result<(), DispatchError> {
+		Budget::unreserve(job_id, fee)
+			.map_err(|_| DispatchError::Other("Severe Error: JobBudget::unreserve failed"))?;
+
+		let pallet_account: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
+		// Transfer the cancellation fee to Acurast fees manager account
+		let fee_pallet_account: T::AccountId = AssetSplit::pallet_id().into_account_truncating();
+
+		if fee.gt(&(0u128.into())) {
+			Currency::transfer(
+				&pallet_account,
+				&fee_pallet_account,
+				fee.saturated_into::<<Currency as fungible::Inspect<T::AccountId>>::Balance>(),
+				Preservation::Preserve,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	fn pay_result_delivery_fee(
+		job_id: &JobId<T::AccountId>,
+		fee: T::Balance,
+		payer: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		Budget::unreserve(job_id, fee)
+			.map_err(|_| DispatchError::Other("Severe Error: JobBudget::unreserve failed"))?;
+
+		let pallet_account: T::AccountId = <T as Config>::PalletId::get().into_account_truncating();
+		// Refund the account paying the message fee for the delivery
+		if fee.gt(&(0u128.into())) {
+			Currency::transfer(
+				&pallet_account,
+				payer,
+				fee.saturated_into::<<Currency as fungible::Inspect<T::AccountId>>::Balance>(),
+				Preservation::Preserve,
+			)?;
+		}
+
+		Ok(())
+	}
+
 	fn refund(job_id: &JobId<T::AccountId>) -> Result<T::Balance, DispatchError> {
 		let remaining = Budget::unreserve_remaining(job_id);
 		// Send remaining funds to the job creator
@@ -224,6 +300,216 @@ where
 
 		Ok(remaining)
 	}
+
+	fn supports_payment_asset(_asset: PaymentAssetId) -> bool {
+		// pays in the native currency only
+		false
+	}
+}
+
+/// A [`RewardManager`] paying jobs with a [`crate::JobRequirements::payment_asset`] in the `Assets` of a `fungibles` implementation.
+/// All other jobs are paid in the native currency by the `Native` reward manager, e.g. an [`AssetRewardManager`].
+///
+/// The amounts managed by the `Budget` are denominated in the job's payment asset.
+pub struct FungiblesRewardManager<AssetSplit, Native, Assets, JobBudget, AssetsOU>(
+	PhantomData<(AssetSplit, Native, Assets, JobBudget, AssetsOU)>,
+);
+
+/// Transfers `amount` of `asset` if it is not zero.
+fn transfer_asset<T, Assets>(
+	asset: PaymentAssetId,
+	source: &T::AccountId,
+	dest: &T::AccountId,
+	amount: T::Balance,
+) -> Result<(), DispatchError>
+where
+	T: Config + frame_system::Config,
+	Assets: fungibles::Mutate<T::AccountId, Balance = T::Balance>,
+	Assets::AssetId: From<PaymentAssetId>,
+{
+	if amount.gt(&(0u128.into())) {
+		// the pallet account only holds job budgets and is allowed to be reaped when all were paid out
+		let preservation = if source == &pallet_account::<T>() {
+			Preservation::Expendable
+		} else {
+			Preservation::Preserve
+		};
+		Assets::transfer(asset.into(), source, dest, amount, preservation)?;
+	}
+	Ok(())
+}
+
+fn pallet_account<T: Config>() -> T::AccountId {
+	<T as Config>::PalletId::get().into_account_truncating()
+}
+
+impl<T, AssetSplit, Native, Assets, Budget, AssetsOU> RewardManager<T>
+	for FungiblesRewardManager<AssetSplit, Native, Assets, Budget, AssetsOU>
+where
+	T: Config + frame_system::Config,
+	AssetSplit: FeeManager,
+	Native: RewardManager<T>,
+	Assets: fungibles::Mutate<T::AccountId, Balance = T::Balance>
+		+ fungibles::Balanced<T::AccountId, Balance = T::Balance>,
+	Assets::AssetId: From<PaymentAssetId>,
+	Budget: JobBudget<T>,
+	AssetsOU: OnUnbalanced<fungibles::Credit<T::AccountId, Assets>>,
+{
+	fn lock_reward(job_id: &JobId<T::AccountId>, reward: T::Balance) -> Result<(), DispatchError> {
+		let Some(asset) = Pallet::<T>::payment_asset(job_id)? else {
+			return Native::lock_reward(job_id, reward);
+		};
+
+		let hyperdrive_pallet_account: T::AccountId =
+			<T as Config>::HyperdrivePalletId::get().into_account_truncating();
+		match &job_id.0 {
+			MultiOrigin::Acurast(who) => {
+				transfer_asset::<T, Assets>(asset, who, &pallet_account::<T>(), reward)?;
+			},
+			MultiOrigin::Tezos(_)
+			| MultiOrigin::Ethereum(_)
+			| MultiOrigin::AlephZero(_)
+			| MultiOrigin::Vara(_)
+			| MultiOrigin::Ethereum20(_)
+			| MultiOrigin::Solana(_) => {
+				// The availability of these funds was ensured on the target chain side
+				transfer_asset::<T, Assets>(
+					asset,
+					&hyperdrive_pallet_account,
+					&pallet_account::<T>(),
+					reward,
+				)?;
+			},
+			MultiOrigin::AcurastCanary(_) => {
+				return Err(DispatchError::Other("Unexpected MultiOrigin"));
+			},
+		};
+
+		Budget::reserve(job_id, reward)
+			.map_err(|_| DispatchError::Other("Severe Error: JobBudget::reserve failed"))?;
+
+		Ok(())
+	}
+
+	fn handle_reward(
+		job_id: &JobId<T::AccountId>,
+		reward: T::Balance,
+	) -> Result<(), DispatchError> {
+		let Some(asset) = Pallet::<T>::payment_asset(job_id)? else {
+			return Native::handle_reward(job_id, reward);
+		};
+
+		Budget::unreserve(job_id, reward)
+			.map_err(|_| DispatchError::Other("Severe Error: JobBudget::unreserve failed"))?;
+
+		let imbalance = <Assets as fungibles::Balanced<T::AccountId>>::withdraw(
+			asset.into(),
+			&pallet_account::<T>(),
+			reward,
+			Precision::Exact,
+			Preservation::Expendable,
+			Fortitude::Polite,
+		)?;
+
+		AssetsOU::on_unbalanced(imbalance);
+
+		Ok(())
+	}
+
+	fn pay_matcher_reward(
+		job_id: &JobId<T::AccountId>,
+		price_difference: T::Balance,
+		matcher: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		let Some(asset) = Pallet::<T>::payment_asset(job_id)? else {
+			return Native::pay_matcher_reward(job_id, price_difference, matcher);
+		};
+
+		let matcher_fee = AssetSplit::get_matcher_percentage().mul_floor(price_difference);
+		Budget::unreserve(job_id, matcher_fee)
+			.map_err(|_| DispatchError::Other("Severe Error: JobBudget::unreserve failed"))?;
+
+		// Extract fee from the matcher reward
+		let fee = AssetSplit::get_fee_percentage().mul_floor(matcher_fee);
+		let reward_after_fee = matcher_fee - fee;
+
+		// Transfer fees to Acurast fees manager account
+		let fee_pallet_account: T::AccountId = AssetSplit::pallet_id().into_account_truncating();
+		transfer_asset::<T, Assets>(asset, &pallet_account::<T>(), &fee_pallet_account, fee)?;
+		transfer_asset::<T, Assets>(asset, &pallet_account::<T>(), matcher, reward_after_fee)?;
+
+		Ok(())
+	}
+
+	fn pay_cancellation_fee(
+		job_id: &JobId<T::AccountId>,
+		fee: T::Balance,
+	) -> Result<(), DispatchError> {
+		let Some(asset) = Pallet::<T>::payment_asset(job_id)? else {
+			return Native::pay_cancellation_fee(job_id, fee);
+		};
+
+		Budget::unreserve(job_id, fee)
+			.map_err(|_| DispatchError::Other("Severe Error: JobBudget::unreserve failed"))?;
+
+		// Transfer the cancellation fee to Acurast fees manager account
+		let fee_pallet_account: T::AccountId = AssetSplit::pallet_id().into_account_truncating();
+		transfer_asset::<T, Assets>(asset, &pallet_account::<T>(), &fee_pallet_account, fee)?;
+
+		Ok(())
+	}
+
+	fn pay_result_delivery_fee(
+		job_id: &JobId<T::AccountId>,
+		fee: T::Balance,
+		payer: &T::AccountId,
+	) -> Result<(), DispatchError> {
+		if Pallet::<T>::payment_asset(job_id)?.is_some() {
+			// message fees are paid in the native currency, registration rejects result delivery for these jobs
+			return Err(DispatchError::Other("Result delivery fee not payable in asset"));
+		}
+
+		Native::pay_result_delivery_fee(job_id, fee, payer)
+	}
+
+	fn refund(job_id: &JobId<T::AccountId>) -> Result<T::Balance, DispatchError> {
+		let Some(asset) = Pallet::<T>::payment_asset(job_id)? else {
+			return Native::refund(job_id);
+		};
+
+		let remaining = Budget::unreserve_remaining(job_id);
+		// Send remaining funds to the job creator
+		let hyperdrive_pallet_account: T::AccountId =
+			<T as Config>::HyperdrivePalletId::get().into_account_truncating();
+		match &job_id.0 {
+			MultiOrigin::Acurast(who) => {
+				transfer_asset::<T, Assets>(asset, &pallet_account::<T>(), who, remaining)?;
+			},
+			MultiOrigin::Tezos(_)
+			| MultiOrigin::Ethereum(_)
+			| MultiOrigin::AlephZero(_)
+			| MultiOrigin::Vara(_)
+			| MultiOrigin::Ethereum20(_)
+			| MultiOrigin::Solana(_) => {
+				// TODO refunded amount is collected on hyperdrive_pallet_account but not yet refunded to proxy chain
+				transfer_asset::<T, Assets>(
+					asset,
+					&pallet_account::<T>(),
+					&hyperdrive_pallet_account,
+					remaining,
+				)?;
+			},
+			MultiOrigin::AcurastCanary(_) => {
+				return Err(DispatchError::Other("Unexpected MultiOrigin"));
+			},
+		};
+
+		Ok(remaining)
+	}
+
+	fn supports_payment_asset(asset: PaymentAssetId) -> bool {
+		<Assets as fungibles::Inspect<T::AccountId>>::asset_exists(asset.into())
+	}
 }
 
 /// Manages each job's budget by reserving/unreserving rewards that are externally strored, e.g. on a pallet account in `pallet_balances`.
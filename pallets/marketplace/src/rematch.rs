@@ -0,0 +1,179 @@
+use frame_support::{ensure, pallet_prelude::*};
+use pallet_acurast::{JobId, JobRegistrationFor, RequiredMinMetrics, StoredJobRegistration};
+use sp_std::prelude::*;
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Removes a lapsed assignment of a job with [`AssignmentStrategy::Single`] and marks its slot as re-matchable
+	/// from the next execution on.
+	///
+	/// The next execution is the first one not started yet with the start delay of the failed processor. Its reputation and SLA
+	/// are settled for the executions before. Its unused budget stays reserved for the job and pays the replacement processor.
+	/// An acknowledged slot is no longer counted in [`JobStatus::Assigned`] until the replacement acknowledges. The failed
+	/// processor is recorded in [`LapsedProcessors`] so it cannot replace a slot of the job.
+	///
+	/// Returns the slot and the index of the first execution the slot is re-matchable for.
+	pub(crate) fn do_mark_slot_rematchable(
+		job_id: &JobId<T::AccountId>,
+		processor: &T::AccountId,
+	) -> Result<(u8, u64), DispatchError> {
+		let registration = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1)
+			.ok_or(pallet_acurast::Error::<T>::JobRegistrationNotFound)?;
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		ensure!(
+			matches!(requirements.assignment_strategy, AssignmentStrategy::Single(_)),
+			Error::<T>::RematchOnlyForSingleAssignment
+		);
+		ensure!(!<StoredJobExtension<T>>::contains_key(job_id), Error::<T>::JobExtensionInProgress);
+
+		let mut assignment =
+			<StoredMatches<T>>::get(processor, job_id).ok_or(Error::<T>::JobNotAssigned)?;
+		let now = Self::now()?;
+		ensure!(
+			Self::is_assignment_lapsed(job_id, processor, &assignment, &registration, now),
+			Error::<T>::AssignmentNotLapsed
+		);
+
+		let first_execution_index =
+			registration.schedule.next_execution_index(assignment.start_delay, now);
+		ensure!(
+			first_execution_index < registration.schedule.execution_count(),
+			Error::<T>::NoRemainingExecutions
+		);
+
+		// settle the executions that were due for the failed processor
+		let (first, _) = Self::assignment_execution_range(job_id, processor, &assignment);
+		assignment.sla.total = first_execution_index.saturating_sub(first);
+		if assignment.acknowledged {
+			let missing_reports = assignment.sla.total.saturating_sub(assignment.sla.met);
+			Self::do_update_reputation(processor, &assignment, missing_reports)?;
+		}
+		Self::do_slash_for_sla(job_id, processor, &assignment);
+
+		<StoredMatches<T>>::remove(processor, job_id);
+		<AssignedProcessors<T>>::remove(job_id, processor);
+		<ExtendedAssignmentStart<T>>::remove(job_id, processor);
+		<NextReportIndex<T>>::remove(job_id, processor);
+		<RematchableSlots<T>>::insert(job_id, assignment.slot, first_execution_index);
+		<LapsedProcessors<T>>::insert(job_id, processor, ());
+		if assignment.acknowledged {
+			Self::release_assigned_slot(job_id);
+		}
+
+		Ok((assignment.slot, first_execution_index))
+	}
+
+	/// Returns if an assignment lapsed at `now`.
+	///
+	/// An assignment lapsed if it was not acknowledged before its start or if its processor missed to report
+	/// within the report window of the next expected execution.
+	pub(crate) fn is_assignment_lapsed(
+		job_id: &JobId<T::AccountId>,
+		processor: &T::AccountId,
+		assignment: &AssignmentFor<T>,
+		registration: &JobRegistrationFor<T>,
+		now: u64,
+	) -> bool {
+		let report_tolerance = T::ReportTolerance::get();
+		if !assignment.acknowledged {
+			let schedule =
+				Self::assignment_schedule(job_id, processor, assignment, &registration.schedule);
+			return assignment.is_invalid(&schedule, now, report_tolerance);
+		}
+		let Some(next_report_index) = <NextReportIndex<T>>::get(job_id, processor) else {
+			// all executions got reported
+			return false;
+		};
+		registration
+			.schedule
+			.nth_start_time(assignment.start_delay, next_report_index)
+			.map(|start| {
+				start
+					.saturating_add(registration.schedule.duration)
+					.saturating_add(report_tolerance)
+					< now
+			})
+			.unwrap_or(false)
+	}
+
+	/// Matches replacement processors for the re-matchable slots of a job, in ascending order of the slots.
+	///
+	/// Each replacement is assigned for the executions starting at the index its slot is re-matchable for. Processors whose
+	/// assignment to the job lapsed are rejected.
+	pub(crate) fn process_replacement_matching(
+		m: &MatchFor<T>,
+		registration: &JobRegistrationFor<T>,
+		matcher_account: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let mut slots: Vec<(u8, u64)> = <RematchableSlots<T>>::iter_prefix(&m.job_id).collect();
+		slots.sort_by_key(|(slot, _)| *slot);
+		ensure!(m.sources.len() == slots.len(), Error::<T>::IncorrectSourceCountInMatch);
+
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		let min_metrics = <RequiredMinMetrics<T>>::get(&m.job_id);
+		let now = Self::now()?;
+
+		for ((slot, first_execution_index), planned_execution) in
+			slots.into_iter().zip(m.sources.iter())
+		{
+			let remaining_registration =
+				Self::registration_from(registration, first_execution_index)?;
+			// CHECK that the replacement is matched before its first execution, started with its own start delay
+			ensure!(
+				now < remaining_registration.schedule.actual_start(planned_execution.start_delay),
+				Error::<T>::OverdueMatch
+			);
+			ensure!(
+				!<LapsedProcessors<T>>::contains_key(&m.job_id, &planned_execution.source),
+				Error::<T>::LapsedSourceInMatch
+			);
+
+			let fee_per_execution = Self::check_source_matches(
+				&planned_execution.source,
+				&m.job_id.0,
+				&remaining_registration,
+				&requirements,
+				min_metrics.as_ref(),
+				ExecutionSpecifier::All,
+				planned_execution.start_delay,
+			)?;
+
+			<StoredMatches<T>>::try_mutate(
+				&planned_execution.source,
+				&m.job_id,
+				|s| -> Result<(), Error<T>> {
+					ensure!(s.is_none(), Error::<T>::DuplicateSourceInMatch);
+					*s = Some(Assignment {
+						slot,
+						execution: ExecutionSpecifier::All,
+						start_delay: planned_execution.start_delay,
+						fee_per_execution,
+						acknowledged: false,
+						sla: SLA {
+							total: remaining_registration.schedule.execution_count(),
+							met: 0,
+						},
+						pub_keys: PubKeys::default(),
+					});
+					Ok(())
+				},
+			)?;
+			<ExtendedAssignmentStart<T>>::insert(
+				&m.job_id,
+				&planned_execution.source,
+				first_execution_index,
+			);
+			<AssignedProcessors<T>>::insert(&m.job_id, &planned_execution.source, ());
+			<RematchableSlots<T>>::remove(&m.job_id, slot);
+		}
+		if let Some(matcher_account) = matcher_account {
+			<JobMatcher<T>>::insert(&m.job_id, matcher_account);
+		}
+
+		Self::deposit_event(Event::JobSlotsRematched(m.job_id.clone()));
+		Ok(())
+	}
+}
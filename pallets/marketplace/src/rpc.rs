@@ -0,0 +1,146 @@
+use std::{marker::PhantomData, sync::Arc};
+
+use frame_support::{pallet_prelude::Get, sp_runtime::traits::Block as BlockT};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_acurast::{JobId, JobIdSequence, JobRegistration, Metrics, MultiOrigin};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+
+use crate::{JobView, MarketplaceRuntimeApi, MatchingDryRun, PartialJobRegistration};
+
+const RUNTIME_ERROR: i32 = 8001;
+const MARKETPLACE_ERROR: i32 = 8011;
+
+/// Marketplace RPC methods.
+#[rpc(server)]
+pub trait MarketplaceApi<BlockHash, Reward, AccountId, PartialRegistration, Registration, Job> {
+	/// Runs the matching checks for a partially specified job registration against all advertised processors.
+	#[method(name = "filterMatchingSources")]
+	fn filter_matching_sources(
+		&self,
+		registration: PartialRegistration,
+		consumer: Option<MultiOrigin<AccountId>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<MatchingDryRun<AccountId, Reward>>;
+
+	/// Runs the matching checks for a full job registration of `consumer` against all advertised processors.
+	#[method(name = "checkMatching")]
+	fn check_matching(
+		&self,
+		registration: Registration,
+		consumer: MultiOrigin<AccountId>,
+		min_metrics: Option<Metrics>,
+		at: Option<BlockHash>,
+	) -> RpcResult<MatchingDryRun<AccountId, Reward>>;
+
+	/// Returns an aggregated view on a job's lifecycle or `null` if the job is not known to the marketplace.
+	#[method(name = "job")]
+	fn job(&self, job_id: JobId<AccountId>, at: Option<BlockHash>) -> RpcResult<Option<Job>>;
+
+	/// Returns a page of aggregated job views for the jobs registered by `owner`.
+	#[method(name = "jobs")]
+	fn jobs(
+		&self,
+		owner: MultiOrigin<AccountId>,
+		start_after: Option<JobIdSequence>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Job>>;
+}
+
+/// Implements the [MarketplaceApiServer] RPC trait for interacting with Marketplace.
+pub struct Marketplace<C, B> {
+	client: Arc<C>,
+	_marker: PhantomData<B>,
+}
+
+impl<C, B> Marketplace<C, B> {
+	/// Creates a new instance of the Marketplace Rpc helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, Reward, AccountId, Extra, MaxAllowedSources>
+	MarketplaceApiServer<
+		<Block as BlockT>::Hash,
+		Reward,
+		AccountId,
+		PartialJobRegistration<Reward, AccountId, MaxAllowedSources>,
+		JobRegistration<AccountId, MaxAllowedSources, Extra>,
+		JobView<Reward, AccountId, MaxAllowedSources, Extra>,
+	> for Marketplace<C, (Block, Reward, AccountId, Extra, MaxAllowedSources)>
+where
+	Block: BlockT,
+	C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+	C::Api: MarketplaceRuntimeApi<Block, Reward, AccountId, Extra, MaxAllowedSources>,
+	Reward: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Extra: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	MaxAllowedSources: Get<u32> + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn filter_matching_sources(
+		&self,
+		registration: PartialJobRegistration<Reward, AccountId, MaxAllowedSources>,
+		consumer: Option<MultiOrigin<AccountId>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<MatchingDryRun<AccountId, Reward>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.filter_matching_sources(at, registration, consumer)
+			.map_err(|e| runtime_error(e, "Unable to run matching checks."))
+	}
+
+	fn check_matching(
+		&self,
+		registration: JobRegistration<AccountId, MaxAllowedSources, Extra>,
+		consumer: MultiOrigin<AccountId>,
+		min_metrics: Option<Metrics>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<MatchingDryRun<AccountId, Reward>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.check_matching(at, registration, consumer, min_metrics)
+			.map_err(|e| runtime_error(e, "Unable to run matching checks."))?
+			.map_err(|e| {
+				ErrorObject::owned(MARKETPLACE_ERROR, e.message(), Some(format!("{:?}", e)))
+			})
+	}
+
+	fn job(
+		&self,
+		job_id: JobId<AccountId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<JobView<Reward, AccountId, MaxAllowedSources, Extra>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.job(at, job_id).map_err(|e| runtime_error(e, "Unable to get job."))
+	}
+
+	fn jobs(
+		&self,
+		owner: MultiOrigin<AccountId>,
+		start_after: Option<JobIdSequence>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<JobView<Reward, AccountId, MaxAllowedSources, Extra>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.jobs(at, owner, start_after, limit)
+			.map_err(|e| runtime_error(e, "Unable to get jobs."))
+	}
+}
+
+fn runtime_error(error: impl std::fmt::Debug, message: &'static str) -> ErrorObjectOwned {
+	ErrorObject::owned(RUNTIME_ERROR, message, Some(format!("{:?}", error)))
+}
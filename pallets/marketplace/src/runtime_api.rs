@@ -0,0 +1,42 @@
+use frame_support::pallet_prelude::*;
+use pallet_acurast::{JobId, JobIdSequence, JobRegistration, Metrics, MultiOrigin};
+use parity_scale_codec::Codec;
+use sp_std::prelude::*;
+
+use crate::{JobView, MatchingDryRun, PartialJobRegistration, RuntimeApiError};
+
+sp_api::decl_runtime_apis! {
+	/// API to run the marketplace's matching logic without submitting a match and to query jobs' lifecycle.
+	pub trait MarketplaceRuntimeApi<Reward, AccountId, Extra, MaxAllowedSources>
+		where
+			Reward: Codec,
+			AccountId: Codec,
+			Extra: Codec,
+			MaxAllowedSources: Get<u32>,
+	{
+		/// Runs the matching checks for a partially specified job registration against all advertised processors.
+		///
+		/// Checks depending on unspecified fields are skipped, as is the consumer whitelist check if no `consumer` is provided.
+		fn filter_matching_sources(
+			registration: PartialJobRegistration<Reward, AccountId, MaxAllowedSources>,
+			consumer: Option<MultiOrigin<AccountId>>,
+		) -> MatchingDryRun<AccountId, Reward>;
+
+		/// Runs the matching checks for a full job registration of `consumer` against all advertised processors.
+		fn check_matching(
+			registration: JobRegistration<AccountId, MaxAllowedSources, Extra>,
+			consumer: MultiOrigin<AccountId>,
+			min_metrics: Option<Metrics>,
+		) -> Result<MatchingDryRun<AccountId, Reward>, RuntimeApiError>;
+
+		/// Returns an aggregated view on a job's lifecycle or [None] if the job is not known to the marketplace.
+		fn job(job_id: JobId<AccountId>) -> Option<JobView<Reward, AccountId, MaxAllowedSources, Extra>>;
+
+		/// Returns a page of at most `limit` aggregated job views for the jobs registered by `owner`, starting after the job with sequence `start_after`.
+		fn jobs(
+			owner: MultiOrigin<AccountId>,
+			start_after: Option<JobIdSequence>,
+			limit: u32,
+		) -> Vec<JobView<Reward, AccountId, MaxAllowedSources, Extra>>;
+	}
+}
@@ -3,15 +3,15 @@ use frame_support::{
 	sp_runtime::{
 		bounded_vec,
 		traits::{Hash, Scale},
-		Permill, Perquintill,
+		DispatchError, Permill, Perquintill,
 	},
 	traits::{Hooks, TypedGet},
 };
 
 use hex_literal::hex;
 use pallet_acurast::{
-	utils::validate_and_extract_attestation, Attestation, ComputeHooks, JobModules,
-	JobRegistrationFor, ManagerLookup, MultiOrigin, Schedule,
+	utils::validate_and_extract_attestation, Attestation, ComputeHooks, JobHooks, JobId,
+	JobModules, JobRegistrationFor, ManagerLookup, MultiOrigin, Schedule,
 };
 use pallet_acurast_compute::{MetricPool, ProvisionalBuffer, SlidingBuffer};
 use parity_scale_codec::Encode;
@@ -20,9 +20,11 @@ use sp_core::H256;
 
 use crate::{
 	mock::*, payments::JobBudget, stub::*, AdvertisementRestriction, Assignment,
-	AssignmentStrategy, Config, Error, ExecutionMatch, ExecutionResult, ExecutionSpecifier,
-	FeeManager, JobRequirements, JobStatus, Match, PlannedExecution, PlannedExecutions, PubKeys,
-	RegistrationExtra, Runtime, SLA,
+	AssignmentStrategy, Config, Error, ExecutionCandidateFor, ExecutionMatch,
+	ExecutionOperationHash, ExecutionResult, ExecutionSpecifier, FeeManager, JobRequirements,
+	JobStatus, JobViewBudget, Match, MatchingCheckFailure, PlannedExecution, PlannedExecutions,
+	PubKeys, RegistrationExtra, ResultCommitment, ResultCommitmentFor, Runtime,
+	UnavailabilityWindow, UnavailabilityWindows, MAX_EXECUTIONS_PER_JOB, SLA,
 };
 
 /// Job is not assigned and gets deregistered successfully.
@@ -53,6 +55,9 @@ fn test_valid_deregister() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -162,6 +167,9 @@ fn test_deregister_on_matched_job() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -286,6 +294,9 @@ fn test_deregister_on_assigned_job() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -362,62 +373,1723 @@ fn test_deregister_on_assigned_job() {
 		assert_eq!(Balances::free_balance(processor_account_id()), processor_initial_balance);
 		assert_eq!(Balances::free_balance(processor_2_account_id()), processor_initial_balance);
 
+		// deregistering a running job applies the cancellation policy: the executions at 13:00 and 13:30
+		// of the acknowledged processor start within the notice period and get compensated
 		assert_ok!(Acurast::deregister(RuntimeOrigin::signed(alice_account_id()), job_id1.1));
+		let compensation = 2 * assignment.fee_per_execution;
+		let cancellation_fee = CancellationFee::get().mul_floor(total_reward - compensation);
+		let refund = total_reward - compensation - cancellation_fee;
 		assert_eq!(
 			Balances::free_balance(alice_account_id()),
-			consumer_initial_balance - assignment.fee_per_execution
+			consumer_initial_balance - compensation - cancellation_fee
+		);
+		assert_eq!(
+			Balances::free_balance(pallet_fees_account()),
+			pallet_initial_balance + cancellation_fee
 		);
 		assert_eq!(Balances::free_balance(processor_2_account_id()), processor_initial_balance);
 
-		// Job got removed after the deregister call
-		assert_eq!(None, AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1));
-		// Job KeyID got removed
-		assert_eq!(None, AcurastMarketplace::job_key_ids(&job_id1));
+		// Job got removed after the deregister call
+		assert_eq!(None, AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1));
+		// Job KeyID got removed
+		assert_eq!(None, AcurastMarketplace::job_key_ids(&job_id1));
+
+		// the full budget got refunded
+		assert_eq!(0, AcurastMarketplace::reserved(&job_id1));
+
+		assert_eq!(
+			events(),
+			[
+				RuntimeEvent::Balances(pallet_balances::Event::BalanceSet {
+					who: alice_account_id(),
+					free: 100_000_000
+				}),
+				RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStoredV2(
+					processor_account_id()
+				)),
+				RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStoredV2(
+					processor_2_account_id()
+				)),
+				RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatchedV2(
+					job_id1.clone()
+				)),
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+					from: alice_account_id(),
+					to: pallet_acurast_acount(),
+					amount: total_reward
+				}),
+				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStoredV2(
+					job_id1.clone(),
+				)),
+				RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationAssignedV2(
+					job_id1.clone(),
+					processor_account_id(),
+				)),
+				RuntimeEvent::Balances(pallet_balances::Event::Withdraw {
+					who: pallet_acurast_acount(),
+					amount: compensation
+				}),
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+					from: pallet_acurast_acount(),
+					to: pallet_fees_account(),
+					amount: cancellation_fee
+				}),
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+					from: pallet_acurast_acount(),
+					to: alice_account_id(),
+					amount: refund
+				}),
+				RuntimeEvent::AcurastMarketplace(crate::Event::JobCancelled(
+					job_id1.clone(),
+					vec![(processor_account_id(), compensation)],
+					cancellation_fee,
+					refund
+				)),
+				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationRemoved(
+					job_id1.clone()
+				)),
+			]
+		);
+	});
+}
+
+#[test]
+fn test_job_view() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+			interval: 1_800_000,           // 30min
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 }
+				])),
+				slots: 2,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			ad.clone(),
+		));
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_eq!(None, AcurastMarketplace::job_view(&job_id1));
+
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		assert_ok!(AcurastMarketplace::acknowledge_match(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			PubKeys::default(),
+		));
+
+		let view = AcurastMarketplace::job_view(&job_id1).unwrap();
+		assert_eq!(job_id1, view.job_id);
+		assert_eq!(registration1, view.registration);
+		assert_eq!(JobStatus::Assigned(1), view.status);
+		assert_eq!(JobViewBudget { reserved: 24_000_000, spent: 0 }, view.budget);
+		assert_eq!(2, view.assignments.len());
+
+		let assignment_1 =
+			view.assignments.iter().find(|a| a.processor == processor_account_id()).unwrap();
+		assert!(assignment_1.assignment.acknowledged);
+		assert_eq!(SLA { total: 2, met: 0 }, assignment_1.assignment.sla);
+		assert_eq!(Some(0), assignment_1.next_report_index);
+		assert_eq!(None, assignment_1.execution_status);
+
+		let assignment_2 = view
+			.assignments
+			.iter()
+			.find(|a| a.processor == processor_2_account_id())
+			.unwrap();
+		assert!(!assignment_2.assignment.acknowledged);
+		assert_eq!(None, assignment_2.next_report_index);
+
+		let owner = MultiOrigin::Acurast(alice_account_id());
+		assert_eq!(vec![view], AcurastMarketplace::job_views_for(&owner, None, 10));
+		assert!(AcurastMarketplace::job_views_for(&owner, None, 0).is_empty());
+		assert!(AcurastMarketplace::job_views_for(&owner, Some(job_id1.1), 10).is_empty());
+	});
+}
+
+#[test]
+fn test_cancel_assigned_job() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_807_600_000,   // 23.12.2022 15:00 (two hours later)
+			interval: 1_800_000,           // 30min -> 4 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 }
+				])),
+				slots: 2,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+		let consumer_initial_balance = 100_000_000u128;
+		let fees_initial_balance = Balances::free_balance(pallet_fees_account());
+
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			ad.clone(),
+		));
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		let total_reward = registration1.extra.requirements.reward
+			* (registration1.extra.requirements.slots as u128)
+			* (registration1.schedule.execution_count() as u128);
+		assert_eq!(total_reward, AcurastMarketplace::reserved(&job_id1));
+
+		// only the first processor acknowledges
+		assert_ok!(AcurastMarketplace::acknowledge_match(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			PubKeys::default(),
+		));
+		let assignment =
+			AcurastMarketplace::stored_matches(processor_account_id(), job_id1.clone()).unwrap();
+
+		// cancel 5 minutes before the first execution with a notice period of one hour:
+		// the executions at 13:00 and 13:30 of the acknowledged processor get compensated
+		assert_ok!(AcurastMarketplace::cancel_job(
+			RuntimeOrigin::signed(alice_account_id()),
+			job_id1.1
+		));
+
+		let compensation = 2 * assignment.fee_per_execution;
+		let cancellation_fee = CancellationFee::get().mul_floor(total_reward - compensation);
+		let refund = total_reward - compensation - cancellation_fee;
+
+		assert_eq!(
+			Balances::free_balance(alice_account_id()),
+			consumer_initial_balance - total_reward + refund
+		);
+		assert_eq!(
+			Balances::free_balance(pallet_fees_account()),
+			fees_initial_balance + cancellation_fee
+		);
+
+		// all job related data got removed
+		assert_eq!(0, AcurastMarketplace::reserved(&job_id1));
+		assert_eq!(None, AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1));
+		assert_eq!(None, Acurast::stored_job_registration(&job_id1.0, job_id1.1));
+		assert_eq!(None, AcurastMarketplace::stored_matches(processor_account_id(), &job_id1));
+		assert_eq!(None, AcurastMarketplace::stored_matches(processor_2_account_id(), &job_id1));
+		assert_eq!(None, AcurastMarketplace::next_report_index(&job_id1, processor_account_id()));
+
+		assert_eq!(
+			events().last(),
+			Some(&RuntimeEvent::AcurastMarketplace(crate::Event::JobCancelled(
+				job_id1.clone(),
+				vec![(processor_account_id(), compensation)],
+				cancellation_fee,
+				refund,
+			)))
+		);
+	});
+}
+
+#[test]
+fn test_cancel_open_job() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+			interval: 1_800_000,           // 30min -> 2 executions fit
+			max_start_delay: 5000,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(None),
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let consumer_initial_balance = Balances::free_balance(alice_account_id());
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		assert_ok!(AcurastMarketplace::cancel_job(
+			RuntimeOrigin::signed(alice_account_id()),
+			job_id1.1
+		));
+
+		// no cancellation fee is charged for jobs not yet assigned
+		assert_eq!(Balances::free_balance(alice_account_id()), consumer_initial_balance);
+		assert_eq!(None, AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1));
+		assert_eq!(
+			events().last(),
+			Some(&RuntimeEvent::AcurastMarketplace(crate::Event::JobCancelled(
+				job_id1.clone(),
+				vec![],
+				0,
+				12_000_000,
+			)))
+		);
+
+		// cancelling again fails since job is gone
+		assert_err!(
+			AcurastMarketplace::cancel_job(RuntimeOrigin::signed(alice_account_id()), job_id1.1),
+			Error::<Test>::JobStatusNotFound
+		);
+	});
+}
+
+#[test]
+fn test_extend_assigned_job() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_807_600_000,   // 23.12.2022 15:00 (two hours later)
+			interval: 1_800_000,           // 30min -> 4 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 }
+				])),
+				slots: 2,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+	let extended_end_time = 1_671_811_200_000; // 23.12.2022 16:00 -> 6 executions fit
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+		for processor in
+			[processor_account_id(), processor_2_account_id(), processor_3_account_id()]
+		{
+			assert_ok!(AcurastMarketplace::advertise(RuntimeOrigin::signed(processor), ad.clone()));
+		}
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		for processor in [processor_account_id(), processor_2_account_id()] {
+			assert_ok!(AcurastMarketplace::acknowledge_match(
+				RuntimeOrigin::signed(processor),
+				job_id1.clone(),
+				PubKeys::default(),
+			));
+		}
+
+		assert_err!(
+			AcurastMarketplace::extend_job(
+				RuntimeOrigin::signed(alice_account_id()),
+				job_id1.1,
+				extended_end_time,
+				Some(3_000_000),
+			),
+			Error::<Test>::JobExtensionRewardDecreased
+		);
+		assert_ok!(AcurastMarketplace::extend_job(
+			RuntimeOrigin::signed(alice_account_id()),
+			job_id1.1,
+			extended_end_time,
+			None,
+		));
+
+		// the budget for the two additional executions of both slots got reserved
+		assert_eq!(6_000_000 * 2 * 6, AcurastMarketplace::reserved(&job_id1));
+		assert_eq!(
+			Some(extended_end_time),
+			Acurast::stored_job_registration(&job_id1.0, job_id1.1)
+				.map(|registration| registration.schedule.end_time)
+		);
+		let extension = AcurastMarketplace::stored_job_extension(&job_id1).unwrap();
+		assert_eq!(4, extension.first_execution_index);
+		assert_eq!(2, extension.pending.len());
+		assert_err!(
+			AcurastMarketplace::extend_job(
+				RuntimeOrigin::signed(alice_account_id()),
+				job_id1.1,
+				extended_end_time + 1_800_000,
+				None,
+			),
+			Error::<Test>::JobExtensionInProgress
+		);
+
+		// the first processor keeps processing all executions
+		assert_ok!(AcurastMarketplace::accept_job_extension(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+		));
+		assert_eq!(
+			Some(6),
+			AcurastMarketplace::stored_matches(processor_account_id(), &job_id1)
+				.map(|assignment| assignment.sla.total)
+		);
+
+		// the second processor only processes the executions before the extension
+		assert_ok!(AcurastMarketplace::decline_job_extension(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			job_id1.clone(),
+		));
+		assert_eq!(
+			Some(4),
+			AcurastMarketplace::stored_matches(processor_2_account_id(), &job_id1)
+				.map(|assignment| assignment.sla.total)
+		);
+		assert_eq!(
+			Some(JobStatus::Assigned(1)),
+			AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1)
+		);
+		assert_err!(
+			AcurastMarketplace::decline_job_extension(
+				RuntimeOrigin::signed(processor_2_account_id()),
+				job_id1.clone(),
+			),
+			Error::<Test>::JobExtensionNotPending
+		);
+		assert_eq!(
+			vec![1],
+			AcurastMarketplace::stored_job_extension(&job_id1)
+				.unwrap()
+				.declined_slots
+				.into_inner()
+		);
+
+		// the declined slot gets re-matched for the extended range only
+		assert_ok!(AcurastMarketplace::propose_extension_matching(
+			RuntimeOrigin::signed(bob_account_id()),
+			vec![Match {
+				job_id: job_id1.clone(),
+				sources: bounded_vec![PlannedExecution {
+					source: processor_3_account_id(),
+					start_delay: 0,
+				}],
+			}]
+			.try_into()
+			.unwrap(),
+		));
+		let assignment =
+			AcurastMarketplace::stored_matches(processor_3_account_id(), &job_id1).unwrap();
+		assert_eq!(1, assignment.slot);
+		assert_eq!(SLA { total: 2, met: 0 }, assignment.sla);
+		assert_eq!(
+			Some(4),
+			AcurastMarketplace::extended_assignment_start(&job_id1, processor_3_account_id())
+		);
+		assert_eq!(None, AcurastMarketplace::stored_job_extension(&job_id1));
+		assert_eq!(
+			events().last(),
+			Some(&RuntimeEvent::AcurastMarketplace(crate::Event::JobExtensionMatched(
+				job_id1.clone()
+			)))
+		);
+
+		assert_ok!(AcurastMarketplace::acknowledge_match(
+			RuntimeOrigin::signed(processor_3_account_id()),
+			job_id1.clone(),
+			PubKeys::default(),
+		));
+		// reports are expected from the first execution of the extended range on
+		assert_eq!(
+			Some(4),
+			AcurastMarketplace::next_report_index(&job_id1, processor_3_account_id())
+		);
+		// the declined slot is counted again once its new processor acknowledged
+		assert_eq!(
+			Some(JobStatus::Assigned(2)),
+			AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1)
+		);
+	});
+}
+
+#[test]
+fn test_extension_non_responders_count_as_declined() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_807_600_000,   // 23.12.2022 15:00 (two hours later)
+			interval: 1_800_000,           // 30min -> 4 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 }
+				])),
+				slots: 2,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+	let extended_end_time = 1_671_811_200_000; // 23.12.2022 16:00 -> 6 executions fit
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+		for processor in
+			[processor_account_id(), processor_2_account_id(), processor_3_account_id()]
+		{
+			assert_ok!(AcurastMarketplace::advertise(RuntimeOrigin::signed(processor), ad.clone()));
+		}
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		for processor in [processor_account_id(), processor_2_account_id()] {
+			assert_ok!(AcurastMarketplace::acknowledge_match(
+				RuntimeOrigin::signed(processor),
+				job_id1.clone(),
+				PubKeys::default(),
+			));
+		}
+
+		assert_ok!(AcurastMarketplace::extend_job(
+			RuntimeOrigin::signed(alice_account_id()),
+			job_id1.1,
+			extended_end_time,
+			None,
+		));
+		let accept_deadline = now + <Test as Config>::ExtensionAcceptPeriod::get();
+		assert_eq!(
+			Some(accept_deadline),
+			AcurastMarketplace::stored_job_extension(&job_id1)
+				.map(|extension| extension.accept_deadline)
+		);
+		assert_ok!(AcurastMarketplace::accept_job_extension(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+		));
+
+		// the second processor does not respond in time
+		later(accept_deadline);
+		assert_err!(
+			AcurastMarketplace::accept_job_extension(
+				RuntimeOrigin::signed(processor_2_account_id()),
+				job_id1.clone(),
+			),
+			Error::<Test>::JobExtensionAcceptDeadlinePassed
+		);
+
+		// its slot gets re-matched for the extended range like a declined one
+		assert_ok!(AcurastMarketplace::propose_extension_matching(
+			RuntimeOrigin::signed(bob_account_id()),
+			vec![Match {
+				job_id: job_id1.clone(),
+				sources: bounded_vec![PlannedExecution {
+					source: processor_3_account_id(),
+					start_delay: 0,
+				}],
+			}]
+			.try_into()
+			.unwrap(),
+		));
+		assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+			crate::Event::JobExtensionDeclined(job_id1.clone(), processor_2_account_id())
+		)));
+		assert_eq!(
+			Some(4),
+			AcurastMarketplace::stored_matches(processor_2_account_id(), &job_id1)
+				.map(|assignment| assignment.sla.total)
+		);
+		let assignment =
+			AcurastMarketplace::stored_matches(processor_3_account_id(), &job_id1).unwrap();
+		assert_eq!(1, assignment.slot);
+		assert_eq!(SLA { total: 2, met: 0 }, assignment.sla);
+		assert_eq!(None, AcurastMarketplace::stored_job_extension(&job_id1));
+	});
+}
+
+#[test]
+fn test_extension_with_raised_reward() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_807_600_000,   // 23.12.2022 15:00 (two hours later)
+			interval: 1_800_000,           // 30min -> 4 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 }
+				])),
+				slots: 2,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+	let extended_end_time = 1_671_811_200_000; // 23.12.2022 16:00 -> 6 executions fit
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+		for processor in [processor_account_id(), processor_2_account_id()] {
+			assert_ok!(AcurastMarketplace::advertise(RuntimeOrigin::signed(processor), ad.clone()));
+		}
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		assert_eq!(6_000_000 * 2 * 4, AcurastMarketplace::reserved(&job_id1));
+
+		assert_ok!(AcurastMarketplace::extend_job(
+			RuntimeOrigin::signed(alice_account_id()),
+			job_id1.1,
+			extended_end_time,
+			Some(9_000_000),
+		));
+
+		// only the two additional executions of both slots are budgeted with the raised reward
+		assert_eq!(6_000_000 * 2 * 4 + 9_000_000 * 2 * 2, AcurastMarketplace::reserved(&job_id1));
+	});
+}
+
+#[test]
+fn test_rematch_lapsed_slot() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_807_600_000,   // 23.12.2022 15:00 (two hours later)
+			interval: 1_800_000,           // 30min -> 4 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 }
+				])),
+				slots: 2,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+		for processor in
+			[processor_account_id(), processor_2_account_id(), processor_3_account_id()]
+		{
+			assert_ok!(AcurastMarketplace::advertise(RuntimeOrigin::signed(processor), ad.clone()));
+		}
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		for processor in [processor_account_id(), processor_2_account_id()] {
+			assert_ok!(AcurastMarketplace::acknowledge_match(
+				RuntimeOrigin::signed(processor),
+				job_id1.clone(),
+				PubKeys::default(),
+			));
+		}
+
+		// only the first processor reports the first execution
+		later(registration1.schedule.start_time + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			ExecutionResult::Success(operation_hash())
+		));
+		assert_err!(
+			AcurastMarketplace::mark_slot_rematchable(
+				RuntimeOrigin::signed(bob_account_id()),
+				job_id1.clone(),
+				processor_2_account_id(),
+			),
+			Error::<Test>::AssignmentNotLapsed
+		);
+
+		// 13:10, the report window of the first execution is over
+		later(registration1.schedule.start_time + 600_000);
+		assert_err!(
+			AcurastMarketplace::mark_slot_rematchable(
+				RuntimeOrigin::signed(bob_account_id()),
+				job_id1.clone(),
+				processor_account_id(),
+			),
+			Error::<Test>::AssignmentNotLapsed
+		);
+		assert_ok!(AcurastMarketplace::mark_slot_rematchable(
+			RuntimeOrigin::signed(bob_account_id()),
+			job_id1.clone(),
+			processor_2_account_id(),
+		));
+		assert_eq!(
+			events().last(),
+			Some(&RuntimeEvent::AcurastMarketplace(crate::Event::SlotMarkedRematchable(
+				job_id1.clone(),
+				processor_2_account_id(),
+				1,
+				1
+			)))
+		);
+		assert_eq!(None, AcurastMarketplace::stored_matches(processor_2_account_id(), &job_id1));
+		assert_eq!(Some(1), AcurastMarketplace::rematchable_slots(&job_id1, 1));
+		assert_eq!(
+			Some(JobStatus::Assigned(1)),
+			AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1)
+		);
+
+		// the processor whose assignment lapsed cannot take the slot again
+		assert_err!(
+			AcurastMarketplace::propose_matching(
+				RuntimeOrigin::signed(bob_account_id()),
+				vec![Match {
+					job_id: job_id1.clone(),
+					sources: bounded_vec![PlannedExecution {
+						source: processor_2_account_id(),
+						start_delay: 0,
+					}],
+				}]
+				.try_into()
+				.unwrap(),
+			),
+			Error::<Test>::LapsedSourceInMatch
+		);
+
+		// the replacement is matched for the remaining executions only
+		assert_ok!(AcurastMarketplace::propose_matching(
+			RuntimeOrigin::signed(bob_account_id()),
+			vec![Match {
+				job_id: job_id1.clone(),
+				sources: bounded_vec![PlannedExecution {
+					source: processor_3_account_id(),
+					start_delay: 0,
+				}],
+			}]
+			.try_into()
+			.unwrap(),
+		));
+		let assignment =
+			AcurastMarketplace::stored_matches(processor_3_account_id(), &job_id1).unwrap();
+		assert_eq!(1, assignment.slot);
+		assert_eq!(SLA { total: 3, met: 0 }, assignment.sla);
+		assert_eq!(None, AcurastMarketplace::rematchable_slots(&job_id1, 1));
+
+		assert_ok!(AcurastMarketplace::acknowledge_match(
+			RuntimeOrigin::signed(processor_3_account_id()),
+			job_id1.clone(),
+			PubKeys::default(),
+		));
+		assert_eq!(
+			Some(1),
+			AcurastMarketplace::next_report_index(&job_id1, processor_3_account_id())
+		);
+		assert_eq!(
+			Some(JobStatus::Assigned(2)),
+			AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1)
+		);
+
+		later(registration1.schedule.nth_start_time(0, 1).unwrap() + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_3_account_id()),
+			job_id1.clone(),
+			ExecutionResult::Success(operation_hash())
+		));
+		assert_eq!(
+			Some(SLA { total: 3, met: 1 }),
+			AcurastMarketplace::stored_matches(processor_3_account_id(), &job_id1)
+				.map(|assignment| assignment.sla)
+		);
+	});
+}
+
+#[test]
+fn test_remove_unverified_assignments() {
+	let now = 1_694_795_700_000; // 15.09.2023 17:35
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: true,
+		schedule: Schedule {
+			duration: 1000,
+			start_time: 1_694_796_000_000, // 15.09.2023 17:40
+			end_time: 1_694_796_120_000,   // 15.09.2023 17:42 (2 minutes later)
+			interval: 10000,               // 10 seconds
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(None),
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let _ =
+			Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 1_000_000_000);
+
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		assert_ok!(Acurast::submit_attestation(
+			RuntimeOrigin::signed(processor_account_id()),
+			attestation_chain()
+		));
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration.clone(),
+		));
+		assert_ok!(AcurastMarketplace::propose_matching(
+			RuntimeOrigin::signed(charlie_account_id()),
+			vec![Match {
+				job_id: job_id1.clone(),
+				sources: bounded_vec![PlannedExecution {
+					source: processor_account_id(),
+					start_delay: 0,
+				}],
+			}]
+			.try_into()
+			.unwrap(),
+		));
+		Timestamp::set_timestamp(registration.schedule.start_time);
+		assert_ok!(AcurastMarketplace::acknowledge_match(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			PubKeys::default(),
+		));
+		let _ = events();
+
+		// the attestation of the processor got revoked
+		<AcurastMarketplace as JobHooks<Test>>::attestation_revoked_hook(&processor_account_id());
+		assert_eq!(
+			Some(None),
+			AcurastMarketplace::unverified_assignment_removal(processor_account_id())
+		);
+
+		AcurastMarketplace::remove_unverified_assignments();
+		assert_eq!(None, AcurastMarketplace::unverified_assignment_removal(processor_account_id()));
+		assert_eq!(None, AcurastMarketplace::stored_matches(processor_account_id(), &job_id1));
+		assert_eq!(None, AcurastMarketplace::assigned_processors(&job_id1, processor_account_id()));
+		assert_eq!(Some(1), AcurastMarketplace::rematchable_slots(&job_id1, 0));
+		assert_eq!(
+			events(),
+			[
+				RuntimeEvent::AcurastMarketplace(crate::Event::UnverifiedAssignmentRemoved(
+					job_id1.clone(),
+					processor_account_id(),
+				)),
+				RuntimeEvent::AcurastMarketplace(crate::Event::SlotMarkedRematchable(
+					job_id1.clone(),
+					processor_account_id(),
+					0,
+					1
+				)),
+			]
+		);
+	});
+}
+
+#[test]
+fn test_report_with_consensus() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_807_600_000,   // 23.12.2022 15:00 (two hours later)
+			interval: 1_800_000,           // 30min -> 4 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_3_account_id(), start_delay: 0 }
+				])),
+				slots: 3,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: true,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+	let other_operation_hash: ExecutionOperationHash = b"other".to_vec().try_into().unwrap();
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+		let processors =
+			[processor_account_id(), processor_2_account_id(), processor_3_account_id()];
+		for processor in processors.iter() {
+			assert_ok!(AcurastMarketplace::advertise(
+				RuntimeOrigin::signed(processor.clone()),
+				ad.clone()
+			));
+		}
+
+		let mut single_slot_registration = registration1.clone();
+		single_slot_registration.extra.requirements.assignment_strategy =
+			AssignmentStrategy::Single(None);
+		single_slot_registration.extra.requirements.slots = 1;
+		assert_err!(
+			Acurast::register(RuntimeOrigin::signed(alice_account_id()), single_slot_registration),
+			Error::<Test>::JobRegistrationConsensusRequiresMultipleSlots
+		);
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		for processor in processors.iter() {
+			assert_ok!(AcurastMarketplace::acknowledge_match(
+				RuntimeOrigin::signed(processor.clone()),
+				job_id1.clone(),
+				PubKeys::default(),
+			));
+		}
+		let fee_per_execution =
+			AcurastMarketplace::stored_matches(processor_account_id(), &job_id1)
+				.unwrap()
+				.fee_per_execution;
+		let reserved = AcurastMarketplace::reserved(&job_id1);
+
+		later(registration1.schedule.start_time + 1000);
+		assert_err!(
+			AcurastMarketplace::report(
+				RuntimeOrigin::signed(processor_account_id()),
+				job_id1.clone(),
+				ExecutionResult::Success(operation_hash())
+			),
+			Error::<Test>::InvalidResultCommitment
+		);
+		let salt = H256::repeat_byte(1);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_account_id(), &salt, &operation_hash())
+		));
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_2_account_id(), &salt, &other_operation_hash)
+		));
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_3_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_3_account_id(), &salt, &operation_hash())
+		));
+		// results are not revealed yet, so nobody got paid
+		assert_eq!(3, AcurastMarketplace::result_commitments(&job_id1, 0).len());
+		assert_eq!(None, AcurastMarketplace::consensus_results(&job_id1, 0));
+		assert_eq!(reserved, AcurastMarketplace::reserved(&job_id1));
+		assert_err!(
+			AcurastMarketplace::reveal_result(
+				RuntimeOrigin::signed(processor_account_id()),
+				job_id1.clone(),
+				0,
+				salt,
+				operation_hash()
+			),
+			Error::<Test>::OutsideResultRevealPhase
+		);
+
+		let (reveal_start, _) =
+			AcurastMarketplace::result_reveal_window(&registration1, 0).unwrap();
+		later(reveal_start);
+		assert_err!(
+			AcurastMarketplace::reveal_result(
+				RuntimeOrigin::signed(processor_account_id()),
+				job_id1.clone(),
+				0,
+				H256::repeat_byte(2),
+				operation_hash()
+			),
+			Error::<Test>::ResultCommitmentMismatch
+		);
+		for (processor, result) in [
+			(processor_account_id(), operation_hash()),
+			(processor_2_account_id(), other_operation_hash.clone()),
+			(processor_3_account_id(), operation_hash()),
+		] {
+			assert_ok!(AcurastMarketplace::reveal_result(
+				RuntimeOrigin::signed(processor),
+				job_id1.clone(),
+				0,
+				salt,
+				result
+			));
+		}
+		// the last reveal settled the execution
+		assert_eq!(Some(operation_hash()), AcurastMarketplace::consensus_results(&job_id1, 0));
+		assert!(AcurastMarketplace::result_commitments(&job_id1, 0).is_empty());
+		assert_eq!(
+			vec![ResultCommitment {
+				processor: processor_2_account_id(),
+				commitment: Some(ResultCommitmentFor::<Test>::blinded_result(
+					&processor_2_account_id(),
+					&salt,
+					&other_operation_hash
+				)),
+				result: Some(other_operation_hash.clone()),
+				fee: fee_per_execution,
+			}],
+			AcurastMarketplace::result_disagreements(&job_id1, 0).into_inner()
+		);
+		// only the two processors agreeing with the majority got paid
+		assert_eq!(reserved - fee_per_execution * 2, AcurastMarketplace::reserved(&job_id1));
+
+		let events = events();
+		assert!(events.contains(&RuntimeEvent::AcurastMarketplace(
+			crate::Event::ExecutionConsensusReached(job_id1.clone(), 0, operation_hash())
+		)));
+		assert!(events.contains(&RuntimeEvent::AcurastMarketplace(
+			crate::Event::ExecutionResultDisagreed(job_id1.clone(), 0, processor_2_account_id())
+		)));
+
+		// all slots disagree on the second execution
+		later(registration1.schedule.nth_start_time(0, 1).unwrap() + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_account_id(), &salt, &operation_hash())
+		));
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_2_account_id(), &salt, &other_operation_hash)
+		));
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_3_account_id()),
+			job_id1.clone(),
+			ExecutionResult::Failure(b"failed".to_vec().try_into().unwrap())
+		));
+		let (reveal_start, _) =
+			AcurastMarketplace::result_reveal_window(&registration1, 1).unwrap();
+		later(reveal_start);
+		assert_err!(
+			AcurastMarketplace::settle_execution_results(
+				RuntimeOrigin::signed(alice_account_id()),
+				job_id1.clone(),
+				1
+			),
+			Error::<Test>::ResultRevealPhaseNotOver
+		);
+		assert_ok!(AcurastMarketplace::reveal_result(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			1,
+			salt,
+			operation_hash()
+		));
+		assert_ok!(AcurastMarketplace::reveal_result(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			job_id1.clone(),
+			1,
+			salt,
+			other_operation_hash.clone()
+		));
+		assert_eq!(None, AcurastMarketplace::consensus_results(&job_id1, 1));
+		assert_eq!(3, AcurastMarketplace::result_disagreements(&job_id1, 1).len());
+		// all slots reported without a majority, so nobody got paid
+		assert_eq!(reserved - fee_per_execution * 2, AcurastMarketplace::reserved(&job_id1));
+		assert_eq!(
+			events().last(),
+			Some(&RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionConsensusFailed(
+				job_id1.clone(),
+				1
+			)))
+		);
+	});
+}
+
+/// Results reported by only some slots get settled after the reveal phase or once the job gets cancelled.
+#[test]
+fn test_settle_partially_reported_results() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_807_600_000,   // 23.12.2022 15:00 (two hours later)
+			interval: 1_800_000,           // 30min -> 4 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_3_account_id(), start_delay: 0 }
+				])),
+				slots: 3,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: true,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+	let other_operation_hash: ExecutionOperationHash = b"other".to_vec().try_into().unwrap();
+	let salt = H256::repeat_byte(1);
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+		let processors =
+			[processor_account_id(), processor_2_account_id(), processor_3_account_id()];
+		for processor in processors.iter() {
+			assert_ok!(AcurastMarketplace::advertise(
+				RuntimeOrigin::signed(processor.clone()),
+				ad.clone()
+			));
+		}
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		for processor in processors.iter() {
+			assert_ok!(AcurastMarketplace::acknowledge_match(
+				RuntimeOrigin::signed(processor.clone()),
+				job_id1.clone(),
+				PubKeys::default(),
+			));
+		}
+		let fee_per_execution =
+			AcurastMarketplace::stored_matches(processor_account_id(), &job_id1)
+				.unwrap()
+				.fee_per_execution;
+		let reserved = AcurastMarketplace::reserved(&job_id1);
+
+		// only one slot reports the first execution
+		later(registration1.schedule.start_time + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_account_id(), &salt, &operation_hash())
+		));
+		let (reveal_start, reveal_end) =
+			AcurastMarketplace::result_reveal_window(&registration1, 0).unwrap();
+		later(reveal_start);
+		assert_ok!(AcurastMarketplace::reveal_result(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			0,
+			salt,
+			operation_hash()
+		));
+		assert_eq!(reserved, AcurastMarketplace::reserved(&job_id1));
+
+		later(reveal_end);
+		assert_ok!(AcurastMarketplace::settle_execution_results(
+			RuntimeOrigin::signed(alice_account_id()),
+			job_id1.clone(),
+			0
+		));
+		assert_eq!(Some(operation_hash()), AcurastMarketplace::consensus_results(&job_id1, 0));
+		assert!(AcurastMarketplace::result_disagreements(&job_id1, 0).is_empty());
+		assert_eq!(reserved - fee_per_execution, AcurastMarketplace::reserved(&job_id1));
+		assert_err!(
+			AcurastMarketplace::settle_execution_results(
+				RuntimeOrigin::signed(alice_account_id()),
+				job_id1.clone(),
+				0
+			),
+			Error::<Test>::ResultNotCommitted
+		);
+
+		// two slots report disagreeing results for the second execution, the third slot stays silent
+		later(registration1.schedule.nth_start_time(0, 1).unwrap() + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_account_id(), &salt, &operation_hash())
+		));
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_2_account_id(), &salt, &other_operation_hash)
+		));
+		let (reveal_start, reveal_end) =
+			AcurastMarketplace::result_reveal_window(&registration1, 1).unwrap();
+		later(reveal_start);
+		assert_ok!(AcurastMarketplace::reveal_result(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			1,
+			salt,
+			operation_hash()
+		));
+		assert_ok!(AcurastMarketplace::reveal_result(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			job_id1.clone(),
+			1,
+			salt,
+			other_operation_hash.clone()
+		));
+
+		later(reveal_end);
+		assert_ok!(AcurastMarketplace::settle_execution_results(
+			RuntimeOrigin::signed(alice_account_id()),
+			job_id1.clone(),
+			1
+		));
+		assert_eq!(None, AcurastMarketplace::consensus_results(&job_id1, 1));
+		assert_eq!(2, AcurastMarketplace::result_disagreements(&job_id1, 1).len());
+		// the silent slot prevented a majority, so both reporting processors got paid
+		assert_eq!(reserved - fee_per_execution * 3, AcurastMarketplace::reserved(&job_id1));
+		assert_eq!(
+			events().last(),
+			Some(&RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionConsensusFailed(
+				job_id1.clone(),
+				1
+			)))
+		);
+
+		// the result reported for the third execution gets settled when the job is cancelled
+		later(registration1.schedule.nth_start_time(0, 2).unwrap() + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			blinded_result(&processor_account_id(), &salt, &operation_hash())
+		));
+		let (reveal_start, _) =
+			AcurastMarketplace::result_reveal_window(&registration1, 2).unwrap();
+		later(reveal_start);
+		assert_ok!(AcurastMarketplace::reveal_result(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			2,
+			salt,
+			operation_hash()
+		));
+		assert_ok!(AcurastMarketplace::cancel_job(
+			RuntimeOrigin::signed(alice_account_id()),
+			job_id1.1
+		));
+		assert!(AcurastMarketplace::result_commitments(&job_id1, 2).is_empty());
+		assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+			crate::Event::ExecutionConsensusReached(job_id1.clone(), 2, operation_hash())
+		)));
+	});
+}
+
+/// The report of a processor for a job requiring consensus, carrying its blinded `result`.
+fn blinded_result(
+	processor: &AccountId,
+	salt: &H256,
+	result: &ExecutionOperationHash,
+) -> ExecutionResult {
+	ExecutionResult::Success(
+		ResultCommitmentFor::<Test>::blinded_result(processor, salt, result)
+			.as_bytes()
+			.to_vec()
+			.try_into()
+			.unwrap(),
+	)
+}
+
+#[test]
+fn test_report_with_result_delivery() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+			interval: 1_800_000,           // 30min -> 2 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 }
+				])),
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: true,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+		let _ = Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			result_delivery_fee_payer(),
+			EXISTENTIAL_DEPOSIT,
+		);
+
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone()
+		));
+
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			MOCK_PAYMENT_ASSET,
+			bob_account_id(),
+			true,
+			1
+		));
+		let mut asset_registration = registration1.clone();
+		asset_registration.extra.requirements.payment_asset = Some(MOCK_PAYMENT_ASSET);
+		assert_err!(
+			Acurast::register(RuntimeOrigin::signed(alice_account_id()), asset_registration),
+			Error::<Test>::ResultDeliveryUnsupported
+		);
+
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		assert_ok!(AcurastMarketplace::acknowledge_match(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			PubKeys::default(),
+		));
+		let fee_per_execution =
+			AcurastMarketplace::stored_matches(processor_account_id(), &job_id1)
+				.unwrap()
+				.fee_per_execution;
+		let reserved = AcurastMarketplace::reserved(&job_id1);
+
+		later(registration1.schedule.start_time + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			ExecutionResult::Success(operation_hash())
+		));
+		// the delivery fee got paid from the job's budget
+		assert_eq!(
+			reserved - fee_per_execution - MOCK_RESULT_DELIVERY_FEE,
+			AcurastMarketplace::reserved(&job_id1)
+		);
+		assert_eq!(
+			EXISTENTIAL_DEPOSIT + MOCK_RESULT_DELIVERY_FEE,
+			Balances::free_balance(result_delivery_fee_payer())
+		);
+		assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+			crate::Event::ExecutionResultDelivered(
+				job_id1.clone(),
+				processor_account_id(),
+				MOCK_RESULT_DELIVERY_FEE
+			)
+		)));
+
+		// a failing delivery does not fail the report and leaves the budget for the delivery untouched
+		later(registration1.schedule.nth_start_time(0, 1).unwrap() + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id1.clone(),
+			ExecutionResult::Failure(b"failed".to_vec().try_into().unwrap())
+		));
+		assert_eq!(
+			reserved - fee_per_execution * 2 - MOCK_RESULT_DELIVERY_FEE,
+			AcurastMarketplace::reserved(&job_id1)
+		);
+		assert_eq!(
+			EXISTENTIAL_DEPOSIT + MOCK_RESULT_DELIVERY_FEE,
+			Balances::free_balance(result_delivery_fee_payer())
+		);
+		assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+			crate::Event::ExecutionResultDeliveryFailed(
+				job_id1.clone(),
+				processor_account_id(),
+				DispatchError::Other("delivery failed")
+			)
+		)));
+	});
+}
+
+#[test]
+fn test_report_with_result_delivery_for_all_executions() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+			interval: 1_800_000,           // 30min -> 2 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 },
+				])),
+				slots: 2,
+				// equal to the price of the processors, the rewards use up the reserved rewards exactly
+				reward: 5000,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: true,
+			},
+		},
+	};
+	let processors = [processor_account_id(), processor_2_account_id()];
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+		later(now);
 
-		// the full budget got refunded
-		assert_eq!(0, AcurastMarketplace::reserved(&job_id1));
+		let _ = Balances::force_set_balance(
+			RuntimeOrigin::root(),
+			result_delivery_fee_payer(),
+			EXISTENTIAL_DEPOSIT,
+		);
+		for processor in &processors {
+			assert_ok!(AcurastMarketplace::advertise(
+				RuntimeOrigin::signed(processor.clone()),
+				ad.clone()
+			));
+		}
 
+		let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration.clone()
+		));
 		assert_eq!(
-			events(),
-			[
-				RuntimeEvent::Balances(pallet_balances::Event::BalanceSet {
-					who: alice_account_id(),
-					free: 100_000_000
-				}),
-				RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStoredV2(
-					processor_account_id()
-				)),
-				RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStoredV2(
-					processor_2_account_id()
-				)),
-				RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatchedV2(
-					job_id1.clone()
-				)),
-				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-					from: alice_account_id(),
-					to: pallet_acurast_acount(),
-					amount: total_reward
-				}),
-				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStoredV2(
-					job_id1.clone(),
-				)),
-				RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationAssignedV2(
-					job_id1.clone(),
-					processor_account_id(),
-				)),
-				RuntimeEvent::Balances(pallet_balances::Event::Withdraw {
-					who: pallet_acurast_acount(),
-					amount: assignment.fee_per_execution
-				}),
-				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-					from: pallet_acurast_acount(),
-					to: alice_account_id(),
-					amount: total_reward - assignment.fee_per_execution
-				}),
-				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationRemoved(
-					job_id1.clone()
-				)),
-			]
+			Some(MOCK_RESULT_DELIVERY_FEE),
+			AcurastMarketplace::result_delivery_fee(&job_id)
+		);
+		// rewards and delivery fees for 2 slots and 2 executions
+		assert_eq!(5000 * 4 + MOCK_RESULT_DELIVERY_FEE * 4, AcurastMarketplace::reserved(&job_id));
+
+		for processor in &processors {
+			assert_ok!(AcurastMarketplace::acknowledge_match(
+				RuntimeOrigin::signed(processor.clone()),
+				job_id.clone(),
+				PubKeys::default(),
+			));
+			assert_eq!(
+				5000,
+				AcurastMarketplace::stored_matches(processor, &job_id)
+					.unwrap()
+					.fee_per_execution
+			);
+		}
+		let _ = events();
+
+		for execution_index in 0..2 {
+			later(registration.schedule.nth_start_time(0, execution_index).unwrap() + 1000);
+			for processor in &processors {
+				assert_ok!(AcurastMarketplace::report(
+					RuntimeOrigin::signed(processor.clone()),
+					job_id.clone(),
+					ExecutionResult::Success(operation_hash())
+				));
+				assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+					crate::Event::ExecutionResultDelivered(
+						job_id.clone(),
+						processor.clone(),
+						MOCK_RESULT_DELIVERY_FEE
+					)
+				)));
+			}
+		}
+
+		// every reward and delivery fee got paid from the job's budget
+		assert_eq!(0, AcurastMarketplace::reserved(&job_id));
+		assert_eq!(
+			EXISTENTIAL_DEPOSIT + MOCK_RESULT_DELIVERY_FEE * 4,
+			Balances::free_balance(result_delivery_fee_payer())
 		);
 	});
 }
@@ -451,6 +2123,9 @@ fn test_deregister_on_assigned_job_for_competing() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -568,10 +2243,15 @@ fn test_deregister_on_assigned_job_for_competing() {
 		let matcher_pauout_after_fee_2 = matcher_payout_2 - matcher_payout_fee_2;
 		let matcher_pauout_after_fee = matcher_pauout_after_fee_1 + matcher_pauout_after_fee_2;
 
+		// the acknowledged executions starting within the notice period get compensated
+		let compensation = assignment1.fee_per_execution + assignment2.fee_per_execution;
+		let cancellation_fee =
+			CancellationFee::get().mul_floor(total_reward - matcher_payout - compensation);
+		let refund = total_reward - matcher_payout - compensation - cancellation_fee;
+
 		assert_eq!(
 			Balances::free_balance(alice_account_id()),
-			consumer_initial_balance
-				- (assignment1.fee_per_execution + assignment2.fee_per_execution + matcher_payout)
+			consumer_initial_balance - (compensation + matcher_payout + cancellation_fee)
 		);
 
 		// Job got removed after the deregister call
@@ -642,14 +2322,25 @@ fn test_deregister_on_assigned_job_for_competing() {
 					who: pallet_acurast_acount(),
 					amount: assignment2.fee_per_execution
 				}),
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+					from: pallet_acurast_acount(),
+					to: pallet_fees_account(),
+					amount: cancellation_fee
+				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
 					from: pallet_acurast_acount(),
 					to: alice_account_id(),
-					amount: total_reward
-						- (assignment1.fee_per_execution
-							+ assignment2.fee_per_execution
-							+ matcher_payout)
+					amount: refund
 				}),
+				RuntimeEvent::AcurastMarketplace(crate::Event::JobCancelled(
+					job_id1.clone(),
+					vec![
+						(processor_account_id(), assignment1.fee_per_execution),
+						(processor_2_account_id(), assignment2.fee_per_execution)
+					],
+					cancellation_fee,
+					refund
+				)),
 				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationRemoved(
 					job_id1.clone()
 				)),
@@ -687,6 +2378,9 @@ fn test_deregister_on_assigned_job_for_competing_2() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -823,10 +2517,14 @@ fn test_deregister_on_assigned_job_for_competing_2() {
 		);
 		assert_eq!(Balances::free_balance(processor_2_account_id()), processor_initial_balance);
 		assert_ok!(Acurast::deregister(RuntimeOrigin::signed(alice_account_id()), job_id1.1));
+		// the first processor already got paid for its report, only the second one gets compensated
+		let paid = assignment1.fee_per_execution + assignment2.fee_per_execution + matcher_payout;
+		let cancellation_fee = CancellationFee::get().mul_floor(total_reward - paid);
+		let refund = total_reward - paid - cancellation_fee;
+
 		assert_eq!(
 			Balances::free_balance(alice_account_id()),
-			consumer_initial_balance
-				- (assignment1.fee_per_execution + assignment2.fee_per_execution + matcher_payout)
+			consumer_initial_balance - (paid + cancellation_fee)
 		);
 
 		// Job got removed after the deregister call
@@ -908,14 +2606,22 @@ fn test_deregister_on_assigned_job_for_competing_2() {
 					who: pallet_acurast_acount(),
 					amount: assignment2.fee_per_execution
 				}),
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+					from: pallet_acurast_acount(),
+					to: pallet_fees_account(),
+					amount: cancellation_fee
+				}),
 				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
 					from: pallet_acurast_acount(),
 					to: alice_account_id(),
-					amount: total_reward
-						- (assignment1.fee_per_execution
-							+ assignment2.fee_per_execution
-							+ matcher_payout)
+					amount: refund
 				}),
+				RuntimeEvent::AcurastMarketplace(crate::Event::JobCancelled(
+					job_id1.clone(),
+					vec![(processor_2_account_id(), assignment2.fee_per_execution)],
+					cancellation_fee,
+					refund
+				)),
 				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationRemoved(
 					job_id1.clone()
 				)),
@@ -953,6 +2659,9 @@ fn test_match() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -979,6 +2688,9 @@ fn test_match() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -1301,6 +3013,9 @@ fn test_multi_assignments() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -1499,12 +3214,170 @@ fn test_multi_assignments() {
 }
 
 #[test]
-fn test_no_match_schedule_overlap() {
+fn test_no_match_schedule_overlap() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration1 = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+			interval: 1_800_000,           // 30min -> 2 executions fit
+			max_start_delay: 5000,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(None),
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	let registration2 = JobRegistrationFor::<Test> {
+		script: script_random_value(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_802_200_000, // 23.12.2022 13:30
+			end_time: 1_671_805_800_000,   // 23.12.2022 14:30 (one hour later)
+			interval: 1_200_000,           // 20min -> 3 executions fit
+			max_start_delay: 5000,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(None),
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		let job_id2 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 2);
+
+		// pretend current time
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+
+		// register first job
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration1.clone(),
+		));
+		assert_eq!(
+			Some(JobStatus::Open),
+			AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
+		);
+
+		// register second job
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration2.clone(),
+		));
+		assert_eq!(
+			Some(JobStatus::Open),
+			AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1 + 1)
+		);
+
+		// the first job matches because capacity left
+		let m = Match {
+			job_id: job_id1.clone(),
+			sources: bounded_vec![PlannedExecution {
+				source: processor_account_id(),
+				start_delay: 0,
+			}],
+		};
+		assert_ok!(AcurastMarketplace::propose_matching(
+			RuntimeOrigin::signed(charlie_account_id()),
+			vec![m.clone()].try_into().unwrap(),
+		));
+
+		// this one does not match anymore
+		let m2 = Match {
+			job_id: job_id2.clone(),
+			sources: bounded_vec![PlannedExecution {
+				source: processor_account_id(),
+				start_delay: 0,
+			}],
+		};
+		assert_err!(
+			AcurastMarketplace::propose_matching(
+				RuntimeOrigin::signed(charlie_account_id()),
+				vec![m2.clone()].try_into().unwrap(),
+			),
+			Error::<Test>::ScheduleOverlapInMatch
+		);
+
+		assert_eq!(
+			events(),
+			[
+				RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStoredV2(
+					processor_account_id()
+				)),
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+					from: alice_account_id(),
+					to: pallet_acurast_acount(),
+					amount: 12_000_000
+				}),
+				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStoredV2(
+					job_id1.clone()
+				)),
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
+					from: alice_account_id(),
+					to: pallet_acurast_acount(),
+					amount: 18_000_000
+				}),
+				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStoredV2((
+					job_id2.0.clone(),
+					job_id2.1
+				))),
+				RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatchedV2(
+					job_id1.clone()
+				)),
+			]
+		);
+	});
+}
+
+#[test]
+fn test_no_match_source_unavailable() {
 	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
 
 	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
 	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
-	let registration1 = JobRegistrationFor::<Test> {
+	let registration = JobRegistrationFor::<Test> {
 		script: script(),
 		allowed_sources: None,
 		allow_only_verified_sources: false,
@@ -1527,19 +3400,173 @@ fn test_no_match_schedule_overlap() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
 
-	let registration2 = JobRegistrationFor::<Test> {
-		script: script_random_value(),
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+		let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+		// pretend current time
+		assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
+
+		// daily maintenance from 13:25 to 13:35, set days before
+		let unavailability: UnavailabilityWindows = bounded_vec![UnavailabilityWindow {
+			start: 1_671_801_900_000 - 3 * 86_400_000,
+			duration: 600_000,
+			period: 86_400_000,
+		}];
+		assert_err!(
+			AcurastMarketplace::set_advertisement_unavailability(
+				RuntimeOrigin::signed(processor_account_id()),
+				unavailability.clone(),
+			),
+			Error::<Test>::AdvertisementNotFound
+		);
+
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+		assert_err!(
+			AcurastMarketplace::set_advertisement_unavailability(
+				RuntimeOrigin::signed(processor_account_id()),
+				bounded_vec![UnavailabilityWindow {
+					start: 1_671_801_900_000, // 23.12.2022 13:25
+					duration: 0,
+					period: 86_400_000,
+				}],
+			),
+			Error::<Test>::InvalidUnavailabilityWindow
+		);
+		assert_ok!(AcurastMarketplace::set_advertisement_unavailability(
+			RuntimeOrigin::signed(processor_account_id()),
+			unavailability.clone(),
+		));
+		assert_eq!(
+			Some(unavailability),
+			AcurastMarketplace::stored_advertisement_unavailability(processor_account_id())
+		);
+		assert_eq!(
+			events().last(),
+			Some(&RuntimeEvent::AcurastMarketplace(
+				crate::Event::AdvertisementUnavailabilityStored(processor_account_id())
+			))
+		);
+
+		// advertising again keeps the unavailability
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+		assert!(AcurastMarketplace::stored_advertisement_unavailability(processor_account_id())
+			.is_some());
+
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration.clone(),
+		));
+
+		// the second execution at 13:30 falls into the maintenance window
+		let m = Match {
+			job_id: job_id.clone(),
+			sources: bounded_vec![PlannedExecution {
+				source: processor_account_id(),
+				start_delay: 0,
+			}],
+		};
+		assert_err!(
+			AcurastMarketplace::propose_matching(
+				RuntimeOrigin::signed(charlie_account_id()),
+				vec![m.clone()].try_into().unwrap(),
+			),
+			Error::<Test>::SourceUnavailableInMatch
+		);
+
+		// maintenance from 13:10 to 13:20 only intersects the schedule's range, not any execution
+		assert_ok!(AcurastMarketplace::set_advertisement_unavailability(
+			RuntimeOrigin::signed(processor_account_id()),
+			bounded_vec![UnavailabilityWindow {
+				start: 1_671_801_000_000,
+				duration: 600_000,
+				period: 86_400_000,
+			}],
+		));
+		assert_ok!(AcurastMarketplace::propose_matching(
+			RuntimeOrigin::signed(charlie_account_id()),
+			vec![m.clone()].try_into().unwrap(),
+		));
+		assert_eq!(
+			Some(JobStatus::Matched),
+			AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
+		);
+	});
+}
+
+#[test]
+fn test_unavailability_window_intersects_any() {
+	let windows = [
+		UnavailabilityWindow { start: 0, duration: 10, period: 60 },
+		UnavailabilityWindow { start: 7, duration: 1, period: 13 },
+		UnavailabilityWindow { start: 1_000, duration: 59, period: 60 },
+		UnavailabilityWindow { start: 3, duration: 0, period: 60 },
+	];
+	for window in windows {
+		for first_start in [0u64, 5, 11, 59, 61, 1_234] {
+			for interval in [1u64, 7, 30, 60, 61, 1_000] {
+				for duration in [1u64, 5, 50, 60] {
+					for count in [0u64, 1, 2, 10, 100] {
+						let expected = (0..count).any(|k| {
+							let start = first_start + k * interval;
+							window.intersects(start, start + duration)
+						});
+						assert_eq!(
+							window.intersects_any(first_start, interval, count, duration),
+							expected,
+							"{:?} {} {} {} {}",
+							window,
+							first_start,
+							interval,
+							duration,
+							count
+						);
+					}
+				}
+			}
+		}
+	}
+
+	// a job running every 5 seconds for a year is checked without iterating its executions
+	let nightly = UnavailabilityWindow {
+		start: 1_671_753_600_000, // 23.12.2022 00:00
+		duration: 3_600_000,
+		period: 86_400_000,
+	};
+	assert!(nightly.intersects_any(1_671_800_400_000, 5_000, MAX_EXECUTIONS_PER_JOB, 1_000));
+	// executions at 12:00 every day never hit the window from 00:00 to 01:00
+	assert!(!nightly.intersects_any(1_671_796_800_000, 86_400_000, 365, 3_600_000));
+	// starting at 23:30 and running one hour reaches into the next occurrence
+	assert!(nightly.intersects_any(1_671_751_800_000 + 86_400_000, 86_400_000, 365, 3_600_000));
+}
+
+#[test]
+fn test_match_with_payment_asset() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let mut registration = JobRegistrationFor::<Test> {
+		script: script(),
 		allowed_sources: None,
 		allow_only_verified_sources: false,
 		schedule: Schedule {
 			duration: 5000,
-			start_time: 1_671_802_200_000, // 23.12.2022 13:30
-			end_time: 1_671_805_800_000,   // 23.12.2022 14:30 (one hour later)
-			interval: 1_200_000,           // 20min -> 3 executions fit
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+			interval: 1_800_000,           // 30min -> 2 executions fit
 			max_start_delay: 5000,
 		},
 		memory: 5_000u32,
@@ -1554,14 +3581,16 @@ fn test_no_match_schedule_overlap() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: Some(MOCK_PAYMENT_ASSET + 1),
+				deliver_results_to_origin: false,
 			},
 		},
 	};
 
 	ExtBuilder.build().execute_with(|| {
 		let initial_job_id = Acurast::job_id_sequence();
-		let job_id1 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
-		let job_id2 = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 2);
+		let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
 
 		// pretend current time
 		assert_ok!(Timestamp::set(RuntimeOrigin::none(), now));
@@ -1570,29 +3599,46 @@ fn test_no_match_schedule_overlap() {
 			ad.clone(),
 		));
 
-		// register first job
-		assert_ok!(Acurast::register(
-			RuntimeOrigin::signed(alice_account_id()),
-			registration1.clone(),
-		));
-		assert_eq!(
-			Some(JobStatus::Open),
-			AcurastMarketplace::stored_job_status(&job_id1.0, &job_id1.1)
+		assert_err!(
+			Acurast::register(RuntimeOrigin::signed(alice_account_id()), registration.clone()),
+			Error::<Test>::UnsupportedPaymentAsset
 		);
 
-		// register second job
+		// priced by the oracle but unknown to the reward manager until created
+		registration.extra.requirements.payment_asset = Some(MOCK_PAYMENT_ASSET);
+		assert_err!(
+			Acurast::register(RuntimeOrigin::signed(alice_account_id()), registration.clone()),
+			Error::<Test>::UnsupportedPaymentAsset
+		);
+
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			MOCK_PAYMENT_ASSET,
+			bob_account_id(),
+			true,
+			1
+		));
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(bob_account_id()),
+			MOCK_PAYMENT_ASSET,
+			alice_account_id(),
+			100_000_000
+		));
+		let native_balance = Balances::free_balance(alice_account_id());
+
 		assert_ok!(Acurast::register(
 			RuntimeOrigin::signed(alice_account_id()),
-			registration2.clone(),
+			registration.clone(),
 		));
-		assert_eq!(
-			Some(JobStatus::Open),
-			AcurastMarketplace::stored_job_status(&job_id1.0, job_id1.1 + 1)
-		);
+		assert_eq!(Ok(Some(MOCK_PAYMENT_ASSET)), AcurastMarketplace::payment_asset(&job_id));
+		// the budget is locked in the payment asset, not in the native currency
+		let reserved = AcurastMarketplace::reserved(&job_id);
+		assert!(reserved > 0);
+		assert_eq!(100_000_000 - reserved, Assets::balance(MOCK_PAYMENT_ASSET, alice_account_id()));
+		assert_eq!(native_balance, Balances::free_balance(alice_account_id()));
 
-		// the first job matches because capacity left
 		let m = Match {
-			job_id: job_id1.clone(),
+			job_id: job_id.clone(),
 			sources: bounded_vec![PlannedExecution {
 				source: processor_account_id(),
 				start_delay: 0,
@@ -1603,50 +3649,12 @@ fn test_no_match_schedule_overlap() {
 			vec![m.clone()].try_into().unwrap(),
 		));
 
-		// this one does not match anymore
-		let m2 = Match {
-			job_id: job_id2.clone(),
-			sources: bounded_vec![PlannedExecution {
-				source: processor_account_id(),
-				start_delay: 0,
-			}],
-		};
-		assert_err!(
-			AcurastMarketplace::propose_matching(
-				RuntimeOrigin::signed(charlie_account_id()),
-				vec![m2.clone()].try_into().unwrap(),
-			),
-			Error::<Test>::ScheduleOverlapInMatch
-		);
-
-		assert_eq!(
-			events(),
-			[
-				RuntimeEvent::AcurastMarketplace(crate::Event::AdvertisementStoredV2(
-					processor_account_id()
-				)),
-				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-					from: alice_account_id(),
-					to: pallet_acurast_acount(),
-					amount: 12_000_000
-				}),
-				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStoredV2(
-					job_id1.clone()
-				)),
-				RuntimeEvent::Balances(pallet_balances::Event::Transfer {
-					from: alice_account_id(),
-					to: pallet_acurast_acount(),
-					amount: 18_000_000
-				}),
-				RuntimeEvent::Acurast(pallet_acurast::Event::JobRegistrationStoredV2((
-					job_id2.0.clone(),
-					job_id2.1
-				))),
-				RuntimeEvent::AcurastMarketplace(crate::Event::JobRegistrationMatchedV2(
-					job_id1.clone()
-				)),
-			]
-		);
+		// the native price of 1 per millisecond is converted into the payment asset
+		let assignment =
+			AcurastMarketplace::stored_matches(processor_account_id(), &job_id).unwrap();
+		assert_eq!(2 * 5000, assignment.fee_per_execution);
+		// the average reward is only tracked for jobs paid in the native currency
+		assert_eq!(None, AcurastMarketplace::average_reward());
 	});
 }
 
@@ -1679,6 +3687,9 @@ fn test_no_match_insufficient_reputation() {
 				min_reputation: Some(1_000_000),
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -1740,6 +3751,85 @@ fn test_no_match_insufficient_reputation() {
 	});
 }
 
+#[test]
+fn test_check_matching_dry_run() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: Some(vec![processor_account_id()].try_into().unwrap()),
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_804_000_000,   // 23.12.2022 14:00 (one hour later)
+			interval: 1_800_000,           // 30min -> 2 executions fit
+			max_start_delay: 5000,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(None),
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		// pretend current time
+		later(now);
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			ad.clone(),
+		));
+
+		let result = AcurastMarketplace::check_matching(
+			registration.clone(),
+			MultiOrigin::Acurast(alice_account_id()),
+			None,
+		)
+		.unwrap();
+		assert_eq!(result.matching, vec![(processor_account_id(), Some(5000))]);
+		assert_eq!(
+			result.rejected,
+			vec![(processor_2_account_id(), MatchingCheckFailure::SourceNotAllowed)]
+		);
+
+		// insufficient reward rejects all sources
+		let mut registration = registration;
+		registration.allowed_sources = None;
+		registration.extra.requirements.reward = 4999;
+		let result = AcurastMarketplace::check_matching(
+			registration,
+			MultiOrigin::Acurast(alice_account_id()),
+			None,
+		)
+		.unwrap();
+		assert!(result.matching.is_empty());
+		assert_eq!(result.rejected.len(), 2);
+		assert!(result
+			.rejected
+			.iter()
+			.all(|(_, failure)| *failure == MatchingCheckFailure::InsufficientReward));
+	});
+}
+
 #[test]
 fn test_report_afer_last_report() {
 	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
@@ -1769,6 +3859,9 @@ fn test_report_afer_last_report() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -1908,6 +4001,114 @@ fn test_report_afer_last_report() {
 	});
 }
 
+#[test]
+fn test_slash_for_missed_sla() {
+	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time: 1_671_800_400_000, // 23.12.2022 13:00
+			end_time: 1_671_807_600_000,   // 23.12.2022 15:00 (two hours later)
+			interval: 1_800_000,           // 30min -> 4 executions fit
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::Single(Some(bounded_vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 }
+				])),
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+		let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+		let _ =
+			Balances::force_set_balance(RuntimeOrigin::root(), processor_account_id(), 100_000_000);
+
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration.clone(),
+		));
+		assert_ok!(AcurastMarketplace::acknowledge_match(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id.clone(),
+			PubKeys::default(),
+		));
+
+		// only the first of four executions gets reported
+		later(registration.schedule.start_time + 1000);
+		assert_ok!(AcurastMarketplace::report(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id.clone(),
+			ExecutionResult::Success(operation_hash())
+		));
+		let assignment =
+			AcurastMarketplace::stored_matches(processor_account_id(), &job_id).unwrap();
+		assert_eq!(SLA { total: 4, met: 1 }, assignment.sla);
+
+		// nothing to slash before the assignment concluded
+		assert_ok!(AcurastMarketplace::cleanup_job_assignments(
+			RuntimeOrigin::signed(bob_account_id()),
+			job_id.clone(),
+		));
+		assert!(AcurastMarketplace::stored_matches(processor_account_id(), &job_id).is_some());
+
+		later(registration.schedule.end_time + 100_000);
+		let processor_balance = Balances::free_balance(processor_account_id());
+		let consumer_balance = Balances::free_balance(alice_account_id());
+
+		assert_ok!(AcurastMarketplace::cleanup_job_assignments(
+			RuntimeOrigin::signed(bob_account_id()),
+			job_id.clone(),
+		));
+
+		// the fee of the three missed executions got slashed, most of it is paid to the consumer
+		let slashed = 3 * assignment.fee_per_execution;
+		let paid_to_consumer = SlaSlashConsumerShare::get().mul_floor(slashed);
+		assert_eq!(Balances::free_balance(processor_account_id()), processor_balance - slashed);
+		assert_eq!(Balances::free_balance(alice_account_id()), consumer_balance + paid_to_consumer);
+		assert_eq!(None, AcurastMarketplace::stored_matches(processor_account_id(), &job_id));
+		assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+			crate::Event::ProcessorSlashedForSla(
+				job_id.clone(),
+				processor_account_id(),
+				SLA { total: 4, met: 1 },
+				slashed,
+				paid_to_consumer,
+			)
+		)));
+	});
+}
+
 #[test]
 fn test_deploy_reuse_keys_same_editor() {
 	let now: u64 = 1_671_800_100_000; // 23.12.2022 12:55;
@@ -1940,6 +4141,9 @@ fn test_deploy_reuse_keys_same_editor() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -1966,6 +4170,9 @@ fn test_deploy_reuse_keys_same_editor() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -2068,6 +4275,9 @@ fn test_deploy_reuse_keys_different_editor() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -2094,6 +4304,9 @@ fn test_deploy_reuse_keys_different_editor() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -2189,6 +4402,9 @@ fn test_deploy_reuse_keys_different_editor_script_edit_fails() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -2215,6 +4431,9 @@ fn test_deploy_reuse_keys_different_editor_script_edit_fails() {
 				min_reputation: None,
 				processor_version: None,
 				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		},
 	};
@@ -2273,6 +4492,416 @@ fn test_deploy_reuse_keys_different_editor_script_edit_fails() {
 	});
 }
 
+#[test]
+fn test_commit_reveal_execution_matching() {
+	let start_time: u64 = 1_671_800_400_000;
+	let period = <Test as Config>::MatchingCommitRevealPeriod::get();
+	let now: u64 = start_time - <Test as Config>::MatchingCompetingDueDelta::get();
+
+	// 1000 is the smallest amount accepted by T::AssetTransactor::lock_asset for the asset used
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	let registration = JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time,                  // 23.12.2022 13:00
+			end_time: 1_671_804_000_000, // 23.12.2022 14:00 (one hour later)
+			interval: 1_800_000,         // 30min
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::CompetingCommitReveal,
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	};
+
+	ExtBuilder.build().execute_with(|| {
+		let initial_job_id = Acurast::job_id_sequence();
+
+		// pretend current time
+		later(now);
+
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_account_id()),
+			ad.clone(),
+		));
+		assert_ok!(AcurastMarketplace::advertise(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			ad.clone(),
+		));
+
+		let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+		assert_ok!(Acurast::register(
+			RuntimeOrigin::signed(alice_account_id()),
+			registration.clone(),
+		));
+
+		// more candidates than slots can be matched
+		assert_ok!(AcurastMarketplace::propose_execution_matching(
+			RuntimeOrigin::signed(bob_account_id()),
+			vec![ExecutionMatch {
+				job_id: job_id.clone(),
+				execution_index: 0,
+				sources: vec![
+					PlannedExecution { source: processor_account_id(), start_delay: 0 },
+					PlannedExecution { source: processor_2_account_id(), start_delay: 0 }
+				]
+				.try_into()
+				.unwrap(),
+			}]
+			.try_into()
+			.unwrap(),
+		));
+		assert_eq!(
+			Some(JobStatus::Matched),
+			AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
+		);
+		assert_eq!(2, AcurastMarketplace::execution_candidates(&job_id, 0).unwrap().len());
+		assert_eq!(None, AcurastMarketplace::stored_matches(processor_account_id(), &job_id));
+
+		// the plain acknowledgment is not accepted since no slot is assigned yet
+		assert_err!(
+			AcurastMarketplace::acknowledge_execution_match(
+				RuntimeOrigin::signed(processor_account_id()),
+				job_id.clone(),
+				0,
+				PubKeys::default(),
+			),
+			Error::<Test>::CannotAcknowledgeWhenNotMatched
+		);
+
+		let salt_1 = H256::repeat_byte(1);
+		let salt_2 = H256::repeat_byte(2);
+		for (processor, salt) in
+			[(processor_account_id(), salt_1), (processor_2_account_id(), salt_2)]
+		{
+			assert_ok!(AcurastMarketplace::commit_execution_acknowledgment(
+				RuntimeOrigin::signed(processor.clone()),
+				job_id.clone(),
+				0,
+				ExecutionCandidateFor::<Test>::blinded_acknowledgment(
+					&processor,
+					&salt,
+					&PubKeys::default()
+				),
+			));
+		}
+		assert_err!(
+			AcurastMarketplace::commit_execution_acknowledgment(
+				RuntimeOrigin::signed(charlie_account_id()),
+				job_id.clone(),
+				0,
+				H256::zero(),
+			),
+			Error::<Test>::NotExecutionCandidate
+		);
+		assert_err!(
+			AcurastMarketplace::reveal_execution_acknowledgment(
+				RuntimeOrigin::signed(processor_account_id()),
+				job_id.clone(),
+				0,
+				salt_1,
+				PubKeys::default(),
+			),
+			Error::<Test>::OutsideExecutionRevealPhase
+		);
+
+		// reveal phase
+		later(start_time - 2 * period);
+
+		assert_err!(
+			AcurastMarketplace::commit_execution_acknowledgment(
+				RuntimeOrigin::signed(processor_account_id()),
+				job_id.clone(),
+				0,
+				H256::zero(),
+			),
+			Error::<Test>::ExecutionCommitPhaseOver
+		);
+		assert_err!(
+			AcurastMarketplace::reveal_execution_acknowledgment(
+				RuntimeOrigin::signed(processor_account_id()),
+				job_id.clone(),
+				0,
+				salt_2,
+				PubKeys::default(),
+			),
+			Error::<Test>::ExecutionAcknowledgmentMismatch
+		);
+		assert_ok!(AcurastMarketplace::reveal_execution_acknowledgment(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id.clone(),
+			0,
+			salt_1,
+			PubKeys::default(),
+		));
+		assert_err!(
+			AcurastMarketplace::pick_execution_candidates(
+				RuntimeOrigin::signed(bob_account_id()),
+				job_id.clone(),
+				0,
+			),
+			Error::<Test>::ExecutionRevealPhaseNotOver
+		);
+		// the last reveal picks the candidates
+		assert_ok!(AcurastMarketplace::reveal_execution_acknowledgment(
+			RuntimeOrigin::signed(processor_2_account_id()),
+			job_id.clone(),
+			0,
+			salt_2,
+			PubKeys::default(),
+		));
+
+		assert_eq!(None, AcurastMarketplace::execution_candidates(&job_id, 0));
+		let picked: Vec<_> = [processor_account_id(), processor_2_account_id()]
+			.into_iter()
+			.filter(|p| AcurastMarketplace::stored_matches(p, &job_id).is_some())
+			.collect();
+		assert_eq!(1, picked.len());
+		let assignment = AcurastMarketplace::stored_matches(&picked[0], &job_id).unwrap();
+		assert!(assignment.acknowledged);
+		assert_eq!(0, assignment.slot);
+		assert_eq!(
+			JobStatus::Assigned(1),
+			AcurastMarketplace::stored_job_execution_status(&job_id, 0)
+		);
+		assert_eq!(
+			events().last(),
+			Some(&RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionCandidatesPicked(
+				job_id.clone(),
+				0,
+				picked.clone()
+			)))
+		);
+	});
+}
+
+fn commit_reveal_registration(start_time: u64) -> JobRegistrationFor<Test> {
+	JobRegistrationFor::<Test> {
+		script: script(),
+		allowed_sources: None,
+		allow_only_verified_sources: false,
+		schedule: Schedule {
+			duration: 5000,
+			start_time,
+			end_time: start_time + 3_600_000,
+			interval: 1_800_000,
+			max_start_delay: 0,
+		},
+		memory: 5_000u32,
+		network_requests: 5,
+		storage: 20_000u32,
+		required_modules: JobModules::default(),
+		extra: RegistrationExtra {
+			requirements: JobRequirements {
+				assignment_strategy: AssignmentStrategy::CompetingCommitReveal,
+				slots: 1,
+				reward: 3_000_000 * 2,
+				min_reputation: None,
+				processor_version: None,
+				runtime: Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
+			},
+		},
+	}
+}
+
+/// Registers a commit-reveal job, matches both processors as candidates for its first execution and lets the given ones commit.
+fn match_commit_reveal_candidates(
+	registration: &JobRegistrationFor<Test>,
+	committing: &[(AccountId, H256)],
+) -> JobId<AccountId> {
+	let initial_job_id = Acurast::job_id_sequence();
+	let _ = Balances::force_set_balance(RuntimeOrigin::root(), alice_account_id(), 100_000_000);
+
+	let ad = advertisement(1000, 1, 100_000, 50_000, 8);
+	for processor in [processor_account_id(), processor_2_account_id()] {
+		let _ = Balances::force_set_balance(RuntimeOrigin::root(), processor.clone(), 100_000_000);
+		assert_ok!(AcurastMarketplace::advertise(RuntimeOrigin::signed(processor), ad.clone()));
+	}
+
+	let job_id = (MultiOrigin::Acurast(alice_account_id()), initial_job_id + 1);
+	assert_ok!(Acurast::register(RuntimeOrigin::signed(alice_account_id()), registration.clone()));
+	assert_ok!(AcurastMarketplace::propose_execution_matching(
+		RuntimeOrigin::signed(bob_account_id()),
+		vec![ExecutionMatch {
+			job_id: job_id.clone(),
+			execution_index: 0,
+			sources: vec![
+				PlannedExecution { source: processor_account_id(), start_delay: 0 },
+				PlannedExecution { source: processor_2_account_id(), start_delay: 0 }
+			]
+			.try_into()
+			.unwrap(),
+		}]
+		.try_into()
+		.unwrap(),
+	));
+
+	for (processor, salt) in committing {
+		assert_ok!(AcurastMarketplace::commit_execution_acknowledgment(
+			RuntimeOrigin::signed(processor.clone()),
+			job_id.clone(),
+			0,
+			ExecutionCandidateFor::<Test>::blinded_acknowledgment(
+				processor,
+				salt,
+				&PubKeys::default()
+			),
+		));
+	}
+	job_id
+}
+
+#[test]
+fn test_commit_reveal_withheld_reveal_is_penalized() {
+	let start_time: u64 = 1_671_800_400_000;
+	let period = <Test as Config>::MatchingCommitRevealPeriod::get();
+	let registration = commit_reveal_registration(start_time);
+
+	ExtBuilder.build().execute_with(|| {
+		later(start_time - <Test as Config>::MatchingCompetingDueDelta::get());
+
+		let salt = H256::repeat_byte(1);
+		let job_id = match_commit_reveal_candidates(
+			&registration,
+			&[(processor_account_id(), salt), (processor_2_account_id(), H256::repeat_byte(2))],
+		);
+		let fee_per_execution = AcurastMarketplace::execution_candidates(&job_id, 0)
+			.unwrap()
+			.iter()
+			.find(|c| c.source == processor_2_account_id())
+			.unwrap()
+			.fee_per_execution;
+
+		later(start_time - 2 * period);
+		assert_ok!(AcurastMarketplace::reveal_execution_acknowledgment(
+			RuntimeOrigin::signed(processor_account_id()),
+			job_id.clone(),
+			0,
+			salt,
+			PubKeys::default(),
+		));
+
+		// the second candidate withholds its reveal, so picking has to wait for the end of the reveal phase
+		assert_err!(
+			AcurastMarketplace::pick_execution_candidates(
+				RuntimeOrigin::signed(bob_account_id()),
+				job_id.clone(),
+				0,
+			),
+			Error::<Test>::ExecutionRevealPhaseNotOver
+		);
+
+		later(start_time - period);
+		let processor_balance = Balances::free_balance(processor_2_account_id());
+		let consumer_balance = Balances::free_balance(alice_account_id());
+		assert_ok!(AcurastMarketplace::pick_execution_candidates(
+			RuntimeOrigin::signed(bob_account_id()),
+			job_id.clone(),
+			0,
+		));
+
+		// only the revealed candidate can be picked
+		assert!(AcurastMarketplace::stored_matches(processor_account_id(), &job_id).is_some());
+		assert_eq!(None, AcurastMarketplace::stored_matches(processor_2_account_id(), &job_id));
+
+		// the withholding candidate got slashed the fee of the execution, most of it is paid to the consumer
+		let paid_to_consumer = SlaSlashConsumerShare::get().mul_floor(fee_per_execution);
+		assert_eq!(
+			Balances::free_balance(processor_2_account_id()),
+			processor_balance - fee_per_execution
+		);
+		assert_eq!(Balances::free_balance(alice_account_id()), consumer_balance + paid_to_consumer);
+		assert!(events().contains(&RuntimeEvent::AcurastMarketplace(
+			crate::Event::ExecutionAcknowledgmentNotRevealed(
+				job_id.clone(),
+				0,
+				processor_2_account_id(),
+				fee_per_execution,
+				paid_to_consumer,
+			)
+		)));
+	});
+}
+
+#[test]
+fn test_commit_reveal_without_reveals_reopens_job() {
+	let start_time: u64 = 1_671_800_400_000;
+	let period = <Test as Config>::MatchingCommitRevealPeriod::get();
+	let registration = commit_reveal_registration(start_time);
+
+	ExtBuilder.build().execute_with(|| {
+		later(start_time - <Test as Config>::MatchingCompetingDueDelta::get());
+
+		// the second candidate does not even commit and is therefore not penalized
+		let job_id = match_commit_reveal_candidates(
+			&registration,
+			&[(processor_account_id(), H256::repeat_byte(1))],
+		);
+		assert_eq!(
+			Some(JobStatus::Matched),
+			AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
+		);
+
+		later(start_time - period);
+		let processor_2_balance = Balances::free_balance(processor_2_account_id());
+		assert_ok!(AcurastMarketplace::pick_execution_candidates(
+			RuntimeOrigin::signed(bob_account_id()),
+			job_id.clone(),
+			0,
+		));
+
+		assert_eq!(None, AcurastMarketplace::execution_candidates(&job_id, 0));
+		assert_eq!(None, AcurastMarketplace::stored_matches(processor_account_id(), &job_id));
+		assert_eq!(
+			Some(JobStatus::Open),
+			AcurastMarketplace::stored_job_status(&job_id.0, &job_id.1)
+		);
+		assert_eq!(Balances::free_balance(processor_2_account_id()), processor_2_balance);
+		let events = events();
+		assert!(events.iter().any(|e| matches!(
+			e,
+			RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionAcknowledgmentNotRevealed(
+				_,
+				_,
+				p,
+				_,
+				_
+			)) if p == &processor_account_id()
+		)));
+		assert!(!events.iter().any(|e| matches!(
+			e,
+			RuntimeEvent::AcurastMarketplace(crate::Event::ExecutionAcknowledgmentNotRevealed(
+				_,
+				_,
+				p,
+				_,
+				_
+			)) if p == &processor_2_account_id()
+		)));
+	});
+}
+
 fn next_block() {
 	if System::block_number() >= 1 {
 		// pallet_acurast_marketplace::on_finalize(System::block_number());
@@ -1,6 +1,8 @@
 use frame_support::{sp_runtime::FixedU128, weights::Weight};
 use pallet_acurast::PoolId;
 
+use crate::PaymentAssetId;
+
 /// Trait used to lookup the time a processor was last seen, i.e. sent a heartbeat.
 pub trait ProcessorInfoProvider<T: crate::Config> {
 	fn last_seen(processor: &T::AccountId) -> Option<u128>;
@@ -12,6 +14,18 @@ pub trait PriceProvider<AccountId, Balance> {
 	fn price_per_millisecond_for(processor: &AccountId) -> Option<Balance>;
 }
 
+/// Trait used to convert processor prices, denominated in the native currency, into the assets jobs can be paid in.
+pub trait PaymentAssetPriceOracle<Balance> {
+	/// Converts `amount` in the native currency into `asset`. Returns [None] if the asset is not supported for payments.
+	fn from_native(asset: PaymentAssetId, amount: Balance) -> Option<Balance>;
+}
+
+impl<Balance> PaymentAssetPriceOracle<Balance> for () {
+	fn from_native(_asset: PaymentAssetId, _amount: Balance) -> Option<Balance> {
+		None
+	}
+}
+
 /// Weight functions needed for pallet_acurast_marketplace.
 pub trait WeightInfo {
 	fn advertise() -> Weight;
@@ -32,4 +46,16 @@ pub trait WeightInfo {
 	fn cleanup_job_assignments() -> Weight;
 	fn update_price_settings() -> Weight;
 	fn cleanup_job_matcher() -> Weight;
+	fn cancel_job() -> Weight;
+	fn extend_job() -> Weight;
+	fn accept_job_extension() -> Weight;
+	fn decline_job_extension() -> Weight;
+	fn propose_extension_matching(x: u32) -> Weight;
+	fn mark_slot_rematchable() -> Weight;
+	fn commit_execution_acknowledgment() -> Weight;
+	fn reveal_execution_acknowledgment() -> Weight;
+	fn pick_execution_candidates() -> Weight;
+	fn reveal_result() -> Weight;
+	fn settle_execution_results() -> Weight;
+	fn set_advertisement_unavailability() -> Weight;
 }
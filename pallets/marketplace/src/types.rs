@@ -5,7 +5,8 @@ use sp_core::H256;
 use sp_std::prelude::*;
 
 use pallet_acurast::{
-	AllowedSources, JobId, JobModules, JobRegistration, MultiOrigin, ParameterBound, Schedule,
+	AllowedSources, JobId, JobModules, JobRegistration, MultiOrigin, ParameterBound, PoolId,
+	Schedule,
 };
 
 use serde::{Deserialize, Serialize};
@@ -14,13 +15,20 @@ use crate::Config;
 
 pub(crate) const MAX_EXECUTIONS_PER_JOB: u64 = 6_308_000; // run a job every 5 seconds for a year
 
+/// The maximum number of jobs returned by a single call to [`crate::Pallet::job_views_for`].
+pub const MAX_JOB_VIEWS_PER_PAGE: u32 = 100;
+
 pub(crate) const EXECUTION_OPERATION_HASH_MAX_LENGTH: u32 = 256;
 pub(crate) const EXECUTION_FAILURE_MESSAGE_MAX_LENGTH: u32 = 1024;
+pub(crate) const MAX_UNAVAILABILITY_WINDOWS: u32 = 16;
 
 pub type ExecutionOperationHash = BoundedVec<u8, ConstU32<EXECUTION_OPERATION_HASH_MAX_LENGTH>>;
 pub type ExecutionFailureMessage = BoundedVec<u8, ConstU32<EXECUTION_FAILURE_MESSAGE_MAX_LENGTH>>;
 pub type PlannedExecutions<AccountId, MaxSlots> = BoundedVec<PlannedExecution<AccountId>, MaxSlots>;
 
+/// The identifier of a fungible asset jobs can be paid in, see [`JobRequirements::payment_asset`].
+pub type PaymentAssetId = u32;
+
 pub type DeploymentHash = H256;
 pub type KeyId = H256;
 
@@ -117,6 +125,110 @@ pub type AdvertisementFor<T> = Advertisement<
 	<T as Config>::MaxAllowedConsumers,
 >;
 
+/// A recurring window in which a processor is not available to execute jobs, e.g. a daily maintenance blackout.
+///
+/// The window repeats every `period` milliseconds, in both directions from `start`. A weekdays-only processor can be expressed
+/// with a weekly window starting on a Saturday 00:00 with a `duration` of two days.
+#[derive(
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+)]
+pub struct UnavailabilityWindow {
+	/// The start of one occurrence of the window in milliseconds since Unix Epoch.
+	pub start: u64,
+	/// The duration of the window in milliseconds.
+	pub duration: u64,
+	/// The period in milliseconds after which the window repeats.
+	pub period: u64,
+}
+
+impl UnavailabilityWindow {
+	/// Returns true if `duration` is not zero and shorter than `period`.
+	pub fn is_valid(&self) -> bool {
+		self.duration > 0 && self.duration < self.period
+	}
+
+	/// Returns true if any occurrence of the window intersects the interval `[start, end)`.
+	pub fn intersects(&self, start: u64, end: u64) -> bool {
+		if end <= start || !self.is_valid() {
+			return false;
+		}
+		let period = self.period as u128;
+		// position of `start` relative to the previous occurrence of the window
+		let position = ((start as u128) + period - (self.start as u128 % period)) % period;
+		if position < self.duration as u128 {
+			return true;
+		}
+		// the next occurrence starts within the interval
+		((end - start) as u128) > period - position
+	}
+
+	/// Returns true if any occurrence of the window intersects any of the `count` intervals `[first_start + k * interval, first_start + k * interval + duration)`.
+	///
+	/// Instead of checking every interval, this counts the intervals not intersecting the window in `O(log(period))`.
+	pub fn intersects_any(
+		&self,
+		first_start: u64,
+		interval: u64,
+		count: u64,
+		duration: u64,
+	) -> bool {
+		if count == 0 || duration == 0 || !self.is_valid() {
+			return false;
+		}
+		let period = self.period as u128;
+		// An interval starting at position `r` relative to an occurrence intersects it if `r < self.duration`
+		// or if the next occurrence starts before the interval ends, i.e. `r > period - duration`.
+		// Shifting by `duration - 1` turns both cases into `(offset + k * interval) % period < limit`.
+		let limit = self.duration as u128 + duration as u128 - 1;
+		if limit >= period {
+			return true;
+		}
+		let offset = ((first_start as u128) + period - (self.start as u128 % period)
+			+ duration as u128
+			- 1) % period;
+		let step = interval as u128 % period;
+		let count = count as u128;
+		// `floor((v + period - limit) / period) - floor(v / period)` is 1 exactly if `v % period >= limit`
+		let not_intersecting = floor_sum(count, period, step, offset + period - limit)
+			- floor_sum(count, period, step, offset);
+		not_intersecting < count
+	}
+}
+
+/// Returns `sum(floor((a * k + b) / m))` for `k` in `[0, n)`.
+fn floor_sum(mut n: u128, mut m: u128, mut a: u128, mut b: u128) -> u128 {
+	let mut sum = 0u128;
+	loop {
+		if a >= m {
+			sum = sum.saturating_add((n * n.saturating_sub(1) / 2).saturating_mul(a / m));
+			a %= m;
+		}
+		if b >= m {
+			sum = sum.saturating_add(n.saturating_mul(b / m));
+			b %= m;
+		}
+		let y_max = a * n + b;
+		if y_max < m {
+			return sum;
+		}
+		n = y_max / m;
+		b = y_max % m;
+		core::mem::swap(&mut m, &mut a);
+	}
+}
+
+pub type UnavailabilityWindows =
+	BoundedVec<UnavailabilityWindow, ConstU32<MAX_UNAVAILABILITY_WINDOWS>>;
+
 /// The resource advertisement by a source containing the base restrictions.
 #[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
 pub struct AdvertisementRestriction<AccountId, MaxAllowedConsumers: ParameterBound> {
@@ -330,6 +442,91 @@ pub type JobAssignmentFor<T> = JobAssignment<
 	<T as pallet_acurast::Config>::RegistrationExtra,
 >;
 
+/// A pending extension of a job with [`AssignmentStrategy::Single`] that got assigned before being extended.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+pub struct JobExtension<AccountId, MaxSlots: ParameterBound> {
+	/// The index of the first execution in the extended range, equal to the job's execution count before the extension.
+	pub first_execution_index: u64,
+	/// The timestamp in ms until which the pending processors can respond, after which they count as declined.
+	pub accept_deadline: u64,
+	/// The assigned processors that neither accepted nor declined the extension yet.
+	pub pending: BoundedVec<AccountId, MaxSlots>,
+	/// The slots whose processor declined the extension, waiting to be re-matched for the extended range.
+	pub declined_slots: BoundedVec<u8, MaxSlots>,
+}
+
+impl<AccountId, MaxSlots: ParameterBound> JobExtension<AccountId, MaxSlots> {
+	/// If all processors responded and all declined slots got re-matched.
+	pub fn is_completed(&self) -> bool {
+		self.pending.is_empty() && self.declined_slots.is_empty()
+	}
+}
+
+pub type JobExtensionFor<T> =
+	JobExtension<<T as frame_system::Config>::AccountId, <T as pallet_acurast::Config>::MaxSlots>;
+
+/// A result reported by a processor for an execution of a job requiring [`JobRequirements::consensus`].
+///
+/// The result is reported blinded and revealed once the report windows of all slots closed, so a processor cannot copy the results of others.
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+pub struct ResultCommitment<AccountId, Balance> {
+	/// The reporting processor.
+	pub processor: AccountId,
+	/// The blinded result, see [`ResultCommitment::blinded_result`], `None` if the processor reported a failure.
+	pub commitment: Option<H256>,
+	/// The hash of the execution's result, `None` until revealed.
+	pub result: Option<ExecutionOperationHash>,
+	/// The fee the processor gets paid if its result agrees with the majority.
+	pub fee: Balance,
+}
+
+impl<AccountId: Encode, Balance> ResultCommitment<AccountId, Balance> {
+	/// The blinded result of `processor`, computed as `blake2_256(SCALE(processor, salt, result))`.
+	pub fn blinded_result(
+		processor: &AccountId,
+		salt: &H256,
+		result: &ExecutionOperationHash,
+	) -> H256 {
+		H256(sp_io::hashing::blake2_256(&(processor, salt, result).encode()))
+	}
+}
+
+pub type ResultCommitmentFor<T> =
+	ResultCommitment<<T as frame_system::Config>::AccountId, <T as Config>::Balance>;
+
+/// A processor matched as candidate for an execution of a job with [`AssignmentStrategy::CompetingCommitReveal`].
+#[derive(RuntimeDebug, Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq)]
+pub struct ExecutionCandidate<AccountId, Balance> {
+	/// The candidate.
+	pub source: AccountId,
+	/// The start delay the candidate got matched with.
+	pub start_delay: u64,
+	/// The fee per execution the candidate got matched with.
+	pub fee_per_execution: Balance,
+	/// The blinded acknowledgment, see [`ExecutionCandidate::blinded_acknowledgment`].
+	pub commitment: Option<H256>,
+	/// The public keys of the revealed acknowledgment.
+	pub revealed: Option<PubKeys>,
+	/// The salt of the revealed acknowledgment.
+	pub salt: Option<H256>,
+}
+
+impl<AccountId: Encode, Balance> ExecutionCandidate<AccountId, Balance> {
+	/// The blinded acknowledgment of `source` for `pub_keys`, computed as `blake2_256(SCALE(source, salt, pub_keys))`.
+	pub fn blinded_acknowledgment(source: &AccountId, salt: &H256, pub_keys: &PubKeys) -> H256 {
+		H256(sp_io::hashing::blake2_256(&(source, salt, pub_keys).encode()))
+	}
+}
+
+pub type ExecutionCandidateFor<T> =
+	ExecutionCandidate<<T as frame_system::Config>::AccountId, <T as Config>::Balance>;
+
+pub type ExecutionCandidatesFor<T> =
+	BoundedVec<ExecutionCandidateFor<T>, <T as pallet_acurast::Config>::MaxSlots>;
+
+pub type ResultCommitmentsFor<T> =
+	BoundedVec<ResultCommitmentFor<T>, <T as pallet_acurast::Config>::MaxSlots>;
+
 /// The allowed sources update operation.
 #[derive(
 	RuntimeDebug,
@@ -344,6 +541,7 @@ pub type JobAssignmentFor<T> = JobAssignment<
 	PalletError,
 	Default,
 )]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum JobStatus {
 	/// Status after a job got registered.
 	#[default]
@@ -417,16 +615,25 @@ pub struct JobRequirements<
 	pub processor_version: Option<ProcessorVersionRequirements<Version, MaxVersions>>,
 	/// The required runtime
 	pub runtime: Runtime,
+	/// If the result hashes reported by the slots for each execution must agree. Only processors reporting the majority result get paid.
+	#[serde(default)]
+	pub consensus: bool,
+	/// The asset the job is paid in, or [None] for the native currency. Rewards, matcher fees and refunds are paid in this asset.
+	#[serde(default)]
+	pub payment_asset: Option<PaymentAssetId>,
+	/// If every reported result is sent back to the chain the job was registered from. The delivery fees are paid from the job's budget.
+	#[serde(default)]
+	pub deliver_results_to_origin: bool,
 }
 
 impl<Reward, AccountId, MaxSlots: ParameterBound, Version, MaxVersions: ParameterBound>
 	JobRequirements<Reward, AccountId, MaxSlots, Version, MaxVersions>
 {
 	pub fn is_competing(&self) -> bool {
-		if let AssignmentStrategy::Competing = self.assignment_strategy {
-			return true;
-		}
-		false
+		matches!(
+			self.assignment_strategy,
+			AssignmentStrategy::Competing | AssignmentStrategy::CompetingCommitReveal
+		)
 	}
 
 	pub fn is_single(&self) -> bool {
@@ -503,6 +710,11 @@ pub enum AssignmentStrategy<AccountId, MaxSlots: ParameterBound> {
 	/// `Config<T>::Competing` limits the number of competing processors matched for each execution/slot. If not enough
 	/// processors are available (for the provided reward), less or no processors are matched.
 	Competing,
+	/// Like [`AssignmentStrategy::Competing`], but more processors than slots can be matched as candidates for each execution.
+	/// Candidates commit a blinded acknowledgment, reveal it afterwards and the slots are assigned to revealed candidates
+	/// picked randomly, weighted by their reputation. The pick uses `Config<T>::Randomness`, not known before the reveal phase ends,
+	/// with the revealed salts as extra entropy. Candidates that commit without revealing get penalized.
+	CompetingCommitReveal,
 }
 
 /// A (one-sided) matching of a job to sources such that the requirements of both sides, consumer and source, are met.
@@ -577,6 +789,125 @@ pub struct PartialJobRegistration<Reward, AccountId, MaxAllowedSources: Get<u32>
 	pub reward: Reward,
 	/// Job requirements: Minimum reputation required to process job, in parts per million, `r ∈ [0, 1_000_000]`.
 	pub min_reputation: Option<u128>,
+	/// Job requirements: The asset the reward is offered in, or [None] for the native currency.
+	#[serde(default)]
+	pub payment_asset: Option<PaymentAssetId>,
+}
+
+/// The first matching check a processor failed during a matching dry-run.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum MatchingCheckFailure {
+	/// The processor has no valid attestation (of the required processor type) while `allow_only_verified_sources` is set.
+	UnverifiedSource,
+	/// The processor has no advertisement.
+	AdvertisementNotFound,
+	/// A module required by the job is not available on the processor.
+	ModuleNotAvailable,
+	/// The processor's network request quota is insufficient.
+	NetworkRequestQuotaExceeded,
+	/// The processor is not part of the job's allowed sources.
+	SourceNotAllowed,
+	/// The consumer is not part of the processor's allowed consumers.
+	ConsumerNotAllowed,
+	/// The processor's reputation is below the job's minimum reputation.
+	InsufficientReputation,
+	/// The processor's version does not satisfy the job's version requirements.
+	ProcessorVersionMismatch,
+	/// The processor does not meet the minimum metric for a pool. [pool_id]
+	ProcessorMinMetricsNotMet(PoolId),
+	/// The job's schedule overlaps with the processor's existing assignments.
+	ScheduleOverlap,
+	/// The job's reward is below the processor's price.
+	InsufficientReward,
+	/// Any other failure, e.g. an overflow in calculations.
+	Other,
+	/// The job's payment asset cannot be priced for the processor.
+	UnsupportedPaymentAsset,
+}
+
+/// The result of a matching dry-run against all advertised processors.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct MatchingDryRun<AccountId, Reward> {
+	/// The processors passing all checks with the fee per execution they would be assigned with.
+	///
+	/// The fee is [None] if it could not be determined, e.g. because no schedule was provided.
+	pub matching: Vec<(AccountId, Option<Reward>)>,
+	/// The processors failing a check with the first check failed.
+	pub rejected: Vec<(AccountId, MatchingCheckFailure)>,
+}
+
+impl<AccountId, Reward> Default for MatchingDryRun<AccountId, Reward> {
+	fn default() -> Self {
+		Self { matching: Vec::new(), rejected: Vec::new() }
+	}
+}
+
+pub type MatchingDryRunFor<T> =
+	MatchingDryRun<<T as frame_system::Config>::AccountId, <T as Config>::Balance>;
+
+/// Errors returned by the marketplace runtime API.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum RuntimeApiError {
+	/// Error when running the matching checks for a full job registration.
+	CheckMatching,
+}
+
+impl RuntimeApiError {
+	pub fn message(&self) -> &'static str {
+		match self {
+			RuntimeApiError::CheckMatching => "Could not run the matching checks.",
+		}
+	}
+}
+
+/// Aggregated view on a job's lifecycle in the marketplace.
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct JobView<Reward, AccountId, MaxAllowedSources: Get<u32>, Extra> {
+	pub job_id: JobId<AccountId>,
+	pub registration: JobRegistration<AccountId, MaxAllowedSources, Extra>,
+	pub status: JobStatus,
+	/// One entry per processor assigned to a slot that is not yet finalized.
+	pub assignments: Vec<JobViewAssignment<Reward, AccountId>>,
+	pub budget: JobViewBudget<Reward>,
+}
+
+pub type JobViewFor<T> = JobView<
+	<T as Config>::Balance,
+	<T as frame_system::Config>::AccountId,
+	<T as pallet_acurast::Config>::MaxAllowedSources,
+	<T as pallet_acurast::Config>::RegistrationExtra,
+>;
+
+/// A processor's [Assignment] as part of a [JobView].
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct JobViewAssignment<Reward, AccountId> {
+	pub processor: AccountId,
+	pub assignment: Assignment<Reward>,
+	/// The execution index the next report is expected for, [None] if no further report is expected.
+	pub next_report_index: Option<u64>,
+	/// The status of the assigned execution, only set for competing assignments.
+	pub execution_status: Option<JobStatus>,
+}
+
+/// The budget of a job as part of a [JobView].
+#[derive(RuntimeDebug, Encode, Decode, TypeInfo, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct JobViewBudget<Reward> {
+	/// The amount still reserved for the job.
+	pub reserved: Reward,
+	/// The amount of the job's total reward that is no longer reserved, i.e. paid out or refunded.
+	pub spent: Reward,
 }
 
 /// The details for a single planned slot execution with the delay.
@@ -601,7 +932,15 @@ pub struct PlannedExecution<AccountId> {
 }
 
 #[derive(
-	RuntimeDebug, Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq,
+	RuntimeDebug,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Eq,
+	PartialEq,
 )]
 pub enum ExecutionResult {
 	/// Success with operation hash.
@@ -621,6 +960,20 @@ pub trait MarketplaceHooks<T: Config> {
 		job_id: &JobId<<T as frame_system::Config>::AccountId>,
 		refund: T::Balance,
 	) -> DispatchResultWithPostInfo;
+
+	/// Returns the account paying for the delivery of a result to the origin of a job and the fee it gets refunded
+	/// from the job's budget, or [None] if results cannot be delivered to the job's origin.
+	fn result_delivery_fee(
+		job_id: &JobId<<T as frame_system::Config>::AccountId>,
+	) -> Option<(T::AccountId, T::Balance)>;
+
+	fn deliver_result(
+		job_id: &JobId<<T as frame_system::Config>::AccountId>,
+		execution_result: &ExecutionResult,
+	) -> DispatchResultWithPostInfo;
+
+	/// The worst case weight of [`Self::deliver_result`].
+	fn deliver_result_weight() -> Weight;
 }
 
 impl<T: Config> MarketplaceHooks<T> for () {
@@ -637,6 +990,23 @@ impl<T: Config> MarketplaceHooks<T> for () {
 	) -> DispatchResultWithPostInfo {
 		Ok(().into())
 	}
+
+	fn result_delivery_fee(
+		_job_id: &JobId<<T as frame_system::Config>::AccountId>,
+	) -> Option<(T::AccountId, T::Balance)> {
+		None
+	}
+
+	fn deliver_result(
+		_job_id: &JobId<<T as frame_system::Config>::AccountId>,
+		_execution_result: &ExecutionResult,
+	) -> DispatchResultWithPostInfo {
+		Ok(().into())
+	}
+
+	fn deliver_result_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 /// The details for a single planned slot execution with the delay.
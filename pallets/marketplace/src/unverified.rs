@@ -0,0 +1,100 @@
+use frame_support::pallet_prelude::*;
+use pallet_acurast::{JobId, StoredJobRegistration};
+use sp_std::prelude::*;
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	/// Checks up to [`Config::MaxUnverifiedAssignmentRemovals`] assignments of processors queued in [`UnverifiedAssignmentRemovals`],
+	/// continuing where the previous call stopped.
+	///
+	/// Assignments to jobs allowing only verified sources are removed, see [`Self::do_remove_unverified_assignment`].
+	pub(crate) fn remove_unverified_assignments() -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		let Ok(now) = Self::now() else {
+			return weight;
+		};
+
+		let mut remaining = T::MaxUnverifiedAssignmentRemovals::get() as usize;
+		while remaining > 0 {
+			let Some((processor, cursor)) = <UnverifiedAssignmentRemovals<T>>::iter().next() else {
+				break;
+			};
+			let job_ids: Vec<JobId<T::AccountId>> = match cursor {
+				Some(job_id) => <StoredMatches<T>>::iter_key_prefix_from(
+					&processor,
+					<StoredMatches<T>>::hashed_key_for(&processor, &job_id),
+				)
+				.take(remaining)
+				.collect(),
+				None => <StoredMatches<T>>::iter_key_prefix(&processor).take(remaining).collect(),
+			};
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 1));
+			if job_ids.len() < remaining {
+				<UnverifiedAssignmentRemovals<T>>::remove(&processor);
+				// an empty batch still counts to guarantee progress
+				remaining -= job_ids.len().max(1);
+			} else {
+				<UnverifiedAssignmentRemovals<T>>::insert(&processor, job_ids.last().cloned());
+				remaining = 0;
+			}
+			for job_id in job_ids {
+				weight.saturating_accrue(Self::do_remove_unverified_assignment(
+					&processor, job_id, now,
+				));
+			}
+		}
+
+		weight
+	}
+
+	/// Removes the assignment of `processor` to `job_id` if the job allows only verified sources, after its attestation got revoked.
+	///
+	/// The slot of a job with [`AssignmentStrategy::Single`] is marked as re-matchable from the next execution on,
+	/// unless the job is being extended or has no remaining executions.
+	fn do_remove_unverified_assignment(
+		processor: &T::AccountId,
+		job_id: JobId<T::AccountId>,
+		now: u64,
+	) -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+		let Some(registration) = <StoredJobRegistration<T>>::get(&job_id.0, job_id.1) else {
+			return weight;
+		};
+		if !registration.allow_only_verified_sources {
+			return weight;
+		}
+		let Some(assignment) = <StoredMatches<T>>::take(processor, &job_id) else {
+			return weight;
+		};
+		<AssignedProcessors<T>>::remove(&job_id, processor);
+		<ExtendedAssignmentStart<T>>::remove(&job_id, processor);
+		<NextReportIndex<T>>::remove(&job_id, processor);
+		weight.saturating_accrue(T::DbWeight::get().reads_writes(2, 4));
+		Self::deposit_event(Event::UnverifiedAssignmentRemoved(job_id.clone(), processor.clone()));
+
+		let e: <T as Config>::RegistrationExtra = registration.extra.clone().into();
+		let requirements: JobRequirementsFor<T> = e.into();
+		let first_execution_index =
+			registration.schedule.next_execution_index(assignment.start_delay, now);
+		if matches!(requirements.assignment_strategy, AssignmentStrategy::Single(_))
+			&& !<StoredJobExtension<T>>::contains_key(&job_id)
+			&& first_execution_index < registration.schedule.execution_count()
+		{
+			<RematchableSlots<T>>::insert(&job_id, assignment.slot, first_execution_index);
+			weight.saturating_accrue(T::DbWeight::get().writes(1));
+			if assignment.acknowledged {
+				Self::release_assigned_slot(&job_id);
+				weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+			}
+			Self::deposit_event(Event::SlotMarkedRematchable(
+				job_id,
+				processor.clone(),
+				assignment.slot,
+				first_execution_index,
+			));
+		}
+
+		weight
+	}
+}
@@ -60,6 +60,7 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	/// Storage: `AcurastMarketplace::StoredAdvertisementPricing` (r:0 w:1)
 	/// Proof: `AcurastMarketplace::StoredAdvertisementPricing` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
 	fn delete_advertisement() -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `194`
 		//  Estimated: `7296`
@@ -67,7 +68,7 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		Weight::from_parts(28_270_000, 0)
 			.saturating_add(Weight::from_parts(0, 7296))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AcurastMarketplace::StoredMatches` (r:1 w:1)
 	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
@@ -396,4 +397,106 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 4990))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+
+	fn cancel_job() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(185_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38282))
+			.saturating_add(T::DbWeight::get().reads(200))
+			.saturating_add(T::DbWeight::get().writes(202))
+	}
+
+	fn extend_job() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(120_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38282))
+			.saturating_add(T::DbWeight::get().reads(138))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+
+	fn accept_job_extension() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(150_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38282))
+			.saturating_add(T::DbWeight::get().reads(70))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	fn decline_job_extension() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4820))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	fn propose_extension_matching(x: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(2_101_844_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(Weight::from_parts(2_015_844_609, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((454_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((260_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 403584).saturating_mul(x.into()))
+	}
+
+	fn mark_slot_rematchable() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38282))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+
+	fn commit_execution_acknowledgment() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(38_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 144137))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	fn reveal_execution_acknowledgment() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(1_985_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1186087))
+			.saturating_add(T::DbWeight::get().reads(453))
+			.saturating_add(T::DbWeight::get().writes(325))
+	}
+
+	fn pick_execution_candidates() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(2_420_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1353695))
+			.saturating_add(T::DbWeight::get().reads(518))
+			.saturating_add(T::DbWeight::get().writes(391))
+	}
+
+	fn reveal_result() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(880_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 579842))
+			.saturating_add(T::DbWeight::get().reads(195))
+			.saturating_add(T::DbWeight::get().writes(195))
+	}
+
+	fn settle_execution_results() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(860_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 579842))
+			.saturating_add(T::DbWeight::get().reads(195))
+			.saturating_add(T::DbWeight::get().writes(195))
+	}
+	/// Storage: `AcurastMarketplace::StoredAdvertisementRestriction` (r:1 w:0)
+	/// Proof: `AcurastMarketplace::StoredAdvertisementRestriction` (`max_values`: None, `max_size`: Some(3831), added: 6306, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredAdvertisementUnavailability` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::StoredAdvertisementUnavailability` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	fn set_advertisement_unavailability() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7296))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -613,7 +613,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(14)]
-		#[pallet::weight(T::WeightInfo::onboard())]
+		#[pallet::weight(T::WeightInfo::onboard().saturating_add(T::AttestationHandler::validate_weight()))]
 		pub fn onboard(
 			origin: OriginFor<T>,
 			pairing: ProcessorPairingFor<T>,
@@ -128,12 +128,20 @@ impl pallet_acurast::Config for Test {
 	type RegistrationExtra = ();
 	type MaxAllowedSources = CU32<4>;
 	type MaxCertificateRevocationListUpdates = ConstU32<10>;
+	type MaxAttestationAllowlistUpdates = ConstU32<10>;
+	type MaxAttestationAllowlistEntries = ConstU32<32>;
+	type InitialAttestationAllowlist = ();
+	type ReattestationInterval = ConstU64<{ u64::MAX }>;
+	type ReattestationGracePeriod = ConstU64<0>;
+	type MaxAttestationExpiryChecks = ConstU32<10>;
+	type MaxRevocationPropagations = ConstU32<10>;
 	type MaxSlots = CU32<64>;
 	type PalletId = AcurastPalletId;
 	type MaxEnvVars = CU32<10>;
 	type EnvKeyMaxSize = CU32<32>;
 	type EnvValueMaxSize = CU32<1024>;
 	type KeyAttestationBarrier = ();
+	type AttestationVerifiers = ();
 	type UnixTime = pallet_timestamp::Pallet<Test>;
 	type JobHooks = ();
 	type ProcessorVersion = acurast_common::Version;
@@ -70,6 +70,8 @@ mod runtime {
 	pub type Balances = pallet_balances;
 	#[runtime::pallet_index(11)]
 	pub type TransactionPayment = pallet_transaction_payment;
+	#[runtime::pallet_index(12)]
+	pub type Assets = pallet_assets;
 	#[runtime::pallet_index(14)]
 	pub type Uniques = pallet_uniques;
 	#[runtime::pallet_index(15)]
@@ -9,6 +9,7 @@ mod pallet_acurast_processor_manager_config;
 mod pallet_acurast_rewards_treasury_config;
 mod pallet_acurast_token_claim_config;
 mod pallet_acurast_token_conversion_config;
+mod pallet_assets_config;
 mod pallet_aura_config;
 mod pallet_authorship_config;
 mod pallet_balances_config;
@@ -125,13 +125,15 @@ parameter_types! {
 	pub const CoreSignatureDigest: &'static [u8] = hex_literal::hex!("ec70c2a4e072a0f586552a68357b23697c9d45f1e1257a8c4d29a25ac4982433").as_slice();
 	pub const LiteSignatureDigest: &'static [u8] = hex_literal::hex!("ea21af13f3b724c662f3da05247acc5a68a45331a90220f0d90a6024d7fa8f36").as_slice();
 	pub const LiteSolSignatureDigest: &'static [u8] = hex_literal::hex!("e095733f011ae6934a02d65a0945fcf24c16af7598c1c23405dcc4f3cb9ee5bc").as_slice();
-	pub PackageNames: Vec<&'static [u8]> = vec![CorePackageName::get(), LitePackageName::get(), CorePackageNameCanary::get(), LitePackageNameCanary::get()];
 	pub BundleIds: Vec<&'static [u8]> = vec![BundleId::get()];
 	pub LitePackageNames: Vec<&'static [u8]> = vec![LitePackageName::get(), LitePackageNameCanary::get()];
 	pub CorePackageNames: Vec<&'static [u8]> = vec![CorePackageName::get(), CorePackageNameCanary::get()];
-	pub SignatureDigests: Vec<&'static [u8]> = vec![CoreSignatureDigest::get(), LiteSignatureDigest::get(), LiteSolSignatureDigest::get()];
 	pub LiteSignatureDigests: Vec<&'static [u8]> = vec![LiteSignatureDigest::get(), LiteSolSignatureDigest::get()];
 	pub CoreSignatureDigests: Vec<&'static [u8]> = vec![CoreSignatureDigest::get()];
+	/// Processors have to refresh their attestation every 90 days.
+	pub const ReattestationInterval: u64 = 90 * 24 * 60 * 60 * 1000;
+	/// Expiring attestations are accepted for another 7 days.
+	pub const ReattestationGracePeriod: u64 = 7 * 24 * 60 * 60 * 1000;
 	pub const ReportTolerance: u64 = 120_000;
 
 	pub const ManagerCollectionId: u128 = 0;
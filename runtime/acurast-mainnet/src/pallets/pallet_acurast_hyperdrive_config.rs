@@ -3,16 +3,16 @@ use frame_support::{
 	instances::Instance1, pallet_prelude::DispatchResultWithPostInfo, parameter_types,
 };
 use polkadot_core_primitives::BlakeTwo256;
-use sp_runtime::traits::AccountIdConversion;
+use sp_runtime::{traits::AccountIdConversion, Perbill};
 
 use acurast_runtime_common::{
-	constants::{MainnetTokenConversionPalletId, UNIT},
-	types::{AccountId, Balance},
+	constants::{MainnetTokenConversionPalletId, DAYS, UNIT},
+	types::{AccountId, Balance, BlockNumber},
 	weight,
 };
 use pallet_acurast::{MessageBody, MessageProcessor, ProxyAcurastChain};
 use pallet_acurast_hyperdrive::ParsedAction;
-use pallet_acurast_hyperdrive_ibc::{LayerFor, SubjectFor};
+use pallet_acurast_hyperdrive_ibc::{ethereum::EthereumStorageProofVerifier, LayerFor, SubjectFor};
 
 use crate::{
 	Acurast, AcurastAccountId, AcurastHyperdrive, AcurastHyperdriveIbc, AcurastHyperdriveToken,
@@ -27,6 +27,17 @@ use crate::{
 parameter_types! {
 	pub const MinFee: Balance = UNIT * 20;
 	pub const MinTransferAmount: Balance = UNIT;
+	/// Bonded oracles have no weight towards quorums until governance approves them, and are only approved while
+	/// the bonds reaching a quorum exceed the value a forged message could release.
+	pub const MinOracleBond: Balance = UNIT * 10_000;
+	pub const OracleUnbondingPeriod: BlockNumber = 28 * DAYS;
+	pub const EquivocationBounty: Perbill = Perbill::from_percent(10);
+	/// About a week of finalized Ethereum state roots at one light client update per epoch.
+	pub const MaxEthereumStateRoots: u32 = 1575;
+	/// Delivery confirmations of sent messages can be checked as long as an unbonding oracle stays slashable.
+	pub const SentMessageRetention: BlockNumber = 28 * DAYS;
+	/// Below the minimum confirmation signatures, so no single oracle meets a quorum on its own.
+	pub const MaxOracleWeight: u32 = 2;
 	pub const SelfChain: ProxyAcurastChain = ProxyAcurastChain::Acurast;
 }
 
@@ -60,6 +71,14 @@ impl pallet_acurast_hyperdrive_ibc::Config<Instance1> for Runtime {
 	type UpdateOrigin = EnsureCouncilOrRoot;
 	type ParachainId = ParachainInfo;
 	type SelfChain = SelfChain;
+	type MinOracleBond = MinOracleBond;
+	type OracleUnbondingPeriod = OracleUnbondingPeriod;
+	type EquivocationBounty = EquivocationBounty;
+	type AggregateSignatureVerifier = pallet_acurast_hyperdrive_ibc::bls::BlsVerifier;
+	type MessageProofVerifier = EthereumStorageProofVerifier<Self, Instance1>;
+	type MaxEthereumStateRoots = MaxEthereumStateRoots;
+	type SentMessageRetention = SentMessageRetention;
+	type MaxOracleWeight = MaxOracleWeight;
 	type WeightInfo = weight::pallet_acurast_hyperdrive_ibc::WeightInfo<Self>;
 }
 
@@ -70,6 +89,7 @@ impl pallet_acurast_hyperdrive_token::Config<Instance1> for Runtime {
 	type ParsableAccountId = AcurastAccountId;
 	type Balance = Balance;
 	type Currency = Balances;
+	type Assets = pallet_acurast_hyperdrive_token::NoAssets<Balance>;
 	type MessageSender = AcurastHyperdriveIbc;
 	type MessageIdHasher = BlakeTwo256;
 
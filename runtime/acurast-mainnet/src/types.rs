@@ -108,10 +108,19 @@ impl_opaque_keys! {
 }
 
 /// Extrinsic Call Filter
+///
+/// Filters the Ethereum light client and storage proof calls until their weights are benchmarked.
 pub struct CallFilter;
 impl frame_support::traits::Contains<RuntimeCall> for CallFilter {
 	fn contains(c: &RuntimeCall) -> bool {
-		!matches!(c, RuntimeCall::Uniques(_))
+		!matches!(
+			c,
+			RuntimeCall::Uniques(_)
+				| RuntimeCall::AcurastHyperdriveIbc(
+					pallet_acurast_hyperdrive_ibc::Call::submit_ethereum_finality_update { .. }
+						| pallet_acurast_hyperdrive_ibc::Call::receive_message_with_proof { .. }
+				)
+		)
 	}
 }
 
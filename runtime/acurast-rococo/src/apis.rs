@@ -12,11 +12,19 @@ use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
 use sp_std::prelude::*;
 use sp_version::RuntimeVersion;
 
-use acurast_runtime_common::constants::SLOT_DURATION;
+use acurast_runtime_common::{
+	constants::SLOT_DURATION,
+	types::{ExtraFor, MaxAllowedSources},
+};
+use pallet_acurast::{JobId, JobIdSequence, JobRegistrationFor, Metrics, MultiOrigin};
+use pallet_acurast_marketplace::{
+	JobViewFor, MatchingDryRunFor, PartialJobRegistrationForMarketplace, RuntimeApiError,
+};
 
 use super::{
-	AccountId, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce, ParachainSystem,
-	Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
+	AccountId, AcurastMarketplace, Balance, Block, ConsensusHook, Executive, InherentDataExt, Nonce,
+	ParachainSystem, Runtime, RuntimeCall, RuntimeGenesisConfig, SessionKeys, System,
+	TransactionPayment, VERSION,
 };
 
 impl_runtime_apis! {
@@ -172,6 +180,35 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_acurast_marketplace::MarketplaceRuntimeApi<Block, Balance, AccountId, ExtraFor<Runtime>, MaxAllowedSources> for Runtime {
+		fn filter_matching_sources(
+			registration: PartialJobRegistrationForMarketplace<Runtime>,
+			consumer: Option<MultiOrigin<AccountId>>,
+		) -> MatchingDryRunFor<Runtime> {
+			AcurastMarketplace::filter_matching_sources(registration, consumer)
+		}
+
+		fn check_matching(
+			registration: JobRegistrationFor<Runtime>,
+			consumer: MultiOrigin<AccountId>,
+			min_metrics: Option<Metrics>,
+		) -> Result<MatchingDryRunFor<Runtime>, RuntimeApiError> {
+			AcurastMarketplace::check_matching(registration, consumer, min_metrics)
+		}
+
+		fn job(job_id: JobId<AccountId>) -> Option<JobViewFor<Runtime>> {
+			AcurastMarketplace::job_view(&job_id)
+		}
+
+		fn jobs(
+			owner: MultiOrigin<AccountId>,
+			start_after: Option<JobIdSequence>,
+			limit: u32,
+		) -> Vec<JobViewFor<Runtime>> {
+			AcurastMarketplace::job_views_for(&owner, start_after, limit)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
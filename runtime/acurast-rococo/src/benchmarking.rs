@@ -10,10 +10,10 @@ use sp_std::vec;
 
 use acurast_runtime_common::types::{ExtraFor, Signature};
 use pallet_acurast::{
-	Attestation, AttestationValidity, BoundedAttestationContent, BoundedDeviceAttestation,
-	BoundedDeviceAttestationDeviceOSInformation, BoundedDeviceAttestationKeyUsageProperties,
-	BoundedDeviceAttestationNonce, JobId, JobModules, PoolId, StoredAttestation,
-	StoredJobRegistration,
+	tdx::fixtures as tdx, Attestation, AttestationValidity, BoundedAttestationContent,
+	BoundedDeviceAttestation, BoundedDeviceAttestationDeviceOSInformation,
+	BoundedDeviceAttestationKeyUsageProperties, BoundedDeviceAttestationNonce, JobId, JobModules,
+	PoolId, StoredAttestation, StoredJobRegistration,
 };
 use pallet_acurast_marketplace::{
 	Advertisement, AssignmentStrategy, JobRequirements, PlannedExecution, Pricing, SchedulingWindow,
@@ -91,6 +91,9 @@ impl pallet_acurast::BenchmarkHelper<Runtime> for AcurastBenchmarkHelper {
 				}),
 				processor_version: None,
 				runtime: pallet_acurast_marketplace::Runtime::NodeJS,
+				consensus: false,
+				payment_asset: None,
+				deliver_results_to_origin: false,
 			},
 		}
 	}
@@ -98,6 +101,11 @@ impl pallet_acurast::BenchmarkHelper<Runtime> for AcurastBenchmarkHelper {
 	fn funded_account(index: u32) -> <Runtime as frame_system::Config>::AccountId {
 		create_funded_user("pallet_acurast", index, 1 << 60)
 	}
+
+	fn attestation_report() -> (pallet_acurast::AttestationReport, [u8; 32]) {
+		// accepted since benchmarks configure the TDX verifier with the test root of the synthetic quote
+		(tdx::report(tdx::QUOTE), tdx::NONCE)
+	}
 }
 
 fn setup_pools() {
@@ -1,17 +1,21 @@
 use acurast_runtime_common::{
-	barrier::Barrier,
+	barrier::{Barrier, InitialAttestationAllowlist},
 	types::{
 		EnvKeyMaxSize, EnvValueMaxSize, ExtraFor, MaxAllowedSources, MaxEnvVars, MaxSlots,
 		MaxVersions,
 	},
 	weight,
 };
+#[cfg(feature = "runtime-benchmarks")]
+use pallet_acurast::tdx;
+use pallet_acurast::TdxQuoteVerifier;
 
 #[cfg(feature = "runtime-benchmarks")]
 use crate::benchmarking;
 use crate::{
 	AcurastPalletId, BundleIds, CorePackageNames, CoreSignatureDigests, EnsureCouncilOrRoot,
-	LitePackageNames, LiteSignatureDigests, PackageNames, Runtime, RuntimeEvent, SignatureDigests,
+	LitePackageNames, LiteSignatureDigests, ReattestationGracePeriod, ReattestationInterval,
+	Runtime, RuntimeEvent,
 };
 
 /// Runtime configuration for pallet_acurast.
@@ -25,21 +29,29 @@ impl pallet_acurast::Config for Runtime {
 	type MaxEnvVars = MaxEnvVars;
 	type EnvKeyMaxSize = EnvKeyMaxSize;
 	type EnvValueMaxSize = EnvValueMaxSize;
-	type KeyAttestationBarrier = Barrier<
-		Self,
-		PackageNames,
-		SignatureDigests,
-		CorePackageNames,
-		LitePackageNames,
-		CoreSignatureDigests,
-		LiteSignatureDigests,
-		BundleIds,
-	>;
+	type KeyAttestationBarrier = Barrier<Self>;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type AttestationVerifiers = (TdxQuoteVerifier,);
+	#[cfg(feature = "runtime-benchmarks")]
+	type AttestationVerifiers = (TdxQuoteVerifier<tdx::fixtures::TestTdxRoot>,);
 	type UnixTime = pallet_timestamp::Pallet<Runtime>;
 	type JobHooks = pallet_acurast_marketplace::Pallet<Runtime>;
 	type ProcessorVersion = pallet_acurast::Version;
 	type MaxVersions = MaxVersions;
 	type UpdateOrigin = EnsureCouncilOrRoot;
+	type MaxAttestationAllowlistUpdates = frame_support::traits::ConstU32<10>;
+	type MaxAttestationAllowlistEntries = frame_support::traits::ConstU32<32>;
+	type InitialAttestationAllowlist = InitialAttestationAllowlist<
+		CorePackageNames,
+		CoreSignatureDigests,
+		LitePackageNames,
+		LiteSignatureDigests,
+		BundleIds,
+	>;
+	type ReattestationInterval = ReattestationInterval;
+	type ReattestationGracePeriod = ReattestationGracePeriod;
+	type MaxAttestationExpiryChecks = frame_support::traits::ConstU32<50>;
+	type MaxRevocationPropagations = frame_support::traits::ConstU32<20>;
 	type WeightInfo = weight::pallet_acurast::WeightInfo<Self>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = benchmarking::AcurastBenchmarkHelper;
@@ -1,15 +1,19 @@
-use frame_support::{pallet_prelude::DispatchResultWithPostInfo, PalletId};
+use frame_support::{
+	instances::Instance1, pallet_prelude::DispatchResultWithPostInfo, traits::Get, weights::Weight,
+	PalletId,
+};
 use sp_core::{parameter_types, ConstU32, ConstU64};
-use sp_runtime::{traits::BlakeTwo256, AccountId32, DispatchError, FixedU128};
+use sp_runtime::{traits::BlakeTwo256, AccountId32, DispatchError, FixedU128, Percent};
 use sp_std::prelude::*;
 
 use acurast_runtime_common::{
-	types::{AccountId, Balance, ExtraFor, ProcessorPriceProvider},
+	types::{AccountId, Balance, ExtraFor, ProcessorPriceProvider, RelayChainRandomness},
 	weight,
 };
 use pallet_acurast::{JobId, MultiOrigin, CU32};
 use pallet_acurast_hyperdrive::{IncomingAction, ProxyChain};
-use pallet_acurast_marketplace::{MarketplaceHooks, PubKey, PubKeys};
+use pallet_acurast_hyperdrive_ibc::WeightInfo as _;
+use pallet_acurast_marketplace::{ExecutionResult, MarketplaceHooks, PubKey, PubKeys};
 
 #[cfg(feature = "runtime-benchmarks")]
 use crate::benchmarking;
@@ -21,6 +25,10 @@ use crate::{
 };
 
 parameter_types! {
+	pub const CancellationNoticePeriod: u64 = 3_600_000; // 1 hour
+	pub const CancellationFee: Percent = Percent::from_percent(5);
+	pub const SlaSlashThreshold: Percent = Percent::from_percent(50);
+	pub const SlaSlashConsumerShare: Percent = Percent::from_percent(50);
 	pub const MinPrice: Balance = 2_000_000_000;
 	pub const PriceMultiplier: FixedU128 = FixedU128::from_rational(11, 10);
 }
@@ -32,14 +40,24 @@ impl pallet_acurast_marketplace::Config for Runtime {
 	type Competing = CU32<4>;
 	type MatchingCompetingMinInterval = ConstU64<300_000>; // 5 min
 	type MatchingCompetingDueDelta = ConstU64<300_000>; // 4 min
+	type MatchingCommitRevealPeriod = ConstU64<60_000>; // 1 min
+	type Randomness = RelayChainRandomness<Self>;
+	type ResultRevealPeriod = ConstU64<60_000>; // 1 min
+	type ExtensionAcceptPeriod = ConstU64<3_600_000>; // 1 hour
 	type MaxProposedMatches = ConstU32<10>;
 	type MaxProposedExecutionMatches = ConstU32<10>;
 	type MaxFinalizeJobs = ConstU32<10>;
 	type MaxJobCleanups = ConstU32<100>;
+	type MaxUnverifiedAssignmentRemovals = ConstU32<50>;
 	type RegistrationExtra = ExtraFor<Self>;
 	type PalletId = AcurastPalletId;
 	type HyperdrivePalletId = HyperdrivePalletId;
 	type ReportTolerance = ReportTolerance;
+	type CancellationNoticePeriod = CancellationNoticePeriod;
+	type CancellationFee = CancellationFee;
+	type SlaSlashThreshold = SlaSlashThreshold;
+	type SlaSlashConsumerShare = SlaSlashConsumerShare;
+	type Slashable = AcurastCompute;
 	type Balance = Balance;
 	type RewardManager = pallet_acurast_marketplace::AssetRewardManager<
 		FeeManagement,
@@ -53,6 +71,8 @@ impl pallet_acurast_marketplace::Config for Runtime {
 	type KeyIdHashing = BlakeTwo256;
 	type DefaultMinPrice = MinPrice;
 	type DefaultPriceMultiplier = PriceMultiplier;
+	// registrations paid in assets are rejected until a price oracle and a `FungiblesRewardManager` are configured
+	type PaymentAssetPriceOracle = ();
 	type ProcessorPriceProvider = ProcessorPriceProvider<Self, AcurastCompute>;
 	type UpdateOrigin = EnsureCouncilOrRoot;
 	type OperatorOrigin = EnsureCouncilOrRoot;
@@ -137,6 +157,20 @@ impl MarketplaceHooks<Runtime> for HyperdriveOutgoingMarketplaceHooks {
 
 				Ok(().into())
 			},
+			MultiOrigin::Ethereum20(_) => {
+				let key = pub_keys
+					.iter()
+					.find(|key| matches!(key, PubKey::SECP256k1(_)))
+					.ok_or(DispatchError::Other("k256 public key does not exist"))?;
+
+				AcurastHyperdrive::send_to_proxy(
+					ProxyChain::Ethereum,
+					IncomingAction::AssignJob(*job_id_seq, key.clone()),
+					&HyperdriveIbcFeePalletAccount::get(),
+				)?;
+
+				Ok(().into())
+			},
 			_ => Ok(().into()),
 		}
 	}
@@ -167,7 +201,58 @@ impl MarketplaceHooks<Runtime> for HyperdriveOutgoingMarketplaceHooks {
 
 				Ok(().into())
 			},
+			MultiOrigin::Ethereum20(_) => {
+				AcurastHyperdrive::send_to_proxy(
+					ProxyChain::Ethereum,
+					IncomingAction::FinalizeJob(*job_id_seq, refund),
+					&HyperdriveIbcFeePalletAccount::get(),
+				)?;
+
+				Ok(().into())
+			},
 			_ => Ok(().into()),
 		}
 	}
+
+	fn result_delivery_fee(job_id: &JobId<AccountId>) -> Option<(AccountId, Balance)> {
+		// results can only be delivered to the proxy chains reachable by `send_to_proxy`
+		match job_id.0 {
+			MultiOrigin::AlephZero(_) | MultiOrigin::Vara(_) | MultiOrigin::Ethereum20(_) => {
+				Some((
+					HyperdriveIbcFeePalletAccount::get(),
+					<Runtime as pallet_acurast_hyperdrive::Config<Instance1>>::MessageFee::get(),
+				))
+			},
+			_ => None,
+		}
+	}
+
+	fn deliver_result(
+		job_id: &JobId<AccountId>,
+		execution_result: &ExecutionResult,
+	) -> DispatchResultWithPostInfo {
+		// inspect which hyperdrive proxy chain to send action to
+		let (origin, job_id_seq) = job_id;
+
+		let chain = match origin {
+			MultiOrigin::AlephZero(_) => ProxyChain::AlephZero,
+			MultiOrigin::Vara(_) => ProxyChain::Vara,
+			MultiOrigin::Ethereum20(_) => ProxyChain::Ethereum,
+			_ => return Ok(().into()),
+		};
+		AcurastHyperdrive::send_to_proxy(
+			chain,
+			IncomingAction::ExecutionResult(*job_id_seq, execution_result.clone()),
+			&HyperdriveIbcFeePalletAccount::get(),
+		)?;
+
+		Ok(().into())
+	}
+
+	fn deliver_result_weight() -> Weight {
+		// `send_to_proxy` reads the proxy contract and increments the message number before sending the message
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(2, 1).saturating_add(
+			<Runtime as pallet_acurast_hyperdrive_ibc::Config<Instance1>>::WeightInfo::send_test_message(),
+		)
+	}
 }
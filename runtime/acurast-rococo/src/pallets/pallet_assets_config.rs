@@ -0,0 +1,34 @@
+use acurast_runtime_common::types::Balance;
+use frame_support::traits::AsEnsureOriginWithArg;
+use frame_system::EnsureRootWithSuccess;
+use sp_core::{ConstU128, ConstU32};
+
+use crate::{
+	Balances, EnsureCouncilOrRoot, ExistentialDeposit, RootAccountId, Runtime, RuntimeEvent,
+};
+
+/// Runtime configuration for pallet_assets, holding the non-native assets bridged over Hyperdrive.
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = pallet_acurast_hyperdrive_token::BridgedAssetId;
+	type AssetIdParameter = pallet_acurast_hyperdrive_token::BridgedAssetId;
+	type Currency = Balances;
+	type CreateOrigin =
+		AsEnsureOriginWithArg<EnsureRootWithSuccess<Self::AccountId, RootAccountId>>;
+	type ForceOrigin = EnsureCouncilOrRoot;
+	type AssetDeposit = ConstU128<0>;
+	type AssetAccountDeposit = ExistentialDeposit;
+	type MetadataDepositBase = ConstU128<0>;
+	type MetadataDepositPerByte = ConstU128<0>;
+	type ApprovalDeposit = ExistentialDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Holder = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Self>;
+}
@@ -1,73 +1,34 @@
 use core::marker::PhantomData;
 
-use frame_support::traits::Get;
-use pallet_acurast::{Attestation, BoundedAttestationContent, ProcessorType};
+use frame_support::{traits::Get, weights::Weight};
+use pallet_acurast::{
+	Attestation, AttestationAllowlistEntry, AttestationAllowlistValue, BoundedAttestationContent,
+	ProcessorType,
+};
 use sp_std::prelude::*;
 
-use crate::utils::{check_attestation, check_key_description};
+use crate::utils::{check_attestation, check_attestation_report, check_key_description};
 
-pub struct Barrier<
-	Runtime,
-	PackageNames,
-	SignatureDigests,
-	CorePackageNames,
-	LitePackageNames,
-	CoreSignatureDigests,
-	LiteSignatureDigests,
-	BundleIds,
-> {
-	#[allow(clippy::type_complexity)]
-	_phantom_data: PhantomData<(
-		Runtime,
-		PackageNames,
-		SignatureDigests,
-		CorePackageNames,
-		LitePackageNames,
-		CoreSignatureDigests,
-		LiteSignatureDigests,
-		BundleIds,
-	)>,
+/// Accepts attestations of the apps allowed by the attestation allowlist stored in `pallet_acurast`.
+pub struct Barrier<Runtime> {
+	_phantom_data: PhantomData<Runtime>,
 }
 
-impl<
-		Runtime,
-		PackageNames,
-		SignatureDigests,
-		CorePackageNames,
-		CoreSignatureDigests,
-		LitePackageNames,
-		LiteSignatureDigests,
-		BundleIds,
-	> pallet_acurast::KeyAttestationBarrier<Runtime>
-	for Barrier<
-		Runtime,
-		PackageNames,
-		SignatureDigests,
-		CorePackageNames,
-		LitePackageNames,
-		CoreSignatureDigests,
-		LiteSignatureDigests,
-		BundleIds,
-	>
+impl<Runtime> pallet_acurast::KeyAttestationBarrier<Runtime> for Barrier<Runtime>
 where
 	Runtime: frame_system::Config + pallet_acurast::Config,
-	PackageNames: Get<Vec<&'static [u8]>>,
-	SignatureDigests: Get<Vec<&'static [u8]>>,
-	CorePackageNames: Get<Vec<&'static [u8]>>,
-	CoreSignatureDigests: Get<Vec<&'static [u8]>>,
-	LitePackageNames: Get<Vec<&'static [u8]>>,
-	LiteSignatureDigests: Get<Vec<&'static [u8]>>,
-	BundleIds: Get<Vec<&'static [u8]>>,
 {
 	fn accept_attestation_for_origin(
 		_origin: &<Runtime as frame_system::Config>::AccountId,
 		attestation: &Attestation,
 	) -> bool {
+		let allowlist = Allowlist::<Runtime>::load(&[ProcessorType::Core, ProcessorType::Lite]);
 		check_attestation(
 			attestation,
-			PackageNames::get().as_slice(),
-			SignatureDigests::get().as_slice(),
-			BundleIds::get().as_slice(),
+			allowlist.package_names().as_slice(),
+			allowlist.signature_digests().as_slice(),
+			allowlist.bundle_ids().as_slice(),
+			allowlist.measurements().as_slice(),
 		)
 	}
 
@@ -78,24 +39,185 @@ where
 		match processor_type {
 			ProcessorType::Core => match &attestation.content {
 				BoundedAttestationContent::KeyDescription(key_description) => {
+					let allowlist = Allowlist::<Runtime>::load(&[ProcessorType::Core]);
 					check_key_description(
 						key_description,
-						CorePackageNames::get().as_slice(),
-						CoreSignatureDigests::get().as_slice(),
+						allowlist.package_names().as_slice(),
+						allowlist.signature_digests().as_slice(),
 					)
 				},
 				BoundedAttestationContent::DeviceAttestation(_) => false,
+				BoundedAttestationContent::Report(report) => {
+					let allowlist = Allowlist::<Runtime>::load(&[ProcessorType::Core]);
+					check_attestation_report(report, allowlist.measurements().as_slice())
+				},
 			},
 			ProcessorType::Lite => match &attestation.content {
 				BoundedAttestationContent::KeyDescription(key_description) => {
+					let allowlist = Allowlist::<Runtime>::load(&[ProcessorType::Lite]);
 					check_key_description(
 						key_description,
-						LitePackageNames::get().as_slice(),
-						LiteSignatureDigests::get().as_slice(),
+						allowlist.package_names().as_slice(),
+						allowlist.signature_digests().as_slice(),
 					)
 				},
 				BoundedAttestationContent::DeviceAttestation(_) => true,
+				BoundedAttestationContent::Report(report) => {
+					let allowlist = Allowlist::<Runtime>::load(&[ProcessorType::Lite]);
+					check_attestation_report(report, allowlist.measurements().as_slice())
+				},
 			},
 		}
 	}
+
+	fn check_weight() -> Weight {
+		// `Allowlist::load` iterates the allowlists of both processor types, reading one more key per prefix
+		let reads = <Runtime as pallet_acurast::Config>::MaxAttestationAllowlistEntries::get()
+			.saturating_add(1)
+			.saturating_mul(2) as u64;
+		<Runtime as frame_system::Config>::DbWeight::get()
+			.reads(reads)
+			.saturating_add(Weight::from_parts(0, ALLOWLIST_ENTRY_PROOF_SIZE.saturating_mul(reads)))
+	}
+}
+
+/// The proof size of an `Acurast::AttestationAllowlist` entry, see `weight::pallet_acurast::update_attestation_allowlist`.
+const ALLOWLIST_ENTRY_PROOF_SIZE: u64 = 2639;
+
+/// The attestation allowlist entries of one or more [ProcessorType]s.
+struct Allowlist<Runtime> {
+	entries: Vec<AttestationAllowlistEntry>,
+	_phantom_data: PhantomData<Runtime>,
+}
+
+impl<Runtime: pallet_acurast::Config> Allowlist<Runtime> {
+	fn load(processor_types: &[ProcessorType]) -> Self {
+		Self {
+			entries: pallet_acurast::Pallet::<Runtime>::attestation_allowlist_entries(
+				processor_types,
+			),
+			_phantom_data: PhantomData,
+		}
+	}
+
+	fn package_names(&self) -> Vec<&[u8]> {
+		self.entries
+			.iter()
+			.filter_map(|entry| match entry {
+				AttestationAllowlistEntry::PackageName(value) => Some(value.as_slice()),
+				_ => None,
+			})
+			.collect()
+	}
+
+	fn signature_digests(&self) -> Vec<&[u8]> {
+		self.entries
+			.iter()
+			.filter_map(|entry| match entry {
+				AttestationAllowlistEntry::SignatureDigest(value) => Some(value.as_slice()),
+				_ => None,
+			})
+			.collect()
+	}
+
+	fn bundle_ids(&self) -> Vec<&[u8]> {
+		self.entries
+			.iter()
+			.filter_map(|entry| match entry {
+				AttestationAllowlistEntry::BundleId(value) => Some(value.as_slice()),
+				_ => None,
+			})
+			.collect()
+	}
+
+	fn measurements(&self) -> Vec<&[u8]> {
+		self.entries
+			.iter()
+			.filter_map(|entry| match entry {
+				AttestationAllowlistEntry::Measurement(value) => Some(value.as_slice()),
+				_ => None,
+			})
+			.collect()
+	}
+}
+
+/// The initial attestation allowlist, stored at genesis or when migrating to the storage based allowlist.
+///
+/// Bundle IDs are only allowed for [ProcessorType::Lite], since iOS devices can only run lite processors.
+pub struct InitialAttestationAllowlist<
+	CorePackageNames,
+	CoreSignatureDigests,
+	LitePackageNames,
+	LiteSignatureDigests,
+	BundleIds,
+> {
+	#[allow(clippy::type_complexity)]
+	_phantom_data: PhantomData<(
+		CorePackageNames,
+		CoreSignatureDigests,
+		LitePackageNames,
+		LiteSignatureDigests,
+		BundleIds,
+	)>,
+}
+
+impl<CorePackageNames, CoreSignatureDigests, LitePackageNames, LiteSignatureDigests, BundleIds>
+	Get<Vec<(ProcessorType, AttestationAllowlistEntry)>>
+	for InitialAttestationAllowlist<
+		CorePackageNames,
+		CoreSignatureDigests,
+		LitePackageNames,
+		LiteSignatureDigests,
+		BundleIds,
+	>
+where
+	CorePackageNames: Get<Vec<&'static [u8]>>,
+	CoreSignatureDigests: Get<Vec<&'static [u8]>>,
+	LitePackageNames: Get<Vec<&'static [u8]>>,
+	LiteSignatureDigests: Get<Vec<&'static [u8]>>,
+	BundleIds: Get<Vec<&'static [u8]>>,
+{
+	fn get() -> Vec<(ProcessorType, AttestationAllowlistEntry)> {
+		[
+			allowlist_entries(
+				ProcessorType::Core,
+				CorePackageNames::get(),
+				AttestationAllowlistEntry::PackageName,
+			),
+			allowlist_entries(
+				ProcessorType::Core,
+				CoreSignatureDigests::get(),
+				AttestationAllowlistEntry::SignatureDigest,
+			),
+			allowlist_entries(
+				ProcessorType::Lite,
+				LitePackageNames::get(),
+				AttestationAllowlistEntry::PackageName,
+			),
+			allowlist_entries(
+				ProcessorType::Lite,
+				LiteSignatureDigests::get(),
+				AttestationAllowlistEntry::SignatureDigest,
+			),
+			allowlist_entries(
+				ProcessorType::Lite,
+				BundleIds::get(),
+				AttestationAllowlistEntry::BundleId,
+			),
+		]
+		.concat()
+	}
+}
+
+fn allowlist_entries(
+	processor_type: ProcessorType,
+	values: Vec<&'static [u8]>,
+	entry: fn(AttestationAllowlistValue) -> AttestationAllowlistEntry,
+) -> Vec<(ProcessorType, AttestationAllowlistEntry)> {
+	values
+		.into_iter()
+		.map(|value| {
+			(processor_type, entry(AttestationAllowlistValue::truncate_from(value.to_vec())))
+		})
+		.collect()
 }
@@ -1,4 +1,5 @@
 mod price_provider;
+mod relay_chain_randomness;
 mod tracks_info;
 mod transaction_charger;
 
@@ -15,6 +16,7 @@ use pallet_acurast::CU32;
 use pallet_acurast_marketplace::RegistrationExtra;
 
 pub use price_provider::*;
+pub use relay_chain_randomness::*;
 pub use tracks_info::*;
 pub use transaction_charger::*;
 
@@ -54,6 +56,9 @@ pub type ExtraFor<T> = RegistrationExtra<
 	ProcessorVersionFor<T>,
 	MaxVersionsFor<T>,
 >;
+/// The registration extra of all runtimes, independent of a runtime's `Config`.
+pub type MarketplaceRegistrationExtra =
+	RegistrationExtra<Balance, AccountId, MaxSlots, pallet_acurast::Version, MaxVersions>;
 pub type CouncilInstance = pallet_collective::Instance1;
 pub type CouncilMembershipInstance = pallet_membership::Instance1;
 pub type CouncilThreeSeventh =
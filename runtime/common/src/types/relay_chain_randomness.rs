@@ -0,0 +1,41 @@
+use core::marker::PhantomData;
+
+use cumulus_pallet_parachain_system::{RelayChainStateProof, RelayStateProof, ValidationData};
+use cumulus_primitives_core::relay_chain::well_known_keys;
+use frame_support::traits::{Get, Randomness};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::Hash;
+
+/// Randomness derived from the BABE randomness of the relay parent, read from the relay chain state proof of the current block.
+///
+/// It is not known before the relay parent got produced. Outside of a parachain block, e.g. in benchmarks, only the subject is hashed.
+pub struct RelayChainRandomness<T>(PhantomData<T>);
+
+impl<T: cumulus_pallet_parachain_system::Config> RelayChainRandomness<T> {
+	fn relay_parent_randomness() -> Option<[u8; 32]> {
+		let validation_data = ValidationData::<T>::get()?;
+		let proof = RelayStateProof::<T>::get()?;
+		let relay_state = RelayChainStateProof::new(
+			T::SelfParaId::get(),
+			validation_data.relay_parent_storage_root,
+			proof,
+		)
+		.ok()?;
+		relay_state
+			.read_optional_entry::<Option<[u8; 32]>>(well_known_keys::CURRENT_BLOCK_RANDOMNESS)
+			.ok()
+			.flatten()
+			.flatten()
+	}
+}
+
+impl<T: cumulus_pallet_parachain_system::Config> Randomness<T::Hash, BlockNumberFor<T>>
+	for RelayChainRandomness<T>
+{
+	fn random(subject: &[u8]) -> (T::Hash, BlockNumberFor<T>) {
+		(
+			T::Hashing::hash_of(&(subject, Self::relay_parent_randomness())),
+			frame_system::Pallet::<T>::block_number(),
+		)
+	}
+}
@@ -1,7 +1,7 @@
 use frame_support::weights::constants::{ExtrinsicBaseWeight, WEIGHT_REF_TIME_PER_SECOND};
 use pallet_acurast::{
-	Attestation, AttestationSecurityLevel, BoundedAttestationContent, BoundedDeviceAttestation,
-	BoundedKeyDescription, VerifiedBootState,
+	Attestation, AttestationSecurityLevel, BoundedAttestationContent, BoundedAttestationReport,
+	BoundedDeviceAttestation, BoundedKeyDescription, VerifiedBootState,
 };
 use sp_std::prelude::*;
 
@@ -24,6 +24,7 @@ pub fn check_attestation(
 	allowed_package_names: &[&[u8]],
 	allowed_signature_digests: &[&[u8]],
 	allowed_bundle_ids: &[&[u8]],
+	allowed_measurements: &[&[u8]],
 ) -> bool {
 	match &attestation.content {
 		BoundedAttestationContent::KeyDescription(key_description) => {
@@ -32,6 +33,9 @@ pub fn check_attestation(
 		BoundedAttestationContent::DeviceAttestation(device_attestation) => {
 			check_device_attestation(device_attestation, allowed_bundle_ids)
 		},
+		BoundedAttestationContent::Report(report) => {
+			check_attestation_report(report, allowed_measurements)
+		},
 	}
 }
 
@@ -95,3 +99,21 @@ pub fn check_device_attestation(
 	}
 	false
 }
+
+pub fn check_attestation_report(
+	report: &BoundedAttestationReport,
+	allowed_measurements: &[&[u8]],
+) -> bool {
+	if report.summary.verified_boot_state != VerifiedBootState::Verified {
+		return false;
+	}
+
+	if !matches!(
+		report.summary.security_level,
+		AttestationSecurityLevel::StrongBox | AttestationSecurityLevel::TrustedEnvironemnt,
+	) {
+		return false;
+	}
+
+	allowed_measurements.contains(&report.summary.measurement.as_slice())
+}
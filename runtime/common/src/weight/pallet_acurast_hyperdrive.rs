@@ -56,4 +56,13 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive::WeightInfo for WeightIn
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+
+	/// Storage: AcurastHyperdrive EthereumContract (r:0 w:1)
+	/// Proof: AcurastHyperdrive EthereumContract (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	fn update_ethereum_contract() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(14_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
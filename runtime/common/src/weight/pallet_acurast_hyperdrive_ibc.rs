@@ -55,7 +55,12 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive_ibc::WeightInfo for Weig
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (r:0 w:1)
 	/// Proof: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::SentMessages` (r:0 w:2)
+	/// Proof: `AcurastHyperdriveIbc::SentMessages` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::SentMessageIds` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::SentMessageIds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
 	fn send_test_message() -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `42`
 		//  Estimated: `4721`
@@ -63,7 +68,7 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive_ibc::WeightInfo for Weig
 		Weight::from_parts(76_600_000, 0)
 			.saturating_add(Weight::from_parts(0, 4721))
 			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `AcurastHyperdriveIbc::OutgoingMessages` (r:1 w:1)
 	/// Proof: `AcurastHyperdriveIbc::OutgoingMessages` (`max_values`: None, `max_size`: Some(1256), added: 3731, mode: `MaxEncodedLen`)
@@ -77,14 +82,21 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive_ibc::WeightInfo for Weig
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (r:0 w:1)
 	/// Proof: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::RecipientQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::RecipientQuorums` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ChainQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::ChainQuorums` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:3 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	fn confirm_message_delivery() -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `977`
 		//  Estimated: `8589`
 		// Minimum execution time: 102_540_000 picoseconds.
 		Weight::from_parts(105_240_000, 0)
 			.saturating_add(Weight::from_parts(0, 8589))
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(12))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AcurastHyperdriveIbc::OutgoingMessages` (r:1 w:1)
@@ -111,14 +123,23 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive_ibc::WeightInfo for Weig
 	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
 	/// Storage: `AcurastHyperdriveIbc::IncomingMessagesLookup` (r:0 w:1)
 	/// Proof: `AcurastHyperdriveIbc::IncomingMessagesLookup` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::RecipientQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::RecipientQuorums` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ChainQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::ChainQuorums` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::MessageVerifiers` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::MessageVerifiers` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:3 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
 	fn receive_message() -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `359`
 		//  Estimated: `8589`
 		// Minimum execution time: 49_730_000 picoseconds.
 		Weight::from_parts(51_160_000, 0)
 			.saturating_add(Weight::from_parts(0, 8589))
-			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `AcurastHyperdriveIbc::IncomingMessages` (r:50 w:50)
@@ -139,4 +160,338 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive_ibc::WeightInfo for Weig
 			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 3717).saturating_mul(x.into()))
 	}
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleBonds` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OracleBonds` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ActiveBondedOracles` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::ActiveBondedOracles` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn bond_oracle() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(73_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `AcurastHyperdriveIbc::OracleBonds` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OracleBonds` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ActiveBondedOracles` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::ActiveBondedOracles` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::MinOracleSetSize` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::MinOracleSetSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	fn unbond_oracle() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(28_910_000, 0)
+			.saturating_add(Weight::from_parts(0, 3567))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AcurastHyperdriveIbc::OracleBonds` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OracleBonds` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn withdraw_oracle_bond() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(49_650_000, 0)
+			.saturating_add(Weight::from_parts(0, 3658))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `AcurastHyperdriveIbc::OracleBonds` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OracleBonds` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OutgoingMessages` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OutgoingMessages` (`max_values`: None, `max_size`: Some(1256), added: 3731, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::SentMessageIds` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::SentMessageIds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::SentMessages` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::SentMessages` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ActiveBondedOracles` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::ActiveBondedOracles` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	fn report_equivocation() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(138_220_000, 0)
+			.saturating_add(Weight::from_parts(0, 4721))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `AcurastHyperdriveIbc::MinOracleSetSize` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::MinOracleSetSize` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_min_oracle_set_size() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(6_330_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:0 w:50)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn update_oracle_weights(n: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(8_912_441, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(1_987_204, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AcurastHyperdriveIbc::RecipientQuorums` (r:0 w:20)
+	/// Proof: `AcurastHyperdriveIbc::RecipientQuorums` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn update_quorums(n: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_460_118, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(2_331_760, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `AcurastHyperdriveIbc::AggregateOracles` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::AggregateOracles` (`max_values`: Some(1), `max_size`: Some(58371), added: 58866, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 256]`.
+	fn update_aggregate_oracles(n: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_514_027, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(3_500_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AcurastHyperdriveIbc::IncomingMessages` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::IncomingMessages` (`max_values`: None, `max_size`: Some(1242), added: 3717, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::AggregateOracles` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::AggregateOracles` (`max_values`: Some(1), `max_size`: Some(58371), added: 58866, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::RecipientQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::RecipientQuorums` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ChainQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::ChainQuorums` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::MessageVerifiers` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::MessageVerifiers` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:256 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:256 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::IncomingMessagesLookup` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::IncomingMessagesLookup` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 256]`.
+	fn receive_message_aggregated(s: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(3_063_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 59856))
+			.saturating_add(Weight::from_parts(400_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(s.into()))
+	}
+	/// Storage: `AcurastHyperdriveIbc::OutgoingMessages` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OutgoingMessages` (`max_values`: None, `max_size`: Some(1256), added: 3731, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::AggregateOracles` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::AggregateOracles` (`max_values`: Some(1), `max_size`: Some(58371), added: 58866, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::RecipientQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::RecipientQuorums` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ChainQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::ChainQuorums` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:256 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:256 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 256]`.
+	fn confirm_message_delivery_aggregated(s: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(3_117_350_000, 0)
+			.saturating_add(Weight::from_parts(0, 59856))
+			.saturating_add(Weight::from_parts(400_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(s.into()))
+	}
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:3 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:3 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::IncomingMessages` (r:20 w:20)
+	/// Proof: `AcurastHyperdriveIbc::IncomingMessages` (`max_values`: None, `max_size`: Some(1242), added: 3717, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::RecipientQuorums` (r:20 w:0)
+	/// Proof: `AcurastHyperdriveIbc::RecipientQuorums` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ChainQuorums` (r:20 w:0)
+	/// Proof: `AcurastHyperdriveIbc::ChainQuorums` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::MessageVerifiers` (r:20 w:0)
+	/// Proof: `AcurastHyperdriveIbc::MessageVerifiers` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::IncomingMessagesLookup` (r:0 w:20)
+	/// Proof: `AcurastHyperdriveIbc::IncomingMessagesLookup` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn receive_messages(n: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(42_117_503, 0)
+			.saturating_add(Weight::from_parts(0, 8589))
+			.saturating_add(Weight::from_parts(24_905_361, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3717).saturating_mul(n.into()))
+	}
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:3 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:3 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OutgoingMessages` (r:20 w:20)
+	/// Proof: `AcurastHyperdriveIbc::OutgoingMessages` (`max_values`: None, `max_size`: Some(1256), added: 3731, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::RecipientQuorums` (r:20 w:0)
+	/// Proof: `AcurastHyperdriveIbc::RecipientQuorums` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ChainQuorums` (r:20 w:0)
+	/// Proof: `AcurastHyperdriveIbc::ChainQuorums` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:20 w:20)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:20 w:20)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (r:0 w:20)
+	/// Proof: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn confirm_message_deliveries(n: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(51_882_640, 0)
+			.saturating_add(Weight::from_parts(0, 8589))
+			.saturating_add(Weight::from_parts(58_316_925, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 3731).saturating_mul(n.into()))
+	}
+	/// Storage: `AcurastHyperdriveIbc::MessageVerifiers` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::MessageVerifiers` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn update_message_verifier() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(8_920_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AcurastHyperdriveIbc::EthereumIbc` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::EthereumIbc` (`max_values`: Some(1), `max_size`: Some(52), added: 547, mode: `MaxEncodedLen`)
+	fn update_ethereum_ibc() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(8_640_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AcurastHyperdriveIbc::EthereumBeacon` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::EthereumBeacon` (`max_values`: Some(1), `max_size`: Some(481), added: 976, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::EthereumSyncCommittees` (r:1 w:2)
+	/// Proof: `AcurastHyperdriveIbc::EthereumSyncCommittees` (`max_values`: None, `max_size`: Some(49162), added: 51637, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::EthereumFinalizedSlot` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::EthereumFinalizedSlot` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::EthereumStateRootsBufferIndex` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::EthereumStateRootsBufferIndex` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::EthereumStateRootsBuffer` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::EthereumStateRootsBuffer` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::EthereumStateRoots` (r:0 w:2)
+	/// Proof: `AcurastHyperdriveIbc::EthereumStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 1]`.
+	fn submit_ethereum_finality_update(c: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(4_000_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 56102))
+			.saturating_add(Weight::from_parts(52_000_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+	}
+	/// Storage: `AcurastHyperdriveIbc::IncomingMessages` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::IncomingMessages` (`max_values`: None, `max_size`: Some(1242), added: 3717, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::RecipientQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::RecipientQuorums` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::ChainQuorums` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::ChainQuorums` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::MessageVerifiers` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::MessageVerifiers` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::EthereumIbc` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::EthereumIbc` (`max_values`: Some(1), `max_size`: Some(52), added: 547, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::EthereumStateRoots` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::EthereumStateRoots` (`max_values`: None, `max_size`: Some(40), added: 2515, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::IncomingMessagesLookup` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::IncomingMessagesLookup` (`max_values`: None, `max_size`: Some(103), added: 2578, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[2, 32]`.
+	fn receive_message_with_proof(n: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(82_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 4707))
+			.saturating_add(Weight::from_parts(8_400_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `AcurastHyperdriveIbc::AggregateOracles` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveIbc::AggregateOracles` (`max_values`: Some(1), `max_size`: Some(58371), added: 58866, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OraclePublicKeys` (r:256 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OraclePublicKeys` (`max_values`: None, `max_size`: Some(58), added: 2533, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OracleWeights` (r:256 w:0)
+	/// Proof: `AcurastHyperdriveIbc::OracleWeights` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 256]`.
+	fn check_aggregate_signature(s: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(3_000_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 58866))
+			.saturating_add(Weight::from_parts(400_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2533).saturating_mul(s.into()))
+	}
+	/// Storage: `AcurastHyperdriveIbc::EthereumSyncCommittees` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::EthereumSyncCommittees` (`max_values`: None, `max_size`: Some(49162), added: 51637, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::EthereumBeacon` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::EthereumBeacon` (`max_values`: Some(1), `max_size`: Some(481), added: 976, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[0, 1]`.
+	fn update_ethereum_light_client(c: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(10_240_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(52_000_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+	}
+	/// Storage: `AcurastHyperdriveIbc::SentMessageIds` (r:50 w:50)
+	/// Proof: `AcurastHyperdriveIbc::SentMessageIds` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::SentMessages` (r:100 w:100)
+	/// Proof: `AcurastHyperdriveIbc::SentMessages` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	fn clean_sent_messages(x: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(4_260_000, 0)
+			.saturating_add(Weight::from_parts(0, 990))
+			.saturating_add(Weight::from_parts(9_050_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 5050).saturating_mul(x.into()))
+	}
 }
@@ -35,6 +35,12 @@ pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_acurast_hyperdrive_token::WeightInfo for WeightInfo<T> {
 	/// Storage: `AcurastHyperdriveToken::Enabled` (r:1 w:0)
 	/// Proof: `AcurastHyperdriveToken::Enabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::CircuitBreakers` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::CircuitBreakers` (`max_values`: None, `max_size`: Some(2), added: 2477, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::ProxyTransferLimits` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::ProxyTransferLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::OutgoingVolume` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::OutgoingVolume` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
 	/// Storage: `AcurastHyperdriveToken::EthereumContract` (r:1 w:0)
 	/// Proof: `AcurastHyperdriveToken::EthereumContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:2 w:2)
@@ -52,17 +58,20 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive_token::WeightInfo for We
 	/// Storage: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (r:0 w:1)
 	/// Proof: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
 	fn transfer_native() -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `1105`
 		//  Estimated: `6196`
 		// Minimum execution time: 189_950_000 picoseconds.
 		Weight::from_parts(193_831_000, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `AcurastHyperdriveToken::Enabled` (r:1 w:0)
 	/// Proof: `AcurastHyperdriveToken::Enabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::CircuitBreakers` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::CircuitBreakers` (`max_values`: None, `max_size`: Some(2), added: 2477, mode: `MaxEncodedLen`)
 	/// Storage: `AcurastHyperdriveToken::OutgoingTransfers` (r:1 w:0)
 	/// Proof: `AcurastHyperdriveToken::OutgoingTransfers` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
 	/// Storage: `AcurastHyperdriveToken::EthereumContract` (r:1 w:0)
@@ -77,14 +86,15 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive_token::WeightInfo for We
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
 	/// Storage: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (r:0 w:1)
 	/// Proof: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
-	fn retry_transfer_native() -> Weight {
+	fn retry_transfer() -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `1307`
 		//  Estimated: `4721`
 		// Minimum execution time: 221_820_000 picoseconds.
 		Weight::from_parts(228_760_000, 0)
 			.saturating_add(Weight::from_parts(0, 4721))
-			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads(8))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `AcurastHyperdriveToken::EthereumContract` (r:0 w:1)
@@ -155,4 +165,132 @@ impl<T: frame_system::Config> pallet_acurast_hyperdrive_token::WeightInfo for We
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `AcurastHyperdriveToken::Enabled` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::Enabled` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::CircuitBreakers` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::CircuitBreakers` (`max_values`: None, `max_size`: Some(2), added: 2477, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::EthereumContract` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::EthereumContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::BridgedAssetTokens` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::BridgedAssetTokens` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::BridgedAssets` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::BridgedAssets` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::NextTransferNonce` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::NextTransferNonce` (`max_values`: None, `max_size`: Some(9), added: 2484, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::OutgoingTransferTTL` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::OutgoingTransferTTL` (`max_values`: None, `max_size`: Some(5), added: 2480, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OutgoingMessages` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OutgoingMessages` (`max_values`: None, `max_size`: Some(1256), added: 3731, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::OutgoingTransfers` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveToken::OutgoingTransfers` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::OutgoingTransferAssets` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveToken::OutgoingTransferAssets` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveIbc::OutgoingMessagesLookup` (`max_values`: None, `max_size`: Some(135), added: 2610, mode: `MaxEncodedLen`)
+	fn transfer_asset() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(246_910_000, 0)
+			.saturating_add(Weight::from_parts(0, 6208))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: `AcurastHyperdriveToken::BridgedAssetTokens` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::BridgedAssetTokens` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::BridgedAssets` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::BridgedAssets` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	fn update_bridged_asset() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(17_830_000, 0)
+			.saturating_add(Weight::from_parts(0, 3567))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AcurastHyperdriveToken::ProxyTransferLimits` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveToken::ProxyTransferLimits` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn update_transfer_limits() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_540_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AcurastHyperdriveToken::CircuitBreakers` (r:0 w:1)
+	/// Proof: `AcurastHyperdriveToken::CircuitBreakers` (`max_values`: None, `max_size`: Some(2), added: 2477, mode: `MaxEncodedLen`)
+	fn set_circuit_breaker() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(9_130_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AcurastHyperdriveToken::PendingTransfers` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::PendingTransfers` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	fn veto_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(16_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 3532))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `AcurastHyperdriveToken::CircuitBreakers` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::CircuitBreakers` (`max_values`: None, `max_size`: Some(2), added: 2477, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::PendingTransfers` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::PendingTransfers` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::EthereumContract` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::EthereumContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn execute_pending_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(94_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AcurastHyperdriveToken::PendingTransfers` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::PendingTransfers` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::EthereumContract` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::EthereumContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn release_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(91_920_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `AcurastHyperdriveToken::VetoedTransfers` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::VetoedTransfers` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::EthereumContract` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::EthereumContract` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn reclaim_vetoed_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		// not benchmarked yet, same storage accesses as `release_transfer`
+		<Self as pallet_acurast_hyperdrive_token::WeightInfo>::release_transfer()
+	}
+	/// Storage: `AcurastHyperdriveToken::OutgoingTransferAssets` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::OutgoingTransferAssets` (`max_values`: None, `max_size`: Some(76), added: 2551, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::BridgedAssetTokens` (r:1 w:0)
+	/// Proof: `AcurastHyperdriveToken::BridgedAssetTokens` (`max_values`: None, `max_size`: Some(38), added: 2513, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastHyperdriveToken::OutgoingTransfers` (r:1 w:1)
+	/// Proof: `AcurastHyperdriveToken::OutgoingTransfers` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Asset` (r:1 w:1)
+	/// Proof: `Assets::Asset` (`max_values`: None, `max_size`: Some(210), added: 2685, mode: `MaxEncodedLen`)
+	/// Storage: `Assets::Account` (r:2 w:2)
+	/// Proof: `Assets::Account` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn refund_transfer() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		// not benchmarked yet, same storage accesses as `release_transfer` plus the outgoing transfer and the asset registry
+		<Self as pallet_acurast_hyperdrive_token::WeightInfo>::release_transfer()
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
 }
@@ -55,7 +55,10 @@ impl<T: frame_system::Config> pallet_acurast_marketplace::WeightInfo for WeightI
 	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
 	/// Storage: `AcurastMarketplace::StoredAdvertisementPricing` (r:0 w:1)
 	/// Proof: `AcurastMarketplace::StoredAdvertisementPricing` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredAdvertisementUnavailability` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::StoredAdvertisementUnavailability` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
 	fn delete_advertisement() -> Weight {
+		// Storage accesses adjusted by hand, not re-benchmarked yet.
 		// Proof Size summary in bytes:
 		//  Measured:  `195`
 		//  Estimated: `7296`
@@ -63,7 +66,7 @@ impl<T: frame_system::Config> pallet_acurast_marketplace::WeightInfo for WeightI
 		Weight::from_parts(25_990_000, 0)
 			.saturating_add(Weight::from_parts(0, 7296))
 			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `AcurastMarketplace::StoredMatches` (r:1 w:1)
 	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
@@ -413,4 +416,302 @@ impl<T: frame_system::Config> pallet_acurast_marketplace::WeightInfo for WeightI
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `AcurastMarketplace::StoredJobStatus` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredJobStatus` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Acurast::StoredJobRegistration` (r:1 w:1)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::AssignedProcessors` (r:65 w:64)
+	/// Proof: `AcurastMarketplace::AssignedProcessors` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredMatches` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::NextReportIndex` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::NextReportIndex` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobBudgets` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::JobBudgets` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobExecutionStatus` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::StoredJobExecutionStatus` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobKeyIds` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::JobKeyIds` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobMatcher` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::JobMatcher` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `Acurast::RequiredMinMetrics` (r:0 w:1)
+	/// Proof: `Acurast::RequiredMinMetrics` (`max_values`: None, `max_size`: Some(411), added: 2886, mode: `MaxEncodedLen`)
+	fn cancel_job() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(185_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38282))
+			.saturating_add(T::DbWeight::get().reads(200))
+			.saturating_add(T::DbWeight::get().writes(202))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:1 w:1)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobStatus` (r:1 w:0)
+	/// Proof: `AcurastMarketplace::StoredJobStatus` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobExtension` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredJobExtension` (`max_values`: None, `max_size`: Some(2199), added: 4674, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobBudgets` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::JobBudgets` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::AssignedProcessors` (r:65 w:0)
+	/// Proof: `AcurastMarketplace::AssignedProcessors` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredMatches` (r:64 w:0)
+	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ExtendedAssignmentStart` (r:64 w:0)
+	/// Proof: `AcurastMarketplace::ExtendedAssignmentStart` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	fn extend_job() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(120_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38282))
+			.saturating_add(T::DbWeight::get().reads(200))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:1 w:0)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobExtension` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredJobExtension` (`max_values`: None, `max_size`: Some(2199), added: 4674, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredMatches` (r:65 w:1)
+	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredAdvertisementRestriction` (r:1 w:0)
+	/// Proof: `AcurastMarketplace::StoredAdvertisementRestriction` (`max_values`: None, `max_size`: Some(3831), added: 6306, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ExtendedAssignmentStart` (r:65 w:0)
+	/// Proof: `AcurastMarketplace::ExtendedAssignmentStart` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	fn accept_job_extension() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(150_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38282))
+			.saturating_add(T::DbWeight::get().reads(134))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `AcurastMarketplace::StoredJobExtension` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredJobExtension` (`max_values`: None, `max_size`: Some(2199), added: 4674, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredMatches` (r:1 w:0)
+	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobStatus` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredJobStatus` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn decline_job_extension() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(45_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5656))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:10 w:0)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobExtension` (r:10 w:10)
+	/// Proof: `AcurastMarketplace::StoredJobExtension` (`max_values`: None, `max_size`: Some(2199), added: 4674, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Acurast::RequiredMinMetrics` (r:10 w:0)
+	/// Proof: `Acurast::RequiredMinMetrics` (`max_values`: None, `max_size`: Some(411), added: 2886, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredAdvertisementRestriction` (r:640 w:0)
+	/// Proof: `AcurastMarketplace::StoredAdvertisementRestriction` (`max_values`: None, `max_size`: Some(3831), added: 6306, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredMatches` (r:1280 w:640)
+	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ExtendedAssignmentStart` (r:640 w:640)
+	/// Proof: `AcurastMarketplace::ExtendedAssignmentStart` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobMatcher` (r:0 w:10)
+	/// Proof: `AcurastMarketplace::JobMatcher` (`max_values`: None, `max_size`: Some(102), added: 2577, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::AssignedProcessors` (r:0 w:640)
+	/// Proof: `AcurastMarketplace::AssignedProcessors` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobStatus` (r:640 w:640)
+	/// Proof: `AcurastMarketplace::StoredJobStatus` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 10]`.
+	fn propose_extension_matching(x: u32, ) -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(3_617_807_000, 0)
+			.saturating_add(Weight::from_parts(0, 1517))
+			.saturating_add(Weight::from_parts(3_197_935_407, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((835_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes((258_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 973824).saturating_mul(x.into()))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:1 w:0)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobExtension` (r:1 w:0)
+	/// Proof: `AcurastMarketplace::StoredJobExtension` (`max_values`: None, `max_size`: Some(2199), added: 4674, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredMatches` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::NextReportIndex` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::NextReportIndex` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ExtendedAssignmentStart` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::ExtendedAssignmentStart` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastProcessorManager::ManagedProcessors` (r:1 w:0)
+	/// Proof: `AcurastProcessorManager::ManagedProcessors` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredReputation` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredReputation` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredAverageRewardV3` (r:1 w:0)
+	/// Proof: `AcurastMarketplace::StoredAverageRewardV3` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::AssignedProcessors` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::AssignedProcessors` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::RematchableSlots` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::RematchableSlots` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::LapsedProcessors` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::LapsedProcessors` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobStatus` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredJobStatus` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn mark_slot_rematchable() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 38282))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:1 w:0)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ExecutionCandidates` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::ExecutionCandidates` (`max_values`: None, `max_size`: Some(103862), added: 106337, mode: `MaxEncodedLen`)
+	fn commit_execution_acknowledgment() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(38_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 144137))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:65 w:0)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ExecutionCandidates` (r:2 w:2)
+	/// Proof: `AcurastMarketplace::ExecutionCandidates` (`max_values`: None, `max_size`: Some(103862), added: 106337, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredReputation` (r:64 w:0)
+	/// Proof: `AcurastMarketplace::StoredReputation` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredMatches` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobExecutionStatus` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::StoredJobExecutionStatus` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::NextReportIndex` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::NextReportIndex` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobMatcher` (r:64 w:0)
+	/// Proof: `AcurastMarketplace::JobMatcher` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:64 w:64)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredTotalAssignedV3` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredTotalAssignedV3` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredAverageRewardV3` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredAverageRewardV3` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::AssignedProcessors` (r:0 w:64)
+	/// Proof: `AcurastMarketplace::AssignedProcessors` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::RelayStateProof` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelayStateProof` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn reveal_execution_acknowledgment() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(1_985_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1202471))
+			.saturating_add(T::DbWeight::get().reads(455))
+			.saturating_add(T::DbWeight::get().writes(325))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:65 w:0)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ExecutionCandidates` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::ExecutionCandidates` (`max_values`: None, `max_size`: Some(103862), added: 106337, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastProcessorManager::ManagedProcessors` (r:64 w:0)
+	/// Proof: `AcurastProcessorManager::ManagedProcessors` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredReputation` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::StoredReputation` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredMatches` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::StoredMatches` (`max_values`: None, `max_size`: Some(345), added: 2820, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobExecutionStatus` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::StoredJobExecutionStatus` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::NextReportIndex` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::NextReportIndex` (`max_values`: None, `max_size`: Some(126), added: 2601, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobMatcher` (r:64 w:0)
+	/// Proof: `AcurastMarketplace::JobMatcher` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:65 w:65)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredTotalAssignedV3` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredTotalAssignedV3` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredAverageRewardV3` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::StoredAverageRewardV3` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::AssignedProcessors` (r:0 w:64)
+	/// Proof: `AcurastMarketplace::AssignedProcessors` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredJobStatus` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::StoredJobStatus` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::ValidationData` (r:1 w:0)
+	/// Proof: `ParachainSystem::ValidationData` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::RelayStateProof` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelayStateProof` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn pick_execution_candidates() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(2_420_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1370079))
+			.saturating_add(T::DbWeight::get().reads(520))
+			.saturating_add(T::DbWeight::get().writes(391))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:1 w:0)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ResultCommitments` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::ResultCommitments` (`max_values`: None, `max_size`: Some(21855), added: 24330, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobBudgets` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::JobBudgets` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:64 w:64)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredReputation` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::StoredReputation` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ConsensusResults` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::ConsensusResults` (`max_values`: None, `max_size`: Some(352), added: 2827, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ResultDisagreements` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::ResultDisagreements` (`max_values`: None, `max_size`: Some(21855), added: 24330, mode: `MaxEncodedLen`)
+	fn reveal_result() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(880_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 579842))
+			.saturating_add(T::DbWeight::get().reads(195))
+			.saturating_add(T::DbWeight::get().writes(195))
+	}
+	/// Storage: `Acurast::StoredJobRegistration` (r:1 w:0)
+	/// Proof: `Acurast::StoredJobRegistration` (`max_values`: None, `max_size`: Some(34817), added: 37292, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ResultCommitments` (r:1 w:1)
+	/// Proof: `AcurastMarketplace::ResultCommitments` (`max_values`: None, `max_size`: Some(21855), added: 24330, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::JobBudgets` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::JobBudgets` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:64 w:64)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredReputation` (r:64 w:64)
+	/// Proof: `AcurastMarketplace::StoredReputation` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ConsensusResults` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::ConsensusResults` (`max_values`: None, `max_size`: Some(352), added: 2827, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::ResultDisagreements` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::ResultDisagreements` (`max_values`: None, `max_size`: Some(21855), added: 24330, mode: `MaxEncodedLen`)
+	fn settle_execution_results() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(860_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 579842))
+			.saturating_add(T::DbWeight::get().reads(195))
+			.saturating_add(T::DbWeight::get().writes(195))
+	}
+	/// Storage: `AcurastMarketplace::StoredAdvertisementRestriction` (r:1 w:0)
+	/// Proof: `AcurastMarketplace::StoredAdvertisementRestriction` (`max_values`: None, `max_size`: Some(3831), added: 6306, mode: `MaxEncodedLen`)
+	/// Storage: `AcurastMarketplace::StoredAdvertisementUnavailability` (r:0 w:1)
+	/// Proof: `AcurastMarketplace::StoredAdvertisementUnavailability` (`max_values`: None, `max_size`: Some(385), added: 2860, mode: `MaxEncodedLen`)
+	fn set_advertisement_unavailability() -> Weight {
+		// Estimated, not benchmarked yet: regenerate with the benchmark CLI.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7296))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }